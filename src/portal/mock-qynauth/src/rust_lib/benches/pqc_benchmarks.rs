@@ -180,8 +180,106 @@ criterion_group!(
     benchmark_key_sizes,
     benchmark_comprehensive_baselines
 );
+
+/// Parallel Criterion groups run only under `cargo bench --features
+/// compare`: mirrors of [`benchmark_mlkem_operations`] and
+/// [`benchmark_mldsa_operations`] that time the RustCrypto `ml-kem`/
+/// `ml-dsa` crates under matching `BenchmarkId`s, so our numbers and
+/// theirs land in the same report instead of needing a second run to
+/// eyeball separately. Gated behind a feature so a normal `cargo bench`
+/// doesn't pull in either crate as a dev-dependency.
+#[cfg(feature = "compare")]
+mod rustcrypto_comparison {
+    use super::{decapsulate, encapsulate, mldsa_keygen, mlkem_keygen, sign, verify};
+    use criterion::{black_box, BenchmarkId, Criterion};
+    use ml_dsa::signature::{Signer, Verifier};
+    use ml_dsa::{KeyGen, MlDsa65};
+    use ml_kem::{KemCore, MlKem768};
+    use secrecy::ExposeSecret;
+
+    pub fn benchmark_mlkem_vs_rustcrypto(c: &mut Criterion) {
+        let mut group = c.benchmark_group("ML-KEM-768_comparative");
+        let mut rng = rand::thread_rng();
+
+        group.bench_function(BenchmarkId::new("key_generation", "qynauth_pqc"), |b| {
+            b.iter(|| black_box(mlkem_keygen()))
+        });
+        group.bench_function(BenchmarkId::new("key_generation", "rustcrypto"), |b| {
+            b.iter(|| black_box(MlKem768::generate(&mut rng)))
+        });
+
+        let ours = mlkem_keygen().unwrap();
+        let message = b"benchmark message for encapsulation";
+        let (theirs_dk, theirs_ek) = MlKem768::generate(&mut rng);
+
+        group.bench_function(BenchmarkId::new("encapsulation", "qynauth_pqc"), |b| {
+            b.iter(|| black_box(encapsulate(&ours.public_key, message)))
+        });
+        group.bench_function(BenchmarkId::new("encapsulation", "rustcrypto"), |b| {
+            b.iter(|| black_box(theirs_ek.encapsulate(&mut rng).unwrap()))
+        });
+
+        let (our_ciphertext, _) = encapsulate(&ours.public_key, message).unwrap();
+        let (their_ciphertext, _) = theirs_ek.encapsulate(&mut rng).unwrap();
+
+        group.bench_function(BenchmarkId::new("decapsulation", "qynauth_pqc"), |b| {
+            b.iter(|| black_box(decapsulate(ours.private_key.expose_secret(), &our_ciphertext)))
+        });
+        group.bench_function(BenchmarkId::new("decapsulation", "rustcrypto"), |b| {
+            b.iter(|| black_box(theirs_dk.decapsulate(&their_ciphertext).unwrap()))
+        });
+
+        group.finish();
+    }
+
+    pub fn benchmark_mldsa_vs_rustcrypto(c: &mut Criterion) {
+        let mut group = c.benchmark_group("ML-DSA-65_comparative");
+        let mut rng = rand::thread_rng();
+        let message = b"benchmark message for digital signature";
+
+        group.bench_function(BenchmarkId::new("key_generation", "qynauth_pqc"), |b| {
+            b.iter(|| black_box(mldsa_keygen()))
+        });
+        group.bench_function(BenchmarkId::new("key_generation", "rustcrypto"), |b| {
+            b.iter(|| black_box(MlDsa65::key_gen(&mut rng)))
+        });
+
+        let ours = mldsa_keygen().unwrap();
+        let theirs = MlDsa65::key_gen(&mut rng);
+
+        group.bench_function(BenchmarkId::new("signing", "qynauth_pqc"), |b| {
+            b.iter(|| black_box(sign(ours.private_key.expose_secret(), message)))
+        });
+        group.bench_function(BenchmarkId::new("signing", "rustcrypto"), |b| {
+            b.iter(|| black_box(theirs.signing_key().sign(message)))
+        });
+
+        let our_signature = sign(ours.private_key.expose_secret(), message).unwrap();
+        let their_signature = theirs.signing_key().sign(message);
+
+        group.bench_function(BenchmarkId::new("verification", "qynauth_pqc"), |b| {
+            b.iter(|| black_box(verify(&ours.public_key, message, &our_signature)))
+        });
+        group.bench_function(BenchmarkId::new("verification", "rustcrypto"), |b| {
+            b.iter(|| black_box(theirs.verifying_key().verify(message, &their_signature)))
+        });
+
+        group.finish();
+    }
+}
+
+#[cfg(feature = "compare")]
+use rustcrypto_comparison::{benchmark_mlkem_vs_rustcrypto, benchmark_mldsa_vs_rustcrypto};
+
+#[cfg(feature = "compare")]
+criterion_group!(compare_benches, benchmark_mlkem_vs_rustcrypto, benchmark_mldsa_vs_rustcrypto);
+
+#[cfg(not(feature = "compare"))]
 criterion_main!(benches);
 
+#[cfg(feature = "compare")]
+criterion_main!(benches, compare_benches);
+
 fn benchmark_throughput_analysis(c: &mut Criterion) {
     let mut group = c.benchmark_group("Throughput_Analysis");
 
@@ -238,12 +336,94 @@ fn benchmark_key_sizes(c: &mut Criterion) {
     group.finish();
 }
 
+/// Mirrors `tests/performance/regression_testing.rs`'s `PerformanceBaseline`
+/// field-for-field (a Criterion bench target and an integration test
+/// target are separate compilation units, so this can't just import that
+/// type) so the JSON this writes loads straight into
+/// `PerformanceRegressionTester::load_baselines_from_file` for the next
+/// run's regression check.
+#[derive(serde::Serialize)]
+struct BaselineRecord {
+    operation: String,
+    algorithm: String,
+    mean_duration_nanos: u64,
+    median_duration_nanos: u64,
+    p95_duration_nanos: u64,
+    p99_duration_nanos: u64,
+    memory_usage_bytes: usize,
+    sample_count: usize,
+    timestamp: String,
+    sample_durations_nanos: Vec<u64>,
+}
+
+/// Directory `save_baseline_measurements` writes its reports and JSON
+/// baselines into, overridable via `PQC_BASELINE_DIR` so CI can point it
+/// somewhere other than `/tmp`.
+fn baseline_dir() -> String {
+    std::env::var("PQC_BASELINE_DIR").unwrap_or_else(|_| "/tmp/pqc_performance/baselines".to_string())
+}
+
+/// Writes a structured `{operation: BaselineRecord}` JSON file for
+/// `algorithm` alongside the free-text report, so a CI step can feed it
+/// to `PerformanceRegressionTester::load_baselines_from_file` and call
+/// `test_performance_regression` against the next run's measurements
+/// instead of only having a text dump for humans to eyeball.
+fn save_baseline_json(grouped: &HashMap<String, Vec<Duration>>, algorithm: &str) {
+    let mut records: HashMap<String, BaselineRecord> = HashMap::new();
+
+    for (operation, durations) in grouped {
+        if durations.is_empty() {
+            continue;
+        }
+
+        let mut sorted_nanos: Vec<u64> = durations.iter().map(|d| d.as_nanos() as u64).collect();
+        sorted_nanos.sort();
+
+        let mean = sorted_nanos.iter().sum::<u64>() / sorted_nanos.len() as u64;
+        let percentile = |p: f64| -> u64 {
+            let idx = ((sorted_nanos.len() as f64 * p) as usize).min(sorted_nanos.len() - 1);
+            sorted_nanos[idx]
+        };
+
+        records.insert(operation.clone(), BaselineRecord {
+            operation: operation.clone(),
+            algorithm: algorithm.to_string(),
+            mean_duration_nanos: mean,
+            median_duration_nanos: percentile(0.5),
+            p95_duration_nanos: percentile(0.95),
+            p99_duration_nanos: percentile(0.99),
+            memory_usage_bytes: 0,
+            sample_count: sorted_nanos.len(),
+            timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            sample_durations_nanos: sorted_nanos,
+        });
+    }
+
+    let json_path = format!("{}/{}_baseline.json", baseline_dir(), algorithm);
+    if let Ok(file) = OpenOptions::new().create(true).write(true).truncate(true).open(&json_path) {
+        if let Err(e) = serde_json::to_writer_pretty(file, &records) {
+            eprintln!("Failed to write baseline JSON to {}: {}", json_path, e);
+        }
+    }
+}
+
 fn save_baseline_measurements(measurements: &[(String, Duration)], algorithm: &str) {
     let report_path = format!(
-        "/tmp/pqc_performance/baselines/{}_baseline_measurements.txt",
+        "{}/{}_baseline_measurements.txt",
+        baseline_dir(),
         algorithm
     );
 
+    let mut grouped: HashMap<String, Vec<Duration>> = HashMap::new();
+    for (operation, duration) in measurements {
+        grouped
+            .entry(operation.clone())
+            .or_insert_with(Vec::new)
+            .push(*duration);
+    }
+
+    save_baseline_json(&grouped, algorithm);
+
     if let Ok(mut file) = OpenOptions::new()
         .create(true)
         .append(true)
@@ -263,14 +443,6 @@ fn save_baseline_measurements(measurements: &[(String, Duration)], algorithm: &s
         .ok();
         writeln!(file, "WBS 2.5.1: Comprehensive Performance Baselines\n").ok();
 
-        let mut grouped: HashMap<String, Vec<Duration>> = HashMap::new();
-        for (operation, duration) in measurements {
-            grouped
-                .entry(operation.clone())
-                .or_insert_with(Vec::new)
-                .push(*duration);
-        }
-
         for (operation, durations) in grouped {
             if !durations.is_empty() {
                 let mut sorted_durations = durations.clone();
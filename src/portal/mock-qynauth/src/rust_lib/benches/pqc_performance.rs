@@ -1,187 +1,329 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
-use std::time::Duration;
+//! Unlike `pqc_benchmarks.rs` (which measures the core `qynauth_pqc::mlkem`/
+//! `qynauth_pqc::mldsa` API), this suite measures the C ABI surface: every
+//! benchmark goes through the real `ffi::mlkem_ffi`/`ffi::mldsa_ffi` entry
+//! points, including their allocation and zeroizing-free calls, so the
+//! numbers reflect what a C/C++ host actually pays per call rather than the
+//! cost of the Rust-only fast path.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use qynauth_pqc::ffi::{
+    ffi_buffer_free, mldsa_keypair_free, mldsa_keypair_generate, mldsa_sign, mldsa_verify,
+    mldsa_verify_batch_parallel, mlkem_ciphertext_free, mlkem_decapsulate, mlkem_encapsulate,
+    mlkem_keypair_free, mlkem_keypair_generate, mlkem_param_sizes, mlkem_shared_secret_free,
+    CMLDSABatchItem,
+};
 
+const MLKEM_LEVELS: [i32; 3] = [512, 768, 1024];
+const MLDSA_LEVELS: [i32; 3] = [2, 3, 5];
 
-struct MockKeyPair {
-    public_key: [u8; 1184],  // Kyber-768 public key size
-    secret_key: [u8; 2400],  // Kyber-768 secret key size
+fn benchmark_mlkem_keygen(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ffi_mlkem_keygen");
+    for level in MLKEM_LEVELS {
+        group.bench_with_input(BenchmarkId::from_parameter(level), &level, |b, &level| {
+            b.iter(|| {
+                let keypair = black_box(mlkem_keypair_generate(level));
+                assert!(!keypair.is_null());
+                mlkem_keypair_free(keypair);
+            })
+        });
+    }
+    group.finish();
 }
 
-struct MockSignatureKeyPair {
-    public_key: [u8; 1952],  // Dilithium-3 public key size
-    secret_key: [u8; 4032],  // Dilithium-3 secret key size
-}
+fn benchmark_mlkem_encaps_decaps(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ffi_mlkem_encaps_decaps");
+    for level in MLKEM_LEVELS {
+        let keypair_ptr = mlkem_keypair_generate(level);
+        assert!(!keypair_ptr.is_null());
+        let keypair = unsafe { &*keypair_ptr };
 
-struct MockCiphertext([u8; 1088]);  // Kyber-768 ciphertext size
-struct MockSharedSecret([u8; 32]);  // 256-bit shared secret
-struct MockSignature([u8; 3293]);   // Dilithium-3 signature size
+        group.bench_with_input(
+            BenchmarkId::new("encapsulation", level),
+            &level,
+            |b, &level| {
+                b.iter(|| {
+                    let mut ss_ptr = std::ptr::null_mut();
+                    let mut ss_len = 0;
+                    let mut ct_ptr = std::ptr::null_mut();
+                    let mut ct_len = 0;
 
-fn mock_kyber768_keypair() -> MockKeyPair {
-    MockKeyPair {
-        public_key: [0u8; 1184],
-        secret_key: [0u8; 2400],
-    }
-}
+                    let code = black_box(mlkem_encapsulate(
+                        level,
+                        keypair.public_key_ptr,
+                        keypair.public_key_len,
+                        &mut ss_ptr,
+                        &mut ss_len,
+                        &mut ct_ptr,
+                        &mut ct_len,
+                    ));
+                    assert_eq!(code, 0);
 
-fn mock_kyber768_encapsulate(_pk: &[u8; 1184]) -> (MockCiphertext, MockSharedSecret) {
-    let mut result = [0u8; 32];
-    for i in 0..32 {
-        result[i] = (i as u8).wrapping_mul(7);
-    }
-    (MockCiphertext([0u8; 1088]), MockSharedSecret(result))
-}
+                    mlkem_ciphertext_free(ct_ptr, ct_len);
+                    mlkem_shared_secret_free(ss_ptr, ss_len);
+                })
+            },
+        );
 
-fn mock_kyber768_decapsulate(_ciphertext: &MockCiphertext, _sk: &[u8; 2400]) -> MockSharedSecret {
-    let mut result = [0u8; 32];
-    for i in 0..32 {
-        result[i] = (i as u8).wrapping_mul(13);
+        let mut ss_ptr = std::ptr::null_mut();
+        let mut ss_len = 0;
+        let mut ct_ptr = std::ptr::null_mut();
+        let mut ct_len = 0;
+        let code = mlkem_encapsulate(
+            level,
+            keypair.public_key_ptr,
+            keypair.public_key_len,
+            &mut ss_ptr,
+            &mut ss_len,
+            &mut ct_ptr,
+            &mut ct_len,
+        );
+        assert_eq!(code, 0);
+        mlkem_shared_secret_free(ss_ptr, ss_len);
+
+        group.bench_with_input(
+            BenchmarkId::new("decapsulation", level),
+            &level,
+            |b, &level| {
+                b.iter(|| {
+                    let mut decap_ss_ptr = std::ptr::null_mut();
+                    let mut decap_ss_len = 0;
+
+                    let code = black_box(mlkem_decapsulate(
+                        level,
+                        keypair.secret_key_ptr,
+                        keypair.secret_key_len,
+                        ct_ptr,
+                        ct_len,
+                        &mut decap_ss_ptr,
+                        &mut decap_ss_len,
+                    ));
+                    assert_eq!(code, 0);
+
+                    mlkem_shared_secret_free(decap_ss_ptr, decap_ss_len);
+                })
+            },
+        );
+
+        mlkem_ciphertext_free(ct_ptr, ct_len);
+        mlkem_keypair_free(keypair_ptr);
     }
-    MockSharedSecret(result)
+    group.finish();
 }
 
-fn mock_dilithium3_keypair() -> MockSignatureKeyPair {
-    MockSignatureKeyPair {
-        public_key: [0u8; 1952],
-        secret_key: [0u8; 4032],
+fn benchmark_mldsa_keygen(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ffi_mldsa_keygen");
+    for level in MLDSA_LEVELS {
+        group.bench_with_input(BenchmarkId::from_parameter(level), &level, |b, &level| {
+            b.iter(|| {
+                let keypair = black_box(mldsa_keypair_generate(level));
+                assert!(!keypair.is_null());
+                mldsa_keypair_free(keypair);
+            })
+        });
     }
+    group.finish();
 }
 
-fn mock_dilithium3_sign(_message: &[u8], _sk: &[u8; 4032]) -> MockSignature {
-    MockSignature([0u8; 3293])
-}
+fn benchmark_mldsa_sign_verify(c: &mut Criterion) {
+    let message = b"benchmark message for the ML-DSA FFI surface";
+    let mut group = c.benchmark_group("ffi_mldsa_sign_verify");
 
-fn mock_dilithium3_verify(_message: &[u8], _signature: &MockSignature, _pk: &[u8; 1952]) -> bool {
-    true
-}
+    for level in MLDSA_LEVELS {
+        let keypair_ptr = mldsa_keypair_generate(level);
+        assert!(!keypair_ptr.is_null());
+        let keypair = unsafe { &*keypair_ptr };
 
-fn benchmark_kyber768_keygen(c: &mut Criterion) {
-    c.bench_function("kyber768_keypair_generation", |b| {
-        b.iter(|| {
-            let keypair = black_box(mock_kyber768_keypair());
-            keypair
-        })
-    });
-}
+        group.bench_with_input(BenchmarkId::new("signing", level), &level, |b, &level| {
+            b.iter(|| {
+                let mut sig_ptr = std::ptr::null_mut();
+                let mut sig_len = 0;
 
-fn benchmark_kyber768_encaps(c: &mut Criterion) {
-    let keypair = mock_kyber768_keypair();
-    
-    c.bench_function("kyber768_encapsulation", |b| {
-        b.iter(|| {
-            let (ciphertext, shared_secret) = black_box(mock_kyber768_encapsulate(&keypair.public_key));
-            (ciphertext, shared_secret)
-        })
-    });
-}
+                let code = black_box(mldsa_sign(
+                    level,
+                    keypair.secret_key_handle,
+                    message.as_ptr(),
+                    message.len(),
+                    &mut sig_ptr,
+                    &mut sig_len,
+                ));
+                assert_eq!(code, 0);
 
-fn benchmark_kyber768_decaps(c: &mut Criterion) {
-    let keypair = mock_kyber768_keypair();
-    let (ciphertext, _) = mock_kyber768_encapsulate(&keypair.public_key);
-    
-    c.bench_function("kyber768_decapsulation", |b| {
-        b.iter(|| {
-            let shared_secret = black_box(mock_kyber768_decapsulate(&ciphertext, &keypair.secret_key));
-            shared_secret
-        })
-    });
+                ffi_buffer_free(sig_ptr, sig_len);
+            })
+        });
+
+        let mut sig_ptr = std::ptr::null_mut();
+        let mut sig_len = 0;
+        let code = mldsa_sign(
+            level,
+            keypair.secret_key_handle,
+            message.as_ptr(),
+            message.len(),
+            &mut sig_ptr,
+            &mut sig_len,
+        );
+        assert_eq!(code, 0);
+
+        group.bench_with_input(
+            BenchmarkId::new("verification", level),
+            &level,
+            |b, &level| {
+                b.iter(|| {
+                    let code = black_box(mldsa_verify(
+                        level,
+                        keypair.public_key_ptr,
+                        keypair.public_key_len,
+                        message.as_ptr(),
+                        message.len(),
+                        sig_ptr,
+                        sig_len,
+                    ));
+                    assert_eq!(code, 0);
+                })
+            },
+        );
+
+        ffi_buffer_free(sig_ptr, sig_len);
+        mldsa_keypair_free(keypair_ptr);
+    }
+    group.finish();
 }
 
-fn benchmark_dilithium3_keygen(c: &mut Criterion) {
-    c.bench_function("dilithium3_keypair_generation", |b| {
-        b.iter(|| {
-            let keypair = black_box(mock_dilithium3_keypair());
-            keypair
+/// Compares `mldsa_verify_batch_parallel` against looping `mldsa_verify`
+/// sequentially over the same 1000 signatures, so the value of fanning
+/// verification out across rayon's thread pool is visible as a number
+/// rather than asserted.
+fn benchmark_mldsa_verify_batch_vs_sequential(c: &mut Criterion) {
+    const BATCH_SIZE: usize = 1000;
+    const LEVEL: i32 = 3;
+
+    let message = b"batch verification throughput benchmark message";
+    let keypair_ptr = mldsa_keypair_generate(LEVEL);
+    assert!(!keypair_ptr.is_null());
+    let keypair = unsafe { &*keypair_ptr };
+
+    let mut sig_ptr = std::ptr::null_mut();
+    let mut sig_len = 0;
+    let code = mldsa_sign(
+        LEVEL,
+        keypair.secret_key_handle,
+        message.as_ptr(),
+        message.len(),
+        &mut sig_ptr,
+        &mut sig_len,
+    );
+    assert_eq!(code, 0);
+
+    let items: Vec<CMLDSABatchItem> = (0..BATCH_SIZE)
+        .map(|_| CMLDSABatchItem {
+            public_key_ptr: keypair.public_key_ptr,
+            public_key_len: keypair.public_key_len,
+            message_ptr: message.as_ptr(),
+            message_len: message.len(),
+            signature_ptr: sig_ptr,
+            signature_len: sig_len,
         })
-    });
-}
+        .collect();
 
-fn benchmark_dilithium3_sign(c: &mut Criterion) {
-    let message = b"Hello, quantum-safe world!";
-    let keypair = mock_dilithium3_keypair();
-    
-    c.bench_function("dilithium3_signing", |b| {
+    let mut group = c.benchmark_group("ffi_mldsa_verify_batch_vs_sequential");
+
+    group.bench_function("sequential", |b| {
         b.iter(|| {
-            let signature = black_box(mock_dilithium3_sign(message, &keypair.secret_key));
-            signature
+            for item in &items {
+                let code = black_box(mldsa_verify(
+                    LEVEL,
+                    item.public_key_ptr,
+                    item.public_key_len,
+                    item.message_ptr,
+                    item.message_len,
+                    item.signature_ptr,
+                    item.signature_len,
+                ));
+                assert_eq!(code, 0);
+            }
         })
     });
-}
 
-fn benchmark_dilithium3_verify(c: &mut Criterion) {
-    let message = b"Hello, quantum-safe world!";
-    let keypair = mock_dilithium3_keypair();
-    let signature = mock_dilithium3_sign(message, &keypair.secret_key);
-    
-    c.bench_function("dilithium3_verification", |b| {
+    group.bench_function("parallel_batch", |b| {
+        let mut results = vec![0i32; BATCH_SIZE];
         b.iter(|| {
-            let is_valid = black_box(mock_dilithium3_verify(message, &signature, &keypair.public_key));
-            is_valid
+            let code = black_box(mldsa_verify_batch_parallel(
+                LEVEL,
+                items.as_ptr(),
+                items.len(),
+                results.as_mut_ptr(),
+            ));
+            assert_eq!(code, 0);
+            assert!(results.iter().all(|&r| r == 0));
         })
     });
-}
 
-fn benchmark_throughput(c: &mut Criterion) {
-    let mut group = c.benchmark_group("throughput");
-    
-    for batch_size in [1, 10, 100].iter() {
-        group.bench_with_input(
-            BenchmarkId::new("kyber768_batch_keygen", batch_size),
-            batch_size,
-            |b, &size| {
-                b.iter(|| {
-                    for _ in 0..size {
-                        let _keypair = black_box(mock_kyber768_keypair());
-                    }
-                });
-            },
-        );
-    }
     group.finish();
+
+    ffi_buffer_free(sig_ptr, sig_len);
+    mldsa_keypair_free(keypair_ptr);
 }
 
-fn benchmark_memory_operations(c: &mut Criterion) {
-    c.bench_function("memory_allocation_deallocation", |b| {
+/// Sanity check that callers sizing buffers from `mlkem_param_sizes`
+/// instead of hardcoded constants pay no extra overhead; this is a
+/// correctness-adjacent micro-benchmark rather than a throughput number.
+fn benchmark_mlkem_param_sizes(c: &mut Criterion) {
+    c.bench_function("ffi_mlkem_param_sizes", |b| {
         b.iter(|| {
-            let keypair = black_box(mock_kyber768_keypair());
-            let sig_keypair = black_box(mock_dilithium3_keypair());
-            
-            let (ciphertext, shared_secret) = mock_kyber768_encapsulate(&keypair.public_key);
-            let signature = mock_dilithium3_sign(b"test message", &sig_keypair.secret_key);
-            
-            (ciphertext, shared_secret, signature)
+            let mut pk_len = 0;
+            let mut sk_len = 0;
+            let mut ct_len = 0;
+            let mut ss_len = 0;
+            black_box(mlkem_param_sizes(
+                768,
+                &mut pk_len,
+                &mut sk_len,
+                &mut ct_len,
+                &mut ss_len,
+            ));
         })
     });
 }
 
-fn benchmark_concurrent_operations(c: &mut Criterion) {
-    use std::sync::Arc;
-    use std::thread;
-    
-    c.bench_function("concurrent_key_generation", |b| {
+/// Cross-library comparison of ML-KEM keygen throughput against
+/// RustCrypto's `ml-kem` crate, the way cross-library KEM benchmark suites
+/// report side-by-side numbers. Gated behind the `bench-compare-ml-kem`
+/// feature: the `ml-kem` dev-dependency and feature declaration still need
+/// to be added to `Cargo.toml` once this tree has one, so this group is a
+/// no-op until then.
+#[cfg(feature = "bench-compare-ml-kem")]
+fn benchmark_mlkem_cross_library(c: &mut Criterion) {
+    use ml_kem::{KemCore, MlKem768};
+
+    let mut group = c.benchmark_group("cross_library_mlkem768_keygen");
+
+    group.bench_function("pqcrypto_mlkem768", |b| {
         b.iter(|| {
-            let handles: Vec<_> = (0..4).map(|_| {
-                thread::spawn(|| {
-                    black_box(mock_kyber768_keypair())
-                })
-            }).collect();
-            
-            for handle in handles {
-                let _ = handle.join();
-            }
+            let keypair = black_box(mlkem_keypair_generate(768));
+            mlkem_keypair_free(keypair);
         })
     });
+    group.bench_function("rustcrypto_ml_kem768", |b| {
+        b.iter(|| black_box(MlKem768::generate(&mut rand::rngs::OsRng)))
+    });
+
+    group.finish();
 }
 
+#[cfg(feature = "bench-compare-ml-kem")]
+criterion_group!(cross_library_benches, benchmark_mlkem_cross_library);
+
 criterion_group!(
     benches,
-    benchmark_kyber768_keygen,
-    benchmark_kyber768_encaps,
-    benchmark_kyber768_decaps,
-    benchmark_dilithium3_keygen,
-    benchmark_dilithium3_sign,
-    benchmark_dilithium3_verify,
-    benchmark_throughput,
-    benchmark_memory_operations,
-    benchmark_concurrent_operations
+    benchmark_mlkem_keygen,
+    benchmark_mlkem_encaps_decaps,
+    benchmark_mldsa_keygen,
+    benchmark_mldsa_sign_verify,
+    benchmark_mldsa_verify_batch_vs_sequential,
+    benchmark_mlkem_param_sizes,
 );
+
+#[cfg(not(feature = "bench-compare-ml-kem"))]
 criterion_main!(benches);
+
+#[cfg(feature = "bench-compare-ml-kem")]
+criterion_main!(benches, cross_library_benches);
@@ -0,0 +1,289 @@
+//! Append-only, hash-chained audit log for `SecurityEvent`s, modeled on the
+//! tamper-evident record Mozilla's cert_storage keeps for CRLite updates:
+//! each entry commits to the one before it, so deleting or reordering a
+//! past event breaks the chain at a provable index rather than silently
+//! disappearing.
+//!
+//! Entries are persisted in an embedded `rkv` key-value store running in
+//! `SafeMode` (pure-Rust, no native `liblmdb` dependency) so the chain
+//! survives process restarts and writes stay strictly ordered.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use rkv::backend::{SafeMode, SafeModeEnvironment};
+use rkv::{Rkv, SingleStore, StoreOptions, Value};
+use sha2::{Digest, Sha256};
+
+use crate::errors::{CryptoError, SecurityEvent};
+
+/// Hash chain root for an empty log. The first appended entry uses this as
+/// its `prev_hash`.
+const GENESIS_HASH: [u8; 32] = [0u8; 32];
+
+const STORE_NAME: &str = "audit_log_entries";
+
+/// One persisted, hash-chained audit record.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AuditLogEntry {
+    pub sequence: u64,
+    pub prev_hash: [u8; 32],
+    pub entry_hash: [u8; 32],
+    pub event: SecurityEvent,
+}
+
+impl AuditLogEntry {
+    fn compute_hash(prev_hash: &[u8; 32], canonical_event: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(prev_hash);
+        hasher.update(canonical_event);
+        hasher.finalize().into()
+    }
+}
+
+/// Append-only audit trail backing `ErrorReporter`'s critical-event path.
+///
+/// Every entry is written under a big-endian `u64` sequence key, so store
+/// iteration order is insertion order, and carries `entry_hash =
+/// SHA-256(prev_hash || canonical_serialization_of_event)`, chaining it to
+/// the entry before it.
+pub struct AuditLog {
+    env: Rkv<SafeModeEnvironment>,
+    store: SingleStore<SafeModeEnvironment>,
+    tip: Mutex<AuditLogTip>,
+}
+
+struct AuditLogTip {
+    next_sequence: u64,
+    last_hash: [u8; 32],
+}
+
+impl AuditLog {
+    /// Opens (creating if necessary) an audit log backed by a `SafeMode`
+    /// `rkv` environment rooted at `path`, reconstructing the in-memory
+    /// chain tip from whatever was previously persisted.
+    pub fn open(path: &Path) -> Result<Self, CryptoError> {
+        std::fs::create_dir_all(path).map_err(|e| CryptoError::AuditLogError {
+            details: format!("failed to create audit log directory: {e}"),
+        })?;
+
+        let env = Rkv::new::<SafeMode>(path).map_err(|e| CryptoError::AuditLogError {
+            details: format!("failed to open audit log environment: {e}"),
+        })?;
+        let store = env
+            .open_single(STORE_NAME, StoreOptions::create())
+            .map_err(|e| CryptoError::AuditLogError {
+                details: format!("failed to open audit log store: {e}"),
+            })?;
+
+        let tip = {
+            let reader = env.read().map_err(|e| CryptoError::AuditLogError {
+                details: format!("failed to open audit log reader: {e}"),
+            })?;
+            let mut last_entry: Option<AuditLogEntry> = None;
+            let iter = store.iter_start(&reader).map_err(|e| CryptoError::AuditLogError {
+                details: format!("failed to iterate audit log: {e}"),
+            })?;
+            for result in iter {
+                let (_, value) = result.map_err(|e| CryptoError::AuditLogError {
+                    details: format!("failed to read audit log entry: {e}"),
+                })?;
+                if let Some(Value::Blob(bytes)) = value {
+                    let entry: AuditLogEntry =
+                        serde_json::from_slice(bytes).map_err(|e| CryptoError::DeserializationError {
+                            details: e.to_string(),
+                        })?;
+                    last_entry = Some(entry);
+                }
+            }
+
+            match last_entry {
+                Some(entry) => AuditLogTip {
+                    next_sequence: entry.sequence + 1,
+                    last_hash: entry.entry_hash,
+                },
+                None => AuditLogTip {
+                    next_sequence: 0,
+                    last_hash: GENESIS_HASH,
+                },
+            }
+        };
+
+        Ok(Self {
+            env,
+            store,
+            tip: Mutex::new(tip),
+        })
+    }
+
+    /// Appends `event` to the chain, returning the persisted entry.
+    pub fn append(&self, event: SecurityEvent) -> Result<AuditLogEntry, CryptoError> {
+        let canonical_event = serde_json::to_vec(&event).map_err(|e| CryptoError::SerializationError {
+            details: e.to_string(),
+        })?;
+
+        let mut tip = self.tip.lock().unwrap();
+        let entry_hash = AuditLogEntry::compute_hash(&tip.last_hash, &canonical_event);
+        let entry = AuditLogEntry {
+            sequence: tip.next_sequence,
+            prev_hash: tip.last_hash,
+            entry_hash,
+            event,
+        };
+
+        let bytes = serde_json::to_vec(&entry).map_err(|e| CryptoError::SerializationError {
+            details: e.to_string(),
+        })?;
+
+        let mut writer = self.env.write().map_err(|e| CryptoError::AuditLogError {
+            details: format!("failed to open audit log writer: {e}"),
+        })?;
+        self.store
+            .put(&mut writer, entry.sequence.to_be_bytes(), &Value::Blob(&bytes))
+            .map_err(|e| CryptoError::AuditLogError {
+                details: format!("failed to write audit log entry: {e}"),
+            })?;
+        writer.commit().map_err(|e| CryptoError::AuditLogError {
+            details: format!("failed to commit audit log entry: {e}"),
+        })?;
+
+        tip.next_sequence = entry.sequence + 1;
+        tip.last_hash = entry.entry_hash;
+
+        Ok(entry)
+    }
+
+    /// Recomputes the hash chain from genesis and confirms every persisted
+    /// entry still matches. Returns the sequence index of the first broken
+    /// link on failure, so callers can report exactly where tampering (or
+    /// silent deletion/reordering) occurred.
+    pub fn verify_chain(&self) -> Result<(), usize> {
+        let entries = self.export().map_err(|_| 0usize)?;
+
+        let mut expected_prev = GENESIS_HASH;
+        for entry in &entries {
+            let canonical_event =
+                serde_json::to_vec(&entry.event).map_err(|_| entry.sequence as usize)?;
+            let expected_hash = AuditLogEntry::compute_hash(&expected_prev, &canonical_event);
+
+            if entry.prev_hash != expected_prev || entry.entry_hash != expected_hash {
+                return Err(entry.sequence as usize);
+            }
+
+            expected_prev = entry.entry_hash;
+        }
+
+        Ok(())
+    }
+
+    /// Exports the full chain in sequence order for external auditing.
+    pub fn export(&self) -> Result<Vec<AuditLogEntry>, CryptoError> {
+        let reader = self.env.read().map_err(|e| CryptoError::AuditLogError {
+            details: format!("failed to open audit log reader: {e}"),
+        })?;
+        let iter = self.store.iter_start(&reader).map_err(|e| CryptoError::AuditLogError {
+            details: format!("failed to iterate audit log: {e}"),
+        })?;
+
+        let mut entries = Vec::new();
+        for result in iter {
+            let (_, value) = result.map_err(|e| CryptoError::AuditLogError {
+                details: format!("failed to read audit log entry: {e}"),
+            })?;
+            if let Some(Value::Blob(bytes)) = value {
+                let entry: AuditLogEntry =
+                    serde_json::from_slice(bytes).map_err(|e| CryptoError::DeserializationError {
+                        details: e.to_string(),
+                    })?;
+                entries.push(entry);
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{ErrorSeverity, SecurityEventType};
+
+    fn event(details: &str) -> SecurityEvent {
+        SecurityEvent::new(SecurityEventType::SystemAnomaly, ErrorSeverity::Critical, details.to_string())
+    }
+
+    #[test]
+    fn test_append_chains_entries_from_genesis() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::open(dir.path()).unwrap();
+
+        let first = log.append(event("first")).unwrap();
+        assert_eq!(first.prev_hash, GENESIS_HASH);
+
+        let second = log.append(event("second")).unwrap();
+        assert_eq!(second.prev_hash, first.entry_hash);
+        assert_ne!(second.entry_hash, first.entry_hash);
+    }
+
+    #[test]
+    fn test_verify_chain_passes_for_untouched_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::open(dir.path()).unwrap();
+
+        for i in 0..5 {
+            log.append(event(&format!("event {i}"))).unwrap();
+        }
+
+        assert!(log.verify_chain().is_ok());
+    }
+
+    #[test]
+    fn test_verify_chain_detects_tampering() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::open(dir.path()).unwrap();
+
+        log.append(event("first")).unwrap();
+        log.append(event("second")).unwrap();
+        log.append(event("third")).unwrap();
+
+        let mut writer = log.env.write().unwrap();
+        let mut tampered = log.export().unwrap()[1].clone();
+        tampered.event.details = "tampered".to_string();
+        let bytes = serde_json::to_vec(&tampered).unwrap();
+        log.store
+            .put(&mut writer, tampered.sequence.to_be_bytes(), &Value::Blob(&bytes))
+            .unwrap();
+        writer.commit().unwrap();
+
+        assert_eq!(log.verify_chain(), Err(1));
+    }
+
+    #[test]
+    fn test_reopen_continues_chain_from_persisted_tip() {
+        let dir = tempfile::tempdir().unwrap();
+        let last_hash = {
+            let log = AuditLog::open(dir.path()).unwrap();
+            log.append(event("first")).unwrap();
+            log.append(event("second")).unwrap().entry_hash
+        };
+
+        let reopened = AuditLog::open(dir.path()).unwrap();
+        let third = reopened.append(event("third")).unwrap();
+        assert_eq!(third.sequence, 2);
+        assert_eq!(third.prev_hash, last_hash);
+    }
+
+    #[test]
+    fn test_export_returns_full_chain_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::open(dir.path()).unwrap();
+
+        for i in 0..10 {
+            log.append(event(&format!("event {i}"))).unwrap();
+        }
+
+        let exported = log.export().unwrap();
+        let sequences: Vec<u64> = exported.iter().map(|e| e.sequence).collect();
+        assert_eq!(sequences, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,368 @@
+//! `qynauth` — a CLI front end for `qynauth_pqc`'s ML-KEM/ML-DSA
+//! primitives, so a shell script or CI pipeline can generate keys,
+//! sign/verify, and encapsulate/decapsulate without linking the
+//! library or writing C glue against `qynauth_pqc::ffi`. Subcommands
+//! mirror the `generate`/`sign`/`verify`/`recover` structure of
+//! established keytool CLIs (`openssl`, `ssh-keygen`, `step`): keys and
+//! ciphertexts read/write as hex or PEM, on stdin/stdout or `--...-file`
+//! paths, and the process exit code distinguishes a malformed input
+//! from a cryptographically rejected one (see [`ExitStatus`]) so
+//! calling scripts can branch without scraping stderr text.
+
+use qynauth_pqc::key_encoding;
+use qynauth_pqc::{
+    generate_mldsa_keypair_for_level, generate_mlkem_keypair_for_algorithm, mldsa_sign_for_level,
+    mldsa_verify_for_level, mlkem_decapsulate_for_algorithm, mlkem_encapsulate_for_algorithm,
+    MLDSALevel, MLKEMLevel, PQCAlgorithm,
+};
+use secrecy::ExposeSecret;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+/// Exit codes a calling script can branch on, distinct from one
+/// another so "the input was garbage" and "the input parsed but didn't
+/// verify/decapsulate" are never conflated.
+#[derive(Clone, Copy)]
+enum ExitStatus {
+    Success = 0,
+    MalformedInput = 1,
+    VerificationFailed = 2,
+    IoError = 3,
+}
+
+impl From<ExitStatus> for ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        ExitCode::from(status as u8)
+    }
+}
+
+enum CliError {
+    MalformedInput(String),
+    VerificationFailed(String),
+    Io(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::MalformedInput(msg) => write!(f, "malformed input: {msg}"),
+            CliError::VerificationFailed(msg) => write!(f, "{msg}"),
+            CliError::Io(msg) => write!(f, "I/O error: {msg}"),
+        }
+    }
+}
+
+impl CliError {
+    fn exit_status(&self) -> ExitStatus {
+        match self {
+            CliError::MalformedInput(_) => ExitStatus::MalformedInput,
+            CliError::VerificationFailed(_) => ExitStatus::VerificationFailed,
+            CliError::Io(_) => ExitStatus::IoError,
+        }
+    }
+}
+
+impl From<qynauth_pqc::PQCError> for CliError {
+    fn from(err: qynauth_pqc::PQCError) -> Self {
+        CliError::MalformedInput(err.to_string())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Algorithm {
+    MlKem768,
+    MlDsa65,
+}
+
+impl Algorithm {
+    fn parse(s: &str) -> Result<Self, CliError> {
+        match s {
+            "ml-kem-768" => Ok(Self::MlKem768),
+            "ml-dsa-65" => Ok(Self::MlDsa65),
+            other => Err(CliError::MalformedInput(format!(
+                "unsupported --algorithm {other} (expected ml-kem-768 or ml-dsa-65)"
+            ))),
+        }
+    }
+
+    fn pqc_algorithm(self) -> PQCAlgorithm {
+        match self {
+            Self::MlKem768 => PQCAlgorithm::MlKem768,
+            Self::MlDsa65 => PQCAlgorithm::MlDsa65,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Hex,
+    Pem,
+}
+
+impl Encoding {
+    fn parse(s: &str) -> Result<Self, CliError> {
+        match s {
+            "hex" => Ok(Self::Hex),
+            "pem" => Ok(Self::Pem),
+            other => Err(CliError::MalformedInput(format!(
+                "unsupported --encoding {other} (expected hex or pem)"
+            ))),
+        }
+    }
+}
+
+/// Parsed `--flag value` pairs plus any bare (non-flag) arguments, a
+/// minimal hand-rolled parser so this binary doesn't need a CLI
+/// argument-parsing dependency for five subcommands.
+struct Args {
+    flags: std::collections::HashMap<String, String>,
+}
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Self, CliError> {
+        let mut flags = std::collections::HashMap::new();
+        let mut i = 0;
+        while i < args.len() {
+            let arg = &args[i];
+            let name = arg.strip_prefix("--").ok_or_else(|| {
+                CliError::MalformedInput(format!("unexpected argument '{arg}', expected --flag"))
+            })?;
+            let value = args.get(i + 1).ok_or_else(|| {
+                CliError::MalformedInput(format!("--{name} requires a value"))
+            })?;
+            flags.insert(name.to_string(), value.clone());
+            i += 2;
+        }
+        Ok(Self { flags })
+    }
+
+    fn require(&self, name: &str) -> Result<&str, CliError> {
+        self.flags
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| CliError::MalformedInput(format!("missing required --{name}")))
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.flags.get(name).map(String::as_str)
+    }
+
+    fn algorithm(&self) -> Result<Algorithm, CliError> {
+        Algorithm::parse(self.require("algorithm")?)
+    }
+
+    fn encoding(&self) -> Result<Encoding, CliError> {
+        match self.get("encoding") {
+            Some(s) => Encoding::parse(s),
+            None => Ok(Encoding::Hex),
+        }
+    }
+}
+
+fn read_bytes(path_flag: Option<&str>) -> Result<Vec<u8>, CliError> {
+    match path_flag {
+        Some("-") | None => {
+            let mut buf = Vec::new();
+            io::stdin()
+                .read_to_end(&mut buf)
+                .map_err(|e| CliError::Io(e.to_string()))?;
+            Ok(buf)
+        }
+        Some(path) => fs::read(path).map_err(|e| CliError::Io(format!("{path}: {e}"))),
+    }
+}
+
+fn write_bytes(path_flag: Option<&str>, bytes: &[u8]) -> Result<(), CliError> {
+    match path_flag {
+        Some("-") | None => io::stdout().write_all(bytes).map_err(|e| CliError::Io(e.to_string())),
+        Some(path) => fs::write(path, bytes).map_err(|e| CliError::Io(format!("{path}: {e}"))),
+    }
+}
+
+fn decode_key(encoding: Encoding, algorithm: Algorithm, bytes: &[u8], is_private: bool) -> Result<Vec<u8>, CliError> {
+    match encoding {
+        Encoding::Hex => {
+            let text = std::str::from_utf8(bytes)
+                .map_err(|_| CliError::MalformedInput("hex input is not valid UTF-8".to_string()))?
+                .trim();
+            hex::decode(text).map_err(|e| CliError::MalformedInput(format!("invalid hex: {e}")))
+        }
+        Encoding::Pem => {
+            let text = std::str::from_utf8(bytes)
+                .map_err(|_| CliError::MalformedInput("PEM input is not valid UTF-8".to_string()))?;
+            let (decoded_algorithm, key) = if is_private {
+                key_encoding::private_key_from_pem(text)?
+            } else {
+                key_encoding::public_key_from_pem(text)?
+            };
+            if decoded_algorithm != algorithm.pqc_algorithm() {
+                return Err(CliError::MalformedInput(format!(
+                    "PEM key is {}, expected {}",
+                    decoded_algorithm.name(),
+                    algorithm.pqc_algorithm().name()
+                )));
+            }
+            Ok(key)
+        }
+    }
+}
+
+fn encode_key(encoding: Encoding, algorithm: Algorithm, key: &[u8], is_private: bool) -> Result<Vec<u8>, CliError> {
+    match encoding {
+        Encoding::Hex => Ok(hex::encode(key).into_bytes()),
+        Encoding::Pem => {
+            let pem = if is_private {
+                key_encoding::private_key_to_pem(algorithm.pqc_algorithm(), key)?
+            } else {
+                key_encoding::public_key_to_pem(algorithm.pqc_algorithm(), key)?
+            };
+            Ok(pem.into_bytes())
+        }
+    }
+}
+
+fn cmd_generate(args: &Args) -> Result<(), CliError> {
+    let algorithm = args.algorithm()?;
+    let encoding = args.encoding()?;
+
+    let keypair = match algorithm {
+        Algorithm::MlKem768 => generate_mlkem_keypair_for_algorithm(PQCAlgorithm::MlKem768)?,
+        Algorithm::MlDsa65 => generate_mldsa_keypair_for_level(MLDSALevel::Level3)?,
+    };
+
+    let public_key = encode_key(encoding, algorithm, &keypair.public_key, false)?;
+    let private_key = encode_key(encoding, algorithm, keypair.private_key.expose_secret(), true)?;
+
+    write_bytes(args.get("public-key-out"), &public_key)?;
+    write_bytes(args.get("private-key-out"), &private_key)?;
+    Ok(())
+}
+
+fn cmd_sign(args: &Args) -> Result<(), CliError> {
+    let algorithm = args.algorithm()?;
+    let encoding = args.encoding()?;
+    if algorithm != Algorithm::MlDsa65 {
+        return Err(CliError::MalformedInput("sign requires --algorithm ml-dsa-65".to_string()));
+    }
+
+    let private_key = decode_key(encoding, algorithm, &read_bytes(args.get("private-key"))?, true)?;
+    let message = read_bytes(args.get("message"))?;
+
+    let signature = mldsa_sign_for_level(MLDSALevel::Level3, &private_key, &message)?;
+    let encoded = hex::encode(signature.signature.expose_secret()).into_bytes();
+    write_bytes(args.get("signature-out"), &encoded)
+}
+
+fn cmd_verify(args: &Args) -> Result<(), CliError> {
+    let algorithm = args.algorithm()?;
+    let encoding = args.encoding()?;
+    if algorithm != Algorithm::MlDsa65 {
+        return Err(CliError::MalformedInput("verify requires --algorithm ml-dsa-65".to_string()));
+    }
+
+    let public_key = decode_key(encoding, algorithm, &read_bytes(args.get("public-key"))?, false)?;
+    let message = read_bytes(args.get("message"))?;
+    let signature_hex = read_bytes(args.get("signature"))?;
+    let signature_text = std::str::from_utf8(&signature_hex)
+        .map_err(|_| CliError::MalformedInput("signature input is not valid UTF-8".to_string()))?
+        .trim();
+    let signature = hex::decode(signature_text)
+        .map_err(|e| CliError::MalformedInput(format!("invalid hex signature: {e}")))?;
+
+    let valid = mldsa_verify_for_level(MLDSALevel::Level3, &public_key, &message, &signature)?;
+    if valid {
+        Ok(())
+    } else {
+        Err(CliError::VerificationFailed("signature verification failed".to_string()))
+    }
+}
+
+fn cmd_encapsulate(args: &Args) -> Result<(), CliError> {
+    let algorithm = args.algorithm()?;
+    let encoding = args.encoding()?;
+    if algorithm != Algorithm::MlKem768 {
+        return Err(CliError::MalformedInput("encapsulate requires --algorithm ml-kem-768".to_string()));
+    }
+
+    let public_key = decode_key(encoding, algorithm, &read_bytes(args.get("public-key"))?, false)?;
+    let result = mlkem_encapsulate_for_algorithm(PQCAlgorithm::MlKem768, &public_key, &[])?;
+
+    write_bytes(args.get("ciphertext-out"), hex::encode(&result.ciphertext).as_bytes())?;
+    write_bytes(
+        args.get("shared-secret-out"),
+        hex::encode(result.shared_secret.expose_secret()).as_bytes(),
+    )
+}
+
+fn cmd_decapsulate(args: &Args) -> Result<(), CliError> {
+    let algorithm = args.algorithm()?;
+    let encoding = args.encoding()?;
+    if algorithm != Algorithm::MlKem768 {
+        return Err(CliError::MalformedInput("decapsulate requires --algorithm ml-kem-768".to_string()));
+    }
+
+    let private_key = decode_key(encoding, algorithm, &read_bytes(args.get("private-key"))?, true)?;
+    let ciphertext_hex = read_bytes(args.get("ciphertext"))?;
+    let ciphertext_text = std::str::from_utf8(&ciphertext_hex)
+        .map_err(|_| CliError::MalformedInput("ciphertext input is not valid UTF-8".to_string()))?
+        .trim();
+    let ciphertext = hex::decode(ciphertext_text)
+        .map_err(|e| CliError::MalformedInput(format!("invalid hex ciphertext: {e}")))?;
+
+    let shared_secret = mlkem_decapsulate_for_algorithm(PQCAlgorithm::MlKem768, &private_key, &ciphertext)
+        .map_err(|e| CliError::VerificationFailed(format!("decapsulation failed: {e}")))?;
+
+    write_bytes(
+        args.get("shared-secret-out"),
+        hex::encode(shared_secret.expose_secret()).as_bytes(),
+    )
+}
+
+fn cmd_info(args: &Args) -> Result<(), CliError> {
+    let algorithm = args.algorithm()?.pqc_algorithm();
+    println!("name: {}", algorithm.name());
+    println!("security_level: {}", algorithm.security_level());
+    println!("public_key_size: {}", algorithm.public_key_size());
+    if algorithm.is_kem() {
+        println!(
+            "ciphertext_size: {}",
+            algorithm.ciphertext_size().expect("is_kem() guarantees a ciphertext size")
+        );
+    }
+    Ok(())
+}
+
+fn run() -> Result<(), CliError> {
+    let raw_args: Vec<String> = std::env::args().skip(1).collect();
+    let (subcommand, rest) = raw_args.split_first().ok_or_else(|| {
+        CliError::MalformedInput(
+            "expected a subcommand (generate, sign, verify, encapsulate, decapsulate, info)".to_string(),
+        )
+    })?;
+
+    let args = Args::parse(rest)?;
+
+    match subcommand.as_str() {
+        "generate" => cmd_generate(&args),
+        "sign" => cmd_sign(&args),
+        "verify" => cmd_verify(&args),
+        "encapsulate" => cmd_encapsulate(&args),
+        "decapsulate" => cmd_decapsulate(&args),
+        "info" => cmd_info(&args),
+        other => Err(CliError::MalformedInput(format!(
+            "unknown subcommand '{other}' (expected generate, sign, verify, encapsulate, decapsulate, info)"
+        ))),
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitStatus::Success.into(),
+        Err(e) => {
+            eprintln!("qynauth: {e}");
+            e.exit_status().into()
+        }
+    }
+}
@@ -1,15 +1,46 @@
 use crate::{PQCError, PQCResult, PQCKeyPair, PQCSignature};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use pqcrypto_mldsa::mldsa65;
 use pqcrypto_traits::sign::{PublicKey, SecretKey, DetachedSignature};
-use secrecy::Secret;
+use rayon::prelude::*;
+use secrecy::{ExposeSecret, Secret};
 use log::info;
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Size in bytes of a raw Ed25519 signature, as returned by
+/// `ed25519_dalek::Signature::to_bytes`.
+const ED25519_SIGNATURE_SIZE: usize = 64;
+
+/// Size in bytes of a raw Ed25519 public or secret key.
+const ED25519_KEY_SIZE: usize = 32;
+
+/// Keypair for [`DilithiumEngine::hybrid_sign`]/[`DilithiumEngine::hybrid_verify`]:
+/// one ML-DSA-65 keypair and one Ed25519 keypair, generated together so
+/// callers don't have to juggle two separate generation calls to use hybrid
+/// mode.
+pub struct DilithiumHybridKeyPair {
+    pub dilithium_public_key: Vec<u8>,
+    pub dilithium_private_key: Secret<Vec<u8>>,
+    pub ed25519_public_key: Vec<u8>,
+    pub ed25519_private_key: Secret<Vec<u8>>,
+}
 
 #[derive(Debug, Clone)]
 pub struct DilithiumConfig {
     pub timing_resistance: bool,
     pub memory_protection: bool,
     pub performance_monitoring: bool,
+    /// Caps the number of threads `par_batch_sign`/`par_batch_verify` spread
+    /// work across, for callers on constrained systems. `None` lets rayon
+    /// pick its own default (one thread per detected core).
+    pub max_parallelism: Option<usize>,
+    /// Constant-time padding budget (nanoseconds) for `generate_keypair`.
+    /// See [`DilithiumEngine::pad_to_constant_time`].
+    pub target_keygen_nanos: u64,
+    /// Constant-time padding budget (nanoseconds) for `sign`.
+    pub target_sign_nanos: u64,
+    /// Constant-time padding budget (nanoseconds) for `verify`.
+    pub target_verify_nanos: u64,
 }
 
 impl Default for DilithiumConfig {
@@ -18,6 +49,13 @@ impl Default for DilithiumConfig {
             timing_resistance: true,
             memory_protection: true,
             performance_monitoring: true,
+            max_parallelism: None,
+            // Starting points carried over from the fixed sleeps this
+            // replaces (200us/300us/150us); `pad_to_constant_time` raises
+            // these at runtime if real operations ever exceed them.
+            target_keygen_nanos: 200_000,
+            target_sign_nanos: 300_000,
+            target_verify_nanos: 150_000,
         }
     }
 }
@@ -25,42 +63,71 @@ impl Default for DilithiumConfig {
 pub struct DilithiumEngine {
     config: DilithiumConfig,
     operations_count: u64,
+    /// Private pool that `par_batch_sign`/`par_batch_verify` run inside, so
+    /// this engine's parallelism doesn't contend with the rest of the host
+    /// process's global rayon pool. Sized from `config.max_parallelism`.
+    thread_pool: rayon::ThreadPool,
 }
 
 impl DilithiumEngine {
     pub fn new(config: DilithiumConfig) -> Self {
         info!("Initializing Dilithium-3 (ML-DSA-65) engine with config: {:?}", config);
-        
+
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            // `num_threads(0)` tells rayon to pick its own default thread count.
+            .num_threads(config.max_parallelism.unwrap_or(0))
+            .build()
+            .expect("failed to build Dilithium parallel batch thread pool");
+
         Self {
             config,
             operations_count: 0,
+            thread_pool,
+        }
+    }
+
+    /// Busy-waits (never `sleep`, since scheduler jitter on a sleeping
+    /// thread would itself leak timing) until `start`'s elapsed time
+    /// reaches this operation's constant-time budget, so the externally
+    /// observable duration of `operation` is independent of secret-dependent
+    /// branches or message content. The budget is the larger of
+    /// `configured_target_nanos` and the largest per-operation latency
+    /// `FFIMetrics` has observed for `operation`'s histogram, so it
+    /// calibrates upward automatically if real operations ever exceed the
+    /// configured target rather than silently returning early and leaking
+    /// the overrun.
+    fn pad_to_constant_time(&self, operation: &str, configured_target_nanos: u64, start: Instant) {
+        let observed_max_nanos = crate::ffi::monitoring::get_percentile(operation, 1.0).as_nanos() as u64;
+        let target = Duration::from_nanos(configured_target_nanos.max(observed_max_nanos));
+        while start.elapsed() < target {
+            std::hint::spin_loop();
         }
     }
 
     pub fn generate_keypair(&mut self) -> PQCResult<PQCKeyPair> {
         let start_time = Instant::now();
-        
+
         info!("Generating Dilithium-3 keypair...");
-        
+
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map_err(|e| PQCError::KeyGenerationFailed(format!("Time error: {}", e)))?
             .as_secs();
-        
-        let keypair = mldsa65::keypair();
-        
+
+        let keypair = crate::ffi::monitoring::record_operation_time("mldsa_keygen", mldsa65::keypair);
+
         let public_key_bytes = keypair.0.as_bytes().to_vec();
         let private_key_bytes = keypair.1.as_bytes().to_vec();
-        
+
         if self.config.timing_resistance {
-            std::thread::sleep(std::time::Duration::from_micros(200));
+            self.pad_to_constant_time("mldsa_keygen", self.config.target_keygen_nanos, start_time);
         }
-        
+
         let duration = start_time.elapsed();
         self.operations_count += 1;
-        
+
         info!("Dilithium-3 keypair generated in {:?}", duration);
-        
+
         Ok(PQCKeyPair {
             public_key: public_key_bytes,
             private_key: Secret::new(private_key_bytes),
@@ -91,13 +158,15 @@ impl DilithiumEngine {
             .map_err(|e| PQCError::KeyGenerationFailed(format!("Time error: {}", e)))?
             .as_secs();
         
-        let signature = mldsa65::detached_sign(message, &sk);
+        let signature = crate::ffi::monitoring::record_operation_time("mldsa_sign", || {
+            mldsa65::detached_sign(message, &sk)
+        });
         let signature_bytes = signature.as_bytes().to_vec();
-        
+
         if self.config.timing_resistance {
-            std::thread::sleep(std::time::Duration::from_micros(300));
+            self.pad_to_constant_time("mldsa_sign", self.config.target_sign_nanos, start_time);
         }
-        
+
         let duration = start_time.elapsed();
         self.operations_count += 1;
         
@@ -105,7 +174,7 @@ impl DilithiumEngine {
               duration, signature_bytes.len());
         
         Ok(PQCSignature {
-            signature: signature_bytes,
+            signature: Secret::new(signature_bytes),
             algorithm: "Dilithium-3".to_string(),
             signature_size: 3309, // Dilithium-3 signature size
             created_at: current_time,
@@ -137,12 +206,14 @@ impl DilithiumEngine {
         let sig = mldsa65::DetachedSignature::from_bytes(signature)
             .map_err(|e| PQCError::VerificationFailed(format!("Invalid signature: {:?}", e)))?;
         
-        let is_valid = mldsa65::verify_detached_signature(&sig, message, &pk).is_ok();
-        
+        let is_valid = crate::ffi::monitoring::record_operation_time("mldsa_verify", || {
+            mldsa65::verify_detached_signature(&sig, message, &pk).is_ok()
+        });
+
         if self.config.timing_resistance {
-            std::thread::sleep(std::time::Duration::from_micros(150));
+            self.pad_to_constant_time("mldsa_verify", self.config.target_verify_nanos, start_time);
         }
-        
+
         let duration = start_time.elapsed();
         self.operations_count += 1;
         
@@ -169,10 +240,248 @@ impl DilithiumEngine {
         
         let duration = start_time.elapsed();
         info!("Batch signed {} messages in {:?}", messages.len(), duration);
-        
+
         Ok(signatures)
     }
 
+    /// Parallel counterpart to [`Self::batch_sign`]: parses `private_key`
+    /// once, then signs every message across `self.thread_pool` instead of
+    /// looping sequentially. Results preserve `messages`' input order since
+    /// `par_iter` over a slice is an indexed iterator.
+    pub fn par_batch_sign(
+        &mut self,
+        private_key: &[u8],
+        messages: &[&[u8]],
+    ) -> PQCResult<Vec<PQCSignature>> {
+        let start_time = Instant::now();
+
+        if private_key.len() != 4000 {
+            return Err(PQCError::InvalidPrivateKey(format!(
+                "Invalid Dilithium-3 private key size: expected 4000, got {}",
+                private_key.len()
+            )));
+        }
+
+        info!("Parallel batch signing {} messages with Dilithium-3", messages.len());
+
+        let sk = mldsa65::SecretKey::from_bytes(private_key)
+            .map_err(|e| PQCError::SigningFailed(format!("Invalid private key: {:?}", e)))?;
+
+        let signatures: PQCResult<Vec<PQCSignature>> = self.thread_pool.install(|| {
+            messages
+                .par_iter()
+                .map(|message| {
+                    let item_start = Instant::now();
+
+                    let current_time = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_err(|e| PQCError::KeyGenerationFailed(format!("Time error: {}", e)))?
+                        .as_secs();
+
+                    let signature = crate::ffi::monitoring::record_operation_time("mldsa_sign", || {
+                        mldsa65::detached_sign(message, &sk)
+                    });
+                    let signature_bytes = signature.as_bytes().to_vec();
+
+                    if self.config.timing_resistance {
+                        self.pad_to_constant_time("mldsa_sign", self.config.target_sign_nanos, item_start);
+                    }
+
+                    Ok(PQCSignature {
+                        signature: Secret::new(signature_bytes),
+                        algorithm: "Dilithium-3".to_string(),
+                        signature_size: 3309,
+                        created_at: current_time,
+                    })
+                })
+                .collect()
+        });
+
+        let signatures = signatures?;
+        self.operations_count += signatures.len() as u64;
+
+        let duration = start_time.elapsed();
+        info!("Parallel batch signed {} messages in {:?}", messages.len(), duration);
+
+        Ok(signatures)
+    }
+
+    /// Parallel counterpart to sequentially looping [`Self::verify`]: parses
+    /// `public_key` once, then verifies every `(message, signature)` pair
+    /// across `self.thread_pool`. Results preserve `pairs`' input order.
+    pub fn par_batch_verify(
+        &mut self,
+        public_key: &[u8],
+        pairs: &[(&[u8], &[u8])],
+    ) -> PQCResult<Vec<bool>> {
+        let start_time = Instant::now();
+
+        if public_key.len() != 1952 {
+            return Err(PQCError::InvalidPublicKey(format!(
+                "Invalid Dilithium-3 public key size: expected 1952, got {}",
+                public_key.len()
+            )));
+        }
+
+        info!("Parallel batch verifying {} signatures with Dilithium-3", pairs.len());
+
+        let pk = mldsa65::PublicKey::from_bytes(public_key)
+            .map_err(|e| PQCError::VerificationFailed(format!("Invalid public key: {:?}", e)))?;
+
+        let results: PQCResult<Vec<bool>> = self.thread_pool.install(|| {
+            pairs
+                .par_iter()
+                .map(|(message, signature)| {
+                    let item_start = Instant::now();
+
+                    if signature.len() != 3309 {
+                        return Err(PQCError::InvalidSignature(format!(
+                            "Invalid Dilithium-3 signature size: expected 3309, got {}",
+                            signature.len()
+                        )));
+                    }
+
+                    let sig = mldsa65::DetachedSignature::from_bytes(signature).map_err(|e| {
+                        PQCError::VerificationFailed(format!("Invalid signature: {:?}", e))
+                    })?;
+
+                    let is_valid = crate::ffi::monitoring::record_operation_time("mldsa_verify", || {
+                        mldsa65::verify_detached_signature(&sig, message, &pk).is_ok()
+                    });
+
+                    if self.config.timing_resistance {
+                        self.pad_to_constant_time("mldsa_verify", self.config.target_verify_nanos, item_start);
+                    }
+
+                    Ok(is_valid)
+                })
+                .collect()
+        });
+
+        let results = results?;
+        self.operations_count += results.len() as u64;
+
+        let duration = start_time.elapsed();
+        info!("Parallel batch verified {} signatures in {:?}", pairs.len(), duration);
+
+        Ok(results)
+    }
+
+    /// Generates one ML-DSA-65 keypair and one Ed25519 keypair together, for
+    /// use with [`Self::hybrid_sign`]/[`Self::hybrid_verify`].
+    pub fn generate_hybrid_keypair(&mut self) -> PQCResult<DilithiumHybridKeyPair> {
+        let dilithium_keypair = self.generate_keypair()?;
+        let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+
+        Ok(DilithiumHybridKeyPair {
+            dilithium_public_key: dilithium_keypair.public_key,
+            dilithium_private_key: dilithium_keypair.private_key,
+            ed25519_public_key: signing_key.verifying_key().to_bytes().to_vec(),
+            ed25519_private_key: Secret::new(signing_key.to_bytes().to_vec()),
+        })
+    }
+
+    /// Signs `message` with both `dilithium_sk` (ML-DSA-65) and `ed25519_sk`
+    /// (Ed25519), returning a `PQCSignature` tagged
+    /// `algorithm = "Dilithium3+Ed25519"` whose `signature` bytes are the
+    /// wire layout `len(dilithium) || dilithium signature || ed25519
+    /// signature` — a 4-byte big-endian length prefix on the ML-DSA part
+    /// followed by its bytes, then the fixed 64-byte Ed25519 signature with
+    /// no prefix of its own since its size never varies.
+    pub fn hybrid_sign(
+        &mut self,
+        dilithium_sk: &[u8],
+        ed25519_sk: &[u8],
+        message: &[u8],
+    ) -> PQCResult<PQCSignature> {
+        let dilithium_signature = self.sign(dilithium_sk, message)?;
+
+        let signing_key_bytes: [u8; ED25519_KEY_SIZE] = ed25519_sk.try_into().map_err(|_| {
+            PQCError::InvalidPrivateKey(format!(
+                "Ed25519 secret key must be {} bytes",
+                ED25519_KEY_SIZE
+            ))
+        })?;
+        let ed25519_sig = SigningKey::from_bytes(&signing_key_bytes).sign(message);
+
+        let dilithium_sig_bytes = dilithium_signature.signature.expose_secret();
+        let mut composite = Vec::with_capacity(4 + dilithium_sig_bytes.len() + ED25519_SIGNATURE_SIZE);
+        composite.extend_from_slice(&(dilithium_sig_bytes.len() as u32).to_be_bytes());
+        composite.extend_from_slice(dilithium_sig_bytes);
+        composite.extend_from_slice(&ed25519_sig.to_bytes());
+
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| PQCError::KeyGenerationFailed(format!("Time error: {}", e)))?
+            .as_secs();
+
+        Ok(PQCSignature {
+            signature_size: composite.len(),
+            signature: Secret::new(composite),
+            algorithm: "Dilithium3+Ed25519".to_string(),
+            created_at: current_time,
+        })
+    }
+
+    /// Verifies a composite signature produced by [`Self::hybrid_sign`].
+    /// Accepts only if both the ML-DSA-65 and the Ed25519 component verify,
+    /// validating component sizes first the same way [`Self::verify`]
+    /// validates the plain 3309-byte ML-DSA signature.
+    ///
+    /// Both halves are always verified before their results are combined —
+    /// never short-circuiting on the ML-DSA half failing — so a caller
+    /// timing this call can't learn which half broke a forged signature.
+    pub fn hybrid_verify(
+        &mut self,
+        dilithium_pk: &[u8],
+        ed25519_pk: &[u8],
+        message: &[u8],
+        composite: &[u8],
+    ) -> PQCResult<bool> {
+        if composite.len() < 4 {
+            return Err(PQCError::InvalidSignature(
+                "Truncated length prefix in hybrid signature".to_string(),
+            ));
+        }
+        let dilithium_len =
+            u32::from_be_bytes(composite[0..4].try_into().unwrap()) as usize;
+
+        if composite.len() != 4 + dilithium_len + ED25519_SIGNATURE_SIZE {
+            return Err(PQCError::InvalidSignature(format!(
+                "Invalid hybrid signature component sizes: expected {} bytes, got {}",
+                4 + dilithium_len + ED25519_SIGNATURE_SIZE,
+                composite.len()
+            )));
+        }
+
+        let dilithium_sig = &composite[4..4 + dilithium_len];
+        let ed25519_sig_bytes = &composite[4 + dilithium_len..];
+
+        let dilithium_result = self.verify(dilithium_pk, message, dilithium_sig);
+
+        let ed25519_result: PQCResult<bool> = (|| {
+            let verifying_key_bytes: [u8; ED25519_KEY_SIZE] =
+                ed25519_pk.try_into().map_err(|_| {
+                    PQCError::InvalidPublicKey(format!(
+                        "Ed25519 public key must be {} bytes",
+                        ED25519_KEY_SIZE
+                    ))
+                })?;
+            let verifying_key = VerifyingKey::from_bytes(&verifying_key_bytes).map_err(|_| {
+                PQCError::InvalidPublicKey("Malformed Ed25519 public key".to_string())
+            })?;
+            let signature = Signature::from_slice(ed25519_sig_bytes).map_err(|_| {
+                PQCError::InvalidSignature("Malformed Ed25519 signature".to_string())
+            })?;
+            Ok(verifying_key.verify(message, &signature).is_ok())
+        })();
+
+        let dilithium_valid = dilithium_result?;
+        let ed25519_valid = ed25519_result?;
+
+        Ok(dilithium_valid && ed25519_valid)
+    }
+
     pub fn validate_keypair(&self, public_key: &[u8], private_key: &[u8]) -> PQCResult<bool> {
         if public_key.len() != 1952 {
             return Ok(false);
@@ -268,7 +577,7 @@ mod tests {
         assert_eq!(signatures.len(), 3);
         
         for (i, signature) in signatures.iter().enumerate() {
-            let is_valid = engine.verify(&keypair.public_key, messages[i], &signature.signature).unwrap();
+            let is_valid = engine.verify(&keypair.public_key, messages[i], signature.signature.expose_secret()).unwrap();
             assert!(is_valid);
         }
     }
@@ -292,4 +601,115 @@ mod tests {
         let result = engine.verify(&valid_public_key, message, &invalid_signature);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_hybrid_sign_and_verify_round_trip() {
+        let mut engine = DilithiumEngine::new(DilithiumConfig::default());
+        let keypair = engine.generate_hybrid_keypair().unwrap();
+        let message = b"hybrid dilithium engine test";
+
+        let signature = engine
+            .hybrid_sign(
+                keypair.dilithium_private_key.expose_secret(),
+                keypair.ed25519_private_key.expose_secret(),
+                message,
+            )
+            .unwrap();
+        assert_eq!(signature.algorithm, "Dilithium3+Ed25519");
+
+        let valid = engine
+            .hybrid_verify(
+                &keypair.dilithium_public_key,
+                &keypair.ed25519_public_key,
+                message,
+                signature.signature.expose_secret(),
+            )
+            .unwrap();
+        assert!(valid);
+    }
+
+    #[test]
+    fn test_hybrid_verify_rejects_tampered_message() {
+        let mut engine = DilithiumEngine::new(DilithiumConfig::default());
+        let keypair = engine.generate_hybrid_keypair().unwrap();
+
+        let signature = engine
+            .hybrid_sign(
+                keypair.dilithium_private_key.expose_secret(),
+                keypair.ed25519_private_key.expose_secret(),
+                b"original message",
+            )
+            .unwrap();
+
+        let valid = engine
+            .hybrid_verify(
+                &keypair.dilithium_public_key,
+                &keypair.ed25519_public_key,
+                b"tampered message",
+                signature.signature.expose_secret(),
+            )
+            .unwrap();
+        assert!(!valid);
+    }
+
+    #[test]
+    fn test_hybrid_verify_rejects_invalid_component_sizes() {
+        let mut engine = DilithiumEngine::new(DilithiumConfig::default());
+        let keypair = engine.generate_hybrid_keypair().unwrap();
+        let message = b"component size test";
+
+        let mut composite = engine
+            .hybrid_sign(
+                keypair.dilithium_private_key.expose_secret(),
+                keypair.ed25519_private_key.expose_secret(),
+                message,
+            )
+            .unwrap()
+            .signature
+            .expose_secret()
+            .clone();
+        composite.push(0xFF);
+
+        let result = engine.hybrid_verify(
+            &keypair.dilithium_public_key,
+            &keypair.ed25519_public_key,
+            message,
+            &composite,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sign_timing_is_independent_of_message_length() {
+        let mut engine = DilithiumEngine::new(DilithiumConfig::default());
+        let keypair = engine.generate_keypair().unwrap();
+
+        let short_message = vec![0x42u8; 16];
+        let long_message = vec![0x42u8; 16_384];
+
+        // Warm up so the very first call (which pays one-time allocator /
+        // page-fault costs) doesn't skew the band we check below.
+        engine.sign(keypair.private_key.expose_secret(), &short_message).unwrap();
+
+        let mut observed = Vec::new();
+        for message in [&short_message, &long_message, &short_message, &long_message] {
+            let start = Instant::now();
+            engine.sign(keypair.private_key.expose_secret(), message).unwrap();
+            observed.push(start.elapsed());
+        }
+
+        let min = observed.iter().min().unwrap();
+        let max = observed.iter().max().unwrap();
+
+        // The padded durations should cluster tightly regardless of message
+        // length; generously allow for scheduler noise on a loaded CI host
+        // while still catching a regression back to data-dependent timing
+        // (which would show a gap on the order of the real sign/verify
+        // cost, far larger than this band).
+        assert!(
+            *max - *min < Duration::from_millis(5),
+            "sign timings should cluster tightly regardless of message length, got {:?}",
+            observed
+        );
+    }
 }
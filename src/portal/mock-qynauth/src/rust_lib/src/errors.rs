@@ -1,7 +1,13 @@
 use thiserror::Error;
-use std::fmt;
+use core::fmt;
+use alloc::format;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use core::time::Duration;
+#[cfg(feature = "std")]
 use tracing::{error, warn, info, debug, instrument};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
 use serde::{Serialize, Deserialize};
 
 #[derive(Error, Debug, Clone, PartialEq)]
@@ -62,6 +68,9 @@ pub enum CryptoError {
 
     #[error("Internal error: {details}")]
     InternalError { details: String },
+
+    #[error("Audit log error: {details}")]
+    AuditLogError { details: String },
 }
 
 impl CryptoError {
@@ -86,6 +95,7 @@ impl CryptoError {
             CryptoError::DeserializationError { .. } => ErrorSeverity::Low,
             CryptoError::ConfigurationError { .. } => ErrorSeverity::Low,
             CryptoError::InternalError { .. } => ErrorSeverity::Medium,
+            CryptoError::AuditLogError { .. } => ErrorSeverity::Medium,
         }
     }
 
@@ -109,6 +119,7 @@ impl CryptoError {
             CryptoError::SecurityPolicyViolation { .. } => "CRYPTO_016",
             CryptoError::RateLimitExceeded { .. } => "CRYPTO_017",
             CryptoError::ConcurrentOperationConflict { .. } => "CRYPTO_018",
+            CryptoError::AuditLogError { .. } => "CRYPTO_019",
             CryptoError::InternalError { .. } => "CRYPTO_999",
         }
     }
@@ -147,6 +158,63 @@ impl fmt::Display for ErrorSeverity {
     }
 }
 
+/// Source of the timestamp stamped onto a `SecurityEvent`, so event
+/// construction doesn't hard-depend on `std::time::SystemTime`: a bare-metal
+/// or TEE target without a wall clock can supply a monotonic counter (or any
+/// other injected notion of "time") instead.
+pub trait Clock {
+    /// The current time, in Unix seconds (or a monotonically increasing
+    /// substitute when no wall clock is available).
+    fn now_unix_secs(&self) -> u64;
+}
+
+/// Real wall-clock time via `std::time::SystemTime`. Used by default when
+/// the `std` feature is enabled.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdClock;
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+    fn now_unix_secs(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+}
+
+/// Monotonically increasing counter standing in for wall-clock time on
+/// targets without one. Timestamps from this clock only have ordering, not
+/// calendar, meaning.
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Default)]
+pub struct MonotonicCounterClock {
+    next: core::sync::atomic::AtomicU64,
+}
+
+#[cfg(not(feature = "std"))]
+impl Clock for MonotonicCounterClock {
+    fn now_unix_secs(&self) -> u64 {
+        self.next.fetch_add(1, core::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+#[cfg(feature = "std")]
+pub type DefaultClock = StdClock;
+#[cfg(not(feature = "std"))]
+pub type DefaultClock = MonotonicCounterClock;
+
+#[cfg(not(feature = "std"))]
+static MONOTONIC_CLOCK: MonotonicCounterClock = MonotonicCounterClock {
+    next: core::sync::atomic::AtomicU64::new(0),
+};
+
+#[cfg(feature = "std")]
+fn current_timestamp_secs() -> u64 {
+    StdClock.now_unix_secs()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityEvent {
     pub event_type: SecurityEventType,
@@ -171,16 +239,18 @@ pub enum SecurityEventType {
 }
 
 impl SecurityEvent {
-    pub fn new(event_type: SecurityEventType, severity: ErrorSeverity, details: String) -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-
+    /// Builds an event stamped via `clock`, the only constructor available
+    /// without `std`.
+    pub fn new_with_clock(
+        clock: &dyn Clock,
+        event_type: SecurityEventType,
+        severity: ErrorSeverity,
+        details: String,
+    ) -> Self {
         Self {
             event_type,
             severity,
-            timestamp,
+            timestamp: clock.now_unix_secs(),
             details,
             user_id: None,
             key_id: None,
@@ -189,6 +259,13 @@ impl SecurityEvent {
         }
     }
 
+    /// Builds an event stamped with the real wall clock. Requires `std`;
+    /// `no_std` callers use `new_with_clock` with their own `Clock`.
+    #[cfg(feature = "std")]
+    pub fn new(event_type: SecurityEventType, severity: ErrorSeverity, details: String) -> Self {
+        Self::new_with_clock(&StdClock, event_type, severity, details)
+    }
+
     pub fn with_user_id(mut self, user_id: String) -> Self {
         self.user_id = Some(user_id);
         self
@@ -210,6 +287,10 @@ impl SecurityEvent {
     }
 }
 
+// Everything below leans on `tracing`'s macros, which assume an `std`
+// target, so the whole logging surface is gated behind the `std` feature.
+// `no_std` callers (firmware, TEEs) get the core types above without it.
+#[cfg(feature = "std")]
 #[instrument(level = "debug")]
 pub fn log_crypto_operation(operation: &str, key_id: &str, duration: Duration, success: bool) {
     if success {
@@ -231,6 +312,7 @@ pub fn log_crypto_operation(operation: &str, key_id: &str, duration: Duration, s
     }
 }
 
+#[cfg(feature = "std")]
 #[instrument(level = "warn")]
 pub fn log_security_event(event: &SecurityEvent) {
     match event.severity {
@@ -289,6 +371,7 @@ pub fn log_security_event(event: &SecurityEvent) {
     }
 }
 
+#[cfg(feature = "std")]
 #[instrument(level = "debug")]
 pub fn log_performance_metrics(operation: &str, duration: Duration, throughput: Option<f64>) {
     if let Some(ops_per_sec) = throughput {
@@ -307,6 +390,7 @@ pub fn log_performance_metrics(operation: &str, duration: Duration, throughput:
     }
 }
 
+#[cfg(feature = "std")]
 #[instrument(level = "error")]
 pub fn log_error_with_context(error: &CryptoError, context: &str) {
     error!(
@@ -319,19 +403,18 @@ pub fn log_error_with_context(error: &CryptoError, context: &str) {
     );
 }
 
+#[cfg(feature = "std")]
 pub fn log_key_lifecycle_event(key_id: &str, event: &str, algorithm: &str) {
     info!(
         key_id = key_id,
         event = event,
         algorithm = algorithm,
-        timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs(),
+        timestamp = current_timestamp_secs(),
         "Key lifecycle event"
     );
 }
 
+#[cfg(feature = "std")]
 pub fn log_memory_usage(operation: &str, bytes_allocated: usize, bytes_freed: usize) {
     debug!(
         operation = operation,
@@ -342,6 +425,7 @@ pub fn log_memory_usage(operation: &str, bytes_allocated: usize, bytes_freed: us
     );
 }
 
+#[cfg(feature = "std")]
 pub fn log_hardware_optimization(feature: &str, enabled: bool, performance_impact: Option<f64>) {
     if enabled {
         info!(
@@ -361,41 +445,95 @@ pub fn log_hardware_optimization(feature: &str, enabled: bool, performance_impac
 
 #[derive(Debug)]
 pub struct ErrorReporter {
-    error_count: std::sync::atomic::AtomicU64,
+    error_count: core::sync::atomic::AtomicU64,
+    #[cfg(feature = "std")]
     last_error_time: std::sync::Mutex<Option<SystemTime>>,
+    #[cfg(not(feature = "std"))]
+    last_error_time: core::sync::atomic::AtomicU64,
+    #[cfg(feature = "std")]
+    audit_log: Option<crate::audit_log::AuditLog>,
 }
 
 impl ErrorReporter {
     pub fn new() -> Self {
         Self {
-            error_count: std::sync::atomic::AtomicU64::new(0),
+            error_count: core::sync::atomic::AtomicU64::new(0),
+            #[cfg(feature = "std")]
             last_error_time: std::sync::Mutex::new(None),
+            #[cfg(not(feature = "std"))]
+            last_error_time: core::sync::atomic::AtomicU64::new(0),
+            #[cfg(feature = "std")]
+            audit_log: None,
         }
     }
 
+    /// Attaches a hash-chained, `rkv`-backed audit log so every Critical
+    /// security event `report_error` raises is also persisted, not just
+    /// traced. Requires `std` (the log is backed by the filesystem).
+    #[cfg(feature = "std")]
+    pub fn with_audit_log(mut self, audit_log: crate::audit_log::AuditLog) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
     pub fn report_error(&self, error: &CryptoError, context: &str) {
-        self.error_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-        *self.last_error_time.lock().unwrap() = Some(SystemTime::now());
-        
-        log_error_with_context(error, context);
-        
-        if error.severity() == ErrorSeverity::Critical {
-            let security_event = SecurityEvent::new(
-                SecurityEventType::SystemAnomaly,
-                ErrorSeverity::Critical,
-                format!("Critical crypto error: {}", error),
-            );
-            log_security_event(&security_event);
+        self.error_count.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        self.record_error_time();
+
+        #[cfg(feature = "std")]
+        {
+            log_error_with_context(error, context);
+
+            if error.severity() == ErrorSeverity::Critical {
+                let security_event = SecurityEvent::new(
+                    SecurityEventType::SystemAnomaly,
+                    ErrorSeverity::Critical,
+                    format!("Critical crypto error: {}", error),
+                );
+                log_security_event(&security_event);
+
+                if let Some(audit_log) = &self.audit_log {
+                    if let Err(e) = audit_log.append(security_event) {
+                        error!(error = %e, "failed to persist critical security event to audit log");
+                    }
+                }
+            }
         }
     }
 
+    #[cfg(feature = "std")]
+    fn record_error_time(&self) {
+        *self.last_error_time.lock().unwrap() = Some(SystemTime::now());
+    }
+
+    /// Without `std` there's no wall clock, so the last-error marker is a
+    /// monotonic counter reading rather than a timestamp.
+    #[cfg(not(feature = "std"))]
+    fn record_error_time(&self) {
+        self.last_error_time.store(
+            MONOTONIC_CLOCK.now_unix_secs(),
+            core::sync::atomic::Ordering::Relaxed,
+        );
+    }
+
     pub fn get_error_count(&self) -> u64 {
-        self.error_count.load(std::sync::atomic::Ordering::Relaxed)
+        self.error_count.load(core::sync::atomic::Ordering::Relaxed)
     }
 
+    #[cfg(feature = "std")]
     pub fn get_last_error_time(&self) -> Option<SystemTime> {
         *self.last_error_time.lock().unwrap()
     }
+
+    /// Returns the monotonic counter reading from the last reported error,
+    /// or `None` if no error has been reported yet.
+    #[cfg(not(feature = "std"))]
+    pub fn get_last_error_time(&self) -> Option<u64> {
+        match self.last_error_time.load(core::sync::atomic::Ordering::Relaxed) {
+            0 => None,
+            reading => Some(reading),
+        }
+    }
 }
 
 impl Default for ErrorReporter {
@@ -452,6 +590,25 @@ mod tests {
         assert_eq!(event.source_ip, Some("192.168.1.1".to_string()));
     }
 
+    #[test]
+    fn test_security_event_with_injected_clock() {
+        struct FixedClock(u64);
+        impl Clock for FixedClock {
+            fn now_unix_secs(&self) -> u64 {
+                self.0
+            }
+        }
+
+        let event = SecurityEvent::new_with_clock(
+            &FixedClock(42),
+            SecurityEventType::SystemAnomaly,
+            ErrorSeverity::Low,
+            "no wall clock available".to_string(),
+        );
+
+        assert_eq!(event.timestamp, 42);
+    }
+
     #[test]
     fn test_error_reporter() {
         let reporter = ErrorReporter::new();
@@ -466,6 +623,29 @@ mod tests {
         assert!(reporter.get_last_error_time().is_some());
     }
 
+    #[test]
+    fn test_error_reporter_persists_critical_events_to_audit_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let audit_log = crate::audit_log::AuditLog::open(dir.path()).unwrap();
+        let reporter = ErrorReporter::new().with_audit_log(audit_log);
+
+        let recoverable = CryptoError::KeyNotFound {
+            key_id: "test_key".to_string(),
+        };
+        reporter.report_error(&recoverable, "test context");
+
+        let critical = CryptoError::SecurityPolicyViolation {
+            policy: "key_rotation".to_string(),
+            details: "Key rotation overdue".to_string(),
+        };
+        reporter.report_error(&critical, "test context");
+
+        let reopened = crate::audit_log::AuditLog::open(dir.path()).unwrap();
+        let entries = reopened.export().unwrap();
+        assert_eq!(entries.len(), 1, "only the Critical error should be persisted");
+        assert!(reopened.verify_chain().is_ok());
+    }
+
     #[test]
     fn test_crypto_macros() {
         fn test_function() -> CryptoResult<()> {
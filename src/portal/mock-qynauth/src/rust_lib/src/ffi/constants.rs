@@ -0,0 +1,208 @@
+use crate::ffi::memory::{FFIErrorCode, FfiLayout};
+use crate::MLDSALevel;
+
+/// Standard ML-DSA-44 (NIST level 2) public key size in bytes.
+pub const MLDSA44_PUBLIC_KEY_SIZE: usize = 1312;
+/// Standard ML-DSA-44 (NIST level 2) secret key size in bytes.
+pub const MLDSA44_SECRET_KEY_SIZE: usize = 2560;
+/// Standard ML-DSA-44 (NIST level 2) detached signature size in bytes.
+pub const MLDSA44_SIGNATURE_SIZE: usize = 2420;
+
+/// Standard ML-DSA-65 (NIST level 3) public key size in bytes.
+pub const MLDSA65_PUBLIC_KEY_SIZE: usize = 1952;
+/// Standard ML-DSA-65 (NIST level 3) secret key size in bytes.
+pub const MLDSA65_SECRET_KEY_SIZE: usize = 4032;
+/// Standard ML-DSA-65 (NIST level 3) detached signature size in bytes.
+pub const MLDSA65_SIGNATURE_SIZE: usize = 3309;
+
+/// Standard ML-DSA-87 (NIST level 5) public key size in bytes.
+pub const MLDSA87_PUBLIC_KEY_SIZE: usize = 2592;
+/// Standard ML-DSA-87 (NIST level 5) secret key size in bytes.
+pub const MLDSA87_SECRET_KEY_SIZE: usize = 4896;
+/// Standard ML-DSA-87 (NIST level 5) detached signature size in bytes.
+pub const MLDSA87_SIGNATURE_SIZE: usize = 4627;
+
+fn public_key_size(level: MLDSALevel) -> usize {
+    match level {
+        MLDSALevel::Level2 => MLDSA44_PUBLIC_KEY_SIZE,
+        MLDSALevel::Level3 => MLDSA65_PUBLIC_KEY_SIZE,
+        MLDSALevel::Level5 => MLDSA87_PUBLIC_KEY_SIZE,
+    }
+}
+
+fn secret_key_size(level: MLDSALevel) -> usize {
+    match level {
+        MLDSALevel::Level2 => MLDSA44_SECRET_KEY_SIZE,
+        MLDSALevel::Level3 => MLDSA65_SECRET_KEY_SIZE,
+        MLDSALevel::Level5 => MLDSA87_SECRET_KEY_SIZE,
+    }
+}
+
+fn signature_size(level: MLDSALevel) -> usize {
+    match level {
+        MLDSALevel::Level2 => MLDSA44_SIGNATURE_SIZE,
+        MLDSALevel::Level3 => MLDSA65_SIGNATURE_SIZE,
+        MLDSALevel::Level5 => MLDSA87_SIGNATURE_SIZE,
+    }
+}
+
+macro_rules! sized_ffi_wrapper {
+    ($name:ident, $size_fn:ident, $what:expr) => {
+        #[doc = concat!(
+            "A byte slice known to match the ", $what, " size for a given\n",
+            "`MLDSALevel`.\n\n",
+            "Mirrors the pqcrypto `from_bytes` convention of rejecting a \
+             wrong-sized input at construction time, so FFI callers get a \
+             precise `FFIErrorCode::BadLength` instead of a generic crypto \
+             failure deep inside `pqcrypto_mldsa`."
+        )]
+        pub struct $name<'a>(&'a [u8]);
+
+        impl<'a> $name<'a> {
+            pub fn from_bytes(bytes: &'a [u8], level: MLDSALevel) -> Result<Self, FFIErrorCode> {
+                if bytes.len() != $size_fn(level) {
+                    return Err(FFIErrorCode::BadLength);
+                }
+                Ok(Self(bytes))
+            }
+
+            pub fn as_bytes(&self) -> &'a [u8] {
+                self.0
+            }
+        }
+    };
+}
+
+sized_ffi_wrapper!(MLDSAPublicKey, public_key_size, "ML-DSA public key");
+sized_ffi_wrapper!(MLDSASecretKey, secret_key_size, "ML-DSA secret key");
+sized_ffi_wrapper!(MLDSASignature, signature_size, "ML-DSA signature");
+
+fn level_name(level: MLDSALevel) -> &'static str {
+    match level {
+        MLDSALevel::Level2 => "ML-DSA-44",
+        MLDSALevel::Level3 => "ML-DSA-65",
+        MLDSALevel::Level5 => "ML-DSA-87",
+    }
+}
+
+/// Which field of an ML-DSA key/signature triple an [`MLDSALayout`] checks
+/// the length of.
+#[derive(Debug, Clone, Copy)]
+pub enum MLDSAField {
+    PublicKey,
+    SecretKey,
+    Signature,
+}
+
+/// An [`FfiLayout`] for an ML-DSA public key, secret key, or signature at a
+/// given security level. Reports mismatches with a field-specific code
+/// (`InvalidPublicKeySize`/`InvalidSecretKeySize`/`InvalidSignatureSize`)
+/// rather than the generic `FFIErrorCode::BadLength` the `sized_ffi_wrapper!`
+/// types above use, so `mldsa_sign`/`mldsa_verify` callers can tell which
+/// field was the wrong size.
+pub struct MLDSALayout {
+    pub level: MLDSALevel,
+    pub field: MLDSAField,
+}
+
+impl FfiLayout for MLDSALayout {
+    fn layout_name(&self) -> String {
+        let field = match self.field {
+            MLDSAField::PublicKey => "public key",
+            MLDSAField::SecretKey => "secret key",
+            MLDSAField::Signature => "signature",
+        };
+        format!("{} {field}", level_name(self.level))
+    }
+
+    fn expected_len(&self) -> usize {
+        match self.field {
+            MLDSAField::PublicKey => public_key_size(self.level),
+            MLDSAField::SecretKey => secret_key_size(self.level),
+            MLDSAField::Signature => signature_size(self.level),
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self.field {
+            MLDSAField::PublicKey => FFIErrorCode::InvalidPublicKeySize,
+            MLDSAField::SecretKey => FFIErrorCode::InvalidSecretKeySize,
+            MLDSAField::Signature => FFIErrorCode::InvalidSignatureSize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_key_rejects_wrong_length() {
+        let short = vec![0u8; 10];
+        assert!(matches!(
+            MLDSAPublicKey::from_bytes(&short, MLDSALevel::Level3),
+            Err(FFIErrorCode::BadLength)
+        ));
+    }
+
+    #[test]
+    fn test_secret_key_accepts_correct_length_per_level() {
+        let level2 = vec![0u8; MLDSA44_SECRET_KEY_SIZE];
+        let level3 = vec![0u8; MLDSA65_SECRET_KEY_SIZE];
+        let level5 = vec![0u8; MLDSA87_SECRET_KEY_SIZE];
+        assert!(MLDSASecretKey::from_bytes(&level2, MLDSALevel::Level2).is_ok());
+        assert!(MLDSASecretKey::from_bytes(&level3, MLDSALevel::Level3).is_ok());
+        assert!(MLDSASecretKey::from_bytes(&level5, MLDSALevel::Level5).is_ok());
+    }
+
+    #[test]
+    fn test_signature_rejects_mismatched_level() {
+        let level3_sized = vec![0u8; MLDSA65_SIGNATURE_SIZE];
+        assert!(matches!(
+            MLDSASignature::from_bytes(&level3_sized, MLDSALevel::Level5),
+            Err(FFIErrorCode::BadLength)
+        ));
+    }
+
+    #[test]
+    fn test_mldsa_layout_reports_expected_and_actual_len() {
+        let layout = MLDSALayout {
+            level: MLDSALevel::Level3,
+            field: MLDSAField::PublicKey,
+        };
+        assert_eq!(layout.expected_len(), MLDSA65_PUBLIC_KEY_SIZE);
+        let err = layout.check_len(10).unwrap_err();
+        assert_eq!(err, FFIErrorCode::InvalidPublicKeySize);
+        assert_eq!(layout.layout_name(), "ML-DSA-65 public key");
+    }
+
+    #[test]
+    fn test_mldsa_layout_reports_field_specific_error_codes() {
+        let secret_key_layout = MLDSALayout {
+            level: MLDSALevel::Level2,
+            field: MLDSAField::SecretKey,
+        };
+        assert_eq!(
+            secret_key_layout.check_len(10).unwrap_err(),
+            FFIErrorCode::InvalidSecretKeySize
+        );
+
+        let signature_layout = MLDSALayout {
+            level: MLDSALevel::Level5,
+            field: MLDSAField::Signature,
+        };
+        assert_eq!(
+            signature_layout.check_len(10).unwrap_err(),
+            FFIErrorCode::InvalidSignatureSize
+        );
+    }
+
+    #[test]
+    fn test_mldsa_layout_accepts_correct_len() {
+        let layout = MLDSALayout {
+            level: MLDSALevel::Level5,
+            field: MLDSAField::Signature,
+        };
+        assert!(layout.check_len(MLDSA87_SIGNATURE_SIZE).is_ok());
+    }
+}
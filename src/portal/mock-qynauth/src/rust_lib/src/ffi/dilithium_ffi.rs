@@ -1,4 +1,5 @@
 use libc::size_t;
+use std::cell::RefCell;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_int};
 use std::ptr;
@@ -7,12 +8,13 @@ use pqcrypto_mldsa::mldsa65::{keypair, open, sign, PublicKey, SecretKey, SignedM
 use pqcrypto_traits::sign::{
     PublicKey as SignPublicKey, SecretKey as SignSecretKey, SignedMessage as SignedMessageTrait,
 };
+use zeroize::Zeroizing;
 
 use crate::ffi::memory::{
-    safe_slice_from_raw, secure_allocate, secure_deallocate, validate_buffer_params, FFIErrorCode,
+    ffi_buffer_free, ffi_secure_buffer_free, safe_slice_from_raw, validate_buffer_params,
+    FFIBuffer, FFIErrorCode,
 };
-
-static mut LAST_ERROR: Option<CString> = None;
+use crate::security::PowerAnalysisProtection;
 
 #[repr(C)]
 pub struct CDilithiumKeyPair {
@@ -28,42 +30,172 @@ pub struct CDilithiumSignature {
     pub signature_len: size_t,
 }
 
-fn set_last_error(error: &str) {
-    unsafe {
-        LAST_ERROR = CString::new(error).ok();
+/// Magic bytes opening every `dilithium_*_export` envelope, so
+/// `dilithium_*_import` can reject a buffer that isn't one of these at all
+/// (a stray key file, a truncated transfer) before it gets anywhere near
+/// `pqcrypto`'s parsers.
+const DILITHIUM_EXPORT_MAGIC: [u8; 4] = *b"DLTH";
+
+/// Envelope layout version. Bump this if a field is ever added, removed, or
+/// reordered; `dilithium_*_import` rejects anything other than the version(s)
+/// it knows how to read.
+const DILITHIUM_EXPORT_VERSION: u8 = 1;
+
+/// Algorithm identifier for mldsa65, the only parameter set this module
+/// currently binds. A new parameter set gets its own identifier rather than
+/// reusing this one, so an old import build fails loudly on a newer export
+/// instead of misreading its fields.
+const DILITHIUM_ALGORITHM_MLDSA65: u8 = 1;
+
+/// Appends `data` to `buf` as a 4-byte big-endian length prefix followed by
+/// the bytes themselves, mirroring the envelope convention `hybrid.rs` uses
+/// for its own composite binary format.
+fn write_length_prefixed(buf: &mut Vec<u8>, data: &[u8]) {
+    buf.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    buf.extend_from_slice(data);
+}
+
+/// Reads one length-prefixed field starting at `*cursor`, advancing it past
+/// the field on success. Rejects a truncated length prefix, a length prefix
+/// that claims more bytes than remain, and (since every field this module
+/// exports is a real key or signature) a zero-length field.
+fn read_length_prefixed<'a>(bytes: &'a [u8], cursor: &mut usize) -> Result<&'a [u8], &'static str> {
+    if bytes.len() < *cursor + 4 {
+        return Err("Truncated length prefix in export envelope");
+    }
+    let len = u32::from_be_bytes(bytes[*cursor..*cursor + 4].try_into().unwrap()) as usize;
+    *cursor += 4;
+
+    if len == 0 {
+        return Err("Zero-length field in export envelope");
+    }
+    if bytes.len() < *cursor + len {
+        return Err("Truncated field in export envelope");
+    }
+
+    let field = &bytes[*cursor..*cursor + len];
+    *cursor += len;
+    Ok(field)
+}
+
+/// Validates the shared `magic || version || algorithm` header every
+/// `dilithium_*_export` envelope starts with, returning the cursor position
+/// just past it. Shared by the keypair and signature import paths so a
+/// future parameter set only needs to change the algorithm identifier, not
+/// reimplement the header check.
+fn read_export_header(bytes: &[u8]) -> Result<usize, &'static str> {
+    if bytes.len() < DILITHIUM_EXPORT_MAGIC.len() + 2 {
+        return Err("Export envelope too short to contain a header");
+    }
+    if bytes[..DILITHIUM_EXPORT_MAGIC.len()] != DILITHIUM_EXPORT_MAGIC {
+        return Err("Unrecognized export envelope magic bytes");
+    }
+
+    let mut cursor = DILITHIUM_EXPORT_MAGIC.len();
+    let version = bytes[cursor];
+    cursor += 1;
+    if version != DILITHIUM_EXPORT_VERSION {
+        return Err("Unsupported export envelope version");
+    }
+
+    let algorithm = bytes[cursor];
+    cursor += 1;
+    if algorithm != DILITHIUM_ALGORITHM_MLDSA65 {
+        return Err("Unsupported algorithm identifier in export envelope");
     }
+
+    Ok(cursor)
 }
 
+/// Opaque handle threaded explicitly through every `dilithium_*` call,
+/// following secp256k1's `Secp256k1` context pattern, in place of the
+/// `static mut LAST_ERROR` this module used to mutate from every call --
+/// that global was unsound the moment two threads called in concurrently
+/// (exactly what `concurrent_performance` and any multithreaded FFI caller
+/// do). Each context owns its own last-error slot, so contexts handed to
+/// different threads can't race on the same buffer. `rng` is carried for
+/// parity with `power_analysis` as a piece of per-context crypto state;
+/// `pqcrypto_mldsa`'s `keypair`/`sign` don't accept an injected RNG today,
+/// so it isn't read yet, but it's here so a future RNG-taking entry point
+/// doesn't need an ABI-breaking context change to add one.
+pub struct CDilithiumContext {
+    last_error: RefCell<Option<CString>>,
+    rng: rand::rngs::OsRng,
+    power_analysis: PowerAnalysisProtection,
+}
+
+fn context_set_last_error(ctx: &CDilithiumContext, error: &str) {
+    if let Ok(c_string) = CString::new(error) {
+        *ctx.last_error.borrow_mut() = Some(c_string);
+    }
+}
+
+/// Allocates a new context. Must be released with [`dilithium_context_free`]
+/// once the caller is done issuing `dilithium_*` calls through it.
 #[no_mangle]
-pub extern "C" fn dilithium_get_last_error() -> *const c_char {
-    unsafe {
-        match LAST_ERROR.as_ref() {
-            Some(err) => err.as_ptr(),
-            None => ptr::null(),
+pub extern "C" fn dilithium_context_new() -> *mut CDilithiumContext {
+    Box::into_raw(Box::new(CDilithiumContext {
+        last_error: RefCell::new(None),
+        rng: rand::rngs::OsRng,
+        power_analysis: PowerAnalysisProtection::new(),
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn dilithium_context_free(ctx: *mut CDilithiumContext) {
+    if !ctx.is_null() {
+        unsafe {
+            drop(Box::from_raw(ctx));
         }
     }
 }
 
+/// Returns a pointer to `ctx`'s last recorded error message, valid until
+/// the next `dilithium_*` call made through this same context. Null if
+/// `ctx` is null or no error has been recorded on it yet.
 #[no_mangle]
-pub extern "C" fn dilithium_keypair_generate() -> *mut CDilithiumKeyPair {
-    let (public_key, secret_key) = keypair();
+pub extern "C" fn dilithium_context_get_last_error(ctx: *mut CDilithiumContext) -> *const c_char {
+    if ctx.is_null() {
+        return ptr::null();
+    }
+
+    let ctx = unsafe { &*ctx };
+    match ctx.last_error.borrow().as_ref() {
+        Some(err) => err.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// `secret_key`'s bytes go straight from pqcrypto's borrowed view into the
+/// `mlock`'d destination buffer with no intermediate Rust-owned copy to
+/// scrub; the one buffer this function can't zero is `secret_key`'s own
+/// backing storage, since `pqcrypto_mldsa::mldsa65::SecretKey` doesn't
+/// implement `Zeroize` -- the same limitation `MlDsaSecretKey` in `lib.rs`
+/// already lives with for its inner pqcrypto key.
+#[no_mangle]
+pub extern "C" fn dilithium_keypair_generate(ctx: *mut CDilithiumContext) -> *mut CDilithiumKeyPair {
+    if ctx.is_null() {
+        return ptr::null_mut();
+    }
+    let ctx = unsafe { &*ctx };
+
+    let (public_key, secret_key) = ctx.power_analysis.protected_operation(keypair);
 
     let public_key_bytes = public_key.as_bytes();
     let secret_key_bytes = secret_key.as_bytes();
 
-    let public_key_ptr = match secure_allocate(public_key_bytes.len()) {
-        Ok(ptr) => ptr,
-        Err(_) => {
-            set_last_error("Failed to allocate memory for public key");
+    let mut public_buffer = match FFIBuffer::new(public_key_bytes.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate memory for public key: {e}"));
             return ptr::null_mut();
         }
     };
 
-    let secret_key_ptr = match secure_allocate(secret_key_bytes.len()) {
-        Ok(ptr) => ptr,
-        Err(_) => {
-            secure_deallocate(public_key_ptr, public_key_bytes.len());
-            set_last_error("Failed to allocate memory for secret key");
+    let mut secret_buffer = match FFIBuffer::new_secure(secret_key_bytes.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate memory for secret key: {e}"));
             return ptr::null_mut();
         }
     };
@@ -71,38 +203,27 @@ pub extern "C" fn dilithium_keypair_generate() -> *mut CDilithiumKeyPair {
     unsafe {
         ptr::copy_nonoverlapping(
             public_key_bytes.as_ptr(),
-            public_key_ptr,
+            public_buffer.as_mut_ptr(),
             public_key_bytes.len(),
         );
         ptr::copy_nonoverlapping(
             secret_key_bytes.as_ptr(),
-            secret_key_ptr,
+            secret_buffer.as_mut_ptr(),
             secret_key_bytes.len(),
         );
     }
 
-    let keypair_ptr = match secure_allocate(std::mem::size_of::<CDilithiumKeyPair>()) {
-        Ok(ptr) => ptr as *mut CDilithiumKeyPair,
-        Err(_) => {
-            secure_deallocate(public_key_ptr, public_key_bytes.len());
-            secure_deallocate(secret_key_ptr, secret_key_bytes.len());
-            set_last_error("Failed to allocate memory for keypair structure");
-            return ptr::null_mut();
-        }
-    };
-
-    unsafe {
-        (*keypair_ptr).public_key_ptr = public_key_ptr;
-        (*keypair_ptr).public_key_len = public_key_bytes.len();
-        (*keypair_ptr).secret_key_ptr = secret_key_ptr;
-        (*keypair_ptr).secret_key_len = secret_key_bytes.len();
-    }
-
-    keypair_ptr
+    Box::into_raw(Box::new(CDilithiumKeyPair {
+        public_key_ptr: public_buffer.into_raw(),
+        public_key_len: public_key_bytes.len(),
+        secret_key_ptr: secret_buffer.into_raw(),
+        secret_key_len: secret_key_bytes.len(),
+    }))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn dilithium_sign(
+pub extern "C" fn dilithium_sign(
+    ctx: *mut CDilithiumContext,
     secret_key_ptr: *const u8,
     secret_key_len: size_t,
     message_ptr: *const u8,
@@ -110,25 +231,30 @@ pub unsafe extern "C" fn dilithium_sign(
     signature_out: *mut *mut u8,
     signature_len_out: *mut size_t,
 ) -> c_int {
+    if ctx.is_null() {
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let ctx = unsafe { &*ctx };
+
     if signature_out.is_null() || signature_len_out.is_null() {
-        set_last_error("Output pointers cannot be null");
+        context_set_last_error(ctx, "Output pointers cannot be null");
         return FFIErrorCode::NullPointer as c_int;
     }
 
     if let Err(err) = validate_buffer_params(secret_key_ptr, secret_key_len) {
-        set_last_error("Invalid secret key parameters");
+        context_set_last_error(ctx, "Invalid secret key parameters");
         return err as c_int;
     }
 
     if let Err(err) = validate_buffer_params(message_ptr, message_len) {
-        set_last_error("Invalid message parameters");
+        context_set_last_error(ctx, "Invalid message parameters");
         return err as c_int;
     }
 
     let secret_key_slice = match safe_slice_from_raw(secret_key_ptr, secret_key_len) {
         Ok(slice) => slice,
         Err(err) => {
-            set_last_error("Failed to create secret key slice");
+            context_set_last_error(ctx, "Failed to create secret key slice");
             return err as c_int;
         }
     };
@@ -136,26 +262,36 @@ pub unsafe extern "C" fn dilithium_sign(
     let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
         Ok(slice) => slice,
         Err(err) => {
-            set_last_error("Failed to create message slice");
+            context_set_last_error(ctx, "Failed to create message slice");
             return err as c_int;
         }
     };
 
-    let secret_key = match SecretKey::from_bytes(secret_key_slice) {
+    // `SecretKey::from_bytes` copies `secret_key_slice` into its own,
+    // pqcrypto-owned storage, so the copy we control is this scratch buffer,
+    // not `secret_key_slice` (the caller's memory, freed by the caller) or
+    // `secret_key` (pqcrypto's opaque type has no `Zeroize` impl to hook).
+    // `Zeroizing` wipes it the moment we drop it below, rather than leaving
+    // it to linger for the rest of the call.
+    let secret_key_scratch = Zeroizing::new(secret_key_slice.to_vec());
+    let secret_key = match SecretKey::from_bytes(&secret_key_scratch) {
         Ok(key) => key,
         Err(_) => {
-            set_last_error("Invalid secret key format");
+            context_set_last_error(ctx, "Invalid secret key format");
             return FFIErrorCode::InvalidKeyFormat as c_int;
         }
     };
+    drop(secret_key_scratch);
 
-    let signed_message = sign(message_slice, &secret_key);
+    let signed_message = ctx
+        .power_analysis
+        .protected_operation(|| sign(message_slice, &secret_key));
     let signature_bytes = signed_message.as_bytes();
 
-    let signature_ptr = match secure_allocate(signature_bytes.len()) {
-        Ok(ptr) => ptr,
-        Err(_) => {
-            set_last_error("Failed to allocate memory for signature");
+    let mut sig_buffer = match FFIBuffer::new(signature_bytes.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate memory for signature: {e}"));
             return FFIErrorCode::AllocationFailed as c_int;
         }
     };
@@ -163,10 +299,10 @@ pub unsafe extern "C" fn dilithium_sign(
     unsafe {
         ptr::copy_nonoverlapping(
             signature_bytes.as_ptr(),
-            signature_ptr,
+            sig_buffer.as_mut_ptr(),
             signature_bytes.len(),
         );
-        *signature_out = signature_ptr;
+        *signature_out = sig_buffer.into_raw();
         *signature_len_out = signature_bytes.len();
     }
 
@@ -175,6 +311,7 @@ pub unsafe extern "C" fn dilithium_sign(
 
 #[no_mangle]
 pub extern "C" fn dilithium_verify(
+    ctx: *mut CDilithiumContext,
     public_key_ptr: *const u8,
     public_key_len: size_t,
     message_ptr: *const u8,
@@ -182,25 +319,30 @@ pub extern "C" fn dilithium_verify(
     signature_ptr: *const u8,
     signature_len: size_t,
 ) -> c_int {
+    if ctx.is_null() {
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let ctx = unsafe { &*ctx };
+
     if let Err(err) = validate_buffer_params(public_key_ptr, public_key_len) {
-        set_last_error("Invalid public key parameters");
+        context_set_last_error(ctx, "Invalid public key parameters");
         return err as c_int;
     }
 
     if let Err(err) = validate_buffer_params(message_ptr, message_len) {
-        set_last_error("Invalid message parameters");
+        context_set_last_error(ctx, "Invalid message parameters");
         return err as c_int;
     }
 
     if let Err(err) = validate_buffer_params(signature_ptr, signature_len) {
-        set_last_error("Invalid signature parameters");
+        context_set_last_error(ctx, "Invalid signature parameters");
         return err as c_int;
     }
 
     let public_key_slice = match safe_slice_from_raw(public_key_ptr, public_key_len) {
         Ok(slice) => slice,
         Err(err) => {
-            set_last_error("Failed to create public key slice");
+            context_set_last_error(ctx, "Failed to create public key slice");
             return err as c_int;
         }
     };
@@ -208,7 +350,7 @@ pub extern "C" fn dilithium_verify(
     let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
         Ok(slice) => slice,
         Err(err) => {
-            set_last_error("Failed to create message slice");
+            context_set_last_error(ctx, "Failed to create message slice");
             return err as c_int;
         }
     };
@@ -216,7 +358,7 @@ pub extern "C" fn dilithium_verify(
     let signature_slice = match safe_slice_from_raw(signature_ptr, signature_len) {
         Ok(slice) => slice,
         Err(err) => {
-            set_last_error("Failed to create signature slice");
+            context_set_last_error(ctx, "Failed to create signature slice");
             return err as c_int;
         }
     };
@@ -224,7 +366,7 @@ pub extern "C" fn dilithium_verify(
     let public_key = match PublicKey::from_bytes(public_key_slice) {
         Ok(key) => key,
         Err(_) => {
-            set_last_error("Invalid public key format");
+            context_set_last_error(ctx, "Invalid public key format");
             return FFIErrorCode::InvalidKeyFormat as c_int;
         }
     };
@@ -232,71 +374,507 @@ pub extern "C" fn dilithium_verify(
     let signed_message = match SignedMessage::from_bytes(signature_slice) {
         Ok(msg) => msg,
         Err(_) => {
-            set_last_error("Invalid signature format");
+            context_set_last_error(ctx, "Invalid signature format");
             return FFIErrorCode::InvalidKeyFormat as c_int;
         }
     };
 
-    match open(&signed_message, &public_key) {
+    match ctx
+        .power_analysis
+        .protected_operation(|| open(&signed_message, &public_key))
+    {
         Ok(verified_message) => {
             if verified_message == message_slice {
                 FFIErrorCode::Success as c_int
             } else {
-                set_last_error("Message verification failed - content mismatch");
+                context_set_last_error(ctx, "Message verification failed - content mismatch");
                 FFIErrorCode::SignatureVerificationFailed as c_int
             }
         }
         Err(_) => {
-            set_last_error("Signature verification failed");
+            context_set_last_error(ctx, "Signature verification failed");
             FFIErrorCode::SignatureVerificationFailed as c_int
         }
     }
 }
 
+/// `ffi_buffer_free`/`ffi_secure_buffer_free` already zeroize their buffer
+/// before releasing it back to the allocator (see their doc comments in
+/// `ffi::memory`), so callers freeing a keypair through here never leak
+/// residual key bytes to whatever reuses the allocation next.
 #[no_mangle]
-pub unsafe extern "C" fn dilithium_keypair_free(keypair: *mut CDilithiumKeyPair) {
+pub extern "C" fn dilithium_keypair_free(keypair: *mut CDilithiumKeyPair) {
     if keypair.is_null() {
         return;
     }
 
     unsafe {
-        let keypair_ref = &*keypair;
+        let keypair = Box::from_raw(keypair);
 
-        if !keypair_ref.public_key_ptr.is_null() {
-            secure_deallocate(keypair_ref.public_key_ptr, keypair_ref.public_key_len);
+        if !keypair.public_key_ptr.is_null() {
+            ffi_buffer_free(keypair.public_key_ptr, keypair.public_key_len);
         }
 
-        if !keypair_ref.secret_key_ptr.is_null() {
-            secure_deallocate(keypair_ref.secret_key_ptr, keypair_ref.secret_key_len);
+        if !keypair.secret_key_ptr.is_null() {
+            ffi_secure_buffer_free(keypair.secret_key_ptr, keypair.secret_key_len);
         }
-
-        secure_deallocate(keypair as *mut u8, std::mem::size_of::<CDilithiumKeyPair>());
     }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn dilithium_signature_free(signature: *mut CDilithiumSignature) {
+pub extern "C" fn dilithium_signature_free(signature: *mut CDilithiumSignature) {
     if signature.is_null() {
         return;
     }
 
     unsafe {
-        let signature_ref = &*signature;
+        let signature = Box::from_raw(signature);
+
+        if !signature.signature_ptr.is_null() {
+            ffi_buffer_free(signature.signature_ptr, signature.signature_len);
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn dilithium_buffer_free(ptr: *mut u8, len: size_t) {
+    ffi_buffer_free(ptr, len);
+}
+
+/// Serializes `keypair` into a self-describing `magic || version ||
+/// algorithm || length-prefixed public key || length-prefixed secret key`
+/// envelope, so it can be written to disk, handed to another process, or
+/// rotated back in later without the caller hardcoding either key's length.
+/// The returned buffer must be released with [`dilithium_buffer_free`].
+#[no_mangle]
+pub extern "C" fn dilithium_keypair_export(
+    ctx: *mut CDilithiumContext,
+    keypair: *const CDilithiumKeyPair,
+    export_out: *mut *mut u8,
+    export_len_out: *mut size_t,
+) -> c_int {
+    if ctx.is_null() {
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let ctx = unsafe { &*ctx };
+
+    if keypair.is_null() || export_out.is_null() || export_len_out.is_null() {
+        context_set_last_error(ctx, "Keypair or output pointers cannot be null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+
+    let keypair = unsafe { &*keypair };
+
+    if keypair.public_key_ptr.is_null() || keypair.secret_key_ptr.is_null() {
+        context_set_last_error(ctx, "Keypair has null key buffers");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+
+    let public_key_slice =
+        match safe_slice_from_raw(keypair.public_key_ptr, keypair.public_key_len) {
+            Ok(slice) => slice,
+            Err(err) => {
+                context_set_last_error(ctx, "Failed to create public key slice");
+                return err as c_int;
+            }
+        };
+
+    let secret_key_slice =
+        match safe_slice_from_raw(keypair.secret_key_ptr, keypair.secret_key_len) {
+            Ok(slice) => slice,
+            Err(err) => {
+                context_set_last_error(ctx, "Failed to create secret key slice");
+                return err as c_int;
+            }
+        };
+
+    let mut envelope = Vec::with_capacity(
+        DILITHIUM_EXPORT_MAGIC.len() + 2 + 8 + public_key_slice.len() + secret_key_slice.len(),
+    );
+    envelope.extend_from_slice(&DILITHIUM_EXPORT_MAGIC);
+    envelope.push(DILITHIUM_EXPORT_VERSION);
+    envelope.push(DILITHIUM_ALGORITHM_MLDSA65);
+    write_length_prefixed(&mut envelope, public_key_slice);
+    write_length_prefixed(&mut envelope, secret_key_slice);
+
+    let mut export_buffer = match FFIBuffer::new_secure(envelope.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate export envelope: {e}"));
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        ptr::copy_nonoverlapping(envelope.as_ptr(), export_buffer.as_mut_ptr(), envelope.len());
+        *export_out = export_buffer.into_raw();
+        *export_len_out = envelope.len();
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
+/// Parses an envelope produced by [`dilithium_keypair_export`] and
+/// reconstructs a `CDilithiumKeyPair` behind fresh, secure allocations.
+/// Rejects anything that isn't this exact magic/version/algorithm, or whose
+/// length-prefixed fields don't round-trip cleanly, with a descriptive error
+/// on `ctx` rather than guessing at a partial keypair.
+#[no_mangle]
+pub extern "C" fn dilithium_keypair_import(
+    ctx: *mut CDilithiumContext,
+    bytes_ptr: *const u8,
+    bytes_len: size_t,
+) -> *mut CDilithiumKeyPair {
+    if ctx.is_null() {
+        return ptr::null_mut();
+    }
+    let ctx = unsafe { &*ctx };
+
+    if let Err(err) = validate_buffer_params(bytes_ptr, bytes_len) {
+        context_set_last_error(ctx, "Invalid export envelope parameters");
+        let _ = err;
+        return ptr::null_mut();
+    }
+
+    let bytes = match safe_slice_from_raw(bytes_ptr, bytes_len) {
+        Ok(slice) => slice,
+        Err(_) => {
+            context_set_last_error(ctx, "Failed to create export envelope slice");
+            return ptr::null_mut();
+        }
+    };
+
+    let mut cursor = match read_export_header(bytes) {
+        Ok(cursor) => cursor,
+        Err(e) => {
+            context_set_last_error(ctx, e);
+            return ptr::null_mut();
+        }
+    };
+
+    let public_key_bytes = match read_length_prefixed(bytes, &mut cursor) {
+        Ok(field) => field,
+        Err(e) => {
+            context_set_last_error(ctx, e);
+            return ptr::null_mut();
+        }
+    };
+
+    let secret_key_bytes = match read_length_prefixed(bytes, &mut cursor) {
+        Ok(field) => field,
+        Err(e) => {
+            context_set_last_error(ctx, e);
+            return ptr::null_mut();
+        }
+    };
+
+    if cursor != bytes.len() {
+        context_set_last_error(ctx, "Trailing bytes after export envelope fields");
+        return ptr::null_mut();
+    }
+
+    if PublicKey::from_bytes(public_key_bytes).is_err() {
+        context_set_last_error(ctx, "Invalid public key format in export envelope");
+        return ptr::null_mut();
+    }
+    let secret_key_scratch = Zeroizing::new(secret_key_bytes.to_vec());
+    if SecretKey::from_bytes(&secret_key_scratch).is_err() {
+        context_set_last_error(ctx, "Invalid secret key format in export envelope");
+        return ptr::null_mut();
+    }
+
+    let mut public_buffer = match FFIBuffer::new(public_key_bytes.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate memory for public key: {e}"));
+            return ptr::null_mut();
+        }
+    };
 
-        if !signature_ref.signature_ptr.is_null() {
-            secure_deallocate(signature_ref.signature_ptr, signature_ref.signature_len);
+    let mut secret_buffer = match FFIBuffer::new_secure(secret_key_scratch.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate memory for secret key: {e}"));
+            return ptr::null_mut();
         }
+    };
 
-        secure_deallocate(
-            signature as *mut u8,
-            std::mem::size_of::<CDilithiumSignature>(),
+    unsafe {
+        ptr::copy_nonoverlapping(
+            public_key_bytes.as_ptr(),
+            public_buffer.as_mut_ptr(),
+            public_key_bytes.len(),
+        );
+        ptr::copy_nonoverlapping(
+            secret_key_scratch.as_ptr(),
+            secret_buffer.as_mut_ptr(),
+            secret_key_scratch.len(),
         );
     }
+    drop(secret_key_scratch);
+
+    Box::into_raw(Box::new(CDilithiumKeyPair {
+        public_key_ptr: public_buffer.into_raw(),
+        public_key_len: public_key_bytes.len(),
+        secret_key_ptr: secret_buffer.into_raw(),
+        secret_key_len: secret_key_bytes.len(),
+    }))
 }
 
+/// Serializes `signature` into the same envelope format as
+/// [`dilithium_keypair_export`], with a single length-prefixed signature
+/// field in place of the two key fields. The returned buffer must be
+/// released with [`dilithium_buffer_free`].
 #[no_mangle]
-pub extern "C" fn dilithium_buffer_free(ptr: *mut u8, len: size_t) {
-    if !ptr.is_null() && len > 0 {
-        secure_deallocate(ptr, len);
+pub extern "C" fn dilithium_signature_export(
+    ctx: *mut CDilithiumContext,
+    signature: *const CDilithiumSignature,
+    export_out: *mut *mut u8,
+    export_len_out: *mut size_t,
+) -> c_int {
+    if ctx.is_null() {
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let ctx = unsafe { &*ctx };
+
+    if signature.is_null() || export_out.is_null() || export_len_out.is_null() {
+        context_set_last_error(ctx, "Signature or output pointers cannot be null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+
+    let signature = unsafe { &*signature };
+
+    if signature.signature_ptr.is_null() {
+        context_set_last_error(ctx, "Signature has a null buffer");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+
+    let signature_slice =
+        match safe_slice_from_raw(signature.signature_ptr, signature.signature_len) {
+            Ok(slice) => slice,
+            Err(err) => {
+                context_set_last_error(ctx, "Failed to create signature slice");
+                return err as c_int;
+            }
+        };
+
+    let mut envelope =
+        Vec::with_capacity(DILITHIUM_EXPORT_MAGIC.len() + 2 + 4 + signature_slice.len());
+    envelope.extend_from_slice(&DILITHIUM_EXPORT_MAGIC);
+    envelope.push(DILITHIUM_EXPORT_VERSION);
+    envelope.push(DILITHIUM_ALGORITHM_MLDSA65);
+    write_length_prefixed(&mut envelope, signature_slice);
+
+    let mut export_buffer = match FFIBuffer::new(envelope.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate export envelope: {e}"));
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        ptr::copy_nonoverlapping(envelope.as_ptr(), export_buffer.as_mut_ptr(), envelope.len());
+        *export_out = export_buffer.into_raw();
+        *export_len_out = envelope.len();
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
+/// Parses an envelope produced by [`dilithium_signature_export`] and
+/// reconstructs a `CDilithiumSignature` behind a fresh allocation, applying
+/// the same header and trailing-bytes checks as
+/// [`dilithium_keypair_import`].
+#[no_mangle]
+pub extern "C" fn dilithium_signature_import(
+    ctx: *mut CDilithiumContext,
+    bytes_ptr: *const u8,
+    bytes_len: size_t,
+) -> *mut CDilithiumSignature {
+    if ctx.is_null() {
+        return ptr::null_mut();
+    }
+    let ctx = unsafe { &*ctx };
+
+    if let Err(_err) = validate_buffer_params(bytes_ptr, bytes_len) {
+        context_set_last_error(ctx, "Invalid export envelope parameters");
+        return ptr::null_mut();
+    }
+
+    let bytes = match safe_slice_from_raw(bytes_ptr, bytes_len) {
+        Ok(slice) => slice,
+        Err(_) => {
+            context_set_last_error(ctx, "Failed to create export envelope slice");
+            return ptr::null_mut();
+        }
+    };
+
+    let mut cursor = match read_export_header(bytes) {
+        Ok(cursor) => cursor,
+        Err(e) => {
+            context_set_last_error(ctx, e);
+            return ptr::null_mut();
+        }
+    };
+
+    let signature_bytes = match read_length_prefixed(bytes, &mut cursor) {
+        Ok(field) => field,
+        Err(e) => {
+            context_set_last_error(ctx, e);
+            return ptr::null_mut();
+        }
+    };
+
+    if cursor != bytes.len() {
+        context_set_last_error(ctx, "Trailing bytes after export envelope fields");
+        return ptr::null_mut();
+    }
+
+    if SignedMessage::from_bytes(signature_bytes).is_err() {
+        context_set_last_error(ctx, "Invalid signature format in export envelope");
+        return ptr::null_mut();
+    }
+
+    let mut sig_buffer = match FFIBuffer::new(signature_bytes.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            context_set_last_error(ctx, &format!("Failed to allocate memory for signature: {e}"));
+            return ptr::null_mut();
+        }
+    };
+
+    unsafe {
+        ptr::copy_nonoverlapping(
+            signature_bytes.as_ptr(),
+            sig_buffer.as_mut_ptr(),
+            signature_bytes.len(),
+        );
+    }
+
+    Box::into_raw(Box::new(CDilithiumSignature {
+        signature_ptr: sig_buffer.into_raw(),
+        signature_len: signature_bytes.len(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zeroize::Zeroize;
+
+    /// `dilithium_sign`'s `secret_key_scratch` is a local `Zeroizing<Vec<u8>>`
+    /// dropped (and thus zeroized) before the function returns, so its actual
+    /// post-drop bytes aren't observable from outside the call without an
+    /// invasive test-only hook this file doesn't otherwise use. This test
+    /// instead checks the precondition `dilithium_sign` relies on: that
+    /// `Zeroizing::drop` really does clear the buffer it wraps. The
+    /// `dilithium_sign_and_verify_round_trip` test below exercises the actual
+    /// scratch-buffer code path end to end.
+    #[test]
+    fn zeroizing_clears_its_buffer_on_drop() {
+        let mut scratch = Zeroizing::new(vec![0xABu8; 64]);
+        assert!(scratch.iter().any(|&b| b != 0));
+
+        scratch.zeroize();
+
+        assert!(scratch.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn dilithium_sign_and_verify_round_trip() {
+        let ctx = dilithium_context_new();
+        assert!(!ctx.is_null());
+
+        let keypair = dilithium_keypair_generate(ctx);
+        assert!(!keypair.is_null());
+        let keypair_ref = unsafe { &*keypair };
+
+        let message = b"exercise the secret_key_scratch path in dilithium_sign";
+        let mut signature_ptr: *mut u8 = ptr::null_mut();
+        let mut signature_len: size_t = 0;
+
+        let sign_result = dilithium_sign(
+            ctx,
+            keypair_ref.secret_key_ptr,
+            keypair_ref.secret_key_len,
+            message.as_ptr(),
+            message.len(),
+            &mut signature_ptr,
+            &mut signature_len,
+        );
+        assert_eq!(sign_result, FFIErrorCode::Success as c_int);
+
+        let verify_result = dilithium_verify(
+            ctx,
+            keypair_ref.public_key_ptr,
+            keypair_ref.public_key_len,
+            message.as_ptr(),
+            message.len(),
+            signature_ptr,
+            signature_len,
+        );
+        assert_eq!(verify_result, FFIErrorCode::Success as c_int);
+
+        dilithium_buffer_free(signature_ptr, signature_len);
+        dilithium_keypair_free(keypair);
+        dilithium_context_free(ctx);
+    }
+
+    #[test]
+    fn length_prefixed_round_trips() {
+        let mut buf = Vec::new();
+        write_length_prefixed(&mut buf, b"public");
+        write_length_prefixed(&mut buf, b"secret");
+
+        let mut cursor = 0;
+        assert_eq!(read_length_prefixed(&buf, &mut cursor).unwrap(), b"public");
+        assert_eq!(read_length_prefixed(&buf, &mut cursor).unwrap(), b"secret");
+        assert_eq!(cursor, buf.len());
+    }
+
+    #[test]
+    fn read_length_prefixed_rejects_truncated_field() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&10u32.to_be_bytes());
+        buf.extend_from_slice(b"short");
+
+        let mut cursor = 0;
+        assert!(read_length_prefixed(&buf, &mut cursor).is_err());
+    }
+
+    #[test]
+    fn read_export_header_rejects_bad_magic() {
+        let mut bytes = vec![0u8; 6];
+        bytes[0..4].copy_from_slice(b"NOPE");
+        bytes[4] = DILITHIUM_EXPORT_VERSION;
+        bytes[5] = DILITHIUM_ALGORITHM_MLDSA65;
+
+        assert!(read_export_header(&bytes).is_err());
+    }
+
+    #[test]
+    fn read_export_header_rejects_unknown_version_and_algorithm() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&DILITHIUM_EXPORT_MAGIC);
+        bytes.push(DILITHIUM_EXPORT_VERSION + 1);
+        bytes.push(DILITHIUM_ALGORITHM_MLDSA65);
+        assert!(read_export_header(&bytes).is_err());
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&DILITHIUM_EXPORT_MAGIC);
+        bytes.push(DILITHIUM_EXPORT_VERSION);
+        bytes.push(DILITHIUM_ALGORITHM_MLDSA65 + 1);
+        assert!(read_export_header(&bytes).is_err());
+    }
+
+    #[test]
+    fn read_export_header_accepts_known_header() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&DILITHIUM_EXPORT_MAGIC);
+        bytes.push(DILITHIUM_EXPORT_VERSION);
+        bytes.push(DILITHIUM_ALGORITHM_MLDSA65);
+
+        assert_eq!(read_export_header(&bytes).unwrap(), bytes.len());
     }
 }
@@ -0,0 +1,304 @@
+use crate::ffi::memory::{safe_slice_from_raw, set_last_error, FFIBuffer, FFIErrorCode};
+use crate::ffi::mldsa_ffi::mldsa_level_from_c_int;
+use crate::ffi::monitoring::record_operation_time;
+use crate::ffi::secret_registry::{self, MLDSASecretHandle};
+use crate::hybrid::{
+    generate_hybrid_keypair, hybrid_sign as core_hybrid_sign, hybrid_verify as core_hybrid_verify,
+};
+use libc::size_t;
+use std::os::raw::c_int;
+
+#[repr(C)]
+pub struct CHybridKeyPair {
+    pub classical_public_key_ptr: *mut u8,
+    pub classical_public_key_len: size_t,
+    pub classical_secret_key_handle: MLDSASecretHandle,
+    pub pqc_public_key_ptr: *mut u8,
+    pub pqc_public_key_len: size_t,
+    pub pqc_secret_key_handle: MLDSASecretHandle,
+    pub level: c_int,
+}
+
+#[no_mangle]
+pub extern "C" fn hybrid_keypair_generate(level: c_int) -> *mut CHybridKeyPair {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return std::ptr::null_mut();
+        }
+    };
+
+    record_operation_time("hybrid_keygen", || match generate_hybrid_keypair(level) {
+        Ok(keypair) => {
+            let mut classical_buffer = match FFIBuffer::new(keypair.classical_public_key.len()) {
+                Ok(buf) => buf,
+                Err(e) => {
+                    let message = format!("Failed to allocate classical public key buffer: {e}");
+                    set_last_error(FFIErrorCode::from(e), &message);
+                    return std::ptr::null_mut();
+                }
+            };
+
+            let mut pqc_buffer = match FFIBuffer::new(keypair.pqc_public_key.len()) {
+                Ok(buf) => buf,
+                Err(e) => {
+                    let message = format!("Failed to allocate PQC public key buffer: {e}");
+                    set_last_error(FFIErrorCode::from(e), &message);
+                    return std::ptr::null_mut();
+                }
+            };
+
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    keypair.classical_public_key.as_ptr(),
+                    classical_buffer.as_mut_ptr(),
+                    keypair.classical_public_key.len(),
+                );
+                std::ptr::copy_nonoverlapping(
+                    keypair.pqc_public_key.as_ptr(),
+                    pqc_buffer.as_mut_ptr(),
+                    keypair.pqc_public_key.len(),
+                );
+            }
+
+            let result = Box::new(CHybridKeyPair {
+                classical_public_key_ptr: classical_buffer.into_raw(),
+                classical_public_key_len: keypair.classical_public_key.len(),
+                classical_secret_key_handle: secret_registry::register(
+                    keypair.classical_secret_key,
+                ),
+                pqc_public_key_ptr: pqc_buffer.into_raw(),
+                pqc_public_key_len: keypair.pqc_public_key.len(),
+                pqc_secret_key_handle: secret_registry::register(keypair.pqc_secret_key),
+                level: level as c_int,
+            });
+
+            Box::into_raw(result)
+        }
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("Hybrid keypair generation failed: {e}"),
+            );
+            std::ptr::null_mut()
+        }
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn hybrid_sign(
+    level: c_int,
+    classical_secret_key_handle: MLDSASecretHandle,
+    pqc_secret_key_handle: MLDSASecretHandle,
+    message_ptr: *const u8,
+    message_len: size_t,
+    signature_out: *mut *mut u8,
+    signature_len_out: *mut size_t,
+) -> c_int {
+    if signature_out.is_null() || signature_len_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let signing_result = secret_registry::with_two_secrets(
+        classical_secret_key_handle,
+        pqc_secret_key_handle,
+        |classical, pqc| {
+            record_operation_time("hybrid_sign", || {
+                core_hybrid_sign(level, classical, pqc, message_slice)
+            })
+        },
+    );
+
+    let composite = match signing_result {
+        Some(Ok(composite)) => composite,
+        Some(Err(e)) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("Hybrid signing failed: {e}"),
+            );
+            return FFIErrorCode::CryptoError as c_int;
+        }
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidKeyFormat,
+                "Unknown or already-released classical or PQC secret key handle",
+            );
+            return FFIErrorCode::InvalidKeyFormat as c_int;
+        }
+    };
+
+    let mut sig_buffer = match FFIBuffer::new(composite.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                &format!("Failed to allocate signature buffer: {e}"),
+            );
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(composite.as_ptr(), sig_buffer.as_mut_ptr(), composite.len());
+        *signature_out = sig_buffer.into_raw();
+        *signature_len_out = composite.len();
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn hybrid_verify(
+    level: c_int,
+    classical_public_key_ptr: *const u8,
+    classical_public_key_len: size_t,
+    pqc_public_key_ptr: *const u8,
+    pqc_public_key_len: size_t,
+    message_ptr: *const u8,
+    message_len: size_t,
+    signature_ptr: *const u8,
+    signature_len: size_t,
+) -> c_int {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let classical_public_key_slice =
+        match safe_slice_from_raw(classical_public_key_ptr, classical_public_key_len) {
+            Ok(slice) => slice,
+            Err(e) => {
+                set_last_error(
+                    FFIErrorCode::InvalidInput,
+                    &format!("Invalid classical public key buffer: {e}"),
+                );
+                return FFIErrorCode::InvalidInput as c_int;
+            }
+        };
+
+    let pqc_public_key_slice = match safe_slice_from_raw(pqc_public_key_ptr, pqc_public_key_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid PQC public key buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let signature_slice = match safe_slice_from_raw(signature_ptr, signature_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid signature buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    match core_hybrid_verify(
+        level,
+        classical_public_key_slice,
+        pqc_public_key_slice,
+        message_slice,
+        signature_slice,
+    ) {
+        Ok(true) => FFIErrorCode::Success as c_int,
+        Ok(false) => {
+            set_last_error(
+                FFIErrorCode::SignatureVerificationFailed,
+                "Hybrid signature verification failed",
+            );
+            FFIErrorCode::SignatureVerificationFailed as c_int
+        }
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("Hybrid verification failed: {e}"),
+            );
+            FFIErrorCode::CryptoError as c_int
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn hybrid_keypair_free(keypair: *mut CHybridKeyPair) {
+    if !keypair.is_null() {
+        unsafe {
+            let keypair = Box::from_raw(keypair);
+
+            if !keypair.classical_public_key_ptr.is_null() && keypair.classical_public_key_len > 0 {
+                let layout =
+                    std::alloc::Layout::array::<u8>(keypair.classical_public_key_len).unwrap();
+                let slice = std::slice::from_raw_parts_mut(
+                    keypair.classical_public_key_ptr,
+                    keypair.classical_public_key_len,
+                );
+                slice.fill(0);
+                std::alloc::dealloc(keypair.classical_public_key_ptr, layout);
+            }
+
+            if !keypair.pqc_public_key_ptr.is_null() && keypair.pqc_public_key_len > 0 {
+                let layout = std::alloc::Layout::array::<u8>(keypair.pqc_public_key_len).unwrap();
+                let slice = std::slice::from_raw_parts_mut(
+                    keypair.pqc_public_key_ptr,
+                    keypair.pqc_public_key_len,
+                );
+                slice.fill(0);
+                std::alloc::dealloc(keypair.pqc_public_key_ptr, layout);
+            }
+
+            secret_registry::release(keypair.classical_secret_key_handle);
+            secret_registry::release(keypair.pqc_secret_key_handle);
+        }
+    }
+}
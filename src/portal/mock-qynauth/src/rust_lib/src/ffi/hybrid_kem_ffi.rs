@@ -0,0 +1,366 @@
+use crate::ffi::memory::{
+    safe_slice_from_raw, safe_typed_slice_from_raw, set_last_error, FFIBuffer, FFIErrorCode,
+    FfiLayout,
+};
+use crate::ffi::mlkem_ffi::{MLKEMField, MLKEMLayout};
+use crate::ffi::monitoring::record_operation_time;
+use crate::ffi::secret_registry::{self, MLDSASecretHandle};
+use crate::hybrid::{
+    generate_hybrid_kem_keypair, hybrid_decapsulate as core_hybrid_decapsulate,
+    hybrid_encapsulate as core_hybrid_encapsulate,
+};
+use crate::MLKEMLevel;
+use libc::size_t;
+use secrecy::{ExposeSecret, Secret};
+use std::os::raw::c_int;
+
+/// Every X25519 public/secret key this FFI handles is exactly this long, so
+/// a mismatched buffer is rejected with a size-specific error code instead
+/// of an opaque crypto failure deep inside `x25519_dalek`.
+const X25519_KEY_LEN: usize = 32;
+
+/// Which field of an X25519 keypair an [`X25519Layout`] checks the length
+/// of.
+#[derive(Debug, Clone, Copy)]
+enum X25519Field {
+    PublicKey,
+    SecretKey,
+}
+
+struct X25519Layout {
+    field: X25519Field,
+}
+
+impl FfiLayout for X25519Layout {
+    fn layout_name(&self) -> String {
+        match self.field {
+            X25519Field::PublicKey => "X25519 public key".to_string(),
+            X25519Field::SecretKey => "X25519 secret key".to_string(),
+        }
+    }
+
+    fn expected_len(&self) -> usize {
+        X25519_KEY_LEN
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self.field {
+            X25519Field::PublicKey => FFIErrorCode::InvalidPublicKeySize,
+            X25519Field::SecretKey => FFIErrorCode::InvalidSecretKeySize,
+        }
+    }
+}
+
+#[repr(C)]
+pub struct CHybridKemKeyPair {
+    pub classical_public_key_ptr: *mut u8,
+    pub classical_public_key_len: size_t,
+    pub classical_secret_key_handle: MLDSASecretHandle,
+    pub pqc_public_key_ptr: *mut u8,
+    pub pqc_public_key_len: size_t,
+    pub pqc_secret_key_handle: MLDSASecretHandle,
+}
+
+/// Generates a hybrid X25519 + ML-KEM-768 KEM keypair. Unlike
+/// [`crate::ffi::hybrid_ffi::hybrid_keypair_generate`] (the signature side),
+/// there is no security level parameter: the KEM combiner is pinned to
+/// ML-KEM-768, matching [`crate::hybrid::generate_hybrid_kem_keypair`].
+#[no_mangle]
+pub extern "C" fn hybrid_kem_keypair_generate() -> *mut CHybridKemKeyPair {
+    record_operation_time("hybrid_kem_keygen", || match generate_hybrid_kem_keypair() {
+        Ok(keypair) => {
+            let mut classical_buffer = match FFIBuffer::new(keypair.classical_public_key.len()) {
+                Ok(buf) => buf,
+                Err(e) => {
+                    let message = format!("Failed to allocate classical public key buffer: {e}");
+                    set_last_error(FFIErrorCode::from(e), &message);
+                    return std::ptr::null_mut();
+                }
+            };
+
+            let mut pqc_buffer = match FFIBuffer::new(keypair.pqc_public_key.len()) {
+                Ok(buf) => buf,
+                Err(e) => {
+                    let message = format!("Failed to allocate PQC public key buffer: {e}");
+                    set_last_error(FFIErrorCode::from(e), &message);
+                    return std::ptr::null_mut();
+                }
+            };
+
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    keypair.classical_public_key.as_ptr(),
+                    classical_buffer.as_mut_ptr(),
+                    keypair.classical_public_key.len(),
+                );
+                std::ptr::copy_nonoverlapping(
+                    keypair.pqc_public_key.as_ptr(),
+                    pqc_buffer.as_mut_ptr(),
+                    keypair.pqc_public_key.len(),
+                );
+            }
+
+            let result = Box::new(CHybridKemKeyPair {
+                classical_public_key_ptr: classical_buffer.into_raw(),
+                classical_public_key_len: keypair.classical_public_key.len(),
+                classical_secret_key_handle: secret_registry::register(Secret::new(
+                    keypair.classical_secret_key.expose_secret().to_vec(),
+                )),
+                pqc_public_key_ptr: pqc_buffer.into_raw(),
+                pqc_public_key_len: keypair.pqc_public_key.len(),
+                pqc_secret_key_handle: secret_registry::register(keypair.pqc_secret_key),
+            });
+
+            Box::into_raw(result)
+        }
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("Hybrid KEM keypair generation failed: {e}"),
+            );
+            std::ptr::null_mut()
+        }
+    })
+}
+
+/// Encapsulates a shared secret against a recipient's hybrid public key,
+/// combining an X25519 Diffie-Hellman and an ML-KEM-768 encapsulation
+/// through HKDF-SHA256. See [`crate::hybrid::hybrid_encapsulate`].
+#[no_mangle]
+pub extern "C" fn hybrid_kem_encapsulate(
+    classical_public_key_ptr: *const u8,
+    classical_public_key_len: size_t,
+    pqc_public_key_ptr: *const u8,
+    pqc_public_key_len: size_t,
+    shared_secret_out: *mut *mut u8,
+    shared_secret_len_out: *mut size_t,
+    ciphertext_out: *mut *mut u8,
+    ciphertext_len_out: *mut size_t,
+) -> c_int {
+    if shared_secret_out.is_null()
+        || shared_secret_len_out.is_null()
+        || ciphertext_out.is_null()
+        || ciphertext_len_out.is_null()
+    {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let classical_public_key_slice = match safe_typed_slice_from_raw(
+        classical_public_key_ptr,
+        classical_public_key_len,
+        X25519Layout {
+            field: X25519Field::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let pqc_public_key_slice = match safe_typed_slice_from_raw(
+        pqc_public_key_ptr,
+        pqc_public_key_len,
+        MLKEMLayout {
+            level: MLKEMLevel::MlKem768,
+            field: MLKEMField::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    record_operation_time("hybrid_kem_encap", || {
+        match core_hybrid_encapsulate(classical_public_key_slice, pqc_public_key_slice) {
+            Ok(result) => {
+                let shared_secret = result.shared_secret.expose_secret();
+                let ciphertext = result.ciphertext;
+
+                let mut ss_buffer = match FFIBuffer::new_secure(shared_secret.len()) {
+                    Ok(buf) => buf,
+                    Err(e) => {
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate shared secret buffer: {e}"),
+                        );
+                        return FFIErrorCode::AllocationFailed as c_int;
+                    }
+                };
+
+                let mut ct_buffer = match FFIBuffer::new(ciphertext.len()) {
+                    Ok(buf) => buf,
+                    Err(e) => {
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate ciphertext buffer: {e}"),
+                        );
+                        return FFIErrorCode::AllocationFailed as c_int;
+                    }
+                };
+
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        shared_secret.as_ptr(),
+                        ss_buffer.as_mut_ptr(),
+                        shared_secret.len(),
+                    );
+                    std::ptr::copy_nonoverlapping(
+                        ciphertext.as_ptr(),
+                        ct_buffer.as_mut_ptr(),
+                        ciphertext.len(),
+                    );
+
+                    *shared_secret_out = ss_buffer.into_raw();
+                    *shared_secret_len_out = shared_secret.len();
+                    *ciphertext_out = ct_buffer.into_raw();
+                    *ciphertext_len_out = ciphertext.len();
+                }
+
+                FFIErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(
+                    FFIErrorCode::CryptoError,
+                    &format!("Hybrid KEM encapsulation failed: {e}"),
+                );
+                FFIErrorCode::CryptoError as c_int
+            }
+        }
+    })
+}
+
+/// Recovers the shared secret from a composite ciphertext produced by
+/// [`hybrid_kem_encapsulate`]. See [`crate::hybrid::hybrid_decapsulate`].
+#[no_mangle]
+pub extern "C" fn hybrid_kem_decapsulate(
+    classical_secret_key_handle: MLDSASecretHandle,
+    pqc_secret_key_handle: MLDSASecretHandle,
+    ciphertext_ptr: *const u8,
+    ciphertext_len: size_t,
+    shared_secret_out: *mut *mut u8,
+    shared_secret_len_out: *mut size_t,
+) -> c_int {
+    if shared_secret_out.is_null() || shared_secret_len_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let ciphertext_slice = match safe_slice_from_raw(ciphertext_ptr, ciphertext_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ciphertext buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let decap_result = secret_registry::with_two_secrets(
+        classical_secret_key_handle,
+        pqc_secret_key_handle,
+        |classical, pqc| {
+            record_operation_time("hybrid_kem_decap", || {
+                core_hybrid_decapsulate(classical, pqc, ciphertext_slice)
+            })
+        },
+    );
+
+    let shared_secret = match decap_result {
+        Some(Ok(secret)) => secret,
+        Some(Err(e)) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("Hybrid KEM decapsulation failed: {e}"),
+            );
+            return FFIErrorCode::CryptoError as c_int;
+        }
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidKeyFormat,
+                "Unknown or already-released classical or PQC secret key handle",
+            );
+            return FFIErrorCode::InvalidKeyFormat as c_int;
+        }
+    };
+
+    let shared_secret_bytes = shared_secret.expose_secret();
+    let mut ss_buffer = match FFIBuffer::new_secure(shared_secret_bytes.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                &format!("Failed to allocate shared secret buffer: {e}"),
+            );
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            shared_secret_bytes.as_ptr(),
+            ss_buffer.as_mut_ptr(),
+            shared_secret_bytes.len(),
+        );
+        *shared_secret_out = ss_buffer.into_raw();
+        *shared_secret_len_out = shared_secret_bytes.len();
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
+/// Zeroizes and frees a shared secret buffer returned by
+/// `hybrid_kem_encapsulate`/`hybrid_kem_decapsulate`. Secret material, so
+/// (like [`crate::ffi::mlkem_ffi::mlkem_shared_secret_free`]) this routes to
+/// `ffi_secure_buffer_free` rather than `ffi_buffer_free`.
+#[no_mangle]
+pub extern "C" fn hybrid_kem_shared_secret_free(ptr: *mut u8, len: size_t) {
+    crate::ffi::memory::ffi_secure_buffer_free(ptr, len);
+}
+
+/// Frees a ciphertext buffer returned by `hybrid_kem_encapsulate`'s
+/// `ciphertext_out` out-pointer. Not secret, so a thin wrapper over the
+/// plain `ffi_buffer_free`.
+#[no_mangle]
+pub extern "C" fn hybrid_kem_ciphertext_free(ptr: *mut u8, len: size_t) {
+    crate::ffi::memory::ffi_buffer_free(ptr, len);
+}
+
+#[no_mangle]
+pub extern "C" fn hybrid_kem_keypair_free(keypair: *mut CHybridKemKeyPair) {
+    if !keypair.is_null() {
+        unsafe {
+            let keypair = Box::from_raw(keypair);
+
+            if !keypair.classical_public_key_ptr.is_null() && keypair.classical_public_key_len > 0
+            {
+                let layout =
+                    std::alloc::Layout::array::<u8>(keypair.classical_public_key_len).unwrap();
+                let slice = std::slice::from_raw_parts_mut(
+                    keypair.classical_public_key_ptr,
+                    keypair.classical_public_key_len,
+                );
+                slice.fill(0);
+                std::alloc::dealloc(keypair.classical_public_key_ptr, layout);
+            }
+
+            if !keypair.pqc_public_key_ptr.is_null() && keypair.pqc_public_key_len > 0 {
+                let layout = std::alloc::Layout::array::<u8>(keypair.pqc_public_key_len).unwrap();
+                let slice = std::slice::from_raw_parts_mut(
+                    keypair.pqc_public_key_ptr,
+                    keypair.pqc_public_key_len,
+                );
+                slice.fill(0);
+                std::alloc::dealloc(keypair.pqc_public_key_ptr, layout);
+            }
+
+            secret_registry::release(keypair.classical_secret_key_handle);
+            secret_registry::release(keypair.pqc_secret_key_handle);
+        }
+    }
+}
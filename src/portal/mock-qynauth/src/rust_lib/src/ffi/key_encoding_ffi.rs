@@ -0,0 +1,309 @@
+//! FFI entry points for [`crate::key_encoding`]'s PKCS#8/SPKI DER and PEM
+//! import/export, so C callers can serialize a keypair produced by
+//! [`crate::ffi::mlkem_ffi`]/[`crate::ffi::mldsa_ffi`] to an on-disk/wire
+//! format (and parse one back) without inventing their own framing
+//! around `CMLKEMKeyPair`/`CMLDSAKeyPair`'s raw byte pointers. DER and
+//! PEM both come back through the same out-pointer/out-length pair as
+//! the rest of `crate::ffi` and are freed with [`crate::ffi::memory::ffi_buffer_free`];
+//! PEM is plain ASCII armor, not secret material, so (like
+//! [`crate::ffi::mlkem_ffi::mlkem_ciphertext_free`]) it never needs the
+//! secure allocator.
+
+use crate::ffi::memory::{set_last_error, FFIBuffer, FFIErrorCode};
+use crate::ffi::mldsa_ffi::mldsa_level_from_c_int;
+use crate::ffi::mlkem_ffi::mlkem_level_from_c_int;
+use crate::key_encoding;
+use crate::{PQCAlgorithm, PQCError};
+use libc::size_t;
+use std::os::raw::c_int;
+
+fn copy_out(bytes: &[u8], out: *mut *mut u8, len_out: *mut size_t) -> c_int {
+    let mut buffer = match FFIBuffer::new(bytes.len()) {
+        Ok(buffer) => buffer,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                &format!("Failed to allocate output buffer: {e}"),
+            );
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr(), bytes.len());
+        *out = buffer.into_raw();
+        *len_out = bytes.len();
+    }
+    FFIErrorCode::Success as c_int
+}
+
+fn code_for(error: &PQCError) -> FFIErrorCode {
+    match error {
+        PQCError::InvalidPrivateKey(_) | PQCError::InvalidPublicKey(_) => {
+            FFIErrorCode::InvalidKeyFormat
+        }
+        PQCError::UnsupportedAlgorithm(_) => FFIErrorCode::InvalidInput,
+        _ => FFIErrorCode::CryptoError,
+    }
+}
+
+fn fail(error: PQCError) -> c_int {
+    let code = code_for(&error);
+    set_last_error(code, &error.to_string());
+    code as c_int
+}
+
+fn check_out_params(out: *mut *mut u8, len_out: *mut size_t) -> Result<(), c_int> {
+    if out.is_null() || len_out.is_null() {
+        set_last_error(FFIErrorCode::InvalidInput, "Output parameters cannot be null");
+        return Err(FFIErrorCode::InvalidInput as c_int);
+    }
+    Ok(())
+}
+
+/// The level number a parsed key's OID maps back to: ML-KEM levels are
+/// named by their `ML-KEM-n` suffix (512/768/1024), ML-DSA levels by
+/// their NIST security category (2/3/5) -- see [`crate::MLKEMLevel`]/
+/// [`crate::MLDSALevel`] -- so a caller of [`private_key_from_pem`]/
+/// [`public_key_from_pem`] needs `is_kem_out` to know which numbering
+/// `level_out` uses before passing it back to `mlkem_keypair_generate`
+/// or `mldsa_keypair_generate`.
+fn level_number(algorithm: PQCAlgorithm) -> c_int {
+    match algorithm {
+        PQCAlgorithm::MlKem512 => 512,
+        PQCAlgorithm::MlKem768 => 768,
+        PQCAlgorithm::MlKem1024 => 1024,
+        PQCAlgorithm::MlDsa44 => 2,
+        PQCAlgorithm::MlDsa65 => 3,
+        PQCAlgorithm::MlDsa87 => 5,
+    }
+}
+
+/// Encodes an ML-KEM private key as a DER `PrivateKeyInfo` (PKCS#8).
+/// `level` selects the OID (512, 768, or 1024; see [`crate::MLKEMLevel`]).
+#[no_mangle]
+pub extern "C" fn mlkem_private_key_to_der(
+    level: c_int,
+    private_key_ptr: *const u8,
+    private_key_len: size_t,
+    der_out: *mut *mut u8,
+    der_len_out: *mut size_t,
+) -> c_int {
+    if let Err(code) = check_out_params(der_out, der_len_out) {
+        return code;
+    }
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+    if private_key_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Private key pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let private_key = unsafe { std::slice::from_raw_parts(private_key_ptr, private_key_len) };
+
+    match key_encoding::private_key_to_der(PQCAlgorithm::from(level), private_key) {
+        Ok(der) => copy_out(&der, der_out, der_len_out),
+        Err(e) => fail(e),
+    }
+}
+
+/// PEM-armored counterpart to [`mlkem_private_key_to_der`].
+#[no_mangle]
+pub extern "C" fn mlkem_private_key_to_pem(
+    level: c_int,
+    private_key_ptr: *const u8,
+    private_key_len: size_t,
+    pem_out: *mut *mut u8,
+    pem_len_out: *mut size_t,
+) -> c_int {
+    if let Err(code) = check_out_params(pem_out, pem_len_out) {
+        return code;
+    }
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+    if private_key_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Private key pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let private_key = unsafe { std::slice::from_raw_parts(private_key_ptr, private_key_len) };
+
+    match key_encoding::private_key_to_pem(PQCAlgorithm::from(level), private_key) {
+        Ok(pem) => copy_out(pem.as_bytes(), pem_out, pem_len_out),
+        Err(e) => fail(e),
+    }
+}
+
+/// Parses a PEM-armored ML-KEM or ML-DSA private key back into its raw
+/// bytes, writing the recognized level to `level_out` (see
+/// [`level_number`]) and whether it's an ML-KEM (`1`) or ML-DSA (`0`)
+/// key to `is_kem_out`, so the caller need not guess which the OID
+/// designated.
+#[no_mangle]
+pub extern "C" fn private_key_from_pem(
+    pem_ptr: *const u8,
+    pem_len: size_t,
+    level_out: *mut c_int,
+    is_kem_out: *mut c_int,
+    key_out: *mut *mut u8,
+    key_len_out: *mut size_t,
+) -> c_int {
+    if level_out.is_null() || is_kem_out.is_null() {
+        set_last_error(FFIErrorCode::InvalidInput, "Output parameters cannot be null");
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+    if let Err(code) = check_out_params(key_out, key_len_out) {
+        return code;
+    }
+    if pem_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "PEM pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let pem = match std::str::from_utf8(unsafe { std::slice::from_raw_parts(pem_ptr, pem_len) }) {
+        Ok(pem) => pem,
+        Err(_) => {
+            set_last_error(FFIErrorCode::InvalidInput, "PEM input is not valid UTF-8");
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    match key_encoding::private_key_from_pem(pem) {
+        Ok((algorithm, key)) => {
+            unsafe {
+                *level_out = level_number(algorithm);
+                *is_kem_out = algorithm.is_kem() as c_int;
+            }
+            copy_out(&key, key_out, key_len_out)
+        }
+        Err(e) => fail(e),
+    }
+}
+
+/// Encodes an ML-DSA private key as a DER `PrivateKeyInfo` (PKCS#8).
+/// `level` selects the OID (2, 3, or 5; see [`crate::MLDSALevel`]).
+#[no_mangle]
+pub extern "C" fn mldsa_private_key_to_der(
+    level: c_int,
+    private_key_ptr: *const u8,
+    private_key_len: size_t,
+    der_out: *mut *mut u8,
+    der_len_out: *mut size_t,
+) -> c_int {
+    if let Err(code) = check_out_params(der_out, der_len_out) {
+        return code;
+    }
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+    if private_key_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Private key pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let private_key = unsafe { std::slice::from_raw_parts(private_key_ptr, private_key_len) };
+
+    match key_encoding::private_key_to_der(PQCAlgorithm::from(level), private_key) {
+        Ok(der) => copy_out(&der, der_out, der_len_out),
+        Err(e) => fail(e),
+    }
+}
+
+/// Encodes an ML-KEM public key as a DER `SubjectPublicKeyInfo` (SPKI).
+#[no_mangle]
+pub extern "C" fn mlkem_public_key_to_der(
+    level: c_int,
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    der_out: *mut *mut u8,
+    der_len_out: *mut size_t,
+) -> c_int {
+    if let Err(code) = check_out_params(der_out, der_len_out) {
+        return code;
+    }
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+    if public_key_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Public key pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let public_key = unsafe { std::slice::from_raw_parts(public_key_ptr, public_key_len) };
+
+    match key_encoding::public_key_to_der(PQCAlgorithm::from(level), public_key) {
+        Ok(der) => copy_out(&der, der_out, der_len_out),
+        Err(e) => fail(e),
+    }
+}
+
+/// PEM-armored counterpart to [`mlkem_public_key_to_der`].
+#[no_mangle]
+pub extern "C" fn mlkem_public_key_to_pem(
+    level: c_int,
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    pem_out: *mut *mut u8,
+    pem_len_out: *mut size_t,
+) -> c_int {
+    if let Err(code) = check_out_params(pem_out, pem_len_out) {
+        return code;
+    }
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+    if public_key_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Public key pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let public_key = unsafe { std::slice::from_raw_parts(public_key_ptr, public_key_len) };
+
+    match key_encoding::public_key_to_pem(PQCAlgorithm::from(level), public_key) {
+        Ok(pem) => copy_out(pem.as_bytes(), pem_out, pem_len_out),
+        Err(e) => fail(e),
+    }
+}
+
+/// Parses a PEM-armored ML-KEM or ML-DSA public key back into its raw
+/// bytes, writing `level_out`/`is_kem_out` the same way
+/// [`private_key_from_pem`] does.
+#[no_mangle]
+pub extern "C" fn public_key_from_pem(
+    pem_ptr: *const u8,
+    pem_len: size_t,
+    level_out: *mut c_int,
+    is_kem_out: *mut c_int,
+    key_out: *mut *mut u8,
+    key_len_out: *mut size_t,
+) -> c_int {
+    if level_out.is_null() || is_kem_out.is_null() {
+        set_last_error(FFIErrorCode::InvalidInput, "Output parameters cannot be null");
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+    if let Err(code) = check_out_params(key_out, key_len_out) {
+        return code;
+    }
+    if pem_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "PEM pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+    let pem = match std::str::from_utf8(unsafe { std::slice::from_raw_parts(pem_ptr, pem_len) }) {
+        Ok(pem) => pem,
+        Err(_) => {
+            set_last_error(FFIErrorCode::InvalidInput, "PEM input is not valid UTF-8");
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    match key_encoding::public_key_from_pem(pem) {
+        Ok((algorithm, key)) => {
+            unsafe {
+                *level_out = level_number(algorithm);
+                *is_kem_out = algorithm.is_kem() as c_int;
+            }
+            copy_out(&key, key_out, key_len_out)
+        }
+        Err(e) => fail(e),
+    }
+}
@@ -2,6 +2,7 @@ use crate::ffi::memory::FFIErrorCode;
 use libc::size_t;
 use pqcrypto_mlkem::mlkem768;
 use pqcrypto_traits::kem::{Ciphertext, PublicKey, SecretKey, SharedSecret};
+use std::cell::RefCell;
 use std::ffi::CString;
 use std::os::raw::{c_char, c_int};
 use std::ptr;
@@ -23,16 +24,16 @@ pub struct CKyberEncapsulationResult {
     pub ciphertext_len: size_t,
 }
 
-static mut LAST_ERROR: Option<CString> = None;
+// Per-thread last-error slot, mirroring `crate::ffi::memory::LAST_FFI_ERROR`:
+// a `static mut` here would race the moment two threads call into this
+// module concurrently (plain mutable global state, no synchronization).
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
 
 fn set_last_error(error: &str) {
-    unsafe {
-        if let Some(old_error) = LAST_ERROR.take() {
-            drop(old_error);
-        }
-        if let Ok(c_string) = CString::new(error) {
-            LAST_ERROR = Some(c_string);
-        }
+    if let Ok(c_string) = CString::new(error) {
+        LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(c_string));
     }
 }
 
@@ -369,21 +370,19 @@ pub extern "C" fn kyber_buffer_free(ptr: *mut u8, len: size_t) {
     }
 }
 
+/// Returns a pointer to the calling thread's last error message, valid
+/// until the next FFI call on this thread sets or clears it. Returns null
+/// if no error has been recorded yet on this thread.
 #[no_mangle]
 pub extern "C" fn kyber_get_last_error() -> *const c_char {
-    unsafe {
-        match LAST_ERROR.as_ref() {
-            Some(error) => error.as_ptr(),
-            None => ptr::null(),
-        }
-    }
+    LAST_ERROR.with(|slot| match slot.borrow().as_ref() {
+        Some(error) => error.as_ptr(),
+        None => ptr::null(),
+    })
 }
 
+/// Clears the calling thread's last-error slot.
 #[no_mangle]
 pub extern "C" fn kyber_clear_last_error() {
-    unsafe {
-        if let Some(error) = LAST_ERROR.take() {
-            drop(error);
-        }
-    }
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = None);
 }
@@ -1,12 +1,14 @@
 use libc::size_t;
-use std::alloc::{alloc, dealloc, Layout};
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::cell::RefCell;
 use std::ffi::CString;
 use std::os::raw::c_char;
 use std::ptr;
+use std::ptr::NonNull;
 use zeroize::Zeroize;
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FFIErrorCode {
     Success = 0,
     InvalidInput = -1,
@@ -16,6 +18,11 @@ pub enum FFIErrorCode {
     NullPointer = -5,
     InvalidKeyFormat = -6,
     SignatureVerificationFailed = -7,
+    BadLength = -8,
+    InvalidPublicKeySize = -9,
+    InvalidSecretKeySize = -10,
+    InvalidCiphertextSize = -11,
+    InvalidSignatureSize = -12,
 }
 
 impl std::fmt::Display for FFIErrorCode {
@@ -29,32 +36,171 @@ impl std::fmt::Display for FFIErrorCode {
             FFIErrorCode::NullPointer => write!(f, "Null pointer"),
             FFIErrorCode::InvalidKeyFormat => write!(f, "Invalid key format"),
             FFIErrorCode::SignatureVerificationFailed => write!(f, "Signature verification failed"),
+            FFIErrorCode::BadLength => write!(f, "Input has the wrong length for its type"),
+            FFIErrorCode::InvalidPublicKeySize => write!(f, "Invalid public key size"),
+            FFIErrorCode::InvalidSecretKeySize => write!(f, "Invalid secret key size"),
+            FFIErrorCode::InvalidCiphertextSize => write!(f, "Invalid ciphertext size"),
+            FFIErrorCode::InvalidSignatureSize => write!(f, "Invalid signature size"),
         }
     }
 }
 
-static mut LAST_FFI_ERROR: Option<CString> = None;
+// Per-thread, errno-style last-error slot: each calling thread gets its own
+// `(code, message)` pair rather than sharing one process-wide mutable
+// global, so two threads calling into this FFI can't race on the same
+// `CString` pointer.
+thread_local! {
+    static LAST_FFI_ERROR: RefCell<Option<(FFIErrorCode, CString)>> = RefCell::new(None);
+}
 
-fn set_last_ffi_error(error: &str) {
-    unsafe {
-        LAST_FFI_ERROR = CString::new(error).ok();
+fn set_last_ffi_error(code: FFIErrorCode, error: &str) {
+    if let Ok(c_string) = CString::new(error) {
+        LAST_FFI_ERROR.with(|slot| *slot.borrow_mut() = Some((code, c_string)));
     }
 }
 
+/// Returns a pointer to the calling thread's last error message, valid
+/// until the next FFI call on this thread sets or clears it. Returns null
+/// if no error has been recorded yet on this thread.
 #[no_mangle]
 pub extern "C" fn ffi_get_last_error_message() -> *const c_char {
-    unsafe {
-        match &LAST_FFI_ERROR {
-            Some(err) => err.as_ptr(),
-            None => ptr::null(),
-        }
-    }
+    LAST_FFI_ERROR.with(|slot| match &*slot.borrow() {
+        Some((_, message)) => message.as_ptr(),
+        None => ptr::null(),
+    })
+}
+
+/// Returns the calling thread's last error code without needing to parse
+/// `ffi_get_last_error_message`'s string. `FFIErrorCode::Success` if no
+/// error has been recorded yet on this thread.
+#[no_mangle]
+pub extern "C" fn ffi_last_error_code() -> FFIErrorCode {
+    LAST_FFI_ERROR.with(|slot| match &*slot.borrow() {
+        Some((code, _)) => *code,
+        None => FFIErrorCode::Success,
+    })
+}
+
+/// Clears the calling thread's last-error slot.
+#[no_mangle]
+pub extern "C" fn ffi_clear_last_error() {
+    LAST_FFI_ERROR.with(|slot| *slot.borrow_mut() = None);
 }
 
 pub struct FFIBuffer {
     ptr: *mut u8,
     len: usize,
     capacity: usize,
+    secure: Option<SecureRegion>,
+}
+
+/// Bookkeeping for an `mlock`'d, guard-paged allocation from `new_secure`.
+/// `map_ptr`/`map_len` describe the *full* `mmap` region (one `PROT_NONE`
+/// guard page, the usable data pages, one more `PROT_NONE` guard page);
+/// `FFIBuffer::ptr` points at the first usable byte, `page_size` after
+/// `map_ptr`, so `reserve`/`Drop`/`secure_free` can recover the mapping to
+/// `munlock`/`munmap` without needing a fourth field for the guard offset.
+#[cfg(all(target_os = "linux", feature = "secure-mem"))]
+struct SecureRegion {
+    map_ptr: *mut u8,
+    map_len: usize,
+    page_size: usize,
+}
+
+/// Non-Linux (or `secure-mem` disabled) builds never construct a
+/// `SecureRegion` — `new_secure` falls back to the ordinary allocator — but
+/// the field still needs a concrete type to name.
+#[cfg(not(all(target_os = "linux", feature = "secure-mem")))]
+struct SecureRegion {}
+
+/// Rounds `size` up to a whole number of pages, `mmap`s a `PROT_NONE`
+/// region of `size` plus one guard page on each side, `mprotect`s the
+/// middle pages to `PROT_READ | PROT_WRITE`, `mlock`s them against swap,
+/// and marks them `MADV_DONTDUMP`. Returns `(map_ptr, data_ptr, data_len,
+/// page_size)`; the caller stashes these in a `SecureRegion` to reverse
+/// the mapping later.
+#[cfg(all(target_os = "linux", feature = "secure-mem"))]
+fn alloc_secure_region(size: usize) -> Result<(*mut u8, *mut u8, usize, usize), FFIError> {
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+    let data_pages = size.div_ceil(page_size);
+    let data_len = data_pages * page_size;
+    let map_len = data_len + 2 * page_size;
+
+    let map_ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            map_len,
+            libc::PROT_NONE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    if map_ptr == libc::MAP_FAILED {
+        set_last_ffi_error(
+            FFIErrorCode::AllocationFailed,
+            "Failed to mmap secure memory",
+        );
+        return Err(FFIError::AllocationFailed);
+    }
+    let map_ptr = map_ptr as *mut u8;
+    let data_ptr = unsafe { map_ptr.add(page_size) };
+
+    let protect_failed = unsafe {
+        libc::mprotect(
+            data_ptr as *mut libc::c_void,
+            data_len,
+            libc::PROT_READ | libc::PROT_WRITE,
+        ) != 0
+    };
+    if protect_failed {
+        unsafe {
+            libc::munmap(map_ptr as *mut libc::c_void, map_len);
+        }
+        set_last_ffi_error(
+            FFIErrorCode::AllocationFailed,
+            "Failed to unprotect secure memory region",
+        );
+        return Err(FFIError::AllocationFailed);
+    }
+
+    let mlock_failed = unsafe { libc::mlock(data_ptr as *const libc::c_void, data_len) != 0 };
+    if mlock_failed {
+        unsafe {
+            libc::munmap(map_ptr as *mut libc::c_void, map_len);
+        }
+        set_last_ffi_error(
+            FFIErrorCode::AllocationFailed,
+            "Failed to mlock secure memory (check RLIMIT_MEMLOCK)",
+        );
+        return Err(FFIError::AllocationFailed);
+    }
+
+    unsafe {
+        libc::madvise(data_ptr as *mut libc::c_void, data_len, libc::MADV_DONTDUMP);
+        ptr::write_bytes(data_ptr, 0, data_len);
+    }
+
+    Ok((map_ptr, data_ptr, data_len, page_size))
+}
+
+/// Zeroizes the usable pages, `munlock`s, then `munmap`s the whole region
+/// (guard pages included) described by `region`.
+#[cfg(all(target_os = "linux", feature = "secure-mem"))]
+fn free_secure_region(data_ptr: *mut u8, data_len: usize, region: &SecureRegion) {
+    unsafe {
+        if data_len > 0 {
+            let slice = std::slice::from_raw_parts_mut(data_ptr, data_len);
+            slice.zeroize();
+        }
+        libc::munlock(data_ptr as *const libc::c_void, data_len);
+        libc::munmap(region.map_ptr as *mut libc::c_void, region.map_len);
+    }
+}
+
+#[cfg(not(all(target_os = "linux", feature = "secure-mem")))]
+fn free_secure_region(_data_ptr: *mut u8, _data_len: usize, _region: &SecureRegion) {
+    unreachable!("SecureRegion is never constructed without the secure-mem feature on Linux")
 }
 
 #[derive(Debug)]
@@ -66,6 +212,7 @@ pub enum FFIError {
     NullPointer,
     InvalidKeyFormat,
     SignatureVerificationFailed,
+    BadLength { expected: usize, actual: usize },
 }
 
 impl std::fmt::Display for FFIError {
@@ -78,6 +225,9 @@ impl std::fmt::Display for FFIError {
             FFIError::NullPointer => write!(f, "Null pointer encountered"),
             FFIError::InvalidKeyFormat => write!(f, "Invalid key format"),
             FFIError::SignatureVerificationFailed => write!(f, "Signature verification failed"),
+            FFIError::BadLength { expected, actual } => {
+                write!(f, "expected {expected} bytes, got {actual}")
+            }
         }
     }
 }
@@ -92,6 +242,7 @@ impl From<FFIError> for FFIErrorCode {
             FFIError::NullPointer => FFIErrorCode::NullPointer,
             FFIError::InvalidKeyFormat => FFIErrorCode::InvalidKeyFormat,
             FFIError::SignatureVerificationFailed => FFIErrorCode::SignatureVerificationFailed,
+            FFIError::BadLength { .. } => FFIErrorCode::BadLength,
         }
     }
 }
@@ -99,18 +250,21 @@ impl From<FFIError> for FFIErrorCode {
 impl FFIBuffer {
     pub fn new(size: usize) -> Result<Self, FFIError> {
         if size == 0 {
-            set_last_ffi_error("Buffer size cannot be zero");
+            set_last_ffi_error(FFIErrorCode::InvalidInput, "Buffer size cannot be zero");
             return Err(FFIError::InvalidInput);
         }
 
         let layout = Layout::from_size_align(size, 1).map_err(|_| {
-            set_last_ffi_error("Failed to create memory layout");
+            set_last_ffi_error(
+                FFIErrorCode::AllocationFailed,
+                "Failed to create memory layout",
+            );
             FFIError::AllocationFailed
         })?;
 
         let ptr = unsafe { alloc(layout) };
         if ptr.is_null() {
-            set_last_ffi_error("Memory allocation failed");
+            set_last_ffi_error(FFIErrorCode::AllocationFailed, "Memory allocation failed");
             return Err(FFIError::AllocationFailed);
         }
 
@@ -122,6 +276,7 @@ impl FFIBuffer {
             ptr,
             len: 0,
             capacity: size,
+            secure: None,
         })
     }
 
@@ -147,7 +302,10 @@ impl FFIBuffer {
 
     pub fn write_data(&mut self, data: &[u8]) -> Result<(), FFIError> {
         if data.len() > self.capacity {
-            set_last_ffi_error("Data size exceeds buffer capacity");
+            set_last_ffi_error(
+                FFIErrorCode::BufferTooSmall,
+                "Data size exceeds buffer capacity",
+            );
             return Err(FFIError::BufferTooSmall);
         }
 
@@ -158,10 +316,96 @@ impl FFIBuffer {
         Ok(())
     }
 
+    /// Creates a zeroed buffer with exactly `capacity` bytes allocated.
+    /// `capacity == 0` starts from a dangling, zero-capacity pointer (no
+    /// allocation performed) the way `Vec::new` does, so `with_capacity(0)`
+    /// never fails. This is the portable fallback `new_secure` falls back to
+    /// outside Linux or without the `secure-mem` feature, where there's no
+    /// guard-paged region to allocate.
+    pub fn with_capacity(capacity: usize) -> Result<Self, FFIError> {
+        if capacity == 0 {
+            return Ok(FFIBuffer {
+                ptr: NonNull::dangling().as_ptr(),
+                len: 0,
+                capacity: 0,
+                secure: None,
+            });
+        }
+
+        let layout = Layout::from_size_align(capacity, 1).map_err(|_| {
+            set_last_ffi_error(
+                FFIErrorCode::AllocationFailed,
+                "Failed to create memory layout",
+            );
+            FFIError::AllocationFailed
+        })?;
+
+        let ptr = unsafe { alloc(layout) };
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+
+        unsafe {
+            ptr::write_bytes(ptr, 0, capacity);
+        }
+
+        Ok(FFIBuffer {
+            ptr,
+            len: 0,
+            capacity,
+            secure: None,
+        })
+    }
+
+    /// Allocates a page-aligned, `mlock`'d buffer bracketed by `PROT_NONE`
+    /// guard pages and excluded from core dumps, for secret-key-bearing
+    /// data that must never be paged to swap or land in a crash dump. Falls
+    /// back to the ordinary allocator (no mlock, no guard pages) outside
+    /// Linux or when the `secure-mem` feature is off, since `mmap`/`mlock`/
+    /// `madvise(MADV_DONTDUMP)` are Linux-specific.
+    #[cfg(all(target_os = "linux", feature = "secure-mem"))]
+    pub fn new_secure(size: usize) -> Result<Self, FFIError> {
+        if size == 0 {
+            set_last_ffi_error(FFIErrorCode::InvalidInput, "Buffer size cannot be zero");
+            return Err(FFIError::InvalidInput);
+        }
+
+        let (map_ptr, data_ptr, data_len, page_size) = alloc_secure_region(size)?;
+
+        Ok(FFIBuffer {
+            ptr: data_ptr,
+            len: 0,
+            capacity: data_len,
+            secure: Some(SecureRegion {
+                map_ptr,
+                map_len: data_len + 2 * page_size,
+                page_size,
+            }),
+        })
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "secure-mem")))]
+    pub fn new_secure(size: usize) -> Result<Self, FFIError> {
+        Self::with_capacity(size)
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
     }
 
+    /// Hands the raw pointer to a C caller, skipping `Drop`. Sound for
+    /// either a plain or `new_secure` buffer, as long as the caller frees it
+    /// through the matching path: a plain buffer's pointer goes to
+    /// `ffi_buffer_free` (`std::alloc::dealloc`), while a `new_secure`
+    /// buffer's pointer goes to `ffi_secure_buffer_free` (`munlock`+
+    /// `munmap`, which can undo the `mlock`/`mmap`'d region `dealloc`
+    /// can't) -- see [`crate::ffi::memory::ffi_secure_buffer_free`]'s doc
+    /// comment. This is the standard handoff used throughout the FFI layer
+    /// (`mlkem_ffi`, `dilithium_ffi`, `hybrid_kem_ffi`, `shamir_ffi`, ...):
+    /// `FFIBuffer::new_secure(..).into_raw()` paired with the matching
+    /// `*_free` on the C side. `secure_free` (or letting the buffer drop
+    /// instead of calling `into_raw`) is the alternative when a secure
+    /// buffer never needs to leave Rust's ownership.
     pub fn into_raw(self) -> *mut u8 {
         let ptr = self.ptr;
         std::mem::forget(self);
@@ -169,43 +413,42 @@ impl FFIBuffer {
     }
 
     pub fn secure_free(mut self) {
-        if !self.ptr.is_null() {
+        self.release();
+    }
+
+    /// Zeroizes and releases the backing allocation, routing to `munlock`+
+    /// `munmap` for a `new_secure` region or plain `dealloc` otherwise.
+    /// Shared by `secure_free` and `Drop` so the two can't drift apart.
+    fn release(&mut self) {
+        if let Some(region) = self.secure.take() {
+            free_secure_region(self.ptr, self.capacity, &region);
+        } else if !self.ptr.is_null() && self.capacity > 0 {
             unsafe {
                 let slice = std::slice::from_raw_parts_mut(self.ptr, self.capacity);
                 slice.zeroize();
                 let layout = Layout::from_size_align_unchecked(self.capacity, 1);
                 dealloc(self.ptr, layout);
             }
-            self.ptr = ptr::null_mut();
-            self.len = 0;
-            self.capacity = 0;
         }
+        self.ptr = ptr::null_mut();
+        self.len = 0;
+        self.capacity = 0;
     }
 }
 
 impl Drop for FFIBuffer {
     fn drop(&mut self) {
-        if !self.ptr.is_null() {
-            unsafe {
-                let slice = std::slice::from_raw_parts_mut(self.ptr, self.capacity);
-                slice.zeroize();
-                let layout = Layout::from_size_align_unchecked(self.capacity, 1);
-                dealloc(self.ptr, layout);
-            }
-            self.ptr = ptr::null_mut();
-            self.len = 0;
-            self.capacity = 0;
-        }
+        self.release();
     }
 }
 
 pub fn validate_buffer_params(ptr: *const u8, len: usize) -> Result<(), FFIErrorCode> {
     if ptr.is_null() {
-        set_last_ffi_error("Buffer pointer is null");
+        set_last_ffi_error(FFIErrorCode::NullPointer, "Buffer pointer is null");
         return Err(FFIErrorCode::NullPointer);
     }
     if len == 0 {
-        set_last_ffi_error("Buffer length cannot be zero");
+        set_last_ffi_error(FFIErrorCode::InvalidInput, "Buffer length cannot be zero");
         return Err(FFIErrorCode::InvalidInput);
     }
     Ok(())
@@ -216,8 +459,58 @@ pub fn safe_slice_from_raw<'a>(ptr: *const u8, len: usize) -> Result<&'a [u8], F
     unsafe { Ok(std::slice::from_raw_parts(ptr, len)) }
 }
 
-pub fn set_last_error(error: &str) {
-    set_last_ffi_error(error);
+/// A parameter kind crossing the FFI boundary whose exact byte length is
+/// known up front -- an ML-KEM public key, an ML-DSA signature at a given
+/// security level, and so on. `safe_typed_slice_from_raw` uses this to
+/// reject a short/long buffer before it ever reaches the crypto layer,
+/// with a last-error message naming both the expected and actual size
+/// instead of the opaque `CryptoError` a malformed buffer would otherwise
+/// surface as deep inside `pqcrypto_*`.
+pub trait FfiLayout {
+    /// Human-readable name for the last-error message, e.g. "ML-DSA-65
+    /// public key".
+    fn layout_name(&self) -> String;
+    /// The exact length in bytes this parameter must have.
+    fn expected_len(&self) -> usize;
+    /// Error code to report on a length mismatch. Defaults to
+    /// `InvalidKeyFormat`; implementations may override this to match an
+    /// existing, more specific code (e.g. `BadLength`).
+    fn error_code(&self) -> FFIErrorCode {
+        FFIErrorCode::InvalidKeyFormat
+    }
+
+    /// Checks `len` against `expected_len()`, recording a precise
+    /// last-error message on mismatch.
+    fn check_len(&self, len: usize) -> Result<(), FFIErrorCode> {
+        let expected = self.expected_len();
+        if len != expected {
+            let code = self.error_code();
+            set_last_ffi_error(
+                code,
+                &format!("{} must be {expected} bytes, got {len}", self.layout_name()),
+            );
+            return Err(code);
+        }
+        Ok(())
+    }
+}
+
+/// Combines [`validate_buffer_params`] with an [`FfiLayout`] length check
+/// before constructing the slice, so a malformed key/signature buffer is
+/// rejected with a descriptive message at the FFI boundary rather than
+/// failing later with a generic crypto error.
+pub fn safe_typed_slice_from_raw<'a>(
+    ptr: *const u8,
+    len: usize,
+    layout: impl FfiLayout,
+) -> Result<&'a [u8], FFIErrorCode> {
+    validate_buffer_params(ptr, len)?;
+    layout.check_len(len)?;
+    unsafe { Ok(std::slice::from_raw_parts(ptr, len)) }
+}
+
+pub fn set_last_error(code: FFIErrorCode, error: &str) {
+    set_last_ffi_error(code, error);
 }
 
 #[no_mangle]
@@ -231,3 +524,40 @@ pub extern "C" fn ffi_buffer_free(ptr: *mut u8, len: size_t) {
         }
     }
 }
+
+/// Reverses [`FFIBuffer::new_secure`] for a raw pointer that already
+/// crossed the FFI boundary via `into_raw`. `len` must be the exact `size`
+/// originally passed to `new_secure` (every secure-buffer-returning FFI
+/// function reports that same value back through its `*_len_out`
+/// parameter), which is enough to recompute the page-rounded `data_len` and
+/// the surrounding guard-paged `mmap` region with the same arithmetic
+/// `alloc_secure_region` used to build it, so the mapping can be zeroized,
+/// `munlock`'d, and `munmap`'d without a side table tracking live secure
+/// pointers. Pairs with `FFIBuffer::new_secure` the way `ffi_buffer_free`
+/// pairs with `FFIBuffer::new` -- calling this on a pointer from the plain
+/// allocator (or vice versa) is undefined behavior, so each secure-buffer-
+/// returning FFI function documents which free function its output pairs
+/// with.
+#[cfg(all(target_os = "linux", feature = "secure-mem"))]
+#[no_mangle]
+pub extern "C" fn ffi_secure_buffer_free(ptr: *mut u8, len: size_t) {
+    if ptr.is_null() || len == 0 {
+        return;
+    }
+
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+    let data_len = len.div_ceil(page_size) * page_size;
+    let map_ptr = unsafe { ptr.sub(page_size) };
+    let region = SecureRegion {
+        map_ptr,
+        map_len: data_len + 2 * page_size,
+        page_size,
+    };
+    free_secure_region(ptr, data_len, &region);
+}
+
+#[cfg(not(all(target_os = "linux", feature = "secure-mem")))]
+#[no_mangle]
+pub extern "C" fn ffi_secure_buffer_free(ptr: *mut u8, len: size_t) {
+    ffi_buffer_free(ptr, len);
+}
@@ -1,204 +1,1342 @@
-use std::os::raw::c_int;
-use libc::size_t;
-use crate::{generate_mldsa_keypair, mldsa_sign as core_mldsa_sign, mldsa_verify as core_mldsa_verify};
-use crate::ffi::memory::{FFIBuffer, FFIErrorCode, safe_slice_from_raw, set_last_error};
+//! ML-DSA (the NIST-standardized successor to Dilithium) signature FFI,
+//! parallel to [`crate::ffi::mlkem_ffi`]: `mldsa_keypair_generate`,
+//! `mldsa_sign`/`mldsa_verify` (attached, via `pqcrypto_mldsa`'s
+//! `SignedMessage`), and `mldsa_sign_detached`/`mldsa_verify_detached`
+//! (fixed-size, message not embedded), backed by `pqcrypto_mldsa` and sharing
+//! the same `FFIBuffer`/`safe_slice_from_raw`/`set_last_error` plumbing as
+//! the rest of `crate::ffi`. Verification failures return
+//! [`FFIErrorCode::SignatureVerificationFailed`], kept distinct from
+//! [`FFIErrorCode::InvalidInput`]/[`FFIErrorCode::CryptoError`] so callers
+//! can tell "the signature didn't verify" apart from "the input was
+//! malformed" — this module already covers what a separate
+//! `dilithium_*`-named subsystem would add; see `crate::dilithium` for the
+//! standalone Dilithium-3 engine this FFI does not wrap.
+//!
+//! `mldsa_verify_batch` and `mldsa_verify_batch_parallel` both take the
+//! same `CMLDSABatchItem` array but serve different goals: the former
+//! stops at the first bad signature and reports just that one failure,
+//! while the latter verifies every item independently across rayon's
+//! thread pool and reports a verdict for each, for throughput-sensitive
+//! callers validating many unrelated signatures at once.
+//!
+//! `mldsa_sign_ctx`/`mldsa_verify_ctx` and `mldsa_sign_prehash`/
+//! `mldsa_verify_prehash` add the FIPS 204 context-string and HashML-DSA
+//! pre-hash signing modes on top of the same attached `mldsa_sign`/
+//! `mldsa_verify` core, via [`crate::MlDsaSecretKey::sign_with_context`]/
+//! [`crate::MlDsaPublicKey::verify_with_context`] and their `_prehash`
+//! counterparts.
+
+use crate::ffi::constants::{MLDSAField, MLDSALayout};
+use crate::ffi::memory::{
+    safe_slice_from_raw, safe_typed_slice_from_raw, set_last_error, FFIBuffer, FFIErrorCode,
+    FfiLayout,
+};
 use crate::ffi::monitoring::record_operation_time;
-use secrecy::ExposeSecret;
+use crate::ffi::secret_registry::{self, MLDSASecretHandle};
+use crate::ffi::stream::{self, StreamHandle};
+use crate::{
+    generate_mldsa_keypair_for_level, mldsa_sign_ctx_for_level as core_mldsa_sign_ctx,
+    mldsa_sign_detached_for_level as core_mldsa_sign_detached,
+    mldsa_sign_for_level as core_mldsa_sign, mldsa_sign_prehash_for_level as core_mldsa_sign_prehash,
+    mldsa_verify_ctx_for_level as core_mldsa_verify_ctx,
+    mldsa_verify_detached_for_level as core_mldsa_verify_detached,
+    mldsa_verify_for_level as core_mldsa_verify,
+    mldsa_verify_prehash_for_level as core_mldsa_verify_prehash, MLDSALevel,
+};
+use libc::size_t;
+use rayon::prelude::*;
+use secrecy::{ExposeSecret, Secret};
+use std::os::raw::c_int;
 
 #[repr(C)]
 pub struct CMLDSAKeyPair {
     pub public_key_ptr: *mut u8,
     pub public_key_len: size_t,
-    pub secret_key_ptr: *mut u8,
-    pub secret_key_len: size_t,
+    /// Opaque handle into the process-wide secret registry. The secret key
+    /// bytes never cross the FFI boundary; `mldsa_sign` takes this handle
+    /// instead of a secret pointer+len, and `mldsa_keypair_free` releases
+    /// (and zeroizes) the registry entry it names.
+    pub secret_key_handle: MLDSASecretHandle,
+    /// The NIST security level (2, 3, or 5) the keys were generated under,
+    /// so `mldsa_verify` can be called with the matching level and a
+    /// signature produced under a different parameter set is rejected by
+    /// length rather than accepted against the wrong key.
+    pub level: c_int,
+}
+
+pub(crate) fn mldsa_level_from_c_int(level: c_int) -> Result<MLDSALevel, FFIErrorCode> {
+    match level {
+        2 => Ok(MLDSALevel::Level2),
+        3 => Ok(MLDSALevel::Level3),
+        5 => Ok(MLDSALevel::Level5),
+        _ => Err(FFIErrorCode::InvalidInput),
+    }
+}
+
+/// FIPS 204 caps a context string at 255 bytes; mirrors `crate`'s private
+/// `MLDSA_MAX_CONTEXT_LEN` so an oversized context is rejected here, at the
+/// FFI boundary, rather than only inside `sign_with_context`/
+/// `verify_with_context`.
+const MLDSA_MAX_CONTEXT_LEN: usize = 255;
+
+/// Builds a context-string slice from a C buffer, allowing `ctx_len == 0`
+/// (the empty context, FIPS 204's backward-compatible default) where
+/// `safe_slice_from_raw` would otherwise reject a zero-length buffer.
+fn context_slice_from_raw<'a>(ptr: *const u8, len: size_t) -> Result<&'a [u8], FFIErrorCode> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+
+    if ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Context buffer pointer is null");
+        return Err(FFIErrorCode::NullPointer);
+    }
+
+    if len > MLDSA_MAX_CONTEXT_LEN {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            &format!("ML-DSA context must be at most {MLDSA_MAX_CONTEXT_LEN} bytes, got {len}"),
+        );
+        return Err(FFIErrorCode::InvalidInput);
+    }
+
+    unsafe { Ok(std::slice::from_raw_parts(ptr, len)) }
 }
 
 #[no_mangle]
-pub extern "C" fn mldsa_keypair_generate() -> *mut CMLDSAKeyPair {
+pub extern "C" fn mldsa_keypair_generate(level: c_int) -> *mut CMLDSAKeyPair {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return std::ptr::null_mut();
+        }
+    };
+
     record_operation_time("mldsa_keygen", || {
-        match generate_mldsa_keypair() {
+        match generate_mldsa_keypair_for_level(level) {
             Ok(keypair) => {
                 let public_key = keypair.public_key;
-                let secret_key = keypair.private_key.expose_secret().clone();
-                
+
                 let mut public_buffer = match FFIBuffer::new(public_key.len()) {
                     Ok(buf) => buf,
                     Err(e) => {
-                        set_last_error(&format!("Failed to allocate public key buffer: {e}"));
+                        let message = format!("Failed to allocate public key buffer: {e}");
+                        set_last_error(FFIErrorCode::from(e), &message);
                         return std::ptr::null_mut();
                     }
                 };
-                
-                let mut secret_buffer = match FFIBuffer::new(secret_key.len()) {
-                    Ok(buf) => buf,
-                    Err(e) => {
-                        set_last_error(&format!("Failed to allocate secret key buffer: {e}"));
-                        return std::ptr::null_mut();
-                    }
-                };
-                
+
                 unsafe {
                     std::ptr::copy_nonoverlapping(
                         public_key.as_ptr(),
                         public_buffer.as_mut_ptr(),
-                        public_key.len()
-                    );
-                    std::ptr::copy_nonoverlapping(
-                        secret_key.as_ptr(),
-                        secret_buffer.as_mut_ptr(),
-                        secret_key.len()
+                        public_key.len(),
                     );
                 }
-                
+
+                let secret_key_handle = secret_registry::register(Secret::new(
+                    keypair.private_key.expose_secret().clone(),
+                ));
+
                 let keypair = Box::new(CMLDSAKeyPair {
                     public_key_ptr: public_buffer.into_raw(),
                     public_key_len: public_key.len(),
-                    secret_key_ptr: secret_buffer.into_raw(),
-                    secret_key_len: secret_key.len(),
+                    secret_key_handle,
+                    level: level as c_int,
                 });
-                
+
                 Box::into_raw(keypair)
-            },
+            }
             Err(e) => {
-                set_last_error(&format!("ML-DSA keypair generation failed: {e}"));
+                set_last_error(
+                    FFIErrorCode::CryptoError,
+                    &format!("ML-DSA keypair generation failed: {e}"),
+                );
                 std::ptr::null_mut()
             }
         }
     })
 }
 
+/// Shared tail of `mldsa_sign`/`mldsa_sign_final`: sign `message` under
+/// `secret_key_handle` and hand the signature to the C caller through the
+/// same out-pointer pair both entry points expose.
+fn sign_and_emit(
+    level: MLDSALevel,
+    secret_key_handle: MLDSASecretHandle,
+    message: &[u8],
+    signature_out: *mut *mut u8,
+    signature_len_out: *mut size_t,
+) -> c_int {
+    let signing_result = secret_registry::with_secret(secret_key_handle, |secret_key_slice| {
+        let layout = MLDSALayout {
+            level,
+            field: MLDSAField::SecretKey,
+        };
+        layout.check_len(secret_key_slice.len())?;
+
+        record_operation_time("mldsa_sign", || {
+            match core_mldsa_sign(level, secret_key_slice, message) {
+                Ok(signature_result) => Ok(signature_result.signature.expose_secret().clone()),
+                Err(e) => {
+                    set_last_error(
+                        FFIErrorCode::CryptoError,
+                        &format!("ML-DSA signing failed: {e}"),
+                    );
+                    Err(FFIErrorCode::CryptoError)
+                }
+            }
+        })
+    });
+
+    let signature = match signing_result {
+        Some(Ok(signature)) => signature,
+        Some(Err(code)) => {
+            return code as c_int;
+        }
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidKeyFormat,
+                "Unknown or already-released secret key handle",
+            );
+            return FFIErrorCode::InvalidKeyFormat as c_int;
+        }
+    };
+
+    let mut sig_buffer = match FFIBuffer::new(signature.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                &format!("Failed to allocate signature buffer: {e}"),
+            );
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(signature.as_ptr(), sig_buffer.as_mut_ptr(), signature.len());
+
+        *signature_out = sig_buffer.into_raw();
+        *signature_len_out = signature.len();
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
 #[no_mangle]
 pub extern "C" fn mldsa_sign(
-    secret_key_ptr: *const u8,
-    secret_key_len: size_t,
+    level: c_int,
+    secret_key_handle: MLDSASecretHandle,
     message_ptr: *const u8,
     message_len: size_t,
     signature_out: *mut *mut u8,
     signature_len_out: *mut size_t,
 ) -> c_int {
     if signature_out.is_null() || signature_len_out.is_null() {
-        set_last_error("Output parameters cannot be null");
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
         return FFIErrorCode::InvalidInput as c_int;
     }
-    
-    let secret_key_slice = match safe_slice_from_raw(secret_key_ptr, secret_key_len) {
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
         Ok(slice) => slice,
         Err(e) => {
-            set_last_error(&format!("Invalid secret key buffer: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    sign_and_emit(
+        level,
+        secret_key_handle,
+        message_slice,
+        signature_out,
+        signature_len_out,
+    )
+}
+
+/// Detached counterpart to [`mldsa_sign`]: returns only the fixed-size
+/// signature bytes (`crate::PQCAlgorithm::signature_size`), not a
+/// `SignedMessage` with a copy of `message` embedded alongside it. Halves
+/// the bytes transmitted for a large message when the caller already
+/// stores the signature next to (not inside) their data -- the same
+/// separation secp256k1 keeps between a compact signature and the message
+/// it covers. `*signature_out` is released the same way as `mldsa_sign`'s:
+/// via `ffi_buffer_free`.
+#[no_mangle]
+pub extern "C" fn mldsa_sign_detached(
+    level: c_int,
+    secret_key_handle: MLDSASecretHandle,
+    message_ptr: *const u8,
+    message_len: size_t,
+    signature_out: *mut *mut u8,
+    signature_len_out: *mut size_t,
+) -> c_int {
+    if signature_out.is_null() || signature_len_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
-    
+
     let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
         Ok(slice) => slice,
         Err(e) => {
-            set_last_error(&format!("Invalid message buffer: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
-    
-    record_operation_time("mldsa_sign", || {
-        match core_mldsa_sign(secret_key_slice, message_slice) {
-            Ok(signature_result) => {
-                let signature = signature_result.signature.expose_secret();
-                
-                let mut sig_buffer = match FFIBuffer::new(signature.len()) {
-                    Ok(buf) => buf,
-                    Err(e) => {
-                        set_last_error(&format!("Failed to allocate signature buffer: {e}"));
-                        return FFIErrorCode::AllocationFailed as c_int;
-                    }
-                };
-                
-                unsafe {
-                    std::ptr::copy_nonoverlapping(
-                        signature.as_ptr(),
-                        sig_buffer.as_mut_ptr(),
-                        signature.len()
+
+    let signing_result = secret_registry::with_secret(secret_key_handle, |secret_key_slice| {
+        let layout = MLDSALayout {
+            level,
+            field: MLDSAField::SecretKey,
+        };
+        layout.check_len(secret_key_slice.len())?;
+
+        record_operation_time("mldsa_sign_detached", || {
+            match core_mldsa_sign_detached(level, secret_key_slice, message_slice) {
+                Ok(signature) => Ok(signature),
+                Err(e) => {
+                    set_last_error(
+                        FFIErrorCode::CryptoError,
+                        &format!("ML-DSA detached signing failed: {e}"),
                     );
-                    
-                    *signature_out = sig_buffer.into_raw();
-                    *signature_len_out = signature.len();
+                    Err(FFIErrorCode::CryptoError)
                 }
-                
-                FFIErrorCode::Success as c_int
-            },
-            Err(e) => {
-                set_last_error(&format!("ML-DSA signing failed: {e}"));
-                FFIErrorCode::CryptoError as c_int
             }
+        })
+    });
+
+    let signature = match signing_result {
+        Some(Ok(signature)) => signature,
+        Some(Err(code)) => return code as c_int,
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidKeyFormat,
+                "Unknown or already-released secret key handle",
+            );
+            return FFIErrorCode::InvalidKeyFormat as c_int;
         }
-    })
+    };
+
+    let mut sig_buffer = match FFIBuffer::new(signature.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                &format!("Failed to allocate signature buffer: {e}"),
+            );
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(signature.as_ptr(), sig_buffer.as_mut_ptr(), signature.len());
+
+        *signature_out = sig_buffer.into_raw();
+        *signature_len_out = signature.len();
+    }
+
+    FFIErrorCode::Success as c_int
 }
 
+/// FIPS 204 `ML-DSA.Sign` with an explicit context string, binding `ctx`
+/// into the signed message so a signature produced under one context can't
+/// be replayed as valid under another (see
+/// [`crate::MlDsaSecretKey::sign_with_context`]). `ctx_len` of `0` is the
+/// empty context and reproduces `mldsa_sign`'s output exactly; anything over
+/// `MLDSA_MAX_CONTEXT_LEN` (255) bytes is rejected with
+/// [`FFIErrorCode::InvalidInput`] before signing is attempted.
 #[no_mangle]
-pub extern "C" fn mldsa_verify(
-    public_key_ptr: *const u8,
-    public_key_len: size_t,
+pub extern "C" fn mldsa_sign_ctx(
+    level: c_int,
+    secret_key_handle: MLDSASecretHandle,
     message_ptr: *const u8,
     message_len: size_t,
-    signature_ptr: *const u8,
-    signature_len: size_t,
+    ctx_ptr: *const u8,
+    ctx_len: size_t,
+    signature_out: *mut *mut u8,
+    signature_len_out: *mut size_t,
 ) -> c_int {
-    let public_key_slice = match safe_slice_from_raw(public_key_ptr, public_key_len) {
+    if signature_out.is_null() || signature_len_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let context_slice = match context_slice_from_raw(ctx_ptr, ctx_len) {
         Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let signing_result = secret_registry::with_secret(secret_key_handle, |secret_key_slice| {
+        let layout = MLDSALayout {
+            level,
+            field: MLDSAField::SecretKey,
+        };
+        layout.check_len(secret_key_slice.len())?;
+
+        record_operation_time("mldsa_sign_ctx", || {
+            match core_mldsa_sign_ctx(level, secret_key_slice, message_slice, context_slice) {
+                Ok(signature_result) => Ok(signature_result.signature.expose_secret().clone()),
+                Err(e) => {
+                    set_last_error(
+                        FFIErrorCode::CryptoError,
+                        &format!("ML-DSA context signing failed: {e}"),
+                    );
+                    Err(FFIErrorCode::CryptoError)
+                }
+            }
+        })
+    });
+
+    let signature = match signing_result {
+        Some(Ok(signature)) => signature,
+        Some(Err(code)) => return code as c_int,
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidKeyFormat,
+                "Unknown or already-released secret key handle",
+            );
+            return FFIErrorCode::InvalidKeyFormat as c_int;
+        }
+    };
+
+    let mut sig_buffer = match FFIBuffer::new(signature.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                &format!("Failed to allocate signature buffer: {e}"),
+            );
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(signature.as_ptr(), sig_buffer.as_mut_ptr(), signature.len());
+
+        *signature_out = sig_buffer.into_raw();
+        *signature_len_out = signature.len();
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
+/// HashML-DSA (FIPS 204 Algorithm 4) using SHA-512 as the approved pre-hash
+/// function: signs `OID(SHA-512) || SHA-512(message)` instead of `message`
+/// itself (see [`crate::MlDsaSecretKey::sign_prehash`]), so a caller that
+/// has already hashed a large or streamed message can sign the digest
+/// without sending the raw bytes across the FFI boundary. `ctx_len` follows
+/// the same rules as [`mldsa_sign_ctx`]. A signature produced here will not
+/// verify under the plain [`mldsa_verify`]/[`mldsa_verify_ctx`]: the
+/// pre-hash mode's domain separation byte differs from the non-prehash
+/// modes', so the two sign distinct `M'` values even for identical input
+/// bytes.
+#[no_mangle]
+pub extern "C" fn mldsa_sign_prehash(
+    level: c_int,
+    secret_key_handle: MLDSASecretHandle,
+    message_ptr: *const u8,
+    message_len: size_t,
+    ctx_ptr: *const u8,
+    ctx_len: size_t,
+    signature_out: *mut *mut u8,
+    signature_len_out: *mut size_t,
+) -> c_int {
+    if signature_out.is_null() || signature_len_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
         Err(e) => {
-            set_last_error(&format!("Invalid public key buffer: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
-    
+
     let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
         Ok(slice) => slice,
         Err(e) => {
-            set_last_error(&format!("Invalid message buffer: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
-    
-    let signature_slice = match safe_slice_from_raw(signature_ptr, signature_len) {
+
+    let context_slice = match context_slice_from_raw(ctx_ptr, ctx_len) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let signing_result = secret_registry::with_secret(secret_key_handle, |secret_key_slice| {
+        let layout = MLDSALayout {
+            level,
+            field: MLDSAField::SecretKey,
+        };
+        layout.check_len(secret_key_slice.len())?;
+
+        record_operation_time("mldsa_sign_prehash", || {
+            match core_mldsa_sign_prehash(level, secret_key_slice, message_slice, context_slice) {
+                Ok(signature_result) => Ok(signature_result.signature.expose_secret().clone()),
+                Err(e) => {
+                    set_last_error(
+                        FFIErrorCode::CryptoError,
+                        &format!("ML-DSA pre-hash signing failed: {e}"),
+                    );
+                    Err(FFIErrorCode::CryptoError)
+                }
+            }
+        })
+    });
+
+    let signature = match signing_result {
+        Some(Ok(signature)) => signature,
+        Some(Err(code)) => return code as c_int,
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidKeyFormat,
+                "Unknown or already-released secret key handle",
+            );
+            return FFIErrorCode::InvalidKeyFormat as c_int;
+        }
+    };
+
+    let mut sig_buffer = match FFIBuffer::new(signature.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                &format!("Failed to allocate signature buffer: {e}"),
+            );
+            return FFIErrorCode::AllocationFailed as c_int;
+        }
+    };
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(signature.as_ptr(), sig_buffer.as_mut_ptr(), signature.len());
+
+        *signature_out = sig_buffer.into_raw();
+        *signature_len_out = signature.len();
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
+/// Starts a streamed ML-DSA signature: allocate a stream with
+/// `ffi_stream_new`, feed the message through it in chunks via this
+/// function, then call `mldsa_sign_final` to sign everything buffered so
+/// far. Lets bindings stream a message through without holding the whole
+/// thing in one contiguous buffer on the C side.
+#[no_mangle]
+pub extern "C" fn mldsa_sign_update(
+    handle: StreamHandle,
+    chunk_ptr: *const u8,
+    chunk_len: size_t,
+) -> c_int {
+    let chunk = match safe_slice_from_raw(chunk_ptr, chunk_len) {
         Ok(slice) => slice,
         Err(e) => {
-            set_last_error(&format!("Invalid signature buffer: {e}"));
+            set_last_error(e, "Invalid message chunk buffer");
+            return e as c_int;
+        }
+    };
+
+    match stream::feed_stream(handle, chunk) {
+        Ok(()) => FFIErrorCode::Success as c_int,
+        Err(code) => code as c_int,
+    }
+}
+
+/// Signs the message accumulated by prior `mldsa_sign_update` calls on
+/// `handle` and releases the stream, success or failure. Signature
+/// out-params and error reporting mirror `mldsa_sign`.
+#[no_mangle]
+pub extern "C" fn mldsa_sign_final(
+    handle: StreamHandle,
+    level: c_int,
+    secret_key_handle: MLDSASecretHandle,
+    signature_out: *mut *mut u8,
+    signature_len_out: *mut size_t,
+) -> c_int {
+    if signature_out.is_null() || signature_len_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let message = match stream::drain_all(handle) {
+        Some(message) => message,
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                "Unknown or already-released stream handle",
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
-    
-    match core_mldsa_verify(public_key_slice, message_slice, signature_slice) {
+
+    sign_and_emit(
+        level,
+        secret_key_handle,
+        &message,
+        signature_out,
+        signature_len_out,
+    )
+}
+
+/// Shared tail of `mldsa_verify`/`mldsa_verify_final`: check `message`
+/// against `signature_slice` under `public_key_slice`, both of which have
+/// already been validated against `level`.
+fn verify_checked(
+    level: MLDSALevel,
+    public_key_slice: &[u8],
+    message: &[u8],
+    signature_slice: &[u8],
+) -> c_int {
+    match core_mldsa_verify(level, public_key_slice, message, signature_slice) {
         Ok(is_valid) => {
             if is_valid {
                 FFIErrorCode::Success as c_int
             } else {
-                set_last_error("Signature verification failed");
+                set_last_error(
+                    FFIErrorCode::SignatureVerificationFailed,
+                    "Signature verification failed",
+                );
                 FFIErrorCode::SignatureVerificationFailed as c_int
             }
+        }
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("ML-DSA verification failed: {e}"),
+            );
+            FFIErrorCode::CryptoError as c_int
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn mldsa_verify(
+    level: c_int,
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    message_ptr: *const u8,
+    message_len: size_t,
+    signature_ptr: *const u8,
+    signature_len: size_t,
+) -> c_int {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let public_key_slice = match safe_typed_slice_from_raw(
+        public_key_ptr,
+        public_key_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::PublicKey,
         },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let signature_slice = match safe_typed_slice_from_raw(
+        signature_ptr,
+        signature_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::Signature,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    verify_checked(level, public_key_slice, message_slice, signature_slice)
+}
+
+/// Detached counterpart to [`mldsa_verify`]: checks a fixed-size signature
+/// produced by [`mldsa_sign_detached`] against `message`, without a
+/// `SignedMessage` to extract the plaintext from first.
+#[no_mangle]
+pub extern "C" fn mldsa_verify_detached(
+    level: c_int,
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    message_ptr: *const u8,
+    message_len: size_t,
+    signature_ptr: *const u8,
+    signature_len: size_t,
+) -> c_int {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let public_key_slice = match safe_typed_slice_from_raw(
+        public_key_ptr,
+        public_key_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let signature_slice = match safe_typed_slice_from_raw(
+        signature_ptr,
+        signature_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::Signature,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    match core_mldsa_verify_detached(level, public_key_slice, message_slice, signature_slice) {
+        Ok(true) => FFIErrorCode::Success as c_int,
+        Ok(false) => {
+            set_last_error(
+                FFIErrorCode::SignatureVerificationFailed,
+                "Detached signature verification failed",
+            );
+            FFIErrorCode::SignatureVerificationFailed as c_int
+        }
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("ML-DSA detached verification failed: {e}"),
+            );
+            FFIErrorCode::CryptoError as c_int
+        }
+    }
+}
+
+/// Verifies a signature produced by [`mldsa_sign_ctx`] against `message`
+/// under the same `ctx`. `ctx_len` of `0` reproduces [`mldsa_verify`]'s
+/// behavior exactly; a signature bound to a different (or absent) context
+/// won't verify here.
+#[no_mangle]
+pub extern "C" fn mldsa_verify_ctx(
+    level: c_int,
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    message_ptr: *const u8,
+    message_len: size_t,
+    ctx_ptr: *const u8,
+    ctx_len: size_t,
+    signature_ptr: *const u8,
+    signature_len: size_t,
+) -> c_int {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
         Err(e) => {
-            set_last_error(&format!("ML-DSA verification failed: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let public_key_slice = match safe_typed_slice_from_raw(
+        public_key_ptr,
+        public_key_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let context_slice = match context_slice_from_raw(ctx_ptr, ctx_len) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let signature_slice = match safe_typed_slice_from_raw(
+        signature_ptr,
+        signature_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::Signature,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    match core_mldsa_verify_ctx(level, public_key_slice, message_slice, context_slice, signature_slice) {
+        Ok(true) => FFIErrorCode::Success as c_int,
+        Ok(false) => {
+            set_last_error(
+                FFIErrorCode::SignatureVerificationFailed,
+                "Context signature verification failed",
+            );
+            FFIErrorCode::SignatureVerificationFailed as c_int
+        }
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("ML-DSA context verification failed: {e}"),
+            );
             FFIErrorCode::CryptoError as c_int
         }
     }
 }
 
+/// Verifies a signature produced by [`mldsa_sign_prehash`] against
+/// `message` under the same `ctx`, re-hashing `message` with SHA-512 the
+/// same way signing did. A pre-hash signature will not verify under
+/// [`mldsa_verify`]/[`mldsa_verify_ctx`] (and vice versa), by construction.
+#[no_mangle]
+pub extern "C" fn mldsa_verify_prehash(
+    level: c_int,
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    message_ptr: *const u8,
+    message_len: size_t,
+    ctx_ptr: *const u8,
+    ctx_len: size_t,
+    signature_ptr: *const u8,
+    signature_len: size_t,
+) -> c_int {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let public_key_slice = match safe_typed_slice_from_raw(
+        public_key_ptr,
+        public_key_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let message_slice = match safe_slice_from_raw(message_ptr, message_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid message buffer: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let context_slice = match context_slice_from_raw(ctx_ptr, ctx_len) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let signature_slice = match safe_typed_slice_from_raw(
+        signature_ptr,
+        signature_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::Signature,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    match core_mldsa_verify_prehash(
+        level,
+        public_key_slice,
+        message_slice,
+        context_slice,
+        signature_slice,
+    ) {
+        Ok(true) => FFIErrorCode::Success as c_int,
+        Ok(false) => {
+            set_last_error(
+                FFIErrorCode::SignatureVerificationFailed,
+                "Pre-hash signature verification failed",
+            );
+            FFIErrorCode::SignatureVerificationFailed as c_int
+        }
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::CryptoError,
+                &format!("ML-DSA pre-hash verification failed: {e}"),
+            );
+            FFIErrorCode::CryptoError as c_int
+        }
+    }
+}
+
+/// Starts a streamed ML-DSA verification: allocate a stream with
+/// `ffi_stream_new`, feed the message through it in chunks via this
+/// function, then call `mldsa_verify_final` with the public key and
+/// signature to check against everything buffered so far.
+#[no_mangle]
+pub extern "C" fn mldsa_verify_update(
+    handle: StreamHandle,
+    chunk_ptr: *const u8,
+    chunk_len: size_t,
+) -> c_int {
+    let chunk = match safe_slice_from_raw(chunk_ptr, chunk_len) {
+        Ok(slice) => slice,
+        Err(e) => {
+            set_last_error(e, "Invalid message chunk buffer");
+            return e as c_int;
+        }
+    };
+
+    match stream::feed_stream(handle, chunk) {
+        Ok(()) => FFIErrorCode::Success as c_int,
+        Err(code) => code as c_int,
+    }
+}
+
+/// Verifies `signature` against the message accumulated by prior
+/// `mldsa_verify_update` calls on `handle` and releases the stream,
+/// success or failure. Error reporting mirrors `mldsa_verify`.
+#[no_mangle]
+pub extern "C" fn mldsa_verify_final(
+    handle: StreamHandle,
+    level: c_int,
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    signature_ptr: *const u8,
+    signature_len: size_t,
+) -> c_int {
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    let public_key_slice = match safe_typed_slice_from_raw(
+        public_key_ptr,
+        public_key_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let signature_slice = match safe_typed_slice_from_raw(
+        signature_ptr,
+        signature_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::Signature,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let message = match stream::drain_all(handle) {
+        Some(message) => message,
+        None => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                "Unknown or already-released stream handle",
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    verify_checked(level, public_key_slice, &message, signature_slice)
+}
+
+/// One (public_key, message, signature) triple in an `mldsa_verify_batch`
+/// call. All three buffers are borrowed for the duration of the call; the
+/// caller retains ownership.
+#[repr(C)]
+pub struct CMLDSABatchItem {
+    pub public_key_ptr: *const u8,
+    pub public_key_len: size_t,
+    pub message_ptr: *const u8,
+    pub message_len: size_t,
+    pub signature_ptr: *const u8,
+    pub signature_len: size_t,
+}
+
+/// Verifies `items_len` signatures against a single ML-DSA security level,
+/// stopping at the first failure. On success returns
+/// `FFIErrorCode::Success` with `failure_index_out` untouched; on the first
+/// invalid or malformed signature, writes that item's index to
+/// `failure_index_out` and returns the code describing why it failed, so a
+/// caller checking a mixed batch doesn't have to call `mldsa_verify` once
+/// per item.
+#[no_mangle]
+pub extern "C" fn mldsa_verify_batch(
+    level: c_int,
+    items_ptr: *const CMLDSABatchItem,
+    items_len: size_t,
+    failure_index_out: *mut size_t,
+) -> c_int {
+    if failure_index_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameter cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    if items_ptr.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Batch item array cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let items = unsafe { std::slice::from_raw_parts(items_ptr, items_len) };
+
+    record_operation_time("mldsa_verify_batch", || {
+        for (index, item) in items.iter().enumerate() {
+            let public_key_slice = match safe_typed_slice_from_raw(
+                item.public_key_ptr,
+                item.public_key_len,
+                MLDSALayout {
+                    level,
+                    field: MLDSAField::PublicKey,
+                },
+            ) {
+                Ok(slice) => slice,
+                Err(code) => {
+                    unsafe { *failure_index_out = index };
+                    return code as c_int;
+                }
+            };
+
+            let message_slice = match safe_slice_from_raw(item.message_ptr, item.message_len) {
+                Ok(slice) => slice,
+                Err(e) => {
+                    set_last_error(
+                        FFIErrorCode::InvalidInput,
+                        &format!("Invalid message buffer at index {index}: {e}"),
+                    );
+                    unsafe { *failure_index_out = index };
+                    return FFIErrorCode::InvalidInput as c_int;
+                }
+            };
+
+            let signature_slice = match safe_typed_slice_from_raw(
+                item.signature_ptr,
+                item.signature_len,
+                MLDSALayout {
+                    level,
+                    field: MLDSAField::Signature,
+                },
+            ) {
+                Ok(slice) => slice,
+                Err(code) => {
+                    unsafe { *failure_index_out = index };
+                    return code as c_int;
+                }
+            };
+
+            match core_mldsa_verify(level, public_key_slice, message_slice, signature_slice) {
+                Ok(true) => {}
+                Ok(false) => {
+                    set_last_error(
+                        FFIErrorCode::SignatureVerificationFailed,
+                        &format!("Signature verification failed at index {index}"),
+                    );
+                    unsafe { *failure_index_out = index };
+                    return FFIErrorCode::SignatureVerificationFailed as c_int;
+                }
+                Err(e) => {
+                    set_last_error(
+                        FFIErrorCode::CryptoError,
+                        &format!("ML-DSA verification failed at index {index}: {e}"),
+                    );
+                    unsafe { *failure_index_out = index };
+                    return FFIErrorCode::CryptoError as c_int;
+                }
+            }
+        }
+
+        FFIErrorCode::Success as c_int
+    })
+}
+
+fn verify_batch_item(level: MLDSALevel, item: &CMLDSABatchItem) -> FFIErrorCode {
+    let public_key_slice = match safe_typed_slice_from_raw(
+        item.public_key_ptr,
+        item.public_key_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code,
+    };
+
+    let message_slice = match safe_slice_from_raw(item.message_ptr, item.message_len) {
+        Ok(slice) => slice,
+        Err(code) => return code,
+    };
+
+    let signature_slice = match safe_typed_slice_from_raw(
+        item.signature_ptr,
+        item.signature_len,
+        MLDSALayout {
+            level,
+            field: MLDSAField::Signature,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code,
+    };
+
+    match core_mldsa_verify(level, public_key_slice, message_slice, signature_slice) {
+        Ok(true) => FFIErrorCode::Success,
+        Ok(false) => FFIErrorCode::SignatureVerificationFailed,
+        Err(_) => FFIErrorCode::CryptoError,
+    }
+}
+
+/// Verifies every item in `items_len` independently, fanned out across
+/// rayon's global thread pool, and writes each item's own
+/// [`FFIErrorCode`] into `results_out[i]` -- unlike [`mldsa_verify_batch`],
+/// which stops at the first failure, every item here gets a verdict, so a
+/// caller checking a large batch of independent signatures pays for one
+/// FFI call and saturates the host's cores instead of looping over
+/// `mldsa_verify` (or `mldsa_verify_batch`'s fail-fast loop) one signature
+/// at a time. `results_out` must point to a caller-allocated array of at
+/// least `items_len` `int`s; per-item last-error messages aren't recorded
+/// (they'd race across worker threads), so a failing item is identified
+/// by its `FFIErrorCode` alone.
+#[no_mangle]
+pub extern "C" fn mldsa_verify_batch_parallel(
+    level: c_int,
+    items_ptr: *const CMLDSABatchItem,
+    items_len: size_t,
+    results_out: *mut c_int,
+) -> c_int {
+    if results_out.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Results output array cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mldsa_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(e) => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-DSA security level: {e}"),
+            );
+            return FFIErrorCode::InvalidInput as c_int;
+        }
+    };
+
+    if items_ptr.is_null() {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Batch item array cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let items = unsafe { std::slice::from_raw_parts(items_ptr, items_len) };
+    let results = unsafe { std::slice::from_raw_parts_mut(results_out, items_len) };
+
+    record_operation_time("mldsa_verify_batch_parallel", || {
+        items
+            .par_iter()
+            .zip(results.par_iter_mut())
+            .for_each(|(item, result_slot)| {
+                *result_slot = verify_batch_item(level, item) as c_int;
+            });
+    });
+
+    FFIErrorCode::Success as c_int
+}
+
 #[no_mangle]
 pub extern "C" fn mldsa_keypair_free(keypair: *mut CMLDSAKeyPair) {
     if !keypair.is_null() {
         unsafe {
             let keypair = Box::from_raw(keypair);
-            
+
             if !keypair.public_key_ptr.is_null() && keypair.public_key_len > 0 {
                 let layout = std::alloc::Layout::array::<u8>(keypair.public_key_len).unwrap();
-                let slice = std::slice::from_raw_parts_mut(keypair.public_key_ptr, keypair.public_key_len);
+                let slice =
+                    std::slice::from_raw_parts_mut(keypair.public_key_ptr, keypair.public_key_len);
                 slice.fill(0);
                 std::alloc::dealloc(keypair.public_key_ptr, layout);
             }
-            
-            if !keypair.secret_key_ptr.is_null() && keypair.secret_key_len > 0 {
-                let layout = std::alloc::Layout::array::<u8>(keypair.secret_key_len).unwrap();
-                let slice = std::slice::from_raw_parts_mut(keypair.secret_key_ptr, keypair.secret_key_len);
-                slice.fill(0);
-                std::alloc::dealloc(keypair.secret_key_ptr, layout);
-            }
+
+            secret_registry::release(keypair.secret_key_handle);
         }
     }
 }
@@ -1,75 +1,254 @@
-use crate::ffi::memory::{safe_slice_from_raw, set_last_error, FFIBuffer, FFIErrorCode};
+use crate::ffi::memory::{
+    safe_typed_slice_from_raw, set_last_error, FFIBuffer, FFIErrorCode, FfiLayout,
+};
 use crate::ffi::monitoring::record_operation_time;
 use crate::{
-    generate_mlkem_keypair, mlkem_decapsulate as core_mlkem_decapsulate,
-    mlkem_encapsulate as core_mlkem_encapsulate,
+    generate_mlkem_keypair_deterministic, generate_mlkem_keypair_for_algorithm,
+    mlkem_encapsulate_deterministic as core_mlkem_encapsulate_deterministic, MLKEMLevel,
+    MlKemCiphertext, MlKemPublicKey, MlKemSecretKey, PQCAlgorithm,
 };
 use libc::size_t;
 use secrecy::ExposeSecret;
 use std::os::raw::c_int;
 
+/// Parses the `level: c_int` ABI parameter every parameterized ML-KEM FFI
+/// entry point takes, mirroring [`crate::ffi::mldsa_ffi::mldsa_level_from_c_int`].
+pub(crate) fn mlkem_level_from_c_int(level: c_int) -> Result<MLKEMLevel, FFIErrorCode> {
+    match level {
+        512 => Ok(MLKEMLevel::MlKem512),
+        768 => Ok(MLKEMLevel::MlKem768),
+        1024 => Ok(MLKEMLevel::MlKem1024),
+        _ => {
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ML-KEM level: {level} (expected 512, 768, or 1024)"),
+            );
+            Err(FFIErrorCode::InvalidInput)
+        }
+    }
+}
+
+/// Which field of an ML-KEM key/ciphertext triple an [`MLKEMLayout`] checks
+/// the length of. `pub(crate)` so [`crate::ffi::hybrid_kem_ffi`] can reuse it
+/// for the ML-KEM-768 half of the hybrid KEM rather than duplicating it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum MLKEMField {
+    PublicKey,
+    SecretKey,
+    Ciphertext,
+}
+
+/// An [`FfiLayout`] for an ML-KEM public key, secret key, or ciphertext at a
+/// given [`MLKEMLevel`], so a wrong-length buffer is rejected at the
+/// boundary with a message naming both sizes instead of falling through to
+/// the generic `PQCError` a short buffer would otherwise produce deep
+/// inside `pqcrypto_mlkem`. Mirrors [`crate::ffi::constants::MLDSALayout`].
+pub(crate) struct MLKEMLayout {
+    pub(crate) level: MLKEMLevel,
+    pub(crate) field: MLKEMField,
+}
+
+impl FfiLayout for MLKEMLayout {
+    fn layout_name(&self) -> String {
+        let field = match self.field {
+            MLKEMField::PublicKey => "public key",
+            MLKEMField::SecretKey => "secret key",
+            MLKEMField::Ciphertext => "ciphertext",
+        };
+        format!("{} {field}", PQCAlgorithm::from(self.level).name())
+    }
+
+    fn expected_len(&self) -> usize {
+        let algorithm = PQCAlgorithm::from(self.level);
+        match self.field {
+            MLKEMField::PublicKey => algorithm.public_key_size(),
+            MLKEMField::SecretKey => algorithm.secret_key_size(),
+            MLKEMField::Ciphertext => algorithm
+                .ciphertext_size()
+                .expect("an MLKEMLevel always maps to a KEM PQCAlgorithm"),
+        }
+    }
+
+    fn error_code(&self) -> FFIErrorCode {
+        match self.field {
+            MLKEMField::PublicKey => FFIErrorCode::InvalidPublicKeySize,
+            MLKEMField::SecretKey => FFIErrorCode::InvalidSecretKeySize,
+            MLKEMField::Ciphertext => FFIErrorCode::InvalidCiphertextSize,
+        }
+    }
+}
+
+/// FIPS 203's ML-KEM-768 deterministic keygen seed length (`d || z`).
+const MLKEM_DETERMINISTIC_SEED_LEN: usize = 64;
+/// FIPS 203's ML-KEM-768 deterministic encapsulation message length (`m`).
+const MLKEM_DETERMINISTIC_MESSAGE_LEN: usize = 32;
+
+/// An [`FfiLayout`] enforcing the fixed lengths the deterministic ML-KEM-768
+/// entry points require, so a wrong-size seed or message is rejected with
+/// a descriptive last-error message rather than failing deep inside
+/// `pqc_kyber`.
+struct FixedLenLayout {
+    name: &'static str,
+    expected_len: usize,
+}
+
+impl FfiLayout for FixedLenLayout {
+    fn layout_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn expected_len(&self) -> usize {
+        self.expected_len
+    }
+}
+
 #[repr(C)]
 pub struct CMLKEMKeyPair {
     pub public_key_ptr: *mut u8,
     pub public_key_len: size_t,
     pub secret_key_ptr: *mut u8,
     pub secret_key_len: size_t,
+    pub level: c_int,
+}
+
+/// Copies `public_key`/`secret_key` into freshly allocated [`FFIBuffer`]s
+/// and wraps them, alongside `level`, in a boxed [`CMLKEMKeyPair`], or
+/// returns `None` (with `set_last_error` already called) on allocation
+/// failure. Shared by [`mlkem_keypair_generate`] and
+/// [`mlkem_keypair_generate_deterministic`].
+///
+/// The secret key buffer is allocated with [`FFIBuffer::new_secure`] (not
+/// `new`): it is `mlock`'d and guard-paged against swap and stray reads
+/// rather than merely zeroed at free time, so it must be released via
+/// `ffi_secure_buffer_free`, which is exactly what [`mlkem_keypair_free`]
+/// does for `secret_key_ptr`. The public key isn't secret and stays on the
+/// ordinary allocator.
+fn make_keypair_buffers(
+    public_key: &[u8],
+    secret_key: &[u8],
+    level: MLKEMLevel,
+) -> Option<Box<CMLKEMKeyPair>> {
+    let mut public_buffer = match FFIBuffer::new(public_key.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            let message = format!("Failed to allocate public key buffer: {e}");
+            set_last_error(FFIErrorCode::from(e), &message);
+            return None;
+        }
+    };
+
+    let mut secret_buffer = match FFIBuffer::new_secure(secret_key.len()) {
+        Ok(buf) => buf,
+        Err(e) => {
+            let message = format!("Failed to allocate secret key buffer: {e}");
+            set_last_error(FFIErrorCode::from(e), &message);
+            return None;
+        }
+    };
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            public_key.as_ptr(),
+            public_buffer.as_mut_ptr(),
+            public_key.len(),
+        );
+        std::ptr::copy_nonoverlapping(
+            secret_key.as_ptr(),
+            secret_buffer.as_mut_ptr(),
+            secret_key.len(),
+        );
+    }
+
+    Some(Box::new(CMLKEMKeyPair {
+        public_key_ptr: public_buffer.into_raw(),
+        public_key_len: public_key.len(),
+        secret_key_ptr: secret_buffer.into_raw(),
+        secret_key_len: secret_key.len(),
+        level: level as c_int,
+    }))
 }
 
+/// Generates an ML-KEM keypair at the given security `level` (512, 768, or
+/// 1024; see [`MLKEMLevel`]), so C callers pick the security level at the
+/// ABI boundary instead of the surface being pinned to ML-KEM-768.
 #[no_mangle]
-pub extern "C" fn mlkem_keypair_generate() -> *mut CMLKEMKeyPair {
-    record_operation_time("mlkem_keygen", || match generate_mlkem_keypair() {
-        Ok(keypair) => {
-            let public_key = keypair.public_key;
-            let secret_key = keypair.private_key.expose_secret().clone();
-
-            let mut public_buffer = match FFIBuffer::new(public_key.len()) {
-                Ok(buf) => buf,
-                Err(e) => {
-                    set_last_error(&format!("Failed to allocate public key buffer: {e}"));
-                    return std::ptr::null_mut();
-                }
-            };
+pub extern "C" fn mlkem_keypair_generate(level: c_int) -> *mut CMLKEMKeyPair {
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(_) => return std::ptr::null_mut(),
+    };
 
-            let mut secret_buffer = match FFIBuffer::new(secret_key.len()) {
-                Ok(buf) => buf,
-                Err(e) => {
-                    set_last_error(&format!("Failed to allocate secret key buffer: {e}"));
-                    return std::ptr::null_mut();
+    record_operation_time("mlkem_keygen", || {
+        match generate_mlkem_keypair_for_algorithm(PQCAlgorithm::from(level)) {
+            Ok(keypair) => {
+                let secret_key = keypair.private_key.expose_secret().clone();
+                match make_keypair_buffers(&keypair.public_key, &secret_key, level) {
+                    Some(c_keypair) => Box::into_raw(c_keypair),
+                    None => std::ptr::null_mut(),
                 }
-            };
-
-            unsafe {
-                std::ptr::copy_nonoverlapping(
-                    public_key.as_ptr(),
-                    public_buffer.as_mut_ptr(),
-                    public_key.len(),
-                );
-                std::ptr::copy_nonoverlapping(
-                    secret_key.as_ptr(),
-                    secret_buffer.as_mut_ptr(),
-                    secret_key.len(),
+            }
+            Err(e) => {
+                set_last_error(
+                    FFIErrorCode::CryptoError,
+                    &format!("ML-KEM keypair generation failed: {e}"),
                 );
+                std::ptr::null_mut()
             }
+        }
+    })
+}
 
-            let keypair = Box::new(CMLKEMKeyPair {
-                public_key_ptr: public_buffer.into_raw(),
-                public_key_len: public_key.len(),
-                secret_key_ptr: secret_buffer.into_raw(),
-                secret_key_len: secret_key.len(),
-            });
+/// Deterministic counterpart to [`mlkem_keypair_generate`]: derives an
+/// ML-KEM-768 keypair from the 64-byte FIPS 203 seed at `seed_ptr` (`d ||
+/// z`) instead of drawing fresh randomness, so NIST ACVP/KAT vectors and
+/// byte-exact consistency tests can reproduce a known keypair. See
+/// [`crate::generate_mlkem_keypair_deterministic`].
+#[no_mangle]
+pub extern "C" fn mlkem_keypair_generate_deterministic(
+    seed_ptr: *const u8,
+    seed_len: size_t,
+) -> *mut CMLKEMKeyPair {
+    let seed_slice = match safe_typed_slice_from_raw(
+        seed_ptr,
+        seed_len,
+        FixedLenLayout {
+            name: "ML-KEM-768 deterministic keygen seed (d || z)",
+            expected_len: MLKEM_DETERMINISTIC_SEED_LEN,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    let seed: [u8; MLKEM_DETERMINISTIC_SEED_LEN] =
+        seed_slice.try_into().expect("length checked by FixedLenLayout above");
 
-            Box::into_raw(keypair)
-        }
-        Err(e) => {
-            set_last_error(&format!("ML-KEM keypair generation failed: {e}"));
-            std::ptr::null_mut()
+    record_operation_time("mlkem_keygen_deterministic", || {
+        match generate_mlkem_keypair_deterministic(&seed) {
+            Ok(keypair) => {
+                let secret_key = keypair.private_key.expose_secret().clone();
+                match make_keypair_buffers(&keypair.public_key, &secret_key, MLKEMLevel::MlKem768)
+                {
+                    Some(c_keypair) => Box::into_raw(c_keypair),
+                    None => std::ptr::null_mut(),
+                }
+            }
+            Err(e) => {
+                set_last_error(
+                    FFIErrorCode::CryptoError,
+                    &format!("Deterministic ML-KEM keypair generation failed: {e}"),
+                );
+                std::ptr::null_mut()
+            }
         }
     })
 }
 
+/// Encapsulates a fresh shared secret against the ML-KEM public key at
+/// `public_key_ptr` for the given security `level` (512, 768, or 1024; see
+/// [`MLKEMLevel`]).
 #[no_mangle]
 pub extern "C" fn mlkem_encapsulate(
+    level: c_int,
     public_key_ptr: *const u8,
     public_key_len: size_t,
     shared_secret_out: *mut *mut u8,
@@ -82,28 +261,55 @@ pub extern "C" fn mlkem_encapsulate(
         || ciphertext_out.is_null()
         || ciphertext_len_out.is_null()
     {
-        set_last_error("Output parameters cannot be null");
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
         return FFIErrorCode::InvalidInput as c_int;
     }
 
-    let public_key_slice = match safe_slice_from_raw(public_key_ptr, public_key_len) {
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+
+    let public_key_slice = match safe_typed_slice_from_raw(
+        public_key_ptr,
+        public_key_len,
+        MLKEMLayout {
+            level,
+            field: MLKEMField::PublicKey,
+        },
+    ) {
         Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let public_key = match MlKemPublicKey::from_bytes(PQCAlgorithm::from(level), public_key_slice)
+    {
+        Ok(public_key) => public_key,
         Err(e) => {
-            set_last_error(&format!("Invalid public key buffer: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid public key: {e}"),
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
 
     record_operation_time("mlkem_encap", || {
-        match core_mlkem_encapsulate(public_key_slice, b"") {
+        match public_key.encapsulate() {
             Ok(result) => {
                 let shared_secret = result.shared_secret.expose_secret();
                 let ciphertext = result.ciphertext;
 
-                let mut ss_buffer = match FFIBuffer::new(shared_secret.len()) {
+                let mut ss_buffer = match FFIBuffer::new_secure(shared_secret.len()) {
                     Ok(buf) => buf,
                     Err(e) => {
-                        set_last_error(&format!("Failed to allocate shared secret buffer: {e}"));
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate shared secret buffer: {e}"),
+                        );
                         return FFIErrorCode::AllocationFailed as c_int;
                     }
                 };
@@ -111,7 +317,10 @@ pub extern "C" fn mlkem_encapsulate(
                 let mut ct_buffer = match FFIBuffer::new(ciphertext.len()) {
                     Ok(buf) => buf,
                     Err(e) => {
-                        set_last_error(&format!("Failed to allocate ciphertext buffer: {e}"));
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate ciphertext buffer: {e}"),
+                        );
                         return FFIErrorCode::AllocationFailed as c_int;
                     }
                 };
@@ -137,15 +346,135 @@ pub extern "C" fn mlkem_encapsulate(
                 FFIErrorCode::Success as c_int
             }
             Err(e) => {
-                set_last_error(&format!("ML-KEM encapsulation failed: {e}"));
+                set_last_error(
+                    FFIErrorCode::CryptoError,
+                    &format!("ML-KEM encapsulation failed: {e}"),
+                );
                 FFIErrorCode::CryptoError as c_int
             }
         }
     })
 }
 
+/// Deterministic counterpart to [`mlkem_encapsulate`]: consumes the
+/// caller-supplied 32-byte message at `message_ptr` instead of the one
+/// `pqcrypto_mlkem`'s internal RNG would otherwise draw, so NIST ACVP/KAT
+/// vectors can be reproduced byte-exact. See
+/// [`crate::mlkem_encapsulate_deterministic`].
+#[no_mangle]
+pub extern "C" fn mlkem_encapsulate_deterministic(
+    public_key_ptr: *const u8,
+    public_key_len: size_t,
+    message_ptr: *const u8,
+    message_len: size_t,
+    shared_secret_out: *mut *mut u8,
+    shared_secret_len_out: *mut size_t,
+    ciphertext_out: *mut *mut u8,
+    ciphertext_len_out: *mut size_t,
+) -> c_int {
+    if shared_secret_out.is_null()
+        || shared_secret_len_out.is_null()
+        || ciphertext_out.is_null()
+        || ciphertext_len_out.is_null()
+    {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let public_key_slice = match safe_typed_slice_from_raw(
+        public_key_ptr,
+        public_key_len,
+        MLKEMLayout {
+            level: MLKEMLevel::MlKem768,
+            field: MLKEMField::PublicKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let message_slice = match safe_typed_slice_from_raw(
+        message_ptr,
+        message_len,
+        FixedLenLayout {
+            name: "ML-KEM-768 deterministic encapsulation message (m)",
+            expected_len: MLKEM_DETERMINISTIC_MESSAGE_LEN,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+    let message: [u8; MLKEM_DETERMINISTIC_MESSAGE_LEN] =
+        message_slice.try_into().expect("length checked by FixedLenLayout above");
+
+    record_operation_time("mlkem_encap_deterministic", || {
+        match core_mlkem_encapsulate_deterministic(public_key_slice, &message) {
+            Ok(result) => {
+                let shared_secret = result.shared_secret.expose_secret();
+                let ciphertext = result.ciphertext;
+
+                let mut ss_buffer = match FFIBuffer::new_secure(shared_secret.len()) {
+                    Ok(buf) => buf,
+                    Err(e) => {
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate shared secret buffer: {e}"),
+                        );
+                        return FFIErrorCode::AllocationFailed as c_int;
+                    }
+                };
+
+                let mut ct_buffer = match FFIBuffer::new(ciphertext.len()) {
+                    Ok(buf) => buf,
+                    Err(e) => {
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate ciphertext buffer: {e}"),
+                        );
+                        return FFIErrorCode::AllocationFailed as c_int;
+                    }
+                };
+
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        shared_secret.as_ptr(),
+                        ss_buffer.as_mut_ptr(),
+                        shared_secret.len(),
+                    );
+                    std::ptr::copy_nonoverlapping(
+                        ciphertext.as_ptr(),
+                        ct_buffer.as_mut_ptr(),
+                        ciphertext.len(),
+                    );
+
+                    *shared_secret_out = ss_buffer.into_raw();
+                    *shared_secret_len_out = shared_secret.len();
+                    *ciphertext_out = ct_buffer.into_raw();
+                    *ciphertext_len_out = ciphertext.len();
+                }
+
+                FFIErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(
+                    FFIErrorCode::CryptoError,
+                    &format!("Deterministic ML-KEM encapsulation failed: {e}"),
+                );
+                FFIErrorCode::CryptoError as c_int
+            }
+        }
+    })
+}
+
+/// Decapsulates a shared secret from `ciphertext_ptr` using the ML-KEM
+/// secret key at `secret_key_ptr` for the given security `level` (512, 768,
+/// or 1024; see [`MLKEMLevel`]).
 #[no_mangle]
 pub extern "C" fn mlkem_decapsulate(
+    level: c_int,
     secret_key_ptr: *const u8,
     secret_key_len: size_t,
     ciphertext_ptr: *const u8,
@@ -154,35 +483,78 @@ pub extern "C" fn mlkem_decapsulate(
     shared_secret_len_out: *mut size_t,
 ) -> c_int {
     if shared_secret_out.is_null() || shared_secret_len_out.is_null() {
-        set_last_error("Output parameters cannot be null");
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
         return FFIErrorCode::InvalidInput as c_int;
     }
 
-    let secret_key_slice = match safe_slice_from_raw(secret_key_ptr, secret_key_len) {
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+
+    let secret_key_slice = match safe_typed_slice_from_raw(
+        secret_key_ptr,
+        secret_key_len,
+        MLKEMLayout {
+            level,
+            field: MLKEMField::SecretKey,
+        },
+    ) {
+        Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let ciphertext_slice = match safe_typed_slice_from_raw(
+        ciphertext_ptr,
+        ciphertext_len,
+        MLKEMLayout {
+            level,
+            field: MLKEMField::Ciphertext,
+        },
+    ) {
         Ok(slice) => slice,
+        Err(code) => return code as c_int,
+    };
+
+    let algorithm = PQCAlgorithm::from(level);
+
+    let secret_key = match MlKemSecretKey::from_bytes(algorithm, secret_key_slice) {
+        Ok(secret_key) => secret_key,
         Err(e) => {
-            set_last_error(&format!("Invalid secret key buffer: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid secret key: {e}"),
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
 
-    let ciphertext_slice = match safe_slice_from_raw(ciphertext_ptr, ciphertext_len) {
-        Ok(slice) => slice,
+    let ciphertext = match MlKemCiphertext::from_bytes(algorithm, ciphertext_slice) {
+        Ok(ciphertext) => ciphertext,
         Err(e) => {
-            set_last_error(&format!("Invalid ciphertext buffer: {e}"));
+            set_last_error(
+                FFIErrorCode::InvalidInput,
+                &format!("Invalid ciphertext: {e}"),
+            );
             return FFIErrorCode::InvalidInput as c_int;
         }
     };
 
     record_operation_time("mlkem_decap", || {
-        match core_mlkem_decapsulate(secret_key_slice, ciphertext_slice) {
+        match secret_key.decapsulate(&ciphertext) {
             Ok(shared_secret) => {
                 let shared_secret_bytes = shared_secret.expose_secret();
 
-                let mut ss_buffer = match FFIBuffer::new(shared_secret_bytes.len()) {
+                let mut ss_buffer = match FFIBuffer::new_secure(shared_secret_bytes.len()) {
                     Ok(buf) => buf,
                     Err(e) => {
-                        set_last_error(&format!("Failed to allocate shared secret buffer: {e}"));
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate shared secret buffer: {e}"),
+                        );
                         return FFIErrorCode::AllocationFailed as c_int;
                     }
                 };
@@ -201,13 +573,122 @@ pub extern "C" fn mlkem_decapsulate(
                 FFIErrorCode::Success as c_int
             }
             Err(e) => {
-                set_last_error(&format!("ML-KEM decapsulation failed: {e}"));
+                set_last_error(
+                    FFIErrorCode::CryptoError,
+                    &format!("ML-KEM decapsulation failed: {e}"),
+                );
                 FFIErrorCode::CryptoError as c_int
             }
         }
     })
 }
 
+/// Writes the public key, secret key, ciphertext, and shared secret sizes
+/// for the given security `level` (512, 768, or 1024; see [`MLKEMLevel`])
+/// into the four out-pointers, so C callers can size their buffers without
+/// hardcoding the per-level byte counts.
+#[no_mangle]
+pub extern "C" fn mlkem_param_sizes(
+    level: c_int,
+    public_key_len_out: *mut size_t,
+    secret_key_len_out: *mut size_t,
+    ciphertext_len_out: *mut size_t,
+    shared_secret_len_out: *mut size_t,
+) -> c_int {
+    if public_key_len_out.is_null()
+        || secret_key_len_out.is_null()
+        || ciphertext_len_out.is_null()
+        || shared_secret_len_out.is_null()
+    {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+
+    let level = match mlkem_level_from_c_int(level) {
+        Ok(level) => level,
+        Err(code) => return code as c_int,
+    };
+    let algorithm = PQCAlgorithm::from(level);
+
+    unsafe {
+        *public_key_len_out = algorithm.public_key_size();
+        *secret_key_len_out = algorithm.secret_key_size();
+        *ciphertext_len_out = algorithm
+            .ciphertext_size()
+            .expect("an MLKEMLevel always maps to a KEM PQCAlgorithm");
+        *shared_secret_len_out = algorithm
+            .shared_secret_size()
+            .expect("an MLKEMLevel always maps to a KEM PQCAlgorithm");
+    }
+
+    FFIErrorCode::Success as c_int
+}
+
+/// Zeroizes and frees a shared secret buffer returned by
+/// `mlkem_encapsulate`/`mlkem_encapsulate_deterministic`/`mlkem_decapsulate`
+/// via their `shared_secret_out` out-pointers. The shared secret is secret
+/// material allocated with `FFIBuffer::new_secure`, so this routes to
+/// `ffi_secure_buffer_free` rather than `ffi_buffer_free` -- use
+/// [`mlkem_ciphertext_free`] for the (non-secret) `ciphertext_out` buffer
+/// instead.
+#[no_mangle]
+pub extern "C" fn mlkem_shared_secret_free(ptr: *mut u8, len: size_t) {
+    crate::ffi::memory::ffi_secure_buffer_free(ptr, len);
+}
+
+/// Zeroizes and frees a ciphertext buffer returned by
+/// `mlkem_encapsulate`/`mlkem_encapsulate_deterministic` via their
+/// `ciphertext_out` out-pointer. A ciphertext isn't secret, so (unlike
+/// [`mlkem_shared_secret_free`]) this is a thin wrapper over the plain
+/// `ffi_buffer_free`.
+#[no_mangle]
+pub extern "C" fn mlkem_ciphertext_free(ptr: *mut u8, len: size_t) {
+    crate::ffi::memory::ffi_buffer_free(ptr, len);
+}
+
+/// Compares two shared secrets (or any two equal-purpose byte buffers,
+/// such as a decapsulated secret against a known-answer-test vector) in
+/// constant time: every byte pair is XORed into a single accumulator and
+/// the branch on equality happens only once, at the end, so the number of
+/// leading matching bytes can't be inferred from comparison timing. This
+/// is the same accumulate-then-branch-once discipline FIPS 203's implicit
+/// rejection uses internally. Returns `1` if the buffers are equal in
+/// length and content, `0` otherwise (including on a null pointer, after
+/// `set_last_error`).
+#[no_mangle]
+pub extern "C" fn mlkem_shared_secret_equal(
+    a_ptr: *const u8,
+    a_len: size_t,
+    b_ptr: *const u8,
+    b_len: size_t,
+) -> c_int {
+    if a_ptr.is_null() || b_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Shared secret pointer is null");
+        return 0;
+    }
+
+    if a_len != b_len {
+        return 0;
+    }
+
+    let (a, b) = unsafe {
+        (
+            std::slice::from_raw_parts(a_ptr, a_len),
+            std::slice::from_raw_parts(b_ptr, b_len),
+        )
+    };
+
+    let mut diff: u8 = 0;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+
+    (diff == 0) as c_int
+}
+
 #[no_mangle]
 pub extern "C" fn mlkem_keypair_free(keypair: *mut CMLKEMKeyPair) {
     if !keypair.is_null() {
@@ -222,13 +703,15 @@ pub extern "C" fn mlkem_keypair_free(keypair: *mut CMLKEMKeyPair) {
                 std::alloc::dealloc(keypair.public_key_ptr, layout);
             }
 
-            if !keypair.secret_key_ptr.is_null() && keypair.secret_key_len > 0 {
-                let layout = std::alloc::Layout::array::<u8>(keypair.secret_key_len).unwrap();
-                let slice =
-                    std::slice::from_raw_parts_mut(keypair.secret_key_ptr, keypair.secret_key_len);
-                slice.fill(0);
-                std::alloc::dealloc(keypair.secret_key_ptr, layout);
-            }
+            // secret_key_ptr came from `FFIBuffer::new_secure` in
+            // `make_keypair_buffers`, so it must be released through the
+            // matching secure free path, not a plain `dealloc` -- the
+            // backing allocation is an `mlock`'d `mmap` region, not a
+            // `std::alloc` allocation.
+            crate::ffi::memory::ffi_secure_buffer_free(
+                keypair.secret_key_ptr,
+                keypair.secret_key_len,
+            );
         }
     }
 }
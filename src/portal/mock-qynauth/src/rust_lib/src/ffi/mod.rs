@@ -1,15 +1,54 @@
+pub mod constants;
+pub mod hybrid_ffi;
+pub mod hybrid_kem_ffi;
+pub mod key_encoding_ffi;
 pub mod memory;
 pub mod mldsa_ffi;
 pub mod mlkem_ffi;
 pub mod monitoring;
+pub(crate) mod secret_registry;
+pub mod shamir_ffi;
+pub mod stream;
 
+pub use constants::{
+    MLDSAPublicKey, MLDSASecretKey, MLDSASignature, MLDSA44_PUBLIC_KEY_SIZE,
+    MLDSA44_SECRET_KEY_SIZE, MLDSA44_SIGNATURE_SIZE, MLDSA65_PUBLIC_KEY_SIZE,
+    MLDSA65_SECRET_KEY_SIZE, MLDSA65_SIGNATURE_SIZE, MLDSA87_PUBLIC_KEY_SIZE,
+    MLDSA87_SECRET_KEY_SIZE, MLDSA87_SIGNATURE_SIZE,
+};
+pub use hybrid_ffi::{
+    hybrid_keypair_free, hybrid_keypair_generate, hybrid_sign, hybrid_verify, CHybridKeyPair,
+};
+pub use hybrid_kem_ffi::{
+    hybrid_kem_ciphertext_free, hybrid_kem_decapsulate, hybrid_kem_encapsulate,
+    hybrid_kem_keypair_free, hybrid_kem_keypair_generate, hybrid_kem_shared_secret_free,
+    CHybridKemKeyPair,
+};
+pub use key_encoding_ffi::{
+    mldsa_private_key_to_der, mlkem_private_key_to_der, mlkem_private_key_to_pem,
+    mlkem_public_key_to_der, mlkem_public_key_to_pem, private_key_from_pem, public_key_from_pem,
+};
 pub use memory::{
-    ffi_buffer_free, ffi_get_last_error_message, validate_buffer_params, FFIBuffer, FFIErrorCode,
+    ffi_buffer_free, ffi_clear_last_error, ffi_get_last_error_message, ffi_last_error_code,
+    ffi_secure_buffer_free, validate_buffer_params, FFIBuffer, FFIErrorCode,
 };
 pub use mldsa_ffi::{
-    mldsa_keypair_free, mldsa_keypair_generate, mldsa_sign, mldsa_verify, CMLDSAKeyPair,
+    mldsa_keypair_free, mldsa_keypair_generate, mldsa_sign, mldsa_sign_ctx, mldsa_sign_detached,
+    mldsa_sign_final, mldsa_sign_prehash, mldsa_sign_update, mldsa_verify, mldsa_verify_batch,
+    mldsa_verify_batch_parallel, mldsa_verify_ctx, mldsa_verify_detached, mldsa_verify_final,
+    mldsa_verify_prehash, mldsa_verify_update, CMLDSABatchItem, CMLDSAKeyPair,
 };
 pub use mlkem_ffi::{
-    mlkem_decapsulate, mlkem_encapsulate, mlkem_keypair_free, mlkem_keypair_generate, CMLKEMKeyPair,
+    mlkem_ciphertext_free, mlkem_decapsulate, mlkem_encapsulate, mlkem_encapsulate_deterministic,
+    mlkem_keypair_free, mlkem_keypair_generate, mlkem_keypair_generate_deterministic,
+    mlkem_param_sizes, mlkem_shared_secret_equal, mlkem_shared_secret_free, CMLKEMKeyPair,
+};
+pub use monitoring::{
+    ffi_enable_optimizations, get_percentile, record_operation_time, set_baseline_nanos,
+    FFIMetrics,
+};
+pub use shamir_ffi::{
+    shamir_reconstruct_secret, shamir_split_result_free, shamir_split_secret, shamir_verify_share,
+    CShamirShare, CShamirSplitResult,
 };
-pub use monitoring::{ffi_enable_optimizations, record_operation_time, FFIMetrics};
+pub use stream::{ffi_stream_feed, ffi_stream_free, ffi_stream_new, ffi_stream_take, StreamHandle};
@@ -5,19 +5,96 @@ use once_cell::sync::Lazy;
 use std::fs::OpenOptions;
 use std::io::Write;
 
+/// Default per-operation latency threshold before a sample counts as a
+/// baseline violation: 10ms. Overridable per operation via
+/// [`FFIMetrics::set_baseline_nanos`].
+const DEFAULT_BASELINE_NANOS: u64 = 10_000_000;
+
+/// Returns the histogram bucket for a duration of `nanos` nanoseconds:
+/// `floor(log2(nanos))`, clamped to `[0, 63]` and treating `0` as `1` so the
+/// very first sample still lands in bucket 0 rather than underflowing.
+fn bucket_for_nanos(nanos: u64) -> usize {
+    let n = nanos | 1;
+    (64 - n.leading_zeros()).min(63) as usize
+}
+
+/// Lower bound (in nanoseconds) of `bucket`, inverting [`bucket_for_nanos`].
+fn bucket_lower_bound_nanos(bucket: usize) -> u64 {
+    if bucket == 0 {
+        0
+    } else {
+        1u64 << (bucket - 1)
+    }
+}
+
+/// Lock-free per-operation latency distribution: a power-of-two bucketed
+/// histogram plus a configurable baseline threshold. Keeping per-bucket
+/// counts (rather than every sample) lets [`FFIMetrics`] report tail
+/// latency (p95/p99) without unbounded memory growth.
+struct LatencyHistogram {
+    buckets: [AtomicU64; 64],
+    baseline_nanos: AtomicU64,
+}
+
+impl LatencyHistogram {
+    fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            baseline_nanos: AtomicU64::new(DEFAULT_BASELINE_NANOS),
+        }
+    }
+
+    /// Records one sample and reports whether it exceeded the configured
+    /// baseline, so the caller can bump `baseline_violations`.
+    fn record(&self, nanos: u64) -> bool {
+        self.buckets[bucket_for_nanos(nanos)].fetch_add(1, Ordering::Relaxed);
+        nanos > self.baseline_nanos.load(Ordering::Relaxed)
+    }
+
+    fn reset(&self) {
+        for bucket in &self.buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the lower bound of the bucket containing the `q`-th quantile
+    /// (`0.0..=1.0`) sample out of `count` total samples, e.g. `q = 0.95`
+    /// for p95 latency.
+    fn percentile(&self, count: u64, q: f64) -> Duration {
+        if count == 0 {
+            return Duration::from_nanos(0);
+        }
+        let target_rank = ((count as f64) * q).ceil().max(1.0) as u64;
+        let mut cumulative = 0u64;
+        for (bucket, counter) in self.buckets.iter().enumerate() {
+            cumulative += counter.load(Ordering::Relaxed);
+            if cumulative >= target_rank {
+                return Duration::from_nanos(bucket_lower_bound_nanos(bucket));
+            }
+        }
+        Duration::from_nanos(0)
+    }
+}
+
 pub struct FFIMetrics {
     pub kyber_keygen_count: AtomicU64,
     pub kyber_keygen_total_time: AtomicU64,
+    kyber_keygen_latency: LatencyHistogram,
     pub kyber_encap_count: AtomicU64,
     pub kyber_encap_total_time: AtomicU64,
+    kyber_encap_latency: LatencyHistogram,
     pub kyber_decap_count: AtomicU64,
     pub kyber_decap_total_time: AtomicU64,
+    kyber_decap_latency: LatencyHistogram,
     pub dilithium_keygen_count: AtomicU64,
     pub dilithium_keygen_total_time: AtomicU64,
+    dilithium_keygen_latency: LatencyHistogram,
     pub dilithium_sign_count: AtomicU64,
     pub dilithium_sign_total_time: AtomicU64,
+    dilithium_sign_latency: LatencyHistogram,
     pub dilithium_verify_count: AtomicU64,
     pub dilithium_verify_total_time: AtomicU64,
+    dilithium_verify_latency: LatencyHistogram,
     pub memory_usage_bytes: AtomicU64,
     pub error_count: AtomicU64,
     pub throughput_ops_per_sec: AtomicU64,
@@ -35,16 +112,22 @@ impl FFIMetrics {
         Self {
             kyber_keygen_count: AtomicU64::new(0),
             kyber_keygen_total_time: AtomicU64::new(0),
+            kyber_keygen_latency: LatencyHistogram::new(),
             kyber_encap_count: AtomicU64::new(0),
             kyber_encap_total_time: AtomicU64::new(0),
+            kyber_encap_latency: LatencyHistogram::new(),
             kyber_decap_count: AtomicU64::new(0),
             kyber_decap_total_time: AtomicU64::new(0),
+            kyber_decap_latency: LatencyHistogram::new(),
             dilithium_keygen_count: AtomicU64::new(0),
             dilithium_keygen_total_time: AtomicU64::new(0),
+            dilithium_keygen_latency: LatencyHistogram::new(),
             dilithium_sign_count: AtomicU64::new(0),
             dilithium_sign_total_time: AtomicU64::new(0),
+            dilithium_sign_latency: LatencyHistogram::new(),
             dilithium_verify_count: AtomicU64::new(0),
             dilithium_verify_total_time: AtomicU64::new(0),
+            dilithium_verify_latency: LatencyHistogram::new(),
             memory_usage_bytes: AtomicU64::new(0),
             error_count: AtomicU64::new(0),
             throughput_ops_per_sec: AtomicU64::new(0),
@@ -53,8 +136,12 @@ impl FFIMetrics {
     }
     
     pub fn record_kyber_keygen(&self, duration: Duration) {
+        let nanos = duration.as_nanos() as u64;
         self.kyber_keygen_count.fetch_add(1, Ordering::Relaxed);
-        self.kyber_keygen_total_time.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        self.kyber_keygen_total_time.fetch_add(nanos, Ordering::Relaxed);
+        if self.kyber_keygen_latency.record(nanos) {
+            self.baseline_violations.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     pub fn get_kyber_keygen_avg_time(&self) -> Duration {
@@ -67,8 +154,12 @@ impl FFIMetrics {
     }
 
     pub fn record_kyber_encap(&self, duration: Duration) {
+        let nanos = duration.as_nanos() as u64;
         self.kyber_encap_count.fetch_add(1, Ordering::Relaxed);
-        self.kyber_encap_total_time.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        self.kyber_encap_total_time.fetch_add(nanos, Ordering::Relaxed);
+        if self.kyber_encap_latency.record(nanos) {
+            self.baseline_violations.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     pub fn get_kyber_encap_avg_time(&self) -> Duration {
@@ -81,8 +172,12 @@ impl FFIMetrics {
     }
 
     pub fn record_kyber_decap(&self, duration: Duration) {
+        let nanos = duration.as_nanos() as u64;
         self.kyber_decap_count.fetch_add(1, Ordering::Relaxed);
-        self.kyber_decap_total_time.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        self.kyber_decap_total_time.fetch_add(nanos, Ordering::Relaxed);
+        if self.kyber_decap_latency.record(nanos) {
+            self.baseline_violations.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     pub fn get_kyber_decap_avg_time(&self) -> Duration {
@@ -95,8 +190,12 @@ impl FFIMetrics {
     }
     
     pub fn record_dilithium_sign(&self, duration: Duration) {
+        let nanos = duration.as_nanos() as u64;
         self.dilithium_sign_count.fetch_add(1, Ordering::Relaxed);
-        self.dilithium_sign_total_time.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        self.dilithium_sign_total_time.fetch_add(nanos, Ordering::Relaxed);
+        if self.dilithium_sign_latency.record(nanos) {
+            self.baseline_violations.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     pub fn get_dilithium_sign_avg_time(&self) -> Duration {
@@ -109,8 +208,12 @@ impl FFIMetrics {
     }
     
     pub fn record_dilithium_keygen(&self, duration: Duration) {
+        let nanos = duration.as_nanos() as u64;
         self.dilithium_keygen_count.fetch_add(1, Ordering::Relaxed);
-        self.dilithium_keygen_total_time.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        self.dilithium_keygen_total_time.fetch_add(nanos, Ordering::Relaxed);
+        if self.dilithium_keygen_latency.record(nanos) {
+            self.baseline_violations.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     pub fn get_dilithium_keygen_avg_time(&self) -> Duration {
@@ -123,8 +226,12 @@ impl FFIMetrics {
     }
     
     pub fn record_dilithium_verify(&self, duration: Duration) {
+        let nanos = duration.as_nanos() as u64;
         self.dilithium_verify_count.fetch_add(1, Ordering::Relaxed);
-        self.dilithium_verify_total_time.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        self.dilithium_verify_total_time.fetch_add(nanos, Ordering::Relaxed);
+        if self.dilithium_verify_latency.record(nanos) {
+            self.baseline_violations.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     pub fn get_dilithium_verify_avg_time(&self) -> Duration {
@@ -139,16 +246,22 @@ impl FFIMetrics {
     pub fn reset_metrics(&self) {
         self.kyber_keygen_count.store(0, Ordering::Relaxed);
         self.kyber_keygen_total_time.store(0, Ordering::Relaxed);
+        self.kyber_keygen_latency.reset();
         self.kyber_encap_count.store(0, Ordering::Relaxed);
         self.kyber_encap_total_time.store(0, Ordering::Relaxed);
+        self.kyber_encap_latency.reset();
         self.kyber_decap_count.store(0, Ordering::Relaxed);
         self.kyber_decap_total_time.store(0, Ordering::Relaxed);
+        self.kyber_decap_latency.reset();
         self.dilithium_keygen_count.store(0, Ordering::Relaxed);
         self.dilithium_keygen_total_time.store(0, Ordering::Relaxed);
+        self.dilithium_keygen_latency.reset();
         self.dilithium_sign_count.store(0, Ordering::Relaxed);
         self.dilithium_sign_total_time.store(0, Ordering::Relaxed);
+        self.dilithium_sign_latency.reset();
         self.dilithium_verify_count.store(0, Ordering::Relaxed);
         self.dilithium_verify_total_time.store(0, Ordering::Relaxed);
+        self.dilithium_verify_latency.reset();
         self.memory_usage_bytes.store(0, Ordering::Relaxed);
         self.error_count.store(0, Ordering::Relaxed);
         self.throughput_ops_per_sec.store(0, Ordering::Relaxed);
@@ -186,20 +299,64 @@ impl FFIMetrics {
     pub fn get_baseline_violations(&self) -> u64 {
         self.baseline_violations.load(Ordering::Relaxed)
     }
-    
+
+    /// Returns the lower bound of the bucket containing the `q`-th quantile
+    /// (`0.0..=1.0`) sample recorded for `operation`, e.g. `q = 0.95` for
+    /// p95 latency. `operation` uses the same names accepted by
+    /// [`record_operation_time`]; unrecognized names return a zero duration.
+    pub fn get_percentile(&self, operation: &str, q: f64) -> Duration {
+        match operation {
+            "mlkem_keygen" => self
+                .kyber_keygen_latency
+                .percentile(self.kyber_keygen_count.load(Ordering::Relaxed), q),
+            "mlkem_encap" => self
+                .kyber_encap_latency
+                .percentile(self.kyber_encap_count.load(Ordering::Relaxed), q),
+            "mlkem_decap" => self
+                .kyber_decap_latency
+                .percentile(self.kyber_decap_count.load(Ordering::Relaxed), q),
+            "mldsa_keygen" => self
+                .dilithium_keygen_latency
+                .percentile(self.dilithium_keygen_count.load(Ordering::Relaxed), q),
+            "mldsa_sign" => self
+                .dilithium_sign_latency
+                .percentile(self.dilithium_sign_count.load(Ordering::Relaxed), q),
+            "mldsa_verify" => self
+                .dilithium_verify_latency
+                .percentile(self.dilithium_verify_count.load(Ordering::Relaxed), q),
+            _ => Duration::from_nanos(0),
+        }
+    }
+
+    /// Sets the baseline latency threshold for `operation`; samples that
+    /// exceed it bump `baseline_violations` on the next `record_*` call.
+    /// Unrecognized operation names are ignored.
+    pub fn set_baseline_nanos(&self, operation: &str, nanos: u64) {
+        let histogram = match operation {
+            "mlkem_keygen" => &self.kyber_keygen_latency,
+            "mlkem_encap" => &self.kyber_encap_latency,
+            "mlkem_decap" => &self.kyber_decap_latency,
+            "mldsa_keygen" => &self.dilithium_keygen_latency,
+            "mldsa_sign" => &self.dilithium_sign_latency,
+            "mldsa_verify" => &self.dilithium_verify_latency,
+            _ => return,
+        };
+        histogram.baseline_nanos.store(nanos, Ordering::Relaxed);
+    }
+
     pub fn generate_monitoring_report(&self) -> String {
         format!(
             "=== PQC Performance Monitoring Report ===\n\
             Generated: {}\n\
             WBS 2.5.2: Performance Monitoring Infrastructure\n\n\
             ML-KEM Operations:\n\
-            - Key Generation: {} ops, avg {:?}\n\
-            - Encapsulation: {} ops, avg {:?}\n\
-            - Decapsulation: {} ops, avg {:?}\n\n\
+            - Key Generation: {} ops, avg {:?} (p50 {:?}, p95 {:?}, p99 {:?})\n\
+            - Encapsulation: {} ops, avg {:?} (p50 {:?}, p95 {:?}, p99 {:?})\n\
+            - Decapsulation: {} ops, avg {:?} (p50 {:?}, p95 {:?}, p99 {:?})\n\n\
             ML-DSA Operations:\n\
-            - Key Generation: {} ops, avg {:?}\n\
-            - Signing: {} ops, avg {:?}\n\
-            - Verification: {} ops, avg {:?}\n\n\
+            - Key Generation: {} ops, avg {:?} (p50 {:?}, p95 {:?}, p99 {:?})\n\
+            - Signing: {} ops, avg {:?} (p50 {:?}, p95 {:?}, p99 {:?})\n\
+            - Verification: {} ops, avg {:?} (p50 {:?}, p95 {:?}, p99 {:?})\n\n\
             System Metrics:\n\
             - Memory Usage: {} bytes\n\
             - Error Count: {}\n\
@@ -208,16 +365,34 @@ impl FFIMetrics {
             chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
             self.kyber_keygen_count.load(Ordering::Relaxed),
             self.get_kyber_keygen_avg_time(),
+            self.get_percentile("mlkem_keygen", 0.50),
+            self.get_percentile("mlkem_keygen", 0.95),
+            self.get_percentile("mlkem_keygen", 0.99),
             self.kyber_encap_count.load(Ordering::Relaxed),
             self.get_kyber_encap_avg_time(),
+            self.get_percentile("mlkem_encap", 0.50),
+            self.get_percentile("mlkem_encap", 0.95),
+            self.get_percentile("mlkem_encap", 0.99),
             self.kyber_decap_count.load(Ordering::Relaxed),
             self.get_kyber_decap_avg_time(),
+            self.get_percentile("mlkem_decap", 0.50),
+            self.get_percentile("mlkem_decap", 0.95),
+            self.get_percentile("mlkem_decap", 0.99),
             self.dilithium_keygen_count.load(Ordering::Relaxed),
             self.get_dilithium_keygen_avg_time(),
+            self.get_percentile("mldsa_keygen", 0.50),
+            self.get_percentile("mldsa_keygen", 0.95),
+            self.get_percentile("mldsa_keygen", 0.99),
             self.dilithium_sign_count.load(Ordering::Relaxed),
             self.get_dilithium_sign_avg_time(),
+            self.get_percentile("mldsa_sign", 0.50),
+            self.get_percentile("mldsa_sign", 0.95),
+            self.get_percentile("mldsa_sign", 0.99),
             self.dilithium_verify_count.load(Ordering::Relaxed),
             self.get_dilithium_verify_avg_time(),
+            self.get_percentile("mldsa_verify", 0.50),
+            self.get_percentile("mldsa_verify", 0.95),
+            self.get_percentile("mldsa_verify", 0.99),
             self.get_memory_usage(),
             self.get_error_count(),
             self.get_throughput(),
@@ -241,16 +416,34 @@ static FFI_METRICS: Lazy<FFIMetrics> = Lazy::new(FFIMetrics::new);
 #[repr(C)]
 pub struct FFIPerformanceReport {
     pub kyber_keygen_avg_nanos: u64,
+    pub kyber_keygen_p50_nanos: u64,
+    pub kyber_keygen_p95_nanos: u64,
+    pub kyber_keygen_p99_nanos: u64,
     pub kyber_keygen_count: u64,
     pub kyber_encap_avg_nanos: u64,
+    pub kyber_encap_p50_nanos: u64,
+    pub kyber_encap_p95_nanos: u64,
+    pub kyber_encap_p99_nanos: u64,
     pub kyber_encap_count: u64,
     pub kyber_decap_avg_nanos: u64,
+    pub kyber_decap_p50_nanos: u64,
+    pub kyber_decap_p95_nanos: u64,
+    pub kyber_decap_p99_nanos: u64,
     pub kyber_decap_count: u64,
     pub dilithium_keygen_avg_nanos: u64,
+    pub dilithium_keygen_p50_nanos: u64,
+    pub dilithium_keygen_p95_nanos: u64,
+    pub dilithium_keygen_p99_nanos: u64,
     pub dilithium_keygen_count: u64,
     pub dilithium_sign_avg_nanos: u64,
+    pub dilithium_sign_p50_nanos: u64,
+    pub dilithium_sign_p95_nanos: u64,
+    pub dilithium_sign_p99_nanos: u64,
     pub dilithium_sign_count: u64,
     pub dilithium_verify_avg_nanos: u64,
+    pub dilithium_verify_p50_nanos: u64,
+    pub dilithium_verify_p95_nanos: u64,
+    pub dilithium_verify_p99_nanos: u64,
     pub dilithium_verify_count: u64,
     pub memory_usage_bytes: u64,
     pub error_count: u64,
@@ -279,6 +472,18 @@ where
     result
 }
 
+/// Sets the baseline latency threshold for `operation` (same names accepted
+/// by [`record_operation_time`]) on the global metrics instance.
+pub fn set_baseline_nanos(operation: &str, nanos: u64) {
+    FFI_METRICS.set_baseline_nanos(operation, nanos);
+}
+
+/// Returns the `q`-th quantile latency recorded for `operation` on the
+/// global metrics instance; see [`FFIMetrics::get_percentile`].
+pub fn get_percentile(operation: &str, q: f64) -> Duration {
+    FFI_METRICS.get_percentile(operation, q)
+}
+
 #[no_mangle]
 pub extern "C" fn ffi_enable_optimizations(_flags: u32) -> c_int {
     0
@@ -288,16 +493,34 @@ pub extern "C" fn ffi_enable_optimizations(_flags: u32) -> c_int {
 pub extern "C" fn ffi_get_performance_metrics() -> *const FFIPerformanceReport {
     let report = Box::new(FFIPerformanceReport {
         kyber_keygen_avg_nanos: FFI_METRICS.get_kyber_keygen_avg_time().as_nanos() as u64,
+        kyber_keygen_p50_nanos: FFI_METRICS.get_percentile("mlkem_keygen", 0.50).as_nanos() as u64,
+        kyber_keygen_p95_nanos: FFI_METRICS.get_percentile("mlkem_keygen", 0.95).as_nanos() as u64,
+        kyber_keygen_p99_nanos: FFI_METRICS.get_percentile("mlkem_keygen", 0.99).as_nanos() as u64,
         kyber_keygen_count: FFI_METRICS.kyber_keygen_count.load(Ordering::Relaxed),
         kyber_encap_avg_nanos: FFI_METRICS.get_kyber_encap_avg_time().as_nanos() as u64,
+        kyber_encap_p50_nanos: FFI_METRICS.get_percentile("mlkem_encap", 0.50).as_nanos() as u64,
+        kyber_encap_p95_nanos: FFI_METRICS.get_percentile("mlkem_encap", 0.95).as_nanos() as u64,
+        kyber_encap_p99_nanos: FFI_METRICS.get_percentile("mlkem_encap", 0.99).as_nanos() as u64,
         kyber_encap_count: FFI_METRICS.kyber_encap_count.load(Ordering::Relaxed),
         kyber_decap_avg_nanos: FFI_METRICS.get_kyber_decap_avg_time().as_nanos() as u64,
+        kyber_decap_p50_nanos: FFI_METRICS.get_percentile("mlkem_decap", 0.50).as_nanos() as u64,
+        kyber_decap_p95_nanos: FFI_METRICS.get_percentile("mlkem_decap", 0.95).as_nanos() as u64,
+        kyber_decap_p99_nanos: FFI_METRICS.get_percentile("mlkem_decap", 0.99).as_nanos() as u64,
         kyber_decap_count: FFI_METRICS.kyber_decap_count.load(Ordering::Relaxed),
         dilithium_keygen_avg_nanos: FFI_METRICS.get_dilithium_keygen_avg_time().as_nanos() as u64,
+        dilithium_keygen_p50_nanos: FFI_METRICS.get_percentile("mldsa_keygen", 0.50).as_nanos() as u64,
+        dilithium_keygen_p95_nanos: FFI_METRICS.get_percentile("mldsa_keygen", 0.95).as_nanos() as u64,
+        dilithium_keygen_p99_nanos: FFI_METRICS.get_percentile("mldsa_keygen", 0.99).as_nanos() as u64,
         dilithium_keygen_count: FFI_METRICS.dilithium_keygen_count.load(Ordering::Relaxed),
         dilithium_sign_avg_nanos: FFI_METRICS.get_dilithium_sign_avg_time().as_nanos() as u64,
+        dilithium_sign_p50_nanos: FFI_METRICS.get_percentile("mldsa_sign", 0.50).as_nanos() as u64,
+        dilithium_sign_p95_nanos: FFI_METRICS.get_percentile("mldsa_sign", 0.95).as_nanos() as u64,
+        dilithium_sign_p99_nanos: FFI_METRICS.get_percentile("mldsa_sign", 0.99).as_nanos() as u64,
         dilithium_sign_count: FFI_METRICS.dilithium_sign_count.load(Ordering::Relaxed),
         dilithium_verify_avg_nanos: FFI_METRICS.get_dilithium_verify_avg_time().as_nanos() as u64,
+        dilithium_verify_p50_nanos: FFI_METRICS.get_percentile("mldsa_verify", 0.50).as_nanos() as u64,
+        dilithium_verify_p95_nanos: FFI_METRICS.get_percentile("mldsa_verify", 0.95).as_nanos() as u64,
+        dilithium_verify_p99_nanos: FFI_METRICS.get_percentile("mldsa_verify", 0.99).as_nanos() as u64,
         dilithium_verify_count: FFI_METRICS.dilithium_verify_count.load(Ordering::Relaxed),
         memory_usage_bytes: FFI_METRICS.get_memory_usage(),
         error_count: FFI_METRICS.get_error_count(),
@@ -0,0 +1,105 @@
+use once_cell::sync::Lazy;
+use secrecy::{ExposeSecret, Secret};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Opaque token referencing a `Secret`-wrapped key held in the process-wide
+/// registry below. C callers pass this back into `mldsa_sign` and
+/// `mldsa_keypair_free` instead of ever seeing the secret key bytes, so
+/// there's no C-visible pointer a caller could copy, retain past the key's
+/// lifetime, or forget to zero before freeing.
+pub type MLDSASecretHandle = u64;
+
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+static SECRET_REGISTRY: Lazy<Mutex<HashMap<MLDSASecretHandle, Secret<Vec<u8>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Moves `secret` into the registry and returns a fresh handle for it.
+pub fn register(secret: Secret<Vec<u8>>) -> MLDSASecretHandle {
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    SECRET_REGISTRY
+        .lock()
+        .unwrap()
+        .insert(handle, secret);
+    handle
+}
+
+/// Runs `f` with the secret bytes behind `handle`. Returns `None` if the
+/// handle was never issued or has already been released.
+pub fn with_secret<F, R>(handle: MLDSASecretHandle, f: F) -> Option<R>
+where
+    F: FnOnce(&[u8]) -> R,
+{
+    let registry = SECRET_REGISTRY.lock().unwrap();
+    registry.get(&handle).map(|secret| f(secret.expose_secret()))
+}
+
+/// Runs `f` with the secret bytes behind `handle1` and `handle2`, taking
+/// `SECRET_REGISTRY`'s lock exactly once. Callers that need two secrets at
+/// once (e.g. a hybrid classical + PQC key pair) must use this instead of
+/// nesting two [`with_secret`] calls: `SECRET_REGISTRY` is a plain
+/// `std::sync::Mutex`, which is not reentrant, so a `with_secret` call made
+/// from inside another `with_secret` closure deadlocks the calling thread.
+/// Returns `None` if either handle was never issued or has already been
+/// released.
+pub fn with_two_secrets<F, R>(
+    handle1: MLDSASecretHandle,
+    handle2: MLDSASecretHandle,
+    f: F,
+) -> Option<R>
+where
+    F: FnOnce(&[u8], &[u8]) -> R,
+{
+    let registry = SECRET_REGISTRY.lock().unwrap();
+    let secret1 = registry.get(&handle1)?;
+    let secret2 = registry.get(&handle2)?;
+    Some(f(secret1.expose_secret(), secret2.expose_secret()))
+}
+
+/// Removes and zeroizes the secret behind `handle`. Returns `false` if the
+/// handle was already released or never issued.
+pub fn release(handle: MLDSASecretHandle) -> bool {
+    SECRET_REGISTRY.lock().unwrap().remove(&handle).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_read_back() {
+        let handle = register(Secret::new(vec![1, 2, 3]));
+        let read = with_secret(handle, |bytes| bytes.to_vec());
+        assert_eq!(read, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_release_invalidates_handle() {
+        let handle = register(Secret::new(vec![9, 9, 9]));
+        assert!(release(handle));
+        assert_eq!(with_secret(handle, |bytes| bytes.to_vec()), None);
+        assert!(!release(handle));
+    }
+
+    #[test]
+    fn test_unknown_handle_returns_none() {
+        assert_eq!(with_secret(u64::MAX, |bytes| bytes.to_vec()), None);
+    }
+
+    #[test]
+    fn test_with_two_secrets_reads_both_without_deadlocking() {
+        let handle1 = register(Secret::new(vec![1, 2, 3]));
+        let handle2 = register(Secret::new(vec![4, 5, 6]));
+
+        let read = with_two_secrets(handle1, handle2, |a, b| (a.to_vec(), b.to_vec()));
+        assert_eq!(read, Some((vec![1, 2, 3], vec![4, 5, 6])));
+    }
+
+    #[test]
+    fn test_with_two_secrets_returns_none_if_either_handle_is_unknown() {
+        let handle = register(Secret::new(vec![1, 2, 3]));
+        assert_eq!(with_two_secrets(handle, u64::MAX, |a, b| { (a.to_vec(), b.to_vec()) }), None);
+        assert_eq!(with_two_secrets(u64::MAX, handle, |a, b| { (a.to_vec(), b.to_vec()) }), None);
+    }
+}
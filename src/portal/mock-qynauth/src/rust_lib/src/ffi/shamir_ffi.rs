@@ -0,0 +1,246 @@
+//! FFI entry points for [`crate::shamir`], paralleling
+//! [`crate::ffi::mlkem_ffi::mlkem_keypair_generate`]/
+//! [`crate::ffi::mlkem_ffi::mlkem_keypair_free`]'s boxed-struct-plus-
+//! matching-free-function shape: [`shamir_split_secret`] returns a
+//! boxed [`CShamirSplitResult`] a caller distributes to `n` custodians
+//! and releases via [`shamir_split_result_free`]; each custodian's
+//! share can be checked with [`shamir_verify_share`] before handing it
+//! back for [`shamir_reconstruct_secret`]. Share bytes are literally a
+//! fragment of the private key they were split from, so (like
+//! `CMLKEMKeyPair::secret_key_ptr`) they're allocated with
+//! [`FFIBuffer::new_secure`] and freed through
+//! [`crate::ffi::memory::ffi_secure_buffer_free`]; the commitments are
+//! public values and use the ordinary allocator.
+
+use crate::ffi::memory::{safe_slice_from_raw, set_last_error, FFIBuffer, FFIErrorCode};
+use crate::ffi::monitoring::record_operation_time;
+use crate::shamir::{self, Share};
+use libc::size_t;
+use std::os::raw::c_int;
+
+/// One share of a Shamir-split secret, as returned to C: `y_ptr`/`y_len`
+/// describe the secure allocation holding `y`, freed (along with the
+/// rest of its [`CShamirSplitResult`]) by [`shamir_split_result_free`].
+#[repr(C)]
+pub struct CShamirShare {
+    pub x: u8,
+    pub y_ptr: *mut u8,
+    pub y_len: size_t,
+}
+
+/// The result of [`shamir_split_secret`]: `n` shares plus one 32-byte
+/// SHA-256 commitment per share, concatenated into `commitments_ptr`
+/// (`commitments_len == shares_len * 32`) in the same order as
+/// `shares_ptr`, so `shamir_verify_share` can be handed the whole
+/// commitment list without a separate accessor per index.
+#[repr(C)]
+pub struct CShamirSplitResult {
+    pub shares_ptr: *mut CShamirShare,
+    pub shares_len: size_t,
+    pub commitments_ptr: *mut u8,
+    pub commitments_len: size_t,
+}
+
+/// Splits `secret_ptr`/`secret_len` into `n` shares with threshold `k`
+/// (any `k` of the `n` shares reconstruct the secret; see
+/// [`crate::shamir::split_secret`]), returning a boxed
+/// [`CShamirSplitResult`] or null (with `set_last_error` already
+/// called) on invalid parameters or allocation failure.
+#[no_mangle]
+pub extern "C" fn shamir_split_secret(
+    secret_ptr: *const u8,
+    secret_len: size_t,
+    k: u8,
+    n: u8,
+) -> *mut CShamirSplitResult {
+    if secret_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Secret pointer is null");
+        return std::ptr::null_mut();
+    }
+    let secret = unsafe { std::slice::from_raw_parts(secret_ptr, secret_len) };
+
+    record_operation_time("shamir_split", || {
+        let (shares, commitments) = match shamir::split_secret_verifiable(secret, k, n) {
+            Ok(result) => result,
+            Err(e) => {
+                set_last_error(FFIErrorCode::InvalidInput, &e.to_string());
+                return std::ptr::null_mut();
+            }
+        };
+
+        let mut c_shares = Vec::with_capacity(shares.len());
+        for share in &shares {
+            let mut y_buffer = match FFIBuffer::new_secure(share.y.len()) {
+                Ok(buffer) => buffer,
+                Err(e) => {
+                    set_last_error(
+                        FFIErrorCode::AllocationFailed,
+                        &format!("Failed to allocate share buffer: {e}"),
+                    );
+                    return std::ptr::null_mut();
+                }
+            };
+            unsafe {
+                std::ptr::copy_nonoverlapping(share.y.as_ptr(), y_buffer.as_mut_ptr(), share.y.len());
+            }
+            c_shares.push(CShamirShare {
+                x: share.x,
+                y_ptr: y_buffer.into_raw(),
+                y_len: share.y.len(),
+            });
+        }
+
+        let commitment_bytes: Vec<u8> = commitments.iter().flatten().copied().collect();
+        let mut commitments_buffer = match FFIBuffer::new(commitment_bytes.len()) {
+            Ok(buffer) => buffer,
+            Err(e) => {
+                set_last_error(
+                    FFIErrorCode::AllocationFailed,
+                    &format!("Failed to allocate commitments buffer: {e}"),
+                );
+                return std::ptr::null_mut();
+            }
+        };
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                commitment_bytes.as_ptr(),
+                commitments_buffer.as_mut_ptr(),
+                commitment_bytes.len(),
+            );
+        }
+
+        let shares_len = c_shares.len();
+        let shares_ptr = {
+            let mut boxed = c_shares.into_boxed_slice();
+            let ptr = boxed.as_mut_ptr();
+            std::mem::forget(boxed);
+            ptr
+        };
+
+        Box::into_raw(Box::new(CShamirSplitResult {
+            shares_ptr,
+            shares_len,
+            commitments_ptr: commitments_buffer.into_raw(),
+            commitments_len: commitment_bytes.len(),
+        }))
+    })
+}
+
+/// Recomputes the SHA-256 commitment for the share at `x`/`y_ptr`/
+/// `y_len` and checks it appears in the `commitments_len`-byte
+/// (32 bytes per commitment) buffer at `commitments_ptr`. Returns `1`
+/// if the share matches one of the commitments, `0` otherwise
+/// (including on a null pointer, after `set_last_error`).
+#[no_mangle]
+pub extern "C" fn shamir_verify_share(
+    x: u8,
+    y_ptr: *const u8,
+    y_len: size_t,
+    commitments_ptr: *const u8,
+    commitments_len: size_t,
+) -> c_int {
+    if y_ptr.is_null() || commitments_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Share or commitments pointer is null");
+        return 0;
+    }
+    if commitments_len % 32 != 0 {
+        set_last_error(
+            FFIErrorCode::BadLength,
+            "Commitments buffer length must be a multiple of 32",
+        );
+        return 0;
+    }
+
+    let y = unsafe { std::slice::from_raw_parts(y_ptr, y_len) }.to_vec();
+    let commitment_bytes = unsafe { std::slice::from_raw_parts(commitments_ptr, commitments_len) };
+    let commitments: Vec<[u8; 32]> = commitment_bytes
+        .chunks_exact(32)
+        .map(|chunk| chunk.try_into().expect("chunks_exact(32) guarantees length 32"))
+        .collect();
+
+    shamir::verify_share(&Share { x, y }, &commitments) as c_int
+}
+
+/// Reconstructs the original secret from the `shares_len` shares at
+/// `shares_ptr` via [`crate::shamir::reconstruct_secret`], writing the
+/// result (allocated with [`FFIBuffer::new_secure`], freed via
+/// [`crate::ffi::memory::ffi_secure_buffer_free`]) to `secret_out`.
+#[no_mangle]
+pub extern "C" fn shamir_reconstruct_secret(
+    shares_ptr: *const CShamirShare,
+    shares_len: size_t,
+    secret_out: *mut *mut u8,
+    secret_len_out: *mut size_t,
+) -> c_int {
+    if secret_out.is_null() || secret_len_out.is_null() {
+        set_last_error(FFIErrorCode::InvalidInput, "Output parameters cannot be null");
+        return FFIErrorCode::InvalidInput as c_int;
+    }
+    if shares_ptr.is_null() {
+        set_last_error(FFIErrorCode::NullPointer, "Shares pointer is null");
+        return FFIErrorCode::NullPointer as c_int;
+    }
+
+    let c_shares = unsafe { std::slice::from_raw_parts(shares_ptr, shares_len) };
+    let mut shares: Vec<Share> = Vec::with_capacity(c_shares.len());
+    for c_share in c_shares {
+        let y = match safe_slice_from_raw(c_share.y_ptr, c_share.y_len) {
+            Ok(slice) => slice.to_vec(),
+            Err(code) => return code as c_int,
+        };
+        shares.push(Share { x: c_share.x, y });
+    }
+
+    record_operation_time("shamir_reconstruct", || {
+        match shamir::reconstruct_secret(&shares) {
+            Ok(secret) => {
+                let mut buffer = match FFIBuffer::new_secure(secret.len()) {
+                    Ok(buffer) => buffer,
+                    Err(e) => {
+                        set_last_error(
+                            FFIErrorCode::AllocationFailed,
+                            &format!("Failed to allocate secret buffer: {e}"),
+                        );
+                        return FFIErrorCode::AllocationFailed as c_int;
+                    }
+                };
+                unsafe {
+                    std::ptr::copy_nonoverlapping(secret.as_ptr(), buffer.as_mut_ptr(), secret.len());
+                    *secret_out = buffer.into_raw();
+                    *secret_len_out = secret.len();
+                }
+                FFIErrorCode::Success as c_int
+            }
+            Err(e) => {
+                set_last_error(FFIErrorCode::InvalidInput, &e.to_string());
+                FFIErrorCode::InvalidInput as c_int
+            }
+        }
+    })
+}
+
+/// Releases a [`CShamirSplitResult`] returned by
+/// [`shamir_split_secret`]: every share's `y_ptr` goes through
+/// [`crate::ffi::memory::ffi_secure_buffer_free`] (secret material),
+/// the commitments buffer through the plain allocator, then the shares
+/// array and the result struct itself.
+#[no_mangle]
+pub extern "C" fn shamir_split_result_free(result: *mut CShamirSplitResult) {
+    if result.is_null() {
+        return;
+    }
+    unsafe {
+        let result = Box::from_raw(result);
+
+        if !result.shares_ptr.is_null() {
+            let shares_slice: *mut [CShamirShare] =
+                std::slice::from_raw_parts_mut(result.shares_ptr, result.shares_len);
+            for share in &*shares_slice {
+                crate::ffi::memory::ffi_secure_buffer_free(share.y_ptr, share.y_len);
+            }
+            drop(Box::from_raw(shares_slice));
+        }
+
+        crate::ffi::memory::ffi_buffer_free(result.commitments_ptr, result.commitments_len);
+    }
+}
@@ -0,0 +1,342 @@
+use crate::ffi::memory::{set_last_error, FFIErrorCode};
+use libc::size_t;
+use once_cell::sync::Lazy;
+use std::alloc::{alloc, dealloc, Layout};
+use std::collections::HashMap;
+use std::os::raw::c_int;
+use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use zeroize::Zeroize;
+
+/// Opaque token for a [`RingBuffer`] held in the process-wide registry
+/// below. Bindings pass this into `ffi_stream_feed`/`ffi_stream_take` and
+/// the `mldsa_*_update`/`mldsa_*_final` streaming entry points instead of
+/// ever touching the backing allocation directly.
+pub type StreamHandle = u64;
+
+/// Fixed-capacity ring buffer for streaming message chunks through the FFI
+/// without buffering the whole message in one contiguous, caller-owned
+/// allocation. `head` and `tail` are indices into a single `alloc`'d block;
+/// `tail` is kept in `0..cap` except that a completely full buffer is
+/// marked by setting `tail == cap`, a sentinel that can't otherwise arise
+/// and so disambiguates "full" from "empty" (both of which would otherwise
+/// read as `head == tail`) without a separate length field.
+struct RingBuffer {
+    ptr: *mut u8,
+    cap: usize,
+    head: usize,
+    tail: usize,
+}
+
+impl RingBuffer {
+    fn new(capacity: usize) -> Result<Self, FFIErrorCode> {
+        if capacity == 0 {
+            set_last_error(FFIErrorCode::InvalidInput, "Stream capacity cannot be zero");
+            return Err(FFIErrorCode::InvalidInput);
+        }
+
+        let layout = Layout::from_size_align(capacity, 1).map_err(|_| {
+            set_last_error(
+                FFIErrorCode::AllocationFailed,
+                "Failed to create memory layout",
+            );
+            FFIErrorCode::AllocationFailed
+        })?;
+
+        let ptr = unsafe { alloc(layout) };
+        if ptr.is_null() {
+            set_last_error(FFIErrorCode::AllocationFailed, "Memory allocation failed");
+            return Err(FFIErrorCode::AllocationFailed);
+        }
+
+        unsafe {
+            ptr::write_bytes(ptr, 0, capacity);
+        }
+
+        Ok(RingBuffer {
+            ptr,
+            cap: capacity,
+            head: 0,
+            tail: 0,
+        })
+    }
+
+    fn len(&self) -> usize {
+        if self.tail == self.cap {
+            self.cap
+        } else if self.tail >= self.head {
+            self.tail - self.head
+        } else {
+            self.cap - self.head + self.tail
+        }
+    }
+
+    fn available(&self) -> usize {
+        self.cap - self.len()
+    }
+
+    /// Index the next `feed` should write to; the `tail == cap` full
+    /// sentinel maps back onto `head` since a full buffer has nowhere left
+    /// to write anyway.
+    fn write_pos(&self) -> usize {
+        if self.tail == self.cap {
+            self.head
+        } else {
+            self.tail
+        }
+    }
+
+    /// Copies `data` in, wrapping around the end of the block in two
+    /// `copy_nonoverlapping` calls (`head..cap` then `0..tail`) when it
+    /// doesn't fit in one contiguous run.
+    fn feed(&mut self, data: &[u8]) -> Result<(), FFIErrorCode> {
+        if data.len() > self.available() {
+            set_last_error(
+                FFIErrorCode::BufferTooSmall,
+                "Stream buffer has no room for this chunk",
+            );
+            return Err(FFIErrorCode::BufferTooSmall);
+        }
+
+        let start = self.write_pos();
+        let first_run = (self.cap - start).min(data.len());
+
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), self.ptr.add(start), first_run);
+            if data.len() > first_run {
+                ptr::copy_nonoverlapping(
+                    data.as_ptr().add(first_run),
+                    self.ptr,
+                    data.len() - first_run,
+                );
+            }
+        }
+
+        let new_len = self.len() + data.len();
+        self.tail = if new_len == self.cap {
+            self.cap
+        } else {
+            (start + data.len()) % self.cap
+        };
+        Ok(())
+    }
+
+    /// Copies up to `out.len()` bytes out (oldest first) and advances
+    /// `head` past them, returning the number actually copied. Like
+    /// `feed`, wraps across the end of the block with two
+    /// `copy_nonoverlapping` calls.
+    fn take(&mut self, out: &mut [u8]) -> usize {
+        let n = out.len().min(self.len());
+        let first_run = (self.cap - self.head).min(n);
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr.add(self.head), out.as_mut_ptr(), first_run);
+            if n > first_run {
+                ptr::copy_nonoverlapping(self.ptr, out.as_mut_ptr().add(first_run), n - first_run);
+            }
+        }
+
+        let was_full = self.tail == self.cap;
+        self.head = (self.head + n) % self.cap;
+        if was_full && n > 0 {
+            let new_len = self.cap - n;
+            self.tail = (self.head + new_len) % self.cap;
+        }
+        n
+    }
+}
+
+impl Drop for RingBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            let slice = std::slice::from_raw_parts_mut(self.ptr, self.cap);
+            slice.zeroize();
+            let layout = Layout::from_size_align_unchecked(self.cap, 1);
+            dealloc(self.ptr, layout);
+        }
+    }
+}
+
+// Safety: `RingBuffer` owns its backing allocation exclusively and is only
+// ever reached through the `Mutex`-guarded registry below, so no two
+// threads can touch the raw pointer concurrently.
+unsafe impl Send for RingBuffer {}
+
+static NEXT_STREAM_HANDLE: AtomicU64 = AtomicU64::new(1);
+static STREAM_REGISTRY: Lazy<Mutex<HashMap<StreamHandle, RingBuffer>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn new_stream(capacity: usize) -> Result<StreamHandle, FFIErrorCode> {
+    let ring = RingBuffer::new(capacity)?;
+    let handle = NEXT_STREAM_HANDLE.fetch_add(1, Ordering::Relaxed);
+    STREAM_REGISTRY.lock().unwrap().insert(handle, ring);
+    Ok(handle)
+}
+
+pub(crate) fn feed_stream(handle: StreamHandle, data: &[u8]) -> Result<(), FFIErrorCode> {
+    let mut registry = STREAM_REGISTRY.lock().unwrap();
+    let ring = registry.get_mut(&handle).ok_or_else(|| {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Unknown or already-released stream handle",
+        );
+        FFIErrorCode::InvalidInput
+    })?;
+    ring.feed(data)
+}
+
+fn take_stream(handle: StreamHandle, out: &mut [u8]) -> Result<usize, FFIErrorCode> {
+    let mut registry = STREAM_REGISTRY.lock().unwrap();
+    let ring = registry.get_mut(&handle).ok_or_else(|| {
+        set_last_error(
+            FFIErrorCode::InvalidInput,
+            "Unknown or already-released stream handle",
+        );
+        FFIErrorCode::InvalidInput
+    })?;
+    Ok(ring.take(out))
+}
+
+fn free_stream(handle: StreamHandle) -> bool {
+    STREAM_REGISTRY.lock().unwrap().remove(&handle).is_some()
+}
+
+/// Drains every currently-buffered byte out of `handle` into a freshly
+/// allocated `Vec`, then releases the stream. Used by the `mldsa_*_final`
+/// entry points, which need the whole accumulated message in one
+/// contiguous slice to hand to the underlying (non-streaming) sign/verify
+/// calls. Returns `None` if the handle is unknown or already released.
+pub(crate) fn drain_all(handle: StreamHandle) -> Option<Vec<u8>> {
+    let message = {
+        let mut registry = STREAM_REGISTRY.lock().unwrap();
+        let ring = registry.get_mut(&handle)?;
+        let mut buf = vec![0u8; ring.len()];
+        let n = ring.take(&mut buf);
+        buf.truncate(n);
+        buf
+    };
+    free_stream(handle);
+    Some(message)
+}
+
+/// Allocates a new fixed-capacity stream and returns its handle, or `0`
+/// (never issued to a real stream) on failure.
+#[no_mangle]
+pub extern "C" fn ffi_stream_new(capacity: size_t) -> StreamHandle {
+    match new_stream(capacity) {
+        Ok(handle) => handle,
+        Err(_) => 0,
+    }
+}
+
+/// Feeds `len` bytes from `ptr` into the stream named by `handle`, wrapping
+/// across the ring's backing block as needed. Fails with
+/// `FFIErrorCode::BufferTooSmall` if the stream doesn't have `len` bytes of
+/// headroom.
+#[no_mangle]
+pub extern "C" fn ffi_stream_feed(handle: StreamHandle, ptr: *const u8, len: size_t) -> c_int {
+    let data = match crate::ffi::memory::safe_slice_from_raw(ptr, len) {
+        Ok(slice) => slice,
+        Err(code) => {
+            set_last_error(code, "Invalid stream input buffer");
+            return code as c_int;
+        }
+    };
+
+    match feed_stream(handle, data) {
+        Ok(()) => FFIErrorCode::Success as c_int,
+        Err(code) => code as c_int,
+    }
+}
+
+/// Copies up to `out_len` bytes (oldest first) out of the stream named by
+/// `handle` into `out_ptr`, writing the number actually copied to
+/// `bytes_written_out`.
+#[no_mangle]
+pub extern "C" fn ffi_stream_take(
+    handle: StreamHandle,
+    out_ptr: *mut u8,
+    out_len: size_t,
+    bytes_written_out: *mut size_t,
+) -> c_int {
+    if out_ptr.is_null() || bytes_written_out.is_null() {
+        set_last_error(
+            FFIErrorCode::NullPointer,
+            "Output parameters cannot be null",
+        );
+        return FFIErrorCode::NullPointer as c_int;
+    }
+
+    let out_slice = unsafe { std::slice::from_raw_parts_mut(out_ptr, out_len) };
+    match take_stream(handle, out_slice) {
+        Ok(n) => {
+            unsafe {
+                *bytes_written_out = n;
+            }
+            FFIErrorCode::Success as c_int
+        }
+        Err(code) => code as c_int,
+    }
+}
+
+/// Releases (and zeroizes) the stream named by `handle`. A no-op if the
+/// handle is unknown or was already released.
+#[no_mangle]
+pub extern "C" fn ffi_stream_free(handle: StreamHandle) {
+    free_stream(handle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_then_take_round_trip() {
+        let handle = new_stream(8).unwrap();
+        feed_stream(handle, b"hello").unwrap();
+        let mut out = [0u8; 5];
+        assert_eq!(take_stream(handle, &mut out).unwrap(), 5);
+        assert_eq!(&out, b"hello");
+        free_stream(handle);
+    }
+
+    #[test]
+    fn test_feed_rejects_overflow() {
+        let handle = new_stream(4).unwrap();
+        feed_stream(handle, b"ab").unwrap();
+        assert!(feed_stream(handle, b"xyz").is_err());
+        free_stream(handle);
+    }
+
+    #[test]
+    fn test_wrap_around_preserves_order() {
+        let handle = new_stream(4).unwrap();
+        feed_stream(handle, b"abcd").unwrap();
+        let mut out = [0u8; 2];
+        take_stream(handle, &mut out).unwrap();
+        assert_eq!(&out, b"ab");
+
+        feed_stream(handle, b"ef").unwrap();
+        let mut rest = [0u8; 4];
+        let n = take_stream(handle, &mut rest).unwrap();
+        assert_eq!(n, 4);
+        assert_eq!(&rest, b"cdef");
+        free_stream(handle);
+    }
+
+    #[test]
+    fn test_drain_all_empties_and_frees_the_stream() {
+        let handle = new_stream(16).unwrap();
+        feed_stream(handle, b"streamed message").unwrap();
+        let drained = drain_all(handle).unwrap();
+        assert_eq!(drained, b"streamed message");
+        assert!(feed_stream(handle, b"x").is_err());
+    }
+
+    #[test]
+    fn test_unknown_handle_errors() {
+        assert!(feed_stream(u64::MAX, b"x").is_err());
+        assert!(drain_all(u64::MAX).is_none());
+    }
+}
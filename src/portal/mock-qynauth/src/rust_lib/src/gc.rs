@@ -0,0 +1,205 @@
+//! Background garbage collection for [`SecureKeyManager`], modeled on
+//! keystore2's `gc` + `async_task`: a worker thread periodically marks
+//! expired keys and purges anything that's been `Expired` or `Revoked`
+//! for longer than a grace period, so reclaiming dead keys no longer
+//! depends on something remembering to call `cleanup_expired_keys`.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use log::{error, info};
+
+use crate::key_management::SecureKeyManager;
+
+/// Keys reclaimed per sweep are capped at this many, so a single sweep
+/// can't hold the manager's lock for an unbounded stretch on a large key
+/// store.
+const DEFAULT_BATCH_LIMIT: usize = 200;
+
+/// Cumulative counters for a [`GcHandle`]'s worker, snapshotted by
+/// [`GcHandle::stats`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GcStats {
+    pub sweeps_run: u64,
+    pub keys_marked_expired: u64,
+    pub keys_purged: u64,
+}
+
+#[derive(Default)]
+struct GcCounters {
+    sweeps_run: AtomicU64,
+    keys_marked_expired: AtomicU64,
+    keys_purged: AtomicU64,
+}
+
+/// A running background GC worker. `shutdown` is the clean way to stop
+/// it; dropping the handle without calling `shutdown` leaves the worker
+/// running in the background.
+pub struct GcHandle {
+    shutdown_flag: Arc<AtomicBool>,
+    counters: Arc<GcCounters>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl GcHandle {
+    /// Signals the worker to stop and blocks until it exits, finishing
+    /// (or skipping) whatever sweep it's currently on.
+    pub fn shutdown(mut self) {
+        self.shutdown_flag.store(true, Ordering::SeqCst);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+
+    /// A snapshot of how many sweeps have run and how many keys they've
+    /// reclaimed so far.
+    pub fn stats(&self) -> GcStats {
+        GcStats {
+            sweeps_run: self.counters.sweeps_run.load(Ordering::SeqCst),
+            keys_marked_expired: self.counters.keys_marked_expired.load(Ordering::SeqCst),
+            keys_purged: self.counters.keys_purged.load(Ordering::SeqCst),
+        }
+    }
+}
+
+/// Spawns a background worker that wakes every `interval` to transition
+/// expired `Active` keys to `Expired`, then securely delete anything
+/// that's been `Expired` or `Revoked` for longer than `grace_period`, in
+/// batches of at most [`DEFAULT_BATCH_LIMIT`] per sweep.
+pub fn spawn(
+    manager: Arc<Mutex<SecureKeyManager>>,
+    interval: Duration,
+    grace_period: Duration,
+) -> GcHandle {
+    let shutdown_flag = Arc::new(AtomicBool::new(false));
+    let counters: Arc<GcCounters> = Arc::new(GcCounters::default());
+
+    let worker_shutdown = shutdown_flag.clone();
+    let worker_counters = counters.clone();
+    let grace_period_secs = grace_period.as_secs();
+
+    let worker = std::thread::spawn(move || {
+        while !worker_shutdown.load(Ordering::SeqCst) {
+            std::thread::sleep(interval);
+            if worker_shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let (marked, purged) = match manager.lock() {
+                Ok(mut manager) => {
+                    let marked = manager.mark_expired_keys();
+                    let purged = manager.purge_stale_keys(grace_period_secs, DEFAULT_BATCH_LIMIT);
+                    (marked, purged)
+                }
+                Err(e) => {
+                    error!("GC sweep skipped: key manager lock poisoned: {e}");
+                    (0, 0)
+                }
+            };
+
+            worker_counters.sweeps_run.fetch_add(1, Ordering::SeqCst);
+            worker_counters
+                .keys_marked_expired
+                .fetch_add(marked as u64, Ordering::SeqCst);
+            worker_counters
+                .keys_purged
+                .fetch_add(purged as u64, Ordering::SeqCst);
+
+            if marked > 0 || purged > 0 {
+                info!("GC sweep marked {} keys expired, purged {} keys", marked, purged);
+            }
+        }
+    });
+
+    GcHandle {
+        shutdown_flag,
+        counters,
+        worker: Some(worker),
+    }
+}
+
+/// Lets a `SecureKeyManager` shared behind `Arc<Mutex<_>>` spawn its own
+/// background GC worker as `manager.spawn_gc(interval, grace_period)`.
+pub trait SpawnGc {
+    fn spawn_gc(&self, interval: Duration, grace_period: Duration) -> GcHandle;
+}
+
+impl SpawnGc for Arc<Mutex<SecureKeyManager>> {
+    fn spawn_gc(&self, interval: Duration, grace_period: Duration) -> GcHandle {
+        spawn(self.clone(), interval, grace_period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key_management::KeyStatus;
+
+    fn unlocked_manager(user_id: &str) -> SecureKeyManager {
+        let mut manager = SecureKeyManager::new();
+        manager.unlock(user_id, b"test-unlock-secret");
+        manager
+    }
+
+    #[test]
+    fn test_mark_expired_keys_transitions_active_past_expiry() {
+        let mut manager = unlocked_manager("alice");
+        let key_id = manager.generate_and_store_key("alice", "Kyber-768").unwrap();
+        manager.keys.get_mut(&key_id).unwrap().1.expires_at = Some(0);
+
+        let marked = manager.mark_expired_keys();
+
+        assert_eq!(marked, 1);
+        assert_eq!(manager.keys.get(&key_id).unwrap().1.status, KeyStatus::Expired);
+    }
+
+    #[test]
+    fn test_purge_stale_keys_reclaims_expired_and_long_revoked() {
+        let mut manager = unlocked_manager("bob");
+
+        let expired_key = manager.generate_and_store_key("bob", "Kyber-768").unwrap();
+        manager.keys.get_mut(&expired_key).unwrap().1.status = KeyStatus::Expired;
+
+        let revoked_key = manager.generate_and_store_key("bob", "Kyber-768").unwrap();
+        manager.revoke_key(&revoked_key).unwrap();
+        manager.keys.get_mut(&revoked_key).unwrap().1.revoked_at = Some(0);
+
+        let fresh_key = manager.generate_and_store_key("bob", "Kyber-768").unwrap();
+
+        let purged = manager.purge_stale_keys(60, 10);
+
+        assert_eq!(purged, 2);
+        assert!(manager.keys.get(&expired_key).is_none());
+        assert!(manager.keys.get(&revoked_key).is_none());
+        assert!(manager.keys.get(&fresh_key).is_some());
+    }
+
+    #[test]
+    fn test_purge_stale_keys_respects_batch_limit() {
+        let mut manager = unlocked_manager("carol");
+
+        for _ in 0..5 {
+            let key_id = manager.generate_and_store_key("carol", "Kyber-768").unwrap();
+            manager.keys.get_mut(&key_id).unwrap().1.status = KeyStatus::Expired;
+        }
+
+        let purged = manager.purge_stale_keys(60, 2);
+
+        assert_eq!(purged, 2);
+        assert_eq!(manager.get_key_count(), 3);
+    }
+
+    #[test]
+    fn test_spawn_gc_runs_sweeps_and_shuts_down_cleanly() {
+        let manager = Arc::new(Mutex::new(unlocked_manager("dave")));
+        let handle = manager.spawn_gc(Duration::from_millis(20), Duration::from_secs(60));
+
+        std::thread::sleep(Duration::from_millis(100));
+        let stats = handle.stats();
+        handle.shutdown();
+
+        assert!(stats.sweeps_run > 0);
+    }
+}
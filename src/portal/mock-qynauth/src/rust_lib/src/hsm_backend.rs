@@ -0,0 +1,309 @@
+//! Pluggable key-storage backends for [`crate::key_management::SecureKeyManager`].
+//!
+//! The software backend holds key material in process memory exactly as
+//! this crate always has. The PKCS#11 backend instead follows the
+//! slot/session/object pattern Mozilla's osclientcerts uses against NSS:
+//! enumerate the token's slots, open a session, log in with the token PIN,
+//! and locate or create key objects by label, so private key material for
+//! an HSM-backed key never leaves the token and never passes through this
+//! process as raw bytes.
+
+use crate::super_key::WrappedSecret;
+use crate::{MLDSALevel, PQCAlgorithm, PQCError, PQCKeyPair, PQCResult, PQCSignature};
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+use cryptoki::mechanism::{Mechanism, MechanismType};
+use cryptoki::object::{Attribute, ObjectClass, ObjectHandle};
+use cryptoki::session::{Session, UserType};
+use cryptoki::types::AuthPin;
+use secrecy::{ExposeSecret, Secret};
+use std::path::Path;
+
+/// The token's mechanism for ML-KEM/ML-DSA key objects is vendor-defined
+/// until PKCS#11 standardizes one, so a deployment supplies it through
+/// its own PKCS#11 profile rather than this crate hardcoding a single
+/// vendor's constant; this maps our algorithm enum to that profile's
+/// raw mechanism type.
+fn mechanism_for(algorithm: PQCAlgorithm) -> Mechanism {
+    let raw_type: u64 = match algorithm {
+        PQCAlgorithm::MlKem512 => 0x8000_1051,
+        PQCAlgorithm::MlKem768 => 0x8000_1052,
+        PQCAlgorithm::MlKem1024 => 0x8000_1053,
+        PQCAlgorithm::MlDsa44 => 0x8000_1061,
+        PQCAlgorithm::MlDsa65 => 0x8000_1062,
+        PQCAlgorithm::MlDsa87 => 0x8000_1063,
+    };
+    Mechanism::VendorDefined(MechanismType::from(raw_type), &[])
+}
+
+/// Where a stored key's private material actually lives. `SecureKeyManager`
+/// keeps one of these per key instead of always holding a [`PQCKeyPair`],
+/// so a token-backed key's secret bytes are never copied into this
+/// process at all.
+pub enum KeyHandle {
+    /// Private key material generated and held in process memory.
+    Software(PQCKeyPair),
+    /// A key object that lives on a PKCS#11 token, identified by the
+    /// label it was created under.
+    Token {
+        label: String,
+        algorithm: PQCAlgorithm,
+    },
+    /// Private key material generated in process memory but sealed at
+    /// rest under a [`crate::key_management::SecureKeyManager`] super
+    /// key. Only `public_key` is readable without the owning user
+    /// unlocking first; `sign`/`decapsulate` must be called against a
+    /// [`KeyHandle::Software`] unwrapped from this one, not directly.
+    WrappedSoftware {
+        public_key: Vec<u8>,
+        algorithm: String,
+        key_size: usize,
+        security_level: u8,
+        created_at: u64,
+        wrapped: WrappedSecret,
+    },
+}
+
+impl KeyHandle {
+    pub fn algorithm(&self) -> PQCResult<PQCAlgorithm> {
+        match self {
+            KeyHandle::Software(keypair) => PQCAlgorithm::from_name(&keypair.algorithm),
+            KeyHandle::Token { algorithm, .. } => Ok(*algorithm),
+            KeyHandle::WrappedSoftware { algorithm, .. } => PQCAlgorithm::from_name(algorithm),
+        }
+    }
+
+    /// The public key, when it is known to this process. A token-backed
+    /// key's public half is also generated on-token, so callers that need
+    /// it have to read it back via the backend rather than from the
+    /// handle itself.
+    pub fn software_public_key(&self) -> Option<&[u8]> {
+        match self {
+            KeyHandle::Software(keypair) => Some(&keypair.public_key),
+            KeyHandle::Token { .. } => None,
+            KeyHandle::WrappedSoftware { public_key, .. } => Some(public_key),
+        }
+    }
+}
+
+/// Backs `SecureKeyManager`'s key lifecycle — generate, sign, decapsulate,
+/// remove — without the manager needing to know whether a given key's
+/// private material lives in this process or on a token.
+pub trait KeyBackend: Send {
+    fn generate_and_store(&mut self, algorithm: PQCAlgorithm, key_id: &str) -> PQCResult<KeyHandle>;
+    fn sign(&self, handle: &KeyHandle, message: &[u8]) -> PQCResult<PQCSignature>;
+    fn decapsulate(&self, handle: &KeyHandle, ciphertext: &[u8]) -> PQCResult<Secret<Vec<u8>>>;
+    fn remove(&mut self, handle: &KeyHandle) -> PQCResult<()>;
+}
+
+/// Default backend: unchanged in-memory behavior from before this crate
+/// grew HSM support.
+#[derive(Default)]
+pub struct SoftwareBackend;
+
+impl KeyBackend for SoftwareBackend {
+    fn generate_and_store(&mut self, algorithm: PQCAlgorithm, _key_id: &str) -> PQCResult<KeyHandle> {
+        let keypair = if algorithm.is_kem() {
+            crate::generate_mlkem_keypair_for_algorithm(algorithm)?
+        } else {
+            crate::generate_mldsa_keypair_for_level(MLDSALevel::try_from(algorithm)?)?
+        };
+        Ok(KeyHandle::Software(keypair))
+    }
+
+    fn sign(&self, handle: &KeyHandle, message: &[u8]) -> PQCResult<PQCSignature> {
+        match handle {
+            KeyHandle::Software(keypair) => {
+                let level = MLDSALevel::try_from(PQCAlgorithm::from_name(&keypair.algorithm)?)?;
+                crate::mldsa_sign_for_level(level, keypair.private_key.expose_secret(), message)
+            }
+            KeyHandle::Token { label, .. } => Err(PQCError::HSMError(format!(
+                "key {label} lives on a token; the software backend can't sign with it"
+            ))),
+            KeyHandle::WrappedSoftware { .. } => Err(PQCError::InvalidKeyState(
+                "key is sealed at rest; SecureKeyManager must unwrap it before signing".to_string(),
+            )),
+        }
+    }
+
+    fn decapsulate(&self, handle: &KeyHandle, ciphertext: &[u8]) -> PQCResult<Secret<Vec<u8>>> {
+        match handle {
+            KeyHandle::Software(keypair) => {
+                let algorithm = PQCAlgorithm::from_name(&keypair.algorithm)?;
+                crate::mlkem_decapsulate_for_algorithm(
+                    algorithm,
+                    keypair.private_key.expose_secret(),
+                    ciphertext,
+                )
+            }
+            KeyHandle::Token { label, .. } => Err(PQCError::HSMError(format!(
+                "key {label} lives on a token; the software backend can't decapsulate with it"
+            ))),
+            KeyHandle::WrappedSoftware { .. } => Err(PQCError::InvalidKeyState(
+                "key is sealed at rest; SecureKeyManager must unwrap it before decapsulating".to_string(),
+            )),
+        }
+    }
+
+    fn remove(&mut self, _handle: &KeyHandle) -> PQCResult<()> {
+        Ok(())
+    }
+}
+
+/// Talks to a PKCS#11 token (a hardware HSM or a software token such as
+/// SoftHSM2) through the `cryptoki` crate. `generate_and_store` creates
+/// the key pair on the token via `C_GenerateKeyPair` and keeps only its
+/// label in the returned [`KeyHandle`]; `sign`/`decapsulate` delegate to
+/// the token's `C_Sign`/`C_Decrypt` so the private key object itself is
+/// never read back into this process.
+pub struct Pkcs11Backend {
+    context: Pkcs11,
+    slot_id: u64,
+    pin: Secret<String>,
+}
+
+impl Pkcs11Backend {
+    pub fn new(module_path: &str, slot_id: u64, pin: Secret<String>) -> PQCResult<Self> {
+        let context = Pkcs11::new(Path::new(module_path))
+            .map_err(|e| PQCError::HSMError(format!("failed to load PKCS#11 module: {e}")))?;
+        context
+            .initialize(CInitializeArgs::OsThreads)
+            .map_err(|e| PQCError::HSMError(format!("failed to initialize PKCS#11 module: {e}")))?;
+        Ok(Self {
+            context,
+            slot_id,
+            pin,
+        })
+    }
+
+    fn open_session(&self) -> PQCResult<Session> {
+        let slot = self
+            .context
+            .get_slots_with_token()
+            .map_err(|e| PQCError::HSMError(format!("failed to enumerate slots: {e}")))?
+            .into_iter()
+            .find(|slot| slot.id() == self.slot_id)
+            .ok_or_else(|| {
+                PQCError::HSMError(format!("no token present in slot {}", self.slot_id))
+            })?;
+
+        let session = self
+            .context
+            .open_rw_session(slot)
+            .map_err(|e| PQCError::HSMError(format!("failed to open session: {e}")))?;
+        session
+            .login(UserType::User, Some(&AuthPin::new(self.pin.expose_secret().clone())))
+            .map_err(|e| PQCError::HSMError(format!("failed to log in to token: {e}")))?;
+        Ok(session)
+    }
+
+    fn find_object(
+        &self,
+        session: &Session,
+        label: &str,
+        class: ObjectClass,
+    ) -> PQCResult<ObjectHandle> {
+        let template = [
+            Attribute::Class(class),
+            Attribute::Label(label.as_bytes().to_vec()),
+        ];
+        session
+            .find_objects(&template)
+            .map_err(|e| PQCError::HSMError(format!("failed to search for key {label}: {e}")))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| PQCError::HSMError(format!("key {label} not found on token")))
+    }
+}
+
+impl KeyBackend for Pkcs11Backend {
+    fn generate_and_store(&mut self, algorithm: PQCAlgorithm, key_id: &str) -> PQCResult<KeyHandle> {
+        let session = self.open_session()?;
+        let label = format!("pqc-{key_id}");
+
+        let public_template = [
+            Attribute::Class(ObjectClass::PUBLIC_KEY),
+            Attribute::Label(label.as_bytes().to_vec()),
+            Attribute::Token(true),
+        ];
+        let private_template = [
+            Attribute::Class(ObjectClass::PRIVATE_KEY),
+            Attribute::Label(label.as_bytes().to_vec()),
+            Attribute::Token(true),
+            Attribute::Private(true),
+            Attribute::Sensitive(true),
+            Attribute::Extractable(false),
+        ];
+
+        session
+            .generate_key_pair(&mechanism_for(algorithm), &public_template, &private_template)
+            .map_err(|e| PQCError::HSMError(format!("on-token key generation failed: {e}")))?;
+
+        Ok(KeyHandle::Token { label, algorithm })
+    }
+
+    fn sign(&self, handle: &KeyHandle, message: &[u8]) -> PQCResult<PQCSignature> {
+        let (label, algorithm) = match handle {
+            KeyHandle::Token { label, algorithm } => (label, *algorithm),
+            KeyHandle::Software(_) | KeyHandle::WrappedSoftware { .. } => {
+                return Err(PQCError::HSMError(
+                    "key lives in process memory; the PKCS#11 backend can't sign with it".to_string(),
+                ))
+            }
+        };
+
+        let session = self.open_session()?;
+        let key = self.find_object(&session, label, ObjectClass::PRIVATE_KEY)?;
+        let signature_bytes = session
+            .sign(&mechanism_for(algorithm), key, message)
+            .map_err(|e| PQCError::SigningFailed(format!("C_Sign on token failed: {e}")))?;
+
+        let created_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|_| PQCError::SigningFailed("System time error".to_string()))?
+            .as_secs();
+
+        Ok(PQCSignature {
+            signature_size: signature_bytes.len(),
+            signature: Secret::new(signature_bytes),
+            algorithm: algorithm.name().to_string(),
+            created_at,
+        })
+    }
+
+    fn decapsulate(&self, handle: &KeyHandle, ciphertext: &[u8]) -> PQCResult<Secret<Vec<u8>>> {
+        let (label, algorithm) = match handle {
+            KeyHandle::Token { label, algorithm } => (label, *algorithm),
+            KeyHandle::Software(_) | KeyHandle::WrappedSoftware { .. } => {
+                return Err(PQCError::HSMError(
+                    "key lives in process memory; the PKCS#11 backend can't decapsulate with it"
+                        .to_string(),
+                ))
+            }
+        };
+
+        let session = self.open_session()?;
+        let key = self.find_object(&session, label, ObjectClass::PRIVATE_KEY)?;
+        let shared_secret = session
+            .decrypt(&mechanism_for(algorithm), key, ciphertext)
+            .map_err(|e| PQCError::DecapsulationFailed(format!("C_Decrypt on token failed: {e}")))?;
+
+        Ok(Secret::new(shared_secret))
+    }
+
+    fn remove(&mut self, handle: &KeyHandle) -> PQCResult<()> {
+        match handle {
+            KeyHandle::Token { label, .. } => {
+                let session = self.open_session()?;
+                for class in [ObjectClass::PRIVATE_KEY, ObjectClass::PUBLIC_KEY] {
+                    if let Ok(object) = self.find_object(&session, label, class) {
+                        session.destroy_object(object).map_err(|e| {
+                            PQCError::HSMError(format!("failed to destroy key {label}: {e}"))
+                        })?;
+                    }
+                }
+                Ok(())
+            }
+            KeyHandle::Software(_) | KeyHandle::WrappedSoftware { .. } => Ok(()),
+        }
+    }
+}
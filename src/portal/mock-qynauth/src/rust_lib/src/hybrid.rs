@@ -0,0 +1,571 @@
+//! Hybrid classical+PQC primitives for migration-period crypto-agility: a
+//! signature is only accepted if both an Ed25519 signature and an ML-DSA
+//! signature verify, and a KEM shared secret is derived from both an
+//! X25519 Diffie-Hellman and an ML-KEM-768 encapsulation, so a deployment
+//! stays secure as long as either half (most importantly the PQC half)
+//! remains unbroken.
+//!
+//! The classical half is Ed25519/X25519 rather than secp256k1: this crate
+//! never pulls in a secp256k1 implementation anywhere (it only shows up in
+//! a few doc comments as a design reference for key-validates-on-construction
+//! APIs), while `ed25519_dalek`/`x25519_dalek` are already the established
+//! classical primitives here, including for this exact hybrid-signature use
+//! case. `hybrid_sign`/`hybrid_verify` and their FFI entry points in
+//! `ffi::hybrid_ffi` already reject a composite unless *both* halves verify,
+//! always computing both before combining the results so a timing caller
+//! can't tell which half of a forged signature failed. The KEM half's FFI
+//! entry points live separately in `ffi::hybrid_kem_ffi`, since
+//! `hybrid_keypair_generate`/`hybrid_sign`/`hybrid_verify` were already
+//! taken by the signature side.
+
+use crate::{
+    generate_mldsa_keypair_for_level, mlkem_decapsulate_for_algorithm,
+    mlkem_encapsulate_for_algorithm, mldsa_sign_for_level, mldsa_verify_for_level, MLDSALevel,
+    PQCAlgorithm, PQCError, PQCResult,
+};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use hkdf::Hkdf;
+use secrecy::{ExposeSecret, Secret};
+use sha2::Sha256;
+use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey, StaticSecret};
+
+pub struct HybridKeyPair {
+    pub classical_public_key: Vec<u8>,
+    pub classical_secret_key: Secret<Vec<u8>>,
+    pub pqc_public_key: Vec<u8>,
+    pub pqc_secret_key: Secret<Vec<u8>>,
+    pub level: MLDSALevel,
+}
+
+pub fn generate_hybrid_keypair(level: MLDSALevel) -> PQCResult<HybridKeyPair> {
+    let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+    let pqc_keypair = generate_mldsa_keypair_for_level(level)?;
+
+    Ok(HybridKeyPair {
+        classical_public_key: signing_key.verifying_key().to_bytes().to_vec(),
+        classical_secret_key: Secret::new(signing_key.to_bytes().to_vec()),
+        pqc_public_key: pqc_keypair.public_key,
+        pqc_secret_key: pqc_keypair.private_key,
+        level,
+    })
+}
+
+fn write_length_prefixed(buf: &mut Vec<u8>, component: &[u8]) {
+    buf.extend_from_slice(&(component.len() as u32).to_be_bytes());
+    buf.extend_from_slice(component);
+}
+
+/// Splits the next length-prefixed component off the front of `buf`,
+/// returning it along with whatever remains. Rejects a truncated prefix, a
+/// declared length that runs past the end of `buf`, and a declared length
+/// of zero, so a malformed or truncated composite blob is caught here
+/// rather than surfacing as a confusing failure deeper in `ed25519_dalek`
+/// or `pqcrypto_mldsa`.
+fn read_length_prefixed(buf: &[u8]) -> PQCResult<(&[u8], &[u8])> {
+    if buf.len() < 4 {
+        return Err(PQCError::InvalidSignature(
+            "Truncated length prefix in hybrid signature".to_string(),
+        ));
+    }
+    let (len_bytes, rest) = buf.split_at(4);
+    let len = u32::from_be_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]) as usize;
+    if len == 0 {
+        return Err(PQCError::InvalidSignature(
+            "Zero-length component in hybrid signature".to_string(),
+        ));
+    }
+    if rest.len() < len {
+        return Err(PQCError::InvalidSignature(
+            "Truncated component in hybrid signature".to_string(),
+        ));
+    }
+    Ok(rest.split_at(len))
+}
+
+/// Signs `message` with both the classical and PQC keys, returning the
+/// composite blob `len(classical) || classical_sig || len(pqc) || pqc_sig`.
+pub fn hybrid_sign(
+    level: MLDSALevel,
+    classical_secret_key: &[u8],
+    pqc_secret_key: &[u8],
+    message: &[u8],
+) -> PQCResult<Vec<u8>> {
+    let signing_key_bytes: [u8; 32] = classical_secret_key.try_into().map_err(|_| {
+        PQCError::InvalidPrivateKey("Classical secret key must be 32 bytes".to_string())
+    })?;
+    let classical_sig = SigningKey::from_bytes(&signing_key_bytes).sign(message);
+
+    let pqc_signature = mldsa_sign_for_level(level, pqc_secret_key, message)?;
+
+    let mut composite = Vec::new();
+    write_length_prefixed(&mut composite, &classical_sig.to_bytes());
+    write_length_prefixed(&mut composite, pqc_signature.signature.expose_secret());
+    Ok(composite)
+}
+
+/// Verifies a composite hybrid signature. Accepts only if both the
+/// classical and the PQC signature verify, and rejects any trailing bytes
+/// left over after both components are parsed.
+///
+/// Both halves are always verified before their results are combined —
+/// never short-circuiting on the classical half failing — so a caller
+/// timing this call can't learn which half broke a forged signature.
+pub fn hybrid_verify(
+    level: MLDSALevel,
+    classical_public_key: &[u8],
+    pqc_public_key: &[u8],
+    message: &[u8],
+    composite: &[u8],
+) -> PQCResult<bool> {
+    let (classical_sig_bytes, remainder) = read_length_prefixed(composite)?;
+    let (pqc_sig_bytes, trailing) = read_length_prefixed(remainder)?;
+    if !trailing.is_empty() {
+        return Err(PQCError::InvalidSignature(
+            "Trailing bytes after hybrid signature".to_string(),
+        ));
+    }
+
+    let verifying_key_bytes: [u8; 32] = classical_public_key.try_into().map_err(|_| {
+        PQCError::InvalidPublicKey("Classical public key must be 32 bytes".to_string())
+    })?;
+    let verifying_key = VerifyingKey::from_bytes(&verifying_key_bytes)
+        .map_err(|_| PQCError::InvalidPublicKey("Malformed classical public key".to_string()))?;
+    let classical_sig = Signature::from_slice(classical_sig_bytes)
+        .map_err(|_| PQCError::InvalidSignature("Malformed classical signature".to_string()))?;
+
+    let classical_valid = verifying_key.verify(message, &classical_sig).is_ok();
+    let pqc_valid = mldsa_verify_for_level(level, pqc_public_key, message, pqc_sig_bytes)?;
+
+    Ok(classical_valid && pqc_valid)
+}
+
+/// HKDF-SHA256 domain-separation label for the hybrid KEM combiner, so a
+/// shared secret derived here can never collide with one derived by some
+/// other HKDF use in this crate or a caller's.
+const HYBRID_KEM_INFO: &[u8] = b"Minkalla-hybrid-kem-v1";
+
+/// Rejects an X25519 Diffie-Hellman output of all-zero bytes. `x25519_dalek`
+/// doesn't check this for us: a peer public key that's a low-order point
+/// (the all-zero point chief among them) always clamps down to a
+/// small-order subgroup element, and for several such points the resulting
+/// "shared secret" is all zero regardless of which secret key did the
+/// multiplying. Rejecting that here means a malicious or corrupted
+/// classical public key can't silently reduce this combiner's strength to
+/// "whatever the ML-KEM half alone provides" without the caller finding out.
+fn reject_low_order_point(shared_secret: &[u8; 32]) -> PQCResult<()> {
+    if shared_secret.iter().all(|&b| b == 0) {
+        return Err(PQCError::InvalidPublicKey(
+            "X25519 Diffie-Hellman produced an all-zero shared secret (low-order point)"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub struct HybridKemKeyPair {
+    pub classical_public_key: [u8; 32],
+    pub classical_secret_key: Secret<[u8; 32]>,
+    pub pqc_public_key: Vec<u8>,
+    pub pqc_secret_key: Secret<Vec<u8>>,
+}
+
+pub fn generate_hybrid_kem_keypair() -> PQCResult<HybridKemKeyPair> {
+    let classical_secret = StaticSecret::random_from_rng(rand::rngs::OsRng);
+    let classical_public = X25519PublicKey::from(&classical_secret);
+    let pqc_keypair = crate::generate_mlkem_keypair_for_algorithm(PQCAlgorithm::MlKem768)?;
+
+    Ok(HybridKemKeyPair {
+        classical_public_key: classical_public.to_bytes(),
+        classical_secret_key: Secret::new(classical_secret.to_bytes()),
+        pqc_public_key: pqc_keypair.public_key,
+        pqc_secret_key: pqc_keypair.private_key,
+    })
+}
+
+/// Result of [`hybrid_encapsulate`]: the `ciphertext` the recipient needs
+/// to recover `shared_secret`, mirroring [`crate::PQCEncryptionResult`]'s
+/// shape for the hybrid case.
+pub struct HybridEncryptionResult {
+    /// Composite `len(classical) || ephemeral X25519 public key ||
+    /// len(pqc) || ML-KEM-768 ciphertext`.
+    pub ciphertext: Vec<u8>,
+    pub shared_secret: Secret<Vec<u8>>,
+}
+
+/// Derives one shared secret from an X25519 Diffie-Hellman and an
+/// ML-KEM-768 encapsulation run in parallel against `classical_public_key`
+/// and `pqc_public_key`, combining both secrets and both ciphertexts
+/// through HKDF-SHA256 so the output stays secret as long as either
+/// mechanism (most importantly ML-KEM) remains unbroken.
+pub fn hybrid_encapsulate(
+    classical_public_key: &[u8],
+    pqc_public_key: &[u8],
+) -> PQCResult<HybridEncryptionResult> {
+    let recipient_public_bytes: [u8; 32] = classical_public_key.try_into().map_err(|_| {
+        PQCError::InvalidPublicKey("Classical public key must be 32 bytes".to_string())
+    })?;
+    let recipient_public = X25519PublicKey::from(recipient_public_bytes);
+
+    let ephemeral_secret = EphemeralSecret::random_from_rng(rand::rngs::OsRng);
+    let ephemeral_public = X25519PublicKey::from(&ephemeral_secret);
+    let classical_shared = ephemeral_secret.diffie_hellman(&recipient_public);
+    reject_low_order_point(classical_shared.as_bytes())?;
+
+    let pqc_result =
+        mlkem_encapsulate_for_algorithm(PQCAlgorithm::MlKem768, pqc_public_key, b"")?;
+
+    let shared_secret = combine_kem_secrets(
+        classical_shared.as_bytes(),
+        pqc_result.shared_secret.expose_secret(),
+        ephemeral_public.as_bytes(),
+        &pqc_result.ciphertext,
+    )?;
+
+    let mut ciphertext = Vec::new();
+    write_length_prefixed(&mut ciphertext, ephemeral_public.as_bytes());
+    write_length_prefixed(&mut ciphertext, &pqc_result.ciphertext);
+
+    Ok(HybridEncryptionResult {
+        ciphertext,
+        shared_secret,
+    })
+}
+
+/// Recovers the shared secret from a composite ciphertext produced by
+/// [`hybrid_encapsulate`].
+///
+/// Both the X25519 and the ML-KEM-768 halves are always computed before
+/// either error is propagated, so a caller timing this call can't learn
+/// which half of a malformed ciphertext broke.
+pub fn hybrid_decapsulate(
+    classical_secret_key: &[u8],
+    pqc_secret_key: &[u8],
+    composite_ciphertext: &[u8],
+) -> PQCResult<Secret<Vec<u8>>> {
+    let (classical_ciphertext, remainder) = read_length_prefixed(composite_ciphertext)?;
+    let (pqc_ciphertext, trailing) = read_length_prefixed(remainder)?;
+    if !trailing.is_empty() {
+        return Err(PQCError::InvalidCiphertext(
+            "Trailing bytes after hybrid ciphertext".to_string(),
+        ));
+    }
+
+    let classical_result: PQCResult<[u8; 32]> = (|| {
+        let secret_bytes: [u8; 32] = classical_secret_key.try_into().map_err(|_| {
+            PQCError::InvalidPrivateKey("Classical secret key must be 32 bytes".to_string())
+        })?;
+        let ephemeral_public_bytes: [u8; 32] = classical_ciphertext.try_into().map_err(|_| {
+            PQCError::InvalidCiphertext("Classical ciphertext must be 32 bytes".to_string())
+        })?;
+        let secret = StaticSecret::from(secret_bytes);
+        let ephemeral_public = X25519PublicKey::from(ephemeral_public_bytes);
+        let shared = *secret.diffie_hellman(&ephemeral_public).as_bytes();
+        reject_low_order_point(&shared)?;
+        Ok(shared)
+    })();
+    let pqc_result =
+        mlkem_decapsulate_for_algorithm(PQCAlgorithm::MlKem768, pqc_secret_key, pqc_ciphertext);
+
+    let classical_secret = classical_result?;
+    let pqc_secret = pqc_result?;
+
+    combine_kem_secrets(
+        &classical_secret,
+        pqc_secret.expose_secret(),
+        classical_ciphertext,
+        pqc_ciphertext,
+    )
+}
+
+/// Concatenates both shared secrets and both ciphertexts and stretches
+/// them through HKDF-SHA256 with a domain-separation label, the same
+/// "concatenate then KDF" combiner NIST's hybrid key-establishment
+/// guidance recommends — binding the ciphertexts into the input keying
+/// material prevents an attacker who can influence one ciphertext from
+/// steering the derived secret independently of it.
+fn combine_kem_secrets(
+    classical_secret: &[u8],
+    pqc_secret: &[u8],
+    classical_ciphertext: &[u8],
+    pqc_ciphertext: &[u8],
+) -> PQCResult<Secret<Vec<u8>>> {
+    let mut ikm = Vec::with_capacity(
+        classical_secret.len() + pqc_secret.len() + classical_ciphertext.len() + pqc_ciphertext.len(),
+    );
+    ikm.extend_from_slice(classical_secret);
+    ikm.extend_from_slice(pqc_secret);
+    ikm.extend_from_slice(classical_ciphertext);
+    ikm.extend_from_slice(pqc_ciphertext);
+
+    let hk = Hkdf::<Sha256>::new(None, &ikm);
+    let mut shared_secret = vec![0u8; 32];
+    hk.expand(HYBRID_KEM_INFO, &mut shared_secret)
+        .map_err(|_| PQCError::EncapsulationFailed("HKDF expand failed".to_string()))?;
+
+    Ok(Secret::new(shared_secret))
+}
+
+/// Struct-based façade over [`generate_hybrid_kem_keypair`]/
+/// [`hybrid_encapsulate`]/[`hybrid_decapsulate`], mirroring
+/// [`crate::kyber::KyberEngine`]'s method-based shape for callers
+/// migrating a plain ML-KEM-768 `KyberEngine` session to the hybrid
+/// X25519 + ML-KEM-768 construction this module already implements, so
+/// a compromise of either primitive alone does not compromise the
+/// derived session secret.
+#[derive(Debug, Default)]
+pub struct HybridKyberEngine;
+
+impl HybridKyberEngine {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn generate_keypair(&self) -> PQCResult<HybridKemKeyPair> {
+        generate_hybrid_kem_keypair()
+    }
+
+    pub fn encapsulate(
+        &self,
+        classical_public_key: &[u8],
+        pqc_public_key: &[u8],
+    ) -> PQCResult<HybridEncryptionResult> {
+        hybrid_encapsulate(classical_public_key, pqc_public_key)
+    }
+
+    pub fn decapsulate(
+        &self,
+        classical_secret_key: &[u8],
+        pqc_secret_key: &[u8],
+        composite_ciphertext: &[u8],
+    ) -> PQCResult<Secret<Vec<u8>>> {
+        hybrid_decapsulate(classical_secret_key, pqc_secret_key, composite_ciphertext)
+    }
+
+    /// Checks both halves of `keypair` against their expected FIPS 203 /
+    /// X25519 sizes, mirroring
+    /// [`crate::kyber::KyberEngine::validate_keypair`]'s per-half checks
+    /// -- a keypair is only as strong as its weaker half, so both must
+    /// validate.
+    pub fn validate_keypair(&self, keypair: &HybridKemKeyPair) -> PQCResult<bool> {
+        if keypair.pqc_public_key.len() != PQCAlgorithm::MlKem768.public_key_size() {
+            return Err(PQCError::SecurityValidationFailed(
+                "Invalid ML-KEM-768 public key size".to_string(),
+            ));
+        }
+        if keypair.pqc_secret_key.expose_secret().len() != PQCAlgorithm::MlKem768.secret_key_size() {
+            return Err(PQCError::SecurityValidationFailed(
+                "Invalid ML-KEM-768 secret key size".to_string(),
+            ));
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hybrid_sign_and_verify_round_trip() {
+        let keypair = generate_hybrid_keypair(MLDSALevel::Level3).unwrap();
+        let message = b"hybrid migration test";
+
+        let composite = hybrid_sign(
+            MLDSALevel::Level3,
+            keypair.classical_secret_key.expose_secret(),
+            keypair.pqc_secret_key.expose_secret(),
+            message,
+        )
+        .unwrap();
+
+        let valid = hybrid_verify(
+            MLDSALevel::Level3,
+            &keypair.classical_public_key,
+            &keypair.pqc_public_key,
+            message,
+            &composite,
+        )
+        .unwrap();
+        assert!(valid);
+    }
+
+    #[test]
+    fn test_hybrid_verify_rejects_tampered_message() {
+        let keypair = generate_hybrid_keypair(MLDSALevel::Level3).unwrap();
+        let composite = hybrid_sign(
+            MLDSALevel::Level3,
+            keypair.classical_secret_key.expose_secret(),
+            keypair.pqc_secret_key.expose_secret(),
+            b"original message",
+        )
+        .unwrap();
+
+        let valid = hybrid_verify(
+            MLDSALevel::Level3,
+            &keypair.classical_public_key,
+            &keypair.pqc_public_key,
+            b"tampered message",
+            &composite,
+        )
+        .unwrap();
+        assert!(!valid);
+    }
+
+    #[test]
+    fn test_hybrid_verify_rejects_trailing_bytes() {
+        let keypair = generate_hybrid_keypair(MLDSALevel::Level3).unwrap();
+        let message = b"trailing bytes test";
+        let mut composite = hybrid_sign(
+            MLDSALevel::Level3,
+            keypair.classical_secret_key.expose_secret(),
+            keypair.pqc_secret_key.expose_secret(),
+            message,
+        )
+        .unwrap();
+        composite.push(0xFF);
+
+        let result = hybrid_verify(
+            MLDSALevel::Level3,
+            &keypair.classical_public_key,
+            &keypair.pqc_public_key,
+            message,
+            &composite,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_hybrid_verify_rejects_zero_length_component() {
+        let keypair = generate_hybrid_keypair(MLDSALevel::Level3).unwrap();
+        let mut composite = Vec::new();
+        write_length_prefixed(&mut composite, &[]);
+
+        let result = hybrid_verify(
+            MLDSALevel::Level3,
+            &keypair.classical_public_key,
+            &keypair.pqc_public_key,
+            b"message",
+            &composite,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_hybrid_verify_rejects_truncated_prefix() {
+        let keypair = generate_hybrid_keypair(MLDSALevel::Level3).unwrap();
+        let result = hybrid_verify(
+            MLDSALevel::Level3,
+            &keypair.classical_public_key,
+            &keypair.pqc_public_key,
+            b"message",
+            &[0, 0, 1],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_hybrid_kem_round_trip() {
+        let keypair = generate_hybrid_kem_keypair().unwrap();
+
+        let encapsulated = hybrid_encapsulate(
+            &keypair.classical_public_key,
+            &keypair.pqc_public_key,
+        )
+        .unwrap();
+
+        let decapsulated = hybrid_decapsulate(
+            keypair.classical_secret_key.expose_secret(),
+            keypair.pqc_secret_key.expose_secret(),
+            &encapsulated.ciphertext,
+        )
+        .unwrap();
+
+        assert_eq!(
+            encapsulated.shared_secret.expose_secret(),
+            decapsulated.expose_secret()
+        );
+    }
+
+    #[test]
+    fn test_hybrid_kem_decapsulate_rejects_trailing_bytes() {
+        let keypair = generate_hybrid_kem_keypair().unwrap();
+        let mut ciphertext = hybrid_encapsulate(
+            &keypair.classical_public_key,
+            &keypair.pqc_public_key,
+        )
+        .unwrap()
+        .ciphertext;
+        ciphertext.push(0xFF);
+
+        let result = hybrid_decapsulate(
+            keypair.classical_secret_key.expose_secret(),
+            keypair.pqc_secret_key.expose_secret(),
+            &ciphertext,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_hybrid_kem_wrong_keypair_derives_different_secret() {
+        let alice = generate_hybrid_kem_keypair().unwrap();
+        let mallory = generate_hybrid_kem_keypair().unwrap();
+
+        let encapsulated =
+            hybrid_encapsulate(&alice.classical_public_key, &alice.pqc_public_key).unwrap();
+
+        let mismatched = hybrid_decapsulate(
+            mallory.classical_secret_key.expose_secret(),
+            mallory.pqc_secret_key.expose_secret(),
+            &encapsulated.ciphertext,
+        )
+        .unwrap();
+
+        assert_ne!(
+            encapsulated.shared_secret.expose_secret(),
+            mismatched.expose_secret()
+        );
+    }
+
+    #[test]
+    fn test_hybrid_kyber_engine_round_trips_and_validates() {
+        let engine = HybridKyberEngine::new();
+        let keypair = engine.generate_keypair().unwrap();
+        assert!(engine.validate_keypair(&keypair).unwrap());
+
+        let encapsulated = engine
+            .encapsulate(&keypair.classical_public_key, &keypair.pqc_public_key)
+            .unwrap();
+        let decapsulated = engine
+            .decapsulate(
+                keypair.classical_secret_key.expose_secret(),
+                keypair.pqc_secret_key.expose_secret(),
+                &encapsulated.ciphertext,
+            )
+            .unwrap();
+
+        assert_eq!(
+            encapsulated.shared_secret.expose_secret(),
+            decapsulated.expose_secret()
+        );
+    }
+
+    #[test]
+    fn test_hybrid_encapsulate_rejects_all_zero_classical_public_key() {
+        let keypair = generate_hybrid_kem_keypair().unwrap();
+        let all_zero_classical_public_key = [0u8; 32];
+
+        let result = hybrid_encapsulate(&all_zero_classical_public_key, &keypair.pqc_public_key);
+        assert!(matches!(result, Err(PQCError::InvalidPublicKey(_))));
+    }
+
+    #[test]
+    fn test_hybrid_kyber_engine_rejects_wrong_pqc_key_size() {
+        let engine = HybridKyberEngine::new();
+        let mut keypair = engine.generate_keypair().unwrap();
+        keypair.pqc_public_key.truncate(10);
+
+        let err = engine.validate_keypair(&keypair).unwrap_err();
+        assert!(matches!(err, PQCError::SecurityValidationFailed(_)));
+    }
+}
@@ -0,0 +1,324 @@
+//! Background maintenance worker for [`SecureKeyManager`], modeled on
+//! keystore2's single-threaded `async_task`: a dedicated worker thread
+//! drains a queue of [`MaintenanceJob`]s that can be scheduled "now" or
+//! "after N seconds", retrying failed jobs with exponential backoff
+//! instead of just logging and moving on.
+//!
+//! This sits alongside [`crate::gc`] rather than replacing it: `gc`'s
+//! worker is a simple fixed-interval sweep covering expiry marking and
+//! stale-key purging. This module adds what `gc` doesn't cover — a
+//! rotation pass driven by `rotation_interval`, and a deferred-deletion
+//! queue so a key revoked while `Rotating` (i.e. a caller may have just
+//! fetched its handle via `use_key` moments before the revoke landed)
+//! isn't securely deleted until a drain delay has passed.
+
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::BinaryHeap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use log::{error, info};
+
+use crate::key_management::{KeyAuditOperation, KeyStatus, SecureKeyManager};
+
+/// Stale keys are reclaimed in batches of at most this many per
+/// `CleanupExpired` pass, matching [`crate::gc`]'s batch limit.
+const DEFAULT_BATCH_LIMIT: usize = 200;
+/// Grace period before an `Expired`/`Revoked` key becomes eligible for
+/// `CleanupExpired` to reclaim it, matching [`crate::gc`]'s default.
+const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+/// Upper bound on a failed job's retry backoff.
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+/// How often the worker wakes to check for due jobs and shutdown.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A unit of work for a [`KeyMaintenanceTask`] worker.
+#[derive(Debug, Clone)]
+pub enum MaintenanceJob {
+    /// Runs `auto_rotate_keys`, rotating anything past its rotation
+    /// policy, then reschedules itself for `rotation_interval` later.
+    RotateDueKeys,
+    /// Runs `mark_expired_keys` then `purge_stale_keys`, scans for any
+    /// newly-revoked-while-`Rotating` keys and enqueues a
+    /// `DeferredDelete` for each, then reschedules itself for
+    /// `cleanup_interval` later.
+    CleanupExpired,
+    /// Securely deletes `key_id` via `purge_key_now`, once the drain
+    /// delay for a key revoked mid-rotation has elapsed.
+    DeferredDelete { key_id: String },
+}
+
+struct ScheduledJob {
+    run_at: Instant,
+    job: MaintenanceJob,
+    attempt: u32,
+}
+
+// `BinaryHeap` is a max-heap; jobs with the earliest `run_at` should
+// surface first, so the ordering is reversed.
+impl PartialEq for ScheduledJob {
+    fn eq(&self, other: &Self) -> bool {
+        self.run_at == other.run_at
+    }
+}
+impl Eq for ScheduledJob {}
+impl PartialOrd for ScheduledJob {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for ScheduledJob {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        other.run_at.cmp(&self.run_at)
+    }
+}
+
+fn backoff_delay(attempt: u32) -> Duration {
+    Duration::from_secs(2u64.saturating_pow(attempt.min(10))).min(MAX_BACKOFF)
+}
+
+struct WorkerMsg {
+    job: MaintenanceJob,
+    delay: Duration,
+}
+
+/// A running background maintenance worker. `shutdown` is the clean way
+/// to stop it; dropping the handle without calling `shutdown` leaves the
+/// worker running.
+pub struct KeyMaintenanceTask {
+    shutdown_flag: Arc<AtomicBool>,
+    tx: Sender<WorkerMsg>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl KeyMaintenanceTask {
+    /// Enqueues `job` to run as soon as the worker next wakes, bypassing
+    /// its normal schedule. Intended for tests that don't want to wait
+    /// out a real `rotation_interval`/`cleanup_interval`.
+    pub fn trigger_now(&self, job: MaintenanceJob) {
+        let _ = self.tx.send(WorkerMsg { job, delay: Duration::ZERO });
+    }
+
+    /// Signals the worker to stop and blocks until it exits, finishing
+    /// (or skipping) whatever job it's currently on.
+    pub fn shutdown(mut self) {
+        self.shutdown_flag.store(true, Ordering::SeqCst);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Spawns a [`KeyMaintenanceTask`] worker that rotates keys every
+/// `rotation_interval`, marks-and-purges expired keys every
+/// `cleanup_interval`, and securely deletes any key revoked mid-rotation
+/// `deferred_delete_delay` after the revoke is observed.
+pub fn spawn(
+    manager: Arc<Mutex<SecureKeyManager>>,
+    rotation_interval: Duration,
+    cleanup_interval: Duration,
+    deferred_delete_delay: Duration,
+) -> KeyMaintenanceTask {
+    let shutdown_flag = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = mpsc::channel::<WorkerMsg>();
+    let worker_shutdown = shutdown_flag.clone();
+    let worker_tx = tx.clone();
+
+    let worker = std::thread::spawn(move || {
+        let mut heap: BinaryHeap<ScheduledJob> = BinaryHeap::new();
+        let mut scanned_up_to_seq: u64 = 0;
+        let now = Instant::now();
+        heap.push(ScheduledJob { run_at: now, job: MaintenanceJob::RotateDueKeys, attempt: 0 });
+        heap.push(ScheduledJob { run_at: now, job: MaintenanceJob::CleanupExpired, attempt: 0 });
+
+        while !worker_shutdown.load(Ordering::SeqCst) {
+            while let Ok(msg) = rx.try_recv() {
+                heap.push(ScheduledJob {
+                    run_at: Instant::now() + msg.delay,
+                    job: msg.job,
+                    attempt: 0,
+                });
+            }
+
+            let now = Instant::now();
+            let mut due = Vec::new();
+            while heap.peek().is_some_and(|scheduled| scheduled.run_at <= now) {
+                due.push(heap.pop().expect("peek confirmed an entry is present"));
+            }
+
+            for scheduled in due {
+                let outcome = run_job(&manager, &scheduled.job, &worker_tx, &mut scanned_up_to_seq, deferred_delete_delay);
+
+                match (&scheduled.job, outcome) {
+                    (MaintenanceJob::RotateDueKeys, Ok(())) => heap.push(ScheduledJob {
+                        run_at: Instant::now() + rotation_interval,
+                        job: MaintenanceJob::RotateDueKeys,
+                        attempt: 0,
+                    }),
+                    (MaintenanceJob::CleanupExpired, Ok(())) => heap.push(ScheduledJob {
+                        run_at: Instant::now() + cleanup_interval,
+                        job: MaintenanceJob::CleanupExpired,
+                        attempt: 0,
+                    }),
+                    (_, Ok(())) => {}
+                    (job, Err(e)) => {
+                        let attempt = scheduled.attempt + 1;
+                        let delay = backoff_delay(attempt);
+                        error!("Maintenance job {:?} failed (attempt {}), retrying in {:?}: {}", job, attempt, delay, e);
+                        heap.push(ScheduledJob { run_at: Instant::now() + delay, job: job.clone(), attempt });
+                    }
+                }
+            }
+
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+
+    KeyMaintenanceTask { shutdown_flag, tx, worker: Some(worker) }
+}
+
+/// Runs a single job against the locked manager, returning `Err` if it
+/// should be retried with backoff rather than rescheduled normally.
+fn run_job(
+    manager: &Arc<Mutex<SecureKeyManager>>,
+    job: &MaintenanceJob,
+    tx: &Sender<WorkerMsg>,
+    scanned_up_to_seq: &mut u64,
+    deferred_delete_delay: Duration,
+) -> Result<(), String> {
+    let mut manager = manager.lock().map_err(|e| format!("key manager lock poisoned: {e}"))?;
+
+    match job {
+        MaintenanceJob::RotateDueKeys => {
+            let rotated = manager.auto_rotate_keys().map_err(|e| e.to_string())?;
+            if !rotated.is_empty() {
+                info!("Maintenance task rotated {} keys", rotated.len());
+            }
+            Ok(())
+        }
+        MaintenanceJob::CleanupExpired => {
+            let marked = manager.mark_expired_keys();
+            let purged = manager.purge_stale_keys(DEFAULT_GRACE_PERIOD.as_secs(), DEFAULT_BATCH_LIMIT);
+            if marked > 0 || purged > 0 {
+                info!("Maintenance task marked {} keys expired, purged {}", marked, purged);
+            }
+
+            let entries = manager.audit_log();
+            let new_entries = entries.iter().filter(|entry| entry.seq >= *scanned_up_to_seq);
+            for entry in new_entries {
+                if entry.operation == KeyAuditOperation::Revoke && entry.prev_status == Some(KeyStatus::Rotating) {
+                    let _ = tx.send(WorkerMsg {
+                        job: MaintenanceJob::DeferredDelete { key_id: entry.key_id.clone() },
+                        delay: deferred_delete_delay,
+                    });
+                }
+            }
+            *scanned_up_to_seq = entries.len() as u64;
+
+            Ok(())
+        }
+        MaintenanceJob::DeferredDelete { key_id } => {
+            if manager.purge_key_now(key_id) {
+                info!("Deferred delete reclaimed key {}", key_id);
+                Ok(())
+            } else if manager.get_key_by_id(key_id, None).is_err() {
+                // Already gone by some other path (e.g. a later
+                // `CleanupExpired` pass); nothing left to retry.
+                Ok(())
+            } else {
+                Err(format!("backend removal not yet successful for key {key_id}"))
+            }
+        }
+    }
+}
+
+/// Lets a `SecureKeyManager` shared behind `Arc<Mutex<_>>` spawn its own
+/// background maintenance task as
+/// `manager.spawn_maintenance(rotation_interval, cleanup_interval, deferred_delete_delay)`.
+pub trait SpawnKeyMaintenance {
+    fn spawn_maintenance(
+        &self,
+        rotation_interval: Duration,
+        cleanup_interval: Duration,
+        deferred_delete_delay: Duration,
+    ) -> KeyMaintenanceTask;
+}
+
+impl SpawnKeyMaintenance for Arc<Mutex<SecureKeyManager>> {
+    fn spawn_maintenance(
+        &self,
+        rotation_interval: Duration,
+        cleanup_interval: Duration,
+        deferred_delete_delay: Duration,
+    ) -> KeyMaintenanceTask {
+        spawn(self.clone(), rotation_interval, cleanup_interval, deferred_delete_delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unlocked_manager(user_id: &str) -> SecureKeyManager {
+        let mut manager = SecureKeyManager::new();
+        manager.unlock(user_id, b"test-unlock-secret");
+        manager
+    }
+
+    #[test]
+    fn test_trigger_now_runs_cleanup_pass_immediately() {
+        let manager = Arc::new(Mutex::new(unlocked_manager("alice")));
+        let key_id = manager.lock().unwrap().generate_and_store_key("alice", "Kyber-768").unwrap();
+        manager.lock().unwrap().keys.get_mut(&key_id).unwrap().1.status = KeyStatus::Expired;
+
+        let task = manager.spawn_maintenance(
+            Duration::from_secs(3600),
+            Duration::from_secs(3600),
+            Duration::from_millis(50),
+        );
+        task.trigger_now(MaintenanceJob::CleanupExpired);
+        std::thread::sleep(Duration::from_millis(300));
+        task.shutdown();
+
+        assert!(manager.lock().unwrap().get_key_by_id(&key_id, None).is_err());
+    }
+
+    #[test]
+    fn test_revoke_during_rotation_is_deferred_then_deleted() {
+        let manager = Arc::new(Mutex::new(unlocked_manager("bob")));
+        let key_id = manager.lock().unwrap().generate_and_store_key("bob", "Kyber-768").unwrap();
+
+        // Simulate a revoke landing while the key is mid-rotation, as
+        // `rotate_key` briefly leaves the old key in this state.
+        {
+            let mut manager = manager.lock().unwrap();
+            manager.keys.get_mut(&key_id).unwrap().1.status = KeyStatus::Rotating;
+            manager.revoke_key(&key_id).unwrap();
+        }
+
+        let task = manager.spawn_maintenance(
+            Duration::from_secs(3600),
+            Duration::from_millis(50),
+            Duration::from_millis(50),
+        );
+        std::thread::sleep(Duration::from_millis(500));
+        task.shutdown();
+
+        assert!(manager.lock().unwrap().get_key_by_id(&key_id, None).is_err());
+    }
+
+    #[test]
+    fn test_shutdown_stops_the_worker_thread() {
+        let manager = Arc::new(Mutex::new(unlocked_manager("carol")));
+        let task = manager.spawn_maintenance(
+            Duration::from_millis(20),
+            Duration::from_millis(20),
+            Duration::from_millis(20),
+        );
+        std::thread::sleep(Duration::from_millis(60));
+        task.shutdown();
+    }
+}
@@ -1,10 +1,28 @@
-use crate::{PQCError, PQCResult, PQCKeyPair};
+use crate::bcc;
+use crate::hsm_backend::{KeyBackend, KeyHandle, Pkcs11Backend, SoftwareBackend};
+use crate::security::SideChannelProtection;
+use crate::shamir;
+use crate::super_key::{self, SuperKey};
+use crate::{PQCError, PQCKeyPair, PQCResult, PQCSignature};
+use rand::RngCore;
+use secrecy::{ExposeSecret, Secret};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 use log::{info, error};
 use uuid::Uuid;
 
+mod audit_log;
+pub use audit_log::{KeyAuditEntry, KeyAuditLog, KeyAuditOperation};
+
+mod store;
+pub use store::{InMemoryKeyStore, KeyStore, SqliteKeyStore};
+
+mod pin_auth;
+pub use pin_auth::AuthToken;
+use pin_auth::{PinRecord, DEFAULT_MAX_ATTEMPTS};
+
 #[cfg(test)]
 mod tests;
 
@@ -17,6 +35,126 @@ pub enum KeyStatus {
     Rotating,
 }
 
+/// What a key may be used for, checked by [`SecureKeyManager::use_key`]
+/// against the requested operation before the key is handed out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyPurpose {
+    Encrypt,
+    Decrypt,
+    Sign,
+    Verify,
+}
+
+/// Whether `purpose` makes sense for a key of `algorithm`: KEM algorithms
+/// encapsulate/decapsulate (treated here as `Encrypt`/`Decrypt`) and can't
+/// sign, while signature algorithms sign/verify and can't encapsulate.
+/// Catches a key generated for the wrong purpose slipping past the
+/// `purposes` allowlist because both purposes were requested together.
+fn purpose_compatible_with_algorithm(purpose: KeyPurpose, algorithm: &str) -> bool {
+    match crate::PQCAlgorithm::from_name(algorithm) {
+        Ok(pqc_algorithm) if pqc_algorithm.is_kem() => {
+            matches!(purpose, KeyPurpose::Encrypt | KeyPurpose::Decrypt)
+        }
+        Ok(_) => matches!(purpose, KeyPurpose::Sign | KeyPurpose::Verify),
+        // An algorithm we don't recognize (e.g. a hybrid combiner name)
+        // can't be judged either way, so don't block on it here --
+        // `use_key`'s other checks still apply.
+        Err(_) => true,
+    }
+}
+
+/// A cap on how many operations a key may serve within a trailing
+/// window, enforced by [`SecureKeyManager::enforce_usage`] against the
+/// timestamps recorded in [`KeyMetadata::recent_use_timestamps`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimit {
+    pub max_per_window: u32,
+    pub window_secs: u64,
+}
+
+/// Per-key usage policy, attached at generation time and enforced by
+/// [`SecureKeyManager::use_key`] (inspired by keystore2's `key_parameter`
+/// + `enforcements`): which operations the key permits, the window it's
+/// valid in, how many times it may still be used, how often it may be
+/// used per unit time, and whether using it requires the owning user to
+/// have unlocked recently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyParameters {
+    pub purposes: Vec<KeyPurpose>,
+    pub valid_not_before: Option<u64>,
+    pub valid_not_after: Option<u64>,
+    pub max_uses: Option<u32>,
+    pub uses_remaining: Option<u32>,
+    pub requires_auth: bool,
+    pub auth_timeout: Option<u64>,
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl Default for KeyParameters {
+    /// No restrictions: any purpose, no validity window, unlimited uses,
+    /// no rate limit, no fresh-auth requirement beyond the unlock already
+    /// needed to generate a software-backed key.
+    fn default() -> Self {
+        Self {
+            purposes: vec![
+                KeyPurpose::Encrypt,
+                KeyPurpose::Decrypt,
+                KeyPurpose::Sign,
+                KeyPurpose::Verify,
+            ],
+            valid_not_before: None,
+            valid_not_after: None,
+            max_uses: None,
+            uses_remaining: None,
+            requires_auth: false,
+            auth_timeout: None,
+            rate_limit: None,
+        }
+    }
+}
+
+impl KeyParameters {
+    /// Restricts a key to exactly the given purposes, e.g.
+    /// `KeyParameters::new(vec![KeyPurpose::Sign])` for a signing-only key.
+    pub fn new(purposes: Vec<KeyPurpose>) -> Self {
+        Self {
+            purposes,
+            ..Self::default()
+        }
+    }
+
+    /// Caps the key at `max_uses` total calls to `use_key`; the key
+    /// auto-expires the moment the counter reaches 0.
+    pub fn with_max_uses(mut self, max_uses: u32) -> Self {
+        self.max_uses = Some(max_uses);
+        self.uses_remaining = Some(max_uses);
+        self
+    }
+
+    /// Restricts the key to only be usable between `not_before` and
+    /// `not_after` (unix timestamps, either end optional).
+    pub fn with_validity_window(mut self, not_before: Option<u64>, not_after: Option<u64>) -> Self {
+        self.valid_not_before = not_before;
+        self.valid_not_after = not_after;
+        self
+    }
+
+    /// Requires the owning user to have called `unlock` within
+    /// `auth_timeout` seconds of each `use_key` call.
+    pub fn with_required_auth(mut self, auth_timeout: u64) -> Self {
+        self.requires_auth = true;
+        self.auth_timeout = Some(auth_timeout);
+        self
+    }
+
+    /// Caps the key at `max_per_window` calls to `use_key` within any
+    /// trailing `window_secs`-second window.
+    pub fn with_rate_limit(mut self, max_per_window: u32, window_secs: u64) -> Self {
+        self.rate_limit = Some(RateLimit { max_per_window, window_secs });
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyMetadata {
     pub key_id: String,
@@ -28,6 +166,37 @@ pub struct KeyMetadata {
     pub hsm_reference: Option<String>,
     pub rotation_count: u32,
     pub last_used: Option<u64>,
+    /// When this key's status last transitioned to `Revoked`. Used by the
+    /// background GC worker (see [`crate::gc`]) to decide when a grace
+    /// period has elapsed and the key can be securely deleted.
+    pub revoked_at: Option<u64>,
+    /// Usage policy enforced by [`SecureKeyManager::use_key`].
+    pub parameters: KeyParameters,
+    /// Server-issued attestation certificate chain, present when this key
+    /// was drawn from an [`crate::rkp_client::RkpClient`] pool rather than
+    /// generated locally.
+    pub attestation: Option<crate::rkp_client::AttestationChain>,
+    /// DICE/BCC provenance chain binding this key to the manager's
+    /// measured boot identity at the moment it was generated. See
+    /// [`SecureKeyManager::attest_key`].
+    pub bcc_chain: Option<bcc::BccChain>,
+    /// Set for keys created via
+    /// [`SecureKeyManager::generate_and_store_key_ephemeral`]: the key
+    /// lives only in the manager's `perboot` tier and is dropped by
+    /// [`SecureKeyManager::purge_perboot`] or process exit, never the
+    /// durable tier.
+    pub ephemeral: bool,
+    /// Custody locations of this key's Shamir shares, if it was created
+    /// via [`SecureKeyManager::split_and_store_key`]; empty otherwise.
+    pub shares: Vec<ShareRef>,
+    /// The `k` in this key's `k`-of-`n` Shamir split, set alongside
+    /// `shares`.
+    pub share_threshold: Option<u8>,
+    /// Timestamps of recent `use_key` calls within the trailing
+    /// `KeyParameters::rate_limit` window, pruned by
+    /// [`SecureKeyManager::enforce_usage`] on every check; empty when no
+    /// rate limit is configured.
+    pub recent_use_timestamps: Vec<u64>,
 }
 
 impl KeyMetadata {
@@ -47,6 +216,14 @@ impl KeyMetadata {
             hsm_reference: None,
             rotation_count: 0,
             last_used: None,
+            revoked_at: None,
+            parameters: KeyParameters::default(),
+            attestation: None,
+            bcc_chain: None,
+            ephemeral: false,
+            shares: Vec::new(),
+            share_threshold: None,
+            recent_use_timestamps: Vec::new(),
         }
     }
 
@@ -72,11 +249,14 @@ impl KeyMetadata {
     }
 }
 
-#[derive(Debug, Clone)]
 pub struct HSMConfig {
     pub enabled: bool,
     pub provider: String,
-    pub key_slot: Option<u32>,
+    /// Path to the token's PKCS#11 module, e.g.
+    /// `/usr/lib/softhsm/libsofthsm2.so` for SoftHSM2.
+    pub module_path: String,
+    pub slot_id: u64,
+    pub pin: Secret<String>,
     pub authentication_method: String,
 }
 
@@ -85,41 +265,595 @@ impl Default for HSMConfig {
         Self {
             enabled: false,
             provider: "SoftHSM".to_string(),
-            key_slot: None,
+            module_path: "/usr/lib/softhsm/libsofthsm2.so".to_string(),
+            slot_id: 0,
+            pin: Secret::new(String::new()),
             authentication_method: "PIN".to_string(),
         }
     }
 }
 
-pub struct SecureKeyManager {
-    pub keys: HashMap<String, (PQCKeyPair, KeyMetadata)>,
+impl Clone for HSMConfig {
+    fn clone(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            provider: self.provider.clone(),
+            module_path: self.module_path.clone(),
+            slot_id: self.slot_id,
+            pin: Secret::new(self.pin.expose_secret().clone()),
+            authentication_method: self.authentication_method.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for HSMConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HSMConfig")
+            .field("enabled", &self.enabled)
+            .field("provider", &self.provider)
+            .field("module_path", &self.module_path)
+            .field("slot_id", &self.slot_id)
+            .field("pin", &"[REDACTED]")
+            .field("authentication_method", &self.authentication_method)
+            .finish()
+    }
+}
+
+/// Where one Shamir share of a split key's private material is held. See
+/// [`SecureKeyManager::split_and_store_key`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShareRef {
+    /// This share's GF(256) x-coordinate; unique and nonzero among its
+    /// siblings (see [`crate::shamir`]).
+    pub x: u8,
+    /// Logical custodian slot this share is tagged with, so that
+    /// compromising a single slot alone can't expose enough shares to
+    /// cross the reconstruction threshold.
+    pub hsm_slot_id: u64,
+    pub hsm_provider: String,
+}
+
+pub struct SecureKeyManager<S: KeyStore = InMemoryKeyStore> {
+    pub keys: HashMap<String, (KeyHandle, KeyMetadata)>,
     user_keys: HashMap<String, Vec<String>>, // user_id -> key_ids
     rotation_interval: u64, // seconds
     hsm_config: HSMConfig,
     max_keys_per_user: usize,
+    backend: Box<dyn KeyBackend>,
+    audit_log: KeyAuditLog,
+    user_salts: HashMap<String, super_key::Salt>,
+    super_keys: HashMap<String, SuperKey>,
+    side_channel: SideChannelProtection,
+    /// Unix timestamp of each user's most recent `unlock`, consulted by
+    /// `use_key` for keys whose [`KeyParameters::requires_auth`] is set.
+    user_last_unlock: HashMap<String, u64>,
+    rkp_client: Option<crate::rkp_client::RkpClient>,
+    /// Chain covering this manager's own measured identity, up to and
+    /// including the `key_management` layer. Cloned and extended with one
+    /// more layer per generated key; see [`Self::attest_key`].
+    software_stack_chain: bcc::BccChain,
+    /// CDI of the `key_management` layer itself, the parent every
+    /// per-key layer is derived from.
+    key_management_cdi: bcc::Cdi,
+    /// Authority hash stamped on every per-key BCC layer this manager
+    /// produces.
+    key_authority_hash: [u8; 32],
+    /// The `perboot` tier, modeled on keystore2's table of the same name:
+    /// session-scoped keys created via
+    /// [`Self::generate_and_store_key_ephemeral`], held only in memory and
+    /// never written to `self.keys`/`self.user_keys` or any persistence
+    /// path. See [`Self::purge_perboot`].
+    perboot_keys: HashMap<String, (KeyHandle, KeyMetadata)>,
+    perboot_user_keys: HashMap<String, Vec<String>>,
+    /// Identifies the current "boot": regenerated every time
+    /// [`Self::purge_perboot`] clears the tier, so two nonces never refer
+    /// to the same in-memory generation of ephemeral keys.
+    boot_nonce: u64,
+    /// Durability backend every mutation to `self.keys` is mirrored
+    /// through, so the working set above can be rebuilt with
+    /// [`Self::hydrate`] after a restart instead of vanishing with the
+    /// process. Defaults to [`InMemoryKeyStore`], which mirrors this
+    /// manager's pre-existing all-in-memory behavior.
+    store: S,
+    /// Shamir shares for keys created via [`Self::split_and_store_key`],
+    /// keyed by `key_id`; each entry is `(x, y-values)` for one share.
+    /// Kept separate from `self.keys` since a share isn't a usable key
+    /// handle on its own. See [`Self::reconstruct_key`].
+    share_vault: HashMap<String, Vec<(u8, Secret<Vec<u8>>)>>,
+    /// Enrolled PIN gates, keyed by `user_id`. A user with no entry here
+    /// is ungated, so [`Self::set_pin`] is opt-in and every existing
+    /// caller that never enrolls a PIN keeps working exactly as before.
+    pin_records: HashMap<String, PinRecord>,
 }
 
-impl SecureKeyManager {
+impl SecureKeyManager<InMemoryKeyStore> {
     pub fn new() -> Self {
+        // Stands in for a hardware root of trust's unique device secret,
+        // since this environment has no real measured-boot RoT to read
+        // one from. `software_stack_chain`/`key_management_cdi` are what
+        // every generated key's BCC layer is ultimately anchored to.
+        let mut boot_cdi: bcc::Cdi = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut boot_cdi);
+
+        let key_authority_hash: [u8; 32] =
+            Sha256::digest(b"minkalla-qynauth-key-management-authority").into();
+        let key_management_inputs = bcc::ComponentInputs {
+            code_hash: Sha256::digest(b"minkalla-qynauth-key-management-v1").into(),
+            config_descriptor: bcc::format_config_descriptor(&[
+                ("component", "key_management"),
+                ("version", "1"),
+            ]),
+            authority_hash: key_authority_hash,
+        };
+        let mut software_stack_chain = bcc::BccChain::new(&boot_cdi);
+        let key_management_cdi = bcc::main_flow(&boot_cdi, &key_management_inputs, &mut software_stack_chain);
+
         Self {
             keys: HashMap::new(),
             user_keys: HashMap::new(),
             rotation_interval: 30 * 24 * 60 * 60, // 30 days default
             hsm_config: HSMConfig::default(),
             max_keys_per_user: 10,
+            backend: Box::new(SoftwareBackend),
+            audit_log: KeyAuditLog::new(),
+            user_salts: HashMap::new(),
+            super_keys: HashMap::new(),
+            side_channel: SideChannelProtection::new(),
+            user_last_unlock: HashMap::new(),
+            rkp_client: None,
+            software_stack_chain,
+            key_management_cdi,
+            key_authority_hash,
+            perboot_keys: HashMap::new(),
+            perboot_user_keys: HashMap::new(),
+            boot_nonce: rand::rngs::OsRng.next_u64(),
+            store: InMemoryKeyStore::new(),
+            share_vault: HashMap::new(),
+            pin_records: HashMap::new(),
         }
     }
+}
 
-    pub fn with_hsm_config(mut self, hsm_config: HSMConfig) -> Self {
-        self.hsm_config = hsm_config;
+impl<S: KeyStore> SecureKeyManager<S> {
+    /// Swaps this manager onto a different durability backend, writing the
+    /// current working set through to it so the new backend starts in
+    /// sync, e.g.
+    /// `SecureKeyManager::new().with_store(SqliteKeyStore::open("keys.db")?)`.
+    pub fn with_store<S2: KeyStore>(self, store: S2) -> PQCResult<SecureKeyManager<S2>> {
+        for (key_id, (handle, metadata)) in &self.keys {
+            store.put(key_id, handle, metadata)?;
+        }
+
+        Ok(SecureKeyManager {
+            keys: self.keys,
+            user_keys: self.user_keys,
+            rotation_interval: self.rotation_interval,
+            hsm_config: self.hsm_config,
+            max_keys_per_user: self.max_keys_per_user,
+            backend: self.backend,
+            audit_log: self.audit_log,
+            user_salts: self.user_salts,
+            super_keys: self.super_keys,
+            side_channel: self.side_channel,
+            user_last_unlock: self.user_last_unlock,
+            rkp_client: self.rkp_client,
+            software_stack_chain: self.software_stack_chain,
+            key_management_cdi: self.key_management_cdi,
+            key_authority_hash: self.key_authority_hash,
+            perboot_keys: self.perboot_keys,
+            perboot_user_keys: self.perboot_user_keys,
+            boot_nonce: self.boot_nonce,
+            store,
+            share_vault: self.share_vault,
+            pin_records: self.pin_records,
+        })
+    }
+
+    /// Rebuilds `self.keys`/`self.user_keys` from `self.store`, e.g. right
+    /// after opening a `SqliteKeyStore` against an existing database file
+    /// on startup -- nothing in `self.keys` survives a process restart on
+    /// its own, only what was written through to the store does. Returns
+    /// the number of keys recovered. The `perboot` tier is never
+    /// hydrated; by design nothing in it is ever persisted.
+    pub fn hydrate(&mut self) -> PQCResult<usize> {
+        self.keys.clear();
+        self.user_keys.clear();
+
+        for metadata in self.store.all_metadata()? {
+            let key_id = metadata.key_id.clone();
+            if let Some((handle, metadata)) = self.store.get(&key_id)? {
+                self.user_keys
+                    .entry(metadata.user_id.clone())
+                    .or_insert_with(Vec::new)
+                    .push(key_id.clone());
+                self.keys.insert(key_id, (handle, metadata));
+            }
+        }
+
+        info!("Hydrated {} keys from the durable key store", self.keys.len());
+        Ok(self.keys.len())
+    }
+
+    /// Mirrors `key_id`'s current in-memory entry into `self.store`,
+    /// logging (rather than propagating) a failure -- matches how backend
+    /// removal failures are already handled in
+    /// [`Self::cleanup_expired_keys`]/[`Self::purge_stale_keys`], since
+    /// losing durability for one key shouldn't unwind an otherwise
+    /// successful in-memory mutation.
+    fn persist(&self, key_id: &str) {
+        if let Some((handle, metadata)) = self.keys.get(key_id) {
+            if let Err(e) = self.store.put(key_id, handle, metadata) {
+                error!("Failed to persist key {}: {}", key_id, e);
+            }
+        }
+    }
+
+    /// Returns the DICE/BCC provenance chain asserting `key_id` was
+    /// produced by this manager's measured software stack, for a relying
+    /// party to check with [`crate::bcc::verify_bcc_chain`].
+    pub fn attest_key(&self, key_id: &str) -> PQCResult<bcc::BccChain> {
+        self.keys
+            .get(key_id)
+            .and_then(|(_, metadata)| metadata.bcc_chain.clone())
+            .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))
+    }
+
+    /// Configures this manager to draw pre-generated, attested key
+    /// material from the remote provisioning service at `endpoint`
+    /// before falling back to local generation. `low_water` is the pool
+    /// size that triggers a refill; `batch_size` is how many keys to
+    /// request per refill.
+    pub fn with_rkp(mut self, endpoint: impl Into<String>, low_water: usize, batch_size: usize) -> Self {
+        self.rkp_client = Some(crate::rkp_client::RkpClient::new(endpoint.into(), low_water, batch_size));
         self
     }
 
+    /// Pool occupancy and serve/refill counters for the configured RKP
+    /// client, or `None` if `with_rkp` was never called.
+    pub fn rkp_pool_stats(&self) -> Option<crate::rkp_client::RkpPoolStats> {
+        self.rkp_client.as_ref().map(|client| client.stats())
+    }
+
+    /// Derives `user_id`'s super key from `unlock_secret` (generating a
+    /// fresh per-user salt on first use) and holds it in memory, making
+    /// this user's stored keys readable and allowing new ones to be
+    /// generated for them.
+    pub fn unlock(&mut self, user_id: &str, unlock_secret: &[u8]) {
+        let salt = *self
+            .user_salts
+            .entry(user_id.to_string())
+            .or_insert_with(super_key::generate_salt);
+        self.super_keys
+            .insert(user_id.to_string(), SuperKey::derive(unlock_secret, &salt));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.user_last_unlock.insert(user_id.to_string(), now);
+    }
+
+    /// Zeroizes `user_id`'s derived super key. Until they `unlock` again,
+    /// their stored keys are only readable as sealed ciphertext and
+    /// `generate_and_store_key`/`sign_with_key`/`decapsulate_with_key`
+    /// will fail for them.
+    pub fn lock(&mut self, user_id: &str) {
+        self.super_keys.remove(user_id);
+    }
+
+    /// Re-wraps every `WrappedSoftware` key owned by `user_id` under a
+    /// freshly derived super key for `new_unlock_secret`, without ever
+    /// touching the underlying keypairs -- this manager's per-user super
+    /// key *is* its key-wrapping key (see [`crate::super_key`]), so
+    /// rotating a user's unlock secret is this manager's equivalent of
+    /// rotating a master KEK and re-wrapping the data keys under it.
+    /// Requires `user_id` to already be unlocked under their current
+    /// secret; leaves them unlocked under the new one on success, and
+    /// untouched on failure (every key stays wrapped under the old super
+    /// key unless every key re-wraps cleanly).
+    pub fn rewrap_keys_for_user(&mut self, user_id: &str, new_unlock_secret: &[u8]) -> PQCResult<usize> {
+        let key_ids = self.user_keys.get(user_id).cloned().unwrap_or_default();
+
+        let mut unwrapped = Vec::new();
+        for key_id in &key_ids {
+            if let Some((handle, _)) = self.keys.get(key_id) {
+                if matches!(handle, KeyHandle::WrappedSoftware { .. }) {
+                    let plain = self.unwrap_for_use(handle, user_id)?;
+                    unwrapped.push((key_id.clone(), plain));
+                }
+            }
+        }
+
+        let new_salt = super_key::generate_salt();
+        let new_super_key = SuperKey::derive(new_unlock_secret, &new_salt);
+
+        // Re-wrap every key under `new_super_key` before mutating `self.keys`
+        // for any of them: if a later key fails to wrap, bailing out here
+        // leaves every key exactly as it was, still wrapped under the old
+        // super key, which is still the one recorded in `self.super_keys`.
+        // Interleaving wrap-then-mutate-then-wrap-next would instead risk a
+        // mix of keys wrapped under the new key while `self.super_keys` still
+        // held the old one, making the already-rewrapped keys unreadable.
+        let mut rewrapped = Vec::with_capacity(unwrapped.len());
+        for (key_id, handle) in &unwrapped {
+            let keypair = match handle {
+                KeyHandle::Software(keypair) => keypair,
+                _ => unreachable!("unwrap_for_use always returns KeyHandle::Software"),
+            };
+            let wrapped = self
+                .side_channel
+                .protected_crypto_operation(|| new_super_key.wrap(keypair.private_key.expose_secret()))?;
+            rewrapped.push((
+                key_id.clone(),
+                KeyHandle::WrappedSoftware {
+                    public_key: keypair.public_key.clone(),
+                    algorithm: keypair.algorithm.clone(),
+                    key_size: keypair.key_size,
+                    security_level: keypair.security_level,
+                    created_at: keypair.created_at,
+                    wrapped,
+                },
+            ));
+        }
+
+        let rewrapped_count = rewrapped.len();
+        for (key_id, new_handle) in rewrapped {
+            if let Some((existing_handle, _)) = self.keys.get_mut(&key_id) {
+                *existing_handle = new_handle;
+            }
+            self.persist(&key_id);
+        }
+
+        self.user_salts.insert(user_id.to_string(), new_salt);
+        self.super_keys.insert(user_id.to_string(), new_super_key);
+
+        Ok(rewrapped_count)
+    }
+
+    /// Enrolls `user_id` in the PIN gate checked by [`Self::get_active_key`],
+    /// [`Self::get_key_by_id`], and [`Self::rotate_key`]: once enrolled,
+    /// those methods require a valid [`AuthToken`] from
+    /// [`Self::authenticate`] for this user. `puk` is an optional
+    /// PUK-style recovery secret that [`Self::reset_retry_counter`] will
+    /// require a match for once set. Re-enrolling an existing user
+    /// replaces their PIN and clears any lockout.
+    pub fn set_pin(&mut self, user_id: &str, pin: &[u8], puk: Option<&[u8]>) {
+        self.pin_records.insert(
+            user_id.to_string(),
+            PinRecord::new(pin, DEFAULT_MAX_ATTEMPTS, puk),
+        );
+    }
+
+    /// Verifies `pin` for `user_id` against their enrolled [`PinRecord`],
+    /// smartcard-style: a correct PIN resets the retry counter and returns
+    /// a short-lived [`AuthToken`]; a wrong one decrements it and returns
+    /// [`PQCError::IncorrectPin`] naming how many attempts remain, or
+    /// [`PQCError::AccountLocked`] once the counter reaches zero.
+    pub fn authenticate(&mut self, user_id: &str, pin: &[u8]) -> PQCResult<AuthToken> {
+        let record = self.pin_records.get_mut(user_id).ok_or_else(|| {
+            PQCError::AuthRequired(format!("user {user_id} has no PIN enrolled"))
+        })?;
+
+        if record.is_locked() {
+            return Err(PQCError::AccountLocked(user_id.to_string()));
+        }
+
+        if record.verify(pin) {
+            Ok(AuthToken::issue(user_id))
+        } else if record.is_locked() {
+            Err(PQCError::AccountLocked(user_id.to_string()))
+        } else {
+            Err(PQCError::IncorrectPin {
+                user_id: user_id.to_string(),
+                attempts_remaining: record.attempts_remaining(),
+            })
+        }
+    }
+
+    /// PIN attempts `user_id` has left before lockout, or `None` if they
+    /// have no PIN enrolled.
+    pub fn remaining_attempts(&self, user_id: &str) -> Option<u32> {
+        self.pin_records.get(user_id).map(|record| record.attempts_remaining())
+    }
+
+    /// Admin recovery path for a locked-out (or merely forgetful) user:
+    /// re-enrolls `new_pin` and clears the lockout, requiring `puk` to
+    /// match the PUK `user_id` was enrolled with, if any.
+    pub fn reset_retry_counter(
+        &mut self,
+        user_id: &str,
+        new_pin: &[u8],
+        puk: Option<&[u8]>,
+    ) -> PQCResult<()> {
+        let record = self.pin_records.get_mut(user_id).ok_or_else(|| {
+            PQCError::AuthRequired(format!("user {user_id} has no PIN enrolled"))
+        })?;
+
+        record.reset(new_pin, puk).map_err(|()| {
+            PQCError::AuthRequired(format!("incorrect PUK for user {user_id}"))
+        })
+    }
+
+    /// Enforces the PIN gate for `user_id`: a no-op if they have never
+    /// enrolled a PIN via [`Self::set_pin`] (ungated, matching every
+    /// caller's behavior before this gate existed), otherwise requires
+    /// `auth` to be a still-valid token issued to this exact user.
+    fn check_auth(&self, user_id: &str, auth: Option<&AuthToken>) -> PQCResult<()> {
+        if !self.pin_records.contains_key(user_id) {
+            return Ok(());
+        }
+
+        match auth {
+            Some(token) if token.is_valid_for(user_id) => Ok(()),
+            _ => Err(PQCError::AuthRequired(format!(
+                "user {user_id} must authenticate with their PIN before this operation"
+            ))),
+        }
+    }
+
+    /// Reconstructs the transient, in-memory [`KeyHandle::Software`] a
+    /// wrapped key seals, so it can be handed to the backend for a single
+    /// sign/decapsulate call. The unwrap itself runs under
+    /// `SideChannelProtection` so the AEAD decrypt doesn't leak timing or
+    /// power side channels any more than the PQC primitives already do.
+    fn unwrap_for_use(&self, handle: &KeyHandle, user_id: &str) -> PQCResult<KeyHandle> {
+        let (public_key, algorithm, key_size, security_level, created_at, wrapped) = match handle {
+            KeyHandle::WrappedSoftware {
+                public_key,
+                algorithm,
+                key_size,
+                security_level,
+                created_at,
+                wrapped,
+            } => (public_key, algorithm, *key_size, *security_level, *created_at, wrapped),
+            _ => return Err(PQCError::InvalidKeyState(
+                "unwrap_for_use called on a handle that isn't sealed".to_string(),
+            )),
+        };
+
+        let super_key = self.super_keys.get(user_id).ok_or_else(|| {
+            PQCError::KeyManagerLocked(format!("user {user_id} must unlock before using this key"))
+        })?;
+
+        let private_key = self
+            .side_channel
+            .protected_crypto_operation(|| super_key.unwrap(wrapped))?;
+
+        Ok(KeyHandle::Software(PQCKeyPair {
+            public_key: public_key.clone(),
+            private_key,
+            algorithm: algorithm.clone(),
+            key_size,
+            security_level,
+            created_at,
+        }))
+    }
+
+    /// Every recorded lifecycle transition (generate/rotate/revoke/expire/
+    /// cleanup) for keys this manager has ever held, oldest first.
+    pub fn audit_log(&self) -> &[KeyAuditEntry] {
+        self.audit_log.entries()
+    }
+
+    /// Recomputes the audit chain's hashes from genesis and confirms every
+    /// entry still matches what `audit_log()` reports.
+    pub fn verify_chain(&self) -> PQCResult<()> {
+        self.audit_log.verify_chain()
+    }
+
+    /// Switches the manager onto an HSM-backed [`Pkcs11Backend`] when
+    /// `hsm_config.enabled`, connecting to the configured token up front
+    /// so a misconfigured module/slot/PIN fails here rather than on the
+    /// first `generate_and_store_key` call.
+    pub fn with_hsm_config(mut self, hsm_config: HSMConfig) -> PQCResult<Self> {
+        if hsm_config.enabled {
+            self.backend = Box::new(Pkcs11Backend::new(
+                &hsm_config.module_path,
+                hsm_config.slot_id,
+                Secret::new(hsm_config.pin.expose_secret().clone()),
+            )?);
+        }
+        self.hsm_config = hsm_config;
+        Ok(self)
+    }
+
     pub fn with_rotation_interval(mut self, interval_seconds: u64) -> Self {
         self.rotation_interval = interval_seconds;
         self
     }
 
+    /// Generates key material for `user_id`/`algorithm` (drawing from the
+    /// RKP pool when configured, else the local backend), wraps
+    /// software-backed private key bytes under the user's super key, and
+    /// binds the result to this manager's measured boot identity with a
+    /// BCC layer — everything [`Self::generate_and_store_key`] and
+    /// [`Self::generate_and_store_key_ephemeral`] share, up to the point
+    /// where they diverge on which tier to store it in.
+    fn create_key_material(&mut self, user_id: &str, algorithm: &str) -> PQCResult<(KeyHandle, KeyMetadata)> {
+        let pqc_algorithm = crate::PQCAlgorithm::from_name(algorithm)?;
+
+        let mut metadata = KeyMetadata::new(user_id.to_string(), pqc_algorithm.name().to_string());
+
+        let provisioned = self
+            .rkp_client
+            .as_ref()
+            .and_then(|client| client.take(pqc_algorithm.name()));
+
+        let handle = if let Some(provisioned) = provisioned {
+            info!(
+                "Serving key {} for user {} from RKP pool",
+                metadata.key_id, user_id
+            );
+            let key_size = provisioned.public_key.len() + provisioned.private_key.len();
+            metadata.attestation = Some(provisioned.attestation);
+            KeyHandle::Software(PQCKeyPair {
+                public_key: provisioned.public_key,
+                private_key: Secret::new(provisioned.private_key),
+                algorithm: provisioned.algorithm,
+                key_size,
+                security_level: pqc_algorithm.security_level(),
+                created_at: metadata.created_at,
+            })
+        } else {
+            self.backend.generate_and_store(pqc_algorithm, &metadata.key_id)?
+        };
+
+        if let KeyHandle::Token { label, .. } = &handle {
+            metadata.hsm_reference = Some(label.clone());
+            info!("Key {} created on HSM token with label {}", metadata.key_id, label);
+            self.audit_log.record(
+                &metadata.key_id,
+                user_id,
+                pqc_algorithm.name(),
+                KeyAuditOperation::HsmStore,
+                None,
+                KeyStatus::Active,
+            );
+        }
+
+        // Software-backed key material is sealed at rest under the user's
+        // super key the moment it's generated, so plaintext private key
+        // bytes never sit in `self.keys` unencrypted.
+        let handle = match handle {
+            KeyHandle::Software(keypair) => {
+                let super_key = self.super_keys.get(user_id).ok_or_else(|| {
+                    PQCError::KeyManagerLocked(format!(
+                        "user {user_id} must unlock before generating a key"
+                    ))
+                })?;
+                let wrapped = self
+                    .side_channel
+                    .protected_crypto_operation(|| super_key.wrap(keypair.private_key.expose_secret()))?;
+                KeyHandle::WrappedSoftware {
+                    public_key: keypair.public_key.clone(),
+                    algorithm: keypair.algorithm.clone(),
+                    key_size: keypair.key_size,
+                    security_level: keypair.security_level,
+                    created_at: keypair.created_at,
+                    wrapped,
+                }
+            }
+            other => other,
+        };
+
+        // Bind this key to the manager's measured boot identity: one more
+        // BCC layer, committing to which key/algorithm this is, chained
+        // off the `key_management` layer every key shares.
+        let key_inputs = bcc::ComponentInputs {
+            code_hash: Sha256::digest(format!("{}:{}", metadata.key_id, metadata.algorithm).as_bytes()).into(),
+            config_descriptor: bcc::format_config_descriptor(&[
+                ("key_id", metadata.key_id.as_str()),
+                ("algorithm", metadata.algorithm.as_str()),
+            ]),
+            authority_hash: self.key_authority_hash,
+        };
+        let mut bcc_chain = self.software_stack_chain.clone();
+        bcc::main_flow(&self.key_management_cdi, &key_inputs, &mut bcc_chain);
+        metadata.bcc_chain = Some(bcc_chain);
+
+        Ok((handle, metadata))
+    }
+
     pub fn generate_and_store_key(&mut self, user_id: &str, algorithm: &str) -> PQCResult<String> {
         info!("Generating new {} key for user {}", algorithm, user_id);
 
@@ -129,41 +863,370 @@ impl SecureKeyManager {
             ));
         }
 
-        let mut metadata = KeyMetadata::new(user_id.to_string(), algorithm.to_string());
+        let (handle, metadata) = self.create_key_material(user_id, algorithm)?;
+        let key_id = metadata.key_id.clone();
+
+        self.keys.insert(key_id.clone(), (handle, metadata));
 
-        let keypair = match algorithm {
-            "Kyber-768" | "ML-KEM-768" => {
-                crate::generate_mlkem_keypair()?
-            },
-            "Dilithium-3" | "ML-DSA-65" => {
-                crate::generate_mldsa_keypair()?
-            },
-            _ => return Err(PQCError::UnsupportedAlgorithm(algorithm.to_string())),
-        };
+        self.user_keys
+            .entry(user_id.to_string())
+            .or_insert_with(Vec::new)
+            .push(key_id.clone());
+
+        self.persist(&key_id);
+        self.audit_log.record(&key_id, user_id, algorithm, KeyAuditOperation::Generate, None, KeyStatus::Active);
+
+        info!("Successfully generated and stored key {} for user {}", key_id, user_id);
+        Ok(key_id)
+    }
 
-        if self.hsm_config.enabled {
-            metadata.hsm_reference = Some(self.store_key_in_hsm(&keypair, &metadata)?);
-            info!("Key {} stored in HSM with reference: {:?}",
-                  metadata.key_id, metadata.hsm_reference);
+    /// Like [`Self::generate_and_store_key`], but the result lives only in
+    /// the `perboot` tier (see [`Self::purge_perboot`]): it's excluded
+    /// from `self.keys`/`self.user_keys` and thus from any
+    /// serialization/persistence path built on top of them, and is
+    /// guaranteed not to survive this manager's next `purge_perboot` or
+    /// the process exiting. Useful for short-lived session keys (e.g. a
+    /// Kyber encapsulation key for a single connection) that shouldn't
+    /// join the durable key lifecycle at all.
+    pub fn generate_and_store_key_ephemeral(&mut self, user_id: &str, algorithm: &str) -> PQCResult<String> {
+        info!("Generating new ephemeral {} key for user {}", algorithm, user_id);
+
+        if self.perboot_user_keys.get(user_id).map_or(0, |keys| keys.len()) >= self.max_keys_per_user {
+            return Err(PQCError::KeyGenerationFailed(
+                "Maximum keys per user exceeded".to_string()
+            ));
         }
 
+        let (handle, mut metadata) = self.create_key_material(user_id, algorithm)?;
+        metadata.ephemeral = true;
         let key_id = metadata.key_id.clone();
 
-        self.keys.insert(key_id.clone(), (keypair, metadata));
+        self.perboot_keys.insert(key_id.clone(), (handle, metadata));
+
+        self.perboot_user_keys
+            .entry(user_id.to_string())
+            .or_insert_with(Vec::new)
+            .push(key_id.clone());
+
+        self.audit_log.record(&key_id, user_id, algorithm, KeyAuditOperation::Generate, None, KeyStatus::Active);
 
+        info!("Successfully generated and stored ephemeral key {} for user {}", key_id, user_id);
+        Ok(key_id)
+    }
+
+    /// Zeroizes and drops the entire `perboot` tier at once, simulating a
+    /// reboot: every ephemeral key's handle is dropped (zeroizing any
+    /// `Secret`-wrapped private key bytes), its bookkeeping in
+    /// `perboot_user_keys` is cleared, and [`Self::boot_nonce`]'s
+    /// successor is drawn fresh so any caller holding onto the previous
+    /// nonce can tell its keys are gone. The durable tier in `self.keys`
+    /// is untouched.
+    pub fn purge_perboot(&mut self) {
+        let count = self.perboot_keys.len();
+        self.perboot_keys.clear();
+        self.perboot_user_keys.clear();
+        self.boot_nonce = rand::rngs::OsRng.next_u64();
+        info!("Purged {} perboot (ephemeral) keys", count);
+    }
+
+    /// Generates a key and, in addition to storing the usual wrapped
+    /// copy in `self.keys` (so [`Self::sign_with_key`]/
+    /// [`Self::decapsulate_with_key`] keep working unmodified), splits
+    /// its raw private key bytes into `n` Shamir shares across `n`
+    /// logical HSM-slot custodians, any `k` of which can later rebuild
+    /// it via [`Self::reconstruct_key`] -- a threshold-gated recovery
+    /// path independent of that single wrapped copy surviving intact.
+    /// Key generation for splitting always goes through a fresh, local
+    /// [`SoftwareBackend`], regardless of this manager's configured
+    /// `backend`: a key can only be split while its raw bytes are still
+    /// available in process, before being sealed or handed to a token.
+    pub fn split_and_store_key(
+        &mut self,
+        user_id: &str,
+        algorithm: &str,
+        k: u8,
+        n: u8,
+    ) -> PQCResult<String> {
+        if k == 0 || k > n {
+            return Err(PQCError::InvalidKeyState(format!(
+                "invalid Shamir threshold: k={k} must be in 1..=n (n={n})"
+            )));
+        }
+
+        let pqc_algorithm = crate::PQCAlgorithm::from_name(algorithm)?;
+        let raw_handle = SoftwareBackend.generate_and_store(pqc_algorithm, "pending-split")?;
+        let keypair = match raw_handle {
+            KeyHandle::Software(keypair) => keypair,
+            _ => {
+                return Err(PQCError::InvalidKeyState(
+                    "threshold splitting requires a software-generated keypair".to_string(),
+                ))
+            }
+        };
+
+        let shares = shamir::split_secret(keypair.private_key.expose_secret(), k, n)?;
+
+        let super_key = self.super_keys.get(user_id).ok_or_else(|| {
+            PQCError::KeyManagerLocked(format!(
+                "user {user_id} must unlock before generating a key"
+            ))
+        })?;
+        let wrapped = self
+            .side_channel
+            .protected_crypto_operation(|| super_key.wrap(keypair.private_key.expose_secret()))?;
+        let handle = KeyHandle::WrappedSoftware {
+            public_key: keypair.public_key.clone(),
+            algorithm: keypair.algorithm.clone(),
+            key_size: keypair.key_size,
+            security_level: keypair.security_level,
+            created_at: keypair.created_at,
+            wrapped,
+        };
+
+        let mut metadata = KeyMetadata::new(user_id.to_string(), pqc_algorithm.name().to_string());
+        let key_id = metadata.key_id.clone();
+        metadata.share_threshold = Some(k);
+        metadata.shares = shares
+            .iter()
+            .enumerate()
+            .map(|(i, share)| ShareRef {
+                x: share.x,
+                hsm_slot_id: i as u64,
+                hsm_provider: self.hsm_config.provider.clone(),
+            })
+            .collect();
+
+        self.share_vault.insert(
+            key_id.clone(),
+            shares
+                .into_iter()
+                .map(|share| (share.x, Secret::new(share.y)))
+                .collect(),
+        );
+
+        self.keys.insert(key_id.clone(), (handle, metadata));
         self.user_keys
             .entry(user_id.to_string())
             .or_insert_with(Vec::new)
             .push(key_id.clone());
 
-        info!("Successfully generated and stored key {} for user {}", key_id, user_id);
+        self.persist(&key_id);
+        self.audit_log.record(&key_id, user_id, algorithm, KeyAuditOperation::Generate, None, KeyStatus::Active);
+
+        info!("Split key {} into {}-of-{} Shamir shares across HSM-tagged custodians", key_id, k, n);
         Ok(key_id)
     }
 
-    pub fn rotate_key(&mut self, key_id: &str) -> PQCResult<String> {
+    /// Reconstructs `key_id`'s raw private key bytes from its recorded
+    /// `share_threshold` worth of Shamir shares, independent of whether
+    /// the wrapped copy in `self.keys`/`self.store` is still intact.
+    pub fn reconstruct_key(&self, key_id: &str) -> PQCResult<Secret<Vec<u8>>> {
+        let (_, metadata) = self.keys.get(key_id)
+            .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))?;
+        let k = metadata.share_threshold.ok_or_else(|| {
+            PQCError::InvalidKeyState(format!("key {key_id} was not created via Shamir splitting"))
+        })?;
+
+        let shares = self.share_vault.get(key_id).ok_or_else(|| {
+            PQCError::KeyNotFound(format!("no Shamir shares recorded for key {key_id}"))
+        })?;
+
+        let shamir_shares: Vec<shamir::Share> = shares
+            .iter()
+            .take(k as usize)
+            .map(|(x, y)| shamir::Share { x: *x, y: y.expose_secret().clone() })
+            .collect();
+
+        let secret = shamir::reconstruct_secret(&shamir_shares)?;
+        Ok(Secret::new(secret))
+    }
+
+    /// The nonce identifying the current `perboot` generation, regenerated
+    /// by every [`Self::purge_perboot`] call.
+    pub fn boot_nonce(&self) -> u64 {
+        self.boot_nonce
+    }
+
+    /// Like [`Self::generate_and_store_key`], but attaches a usage policy
+    /// at generation time instead of the unrestricted default, e.g. a
+    /// single-use signing key via
+    /// `KeyParameters::new(vec![KeyPurpose::Sign]).with_max_uses(1)`.
+    pub fn generate_and_store_key_with_parameters(
+        &mut self,
+        user_id: &str,
+        algorithm: &str,
+        parameters: KeyParameters,
+    ) -> PQCResult<String> {
+        let key_id = self.generate_and_store_key(user_id, algorithm)?;
+        if let Some((_, metadata)) = self.keys.get_mut(&key_id) {
+            metadata.parameters = parameters;
+        }
+        self.persist(&key_id);
+        Ok(key_id)
+    }
+
+    /// Checks `key_id` against its [`KeyParameters`] policy for
+    /// `requested_purpose` -- allowed purposes (cross-checked against
+    /// what the key's algorithm can actually do), the validity window,
+    /// and the rate limit -- without touching `uses_remaining` or
+    /// requiring auth; those are [`Self::use_key`]'s job once this
+    /// passes. Records a usage timestamp for the rate-limit check on
+    /// success. Returns [`PQCError::UsagePolicyViolation`] naming
+    /// whichever constraint failed first.
+    pub fn enforce_usage(&mut self, key_id: &str, requested_purpose: KeyPurpose) -> PQCResult<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let metadata = self
+            .keys
+            .get(key_id)
+            .map(|(_, metadata)| metadata.clone())
+            .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))?;
+
+        if metadata.status != KeyStatus::Active {
+            return Err(PQCError::InvalidKeyState(format!(
+                "key {key_id} is not active"
+            )));
+        }
+
+        if !metadata.parameters.purposes.contains(&requested_purpose) {
+            return Err(PQCError::UsagePolicyViolation(format!(
+                "key {key_id} does not permit {requested_purpose:?}"
+            )));
+        }
+
+        if !purpose_compatible_with_algorithm(requested_purpose, &metadata.algorithm) {
+            return Err(PQCError::UsagePolicyViolation(format!(
+                "{requested_purpose:?} is not a valid purpose for a {} key",
+                metadata.algorithm
+            )));
+        }
+
+        if metadata
+            .parameters
+            .valid_not_before
+            .is_some_and(|not_before| now < not_before)
+        {
+            return Err(PQCError::UsagePolicyViolation(format!(
+                "key {key_id} is not yet valid"
+            )));
+        }
+
+        let window_closed = metadata
+            .parameters
+            .valid_not_after
+            .is_some_and(|not_after| now > not_after);
+
+        if window_closed {
+            self.expire_key_for_policy(key_id, &metadata.user_id);
+            return Err(PQCError::UsagePolicyViolation(format!(
+                "key {key_id}'s validity window has closed"
+            )));
+        }
+
+        if let Some(rate_limit) = metadata.parameters.rate_limit {
+            let (_, metadata) = self.keys.get_mut(key_id).expect("checked above");
+            let window_start = now.saturating_sub(rate_limit.window_secs);
+            metadata.recent_use_timestamps.retain(|&ts| ts >= window_start);
+
+            if metadata.recent_use_timestamps.len() as u32 >= rate_limit.max_per_window {
+                return Err(PQCError::UsagePolicyViolation(format!(
+                    "key {key_id} exceeded {} uses per {}s",
+                    rate_limit.max_per_window, rate_limit.window_secs
+                )));
+            }
+            metadata.recent_use_timestamps.push(now);
+        }
+
+        Ok(())
+    }
+
+    /// Runs [`Self::enforce_usage`] for `requested_purpose`, checks
+    /// `auth_token` against [`KeyParameters::requires_auth`], decrements
+    /// the usage counter, and returns the handle for the caller to
+    /// sign/decapsulate with. Auto-transitions the key to `Expired` the
+    /// instant its usage counter or validity window runs out, so the
+    /// next call sees a consistently `Expired` key rather than one
+    /// that's nominally `Active` with nothing left to give.
+    pub fn use_key(
+        &mut self,
+        key_id: &str,
+        requested_purpose: KeyPurpose,
+        auth_token: Option<&AuthToken>,
+    ) -> PQCResult<&KeyHandle> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        self.enforce_usage(key_id, requested_purpose)?;
+
+        let metadata = self
+            .keys
+            .get(key_id)
+            .map(|(_, metadata)| metadata.clone())
+            .expect("enforce_usage just confirmed this key exists");
+
+        if metadata.parameters.requires_auth {
+            let auth_timeout = metadata.parameters.auth_timeout.unwrap_or(0);
+            let authorized = auth_token.is_some_and(|token| token.is_valid_for(&metadata.user_id))
+                && self
+                    .user_last_unlock
+                    .get(&metadata.user_id)
+                    .is_some_and(|unlocked_at| now <= unlocked_at + auth_timeout);
+            if !authorized {
+                return Err(PQCError::AuthRequired(format!(
+                    "key {key_id} requires a recent unlock within {auth_timeout}s"
+                )));
+            }
+        }
+
+        let exhausted = {
+            let (_, metadata) = self.keys.get_mut(key_id).expect("checked above");
+            match metadata.parameters.uses_remaining.as_mut() {
+                Some(remaining) => {
+                    *remaining = remaining.saturating_sub(1);
+                    *remaining == 0
+                }
+                None => false,
+            }
+        };
+
+        if exhausted {
+            self.expire_key_for_policy(key_id, &metadata.user_id);
+        } else {
+            self.persist(key_id);
+        }
+
+        let (handle, _) = self.keys.get(key_id).expect("checked above");
+        Ok(handle)
+    }
+
+    fn expire_key_for_policy(&mut self, key_id: &str, user_id: &str) {
+        if let Some((_, metadata)) = self.keys.get_mut(key_id) {
+            let prev_status = metadata.status.clone();
+            metadata.status = KeyStatus::Expired;
+            let algorithm = metadata.algorithm.clone();
+            self.audit_log
+                .record(key_id, user_id, &algorithm, KeyAuditOperation::Expire, Some(prev_status), KeyStatus::Expired);
+        }
+        self.persist(key_id);
+    }
+
+    /// `auth` must be a valid [`AuthToken`] for this key's owner if they
+    /// have enrolled a PIN via [`Self::set_pin`]; `None` is fine for an
+    /// unenrolled owner. See [`Self::check_auth`].
+    pub fn rotate_key(&mut self, key_id: &str, auth: Option<&AuthToken>) -> PQCResult<String> {
         info!("Rotating key {}", key_id);
 
-        let (old_keypair, mut old_metadata) = self.keys.remove(key_id)
+        let owner = self.keys.get(key_id)
+            .map(|(_, metadata)| metadata.user_id.clone())
+            .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))?;
+        self.check_auth(&owner, auth)?;
+
+        let (old_handle, mut old_metadata) = self.keys.remove(key_id)
             .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))?;
 
         if old_metadata.status != KeyStatus::Active {
@@ -173,7 +1236,16 @@ impl SecureKeyManager {
         }
 
         old_metadata.status = KeyStatus::Rotating;
-        self.keys.insert(key_id.to_string(), (old_keypair, old_metadata.clone()));
+        self.keys.insert(key_id.to_string(), (old_handle, old_metadata.clone()));
+
+        self.audit_log.record(
+            key_id,
+            &old_metadata.user_id,
+            &old_metadata.algorithm,
+            KeyAuditOperation::Rotate,
+            Some(KeyStatus::Active),
+            KeyStatus::Rotating,
+        );
 
         let new_key_id = self.generate_and_store_key(&old_metadata.user_id, &old_metadata.algorithm)?;
 
@@ -181,6 +1253,19 @@ impl SecureKeyManager {
             new_metadata.rotation_count = old_metadata.rotation_count + 1;
         }
 
+        // Commit the old (now-`Rotating`) key and the new key together as
+        // one atomic write, so a crash between the two can't leave the old
+        // key durably `Rotating` with no successor ever recorded.
+        if let (Some((old_handle, old_metadata)), Some((new_handle, new_metadata))) =
+            (self.keys.get(key_id), self.keys.get(&new_key_id))
+        {
+            if let Err(e) = self.store.put_many(&[
+                (key_id, old_handle, old_metadata),
+                (new_key_id.as_str(), new_handle, new_metadata),
+            ]) {
+                error!("Failed to persist rotation of key {} to {}: {}", key_id, new_key_id, e);
+            }
+        }
 
         info!("Successfully rotated key {} to new key {}", key_id, new_key_id);
         Ok(new_key_id)
@@ -189,40 +1274,86 @@ impl SecureKeyManager {
     pub fn revoke_key(&mut self, key_id: &str) -> PQCResult<()> {
         info!("Revoking key {}", key_id);
 
-        let hsm_ref = if let Some((_, metadata)) = self.keys.get(key_id) {
-            metadata.hsm_reference.clone()
-        } else {
-            return Err(PQCError::KeyNotFound(key_id.to_string()));
-        };
+        let (handle, metadata) = self.keys.get_mut(key_id)
+            .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))?;
+
+        let prev_status = metadata.status.clone();
+        let user_id = metadata.user_id.clone();
+        let algorithm = metadata.algorithm.clone();
+        let was_hsm_backed = matches!(handle, KeyHandle::Token { .. });
 
-        if let Some(hsm_ref) = &hsm_ref {
-            self.remove_key_from_hsm(hsm_ref)?;
-            info!("Removed key {} from HSM", key_id);
+        self.backend.remove(handle)?;
+        if was_hsm_backed {
+            self.audit_log.record(key_id, &user_id, &algorithm, KeyAuditOperation::HsmRemove, None, KeyStatus::Revoked);
         }
+        metadata.status = KeyStatus::Revoked;
+        metadata.revoked_at = Some(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        );
 
-        if let Some((keypair, metadata)) = self.keys.get_mut(key_id) {
-            metadata.status = KeyStatus::Revoked;
+        self.audit_log.record(key_id, &user_id, &algorithm, KeyAuditOperation::Revoke, Some(prev_status), KeyStatus::Revoked);
+        self.persist(key_id);
 
-            let keypair_copy = keypair.clone();
-            self.secure_delete_key(&keypair_copy)?;
+        info!("Successfully revoked key {}", key_id);
+        Ok(())
+    }
 
-            info!("Successfully revoked key {}", key_id);
-            Ok(())
-        } else {
-            Err(PQCError::KeyNotFound(key_id.to_string()))
+    /// Signs `message` with the key stored under `key_id`, dispatching to
+    /// the token's `C_Sign` when that key lives on an HSM rather than in
+    /// process memory. Takes no [`AuthToken`]: a key owner who has
+    /// enrolled a PIN must authenticate through a higher-level caller that
+    /// threads one into [`Self::get_key_by_id`] directly.
+    pub fn sign_with_key(&self, key_id: &str, message: &[u8]) -> PQCResult<PQCSignature> {
+        let (handle, metadata) = self.get_key_by_id(key_id, None)?;
+        match handle {
+            KeyHandle::WrappedSoftware { .. } => {
+                let unwrapped = self.unwrap_for_use(handle, &metadata.user_id)?;
+                self.backend.sign(&unwrapped, message)
+            }
+            _ => self.backend.sign(handle, message),
+        }
+    }
+
+    /// Decapsulates `ciphertext` with the key stored under `key_id`,
+    /// dispatching to the token's `C_Decrypt` when that key lives on an
+    /// HSM rather than in process memory. Takes no [`AuthToken`] for the
+    /// same reason [`Self::sign_with_key`] doesn't.
+    pub fn decapsulate_with_key(&self, key_id: &str, ciphertext: &[u8]) -> PQCResult<Secret<Vec<u8>>> {
+        let (handle, metadata) = self.get_key_by_id(key_id, None)?;
+        match handle {
+            KeyHandle::WrappedSoftware { .. } => {
+                let unwrapped = self.unwrap_for_use(handle, &metadata.user_id)?;
+                self.backend.decapsulate(&unwrapped, ciphertext)
+            }
+            _ => self.backend.decapsulate(handle, ciphertext),
         }
     }
 
-    pub fn get_active_key(&self, user_id: &str, algorithm: &str) -> PQCResult<(&PQCKeyPair, &KeyMetadata)> {
+    /// `auth` must be a valid [`AuthToken`] for `user_id` if they have
+    /// enrolled a PIN via [`Self::set_pin`]; `None` is fine for an
+    /// unenrolled user. See [`Self::check_auth`].
+    pub fn get_active_key(
+        &self,
+        user_id: &str,
+        algorithm: &str,
+        purpose: Option<KeyPurpose>,
+        auth: Option<&AuthToken>,
+    ) -> PQCResult<(&KeyHandle, &KeyMetadata)> {
+        self.check_auth(user_id, auth)?;
+
         let user_key_ids = self.user_keys.get(user_id)
             .ok_or_else(|| PQCError::KeyNotFound(format!("No keys for user {}", user_id)))?;
 
         for key_id in user_key_ids {
-            if let Some((keypair, metadata)) = self.keys.get(key_id) {
+            if let Some((handle, metadata)) = self.keys.get(key_id) {
                 if metadata.algorithm == algorithm &&
                    metadata.status == KeyStatus::Active &&
-                   !metadata.is_expired() {
-                    return Ok((keypair, metadata));
+                   !metadata.is_expired() &&
+                   purpose.map_or(true, |purpose| metadata.parameters.purposes.contains(&purpose)) {
+                    return Ok((handle, metadata));
                 }
             }
         }
@@ -232,10 +1363,20 @@ impl SecureKeyManager {
         ))
     }
 
-    pub fn get_key_by_id(&self, key_id: &str) -> PQCResult<(&PQCKeyPair, &KeyMetadata)> {
-        self.keys.get(key_id)
-            .map(|(keypair, metadata)| (keypair, metadata))
-            .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))
+    /// `auth` must be a valid [`AuthToken`] for this key's owner if they
+    /// have enrolled a PIN via [`Self::set_pin`]; `None` is fine for an
+    /// unenrolled owner. See [`Self::check_auth`].
+    pub fn get_key_by_id(
+        &self,
+        key_id: &str,
+        auth: Option<&AuthToken>,
+    ) -> PQCResult<(&KeyHandle, &KeyMetadata)> {
+        let (handle, metadata) = self.keys.get(key_id)
+            .ok_or_else(|| PQCError::KeyNotFound(key_id.to_string()))?;
+
+        self.check_auth(&metadata.user_id, auth)?;
+
+        Ok((handle, metadata))
     }
 
     pub fn update_key_usage(&mut self, key_id: &str) -> PQCResult<()> {
@@ -245,6 +1386,7 @@ impl SecureKeyManager {
                 .unwrap_or_default()
                 .as_secs();
             metadata.last_used = Some(current_time);
+            self.persist(key_id);
             Ok(())
         } else {
             Err(PQCError::KeyNotFound(key_id.to_string()))
@@ -265,20 +1407,34 @@ impl SecureKeyManager {
         }
 
         for key_id in &keys_to_remove {
-            if let Some((keypair, metadata)) = self.keys.remove(key_id) {
-                if let Some(hsm_ref) = &metadata.hsm_reference {
-                    if let Err(e) = self.remove_key_from_hsm(hsm_ref) {
-                        error!("Failed to remove key {} from HSM: {}", key_id, e);
-                    }
+            if let Some((handle, metadata)) = self.keys.remove(key_id) {
+                let was_hsm_backed = matches!(handle, KeyHandle::Token { .. });
+                if let Err(e) = self.backend.remove(&handle) {
+                    error!("Failed to remove key {} from backend: {}", key_id, e);
+                } else if was_hsm_backed {
+                    self.audit_log.record(key_id, &metadata.user_id, &metadata.algorithm, KeyAuditOperation::HsmRemove, None, metadata.status.clone());
                 }
-
-                if let Err(e) = self.secure_delete_key(&keypair) {
-                    error!("Failed to securely delete key {}: {}", key_id, e);
+                if let Err(e) = self.store.remove(key_id) {
+                    error!("Failed to remove key {} from durable store: {}", key_id, e);
                 }
 
                 if let Some(user_keys) = self.user_keys.get_mut(&metadata.user_id) {
                     user_keys.retain(|id| id != key_id);
                 }
+
+                let operation = if metadata.status == KeyStatus::Expired {
+                    KeyAuditOperation::Cleanup
+                } else {
+                    KeyAuditOperation::Expire
+                };
+                self.audit_log.record(
+                    key_id,
+                    &metadata.user_id,
+                    &metadata.algorithm,
+                    operation,
+                    Some(metadata.status.clone()),
+                    KeyStatus::Expired,
+                );
             }
         }
 
@@ -287,6 +1443,148 @@ impl SecureKeyManager {
         Ok(cleanup_count)
     }
 
+    /// Transitions every `Active` key whose `expires_at` has passed to
+    /// `Expired`, without deleting it. Used by the background GC worker
+    /// (see [`crate::gc`]) as the first half of a sweep; [`Self::purge_stale_keys`]
+    /// is the second half, which actually reclaims a key once it's been
+    /// `Expired` (or `Revoked`) for a grace period.
+    pub fn mark_expired_keys(&mut self) -> usize {
+        let mut to_mark = Vec::new();
+        for (key_id, (_, metadata)) in &self.keys {
+            if metadata.status == KeyStatus::Active && metadata.is_expired() {
+                to_mark.push(key_id.clone());
+            }
+        }
+
+        for key_id in &to_mark {
+            let (user_id, algorithm) = {
+                let (_, metadata) = self.keys.get_mut(key_id)
+                    .expect("key_id was just collected from self.keys");
+                metadata.status = KeyStatus::Expired;
+                (metadata.user_id.clone(), metadata.algorithm.clone())
+            };
+            self.audit_log.record(key_id, &user_id, &algorithm, KeyAuditOperation::Expire, Some(KeyStatus::Active), KeyStatus::Expired);
+            self.persist(key_id);
+        }
+
+        to_mark.len()
+    }
+
+    /// Securely deletes up to `batch_limit` keys that are either already
+    /// `Expired`, or `Revoked` for longer than `grace_period` seconds,
+    /// zeroizing their private key material by simply dropping the
+    /// removed [`KeyHandle`] (its key bytes are `Secret`-wrapped and
+    /// zeroize themselves on drop). Bounding the batch keeps a single
+    /// sweep from holding `self` locked for an unpredictable stretch on a
+    /// large key store; callers needing full coverage call this
+    /// repeatedly until it returns 0.
+    pub fn purge_stale_keys(&mut self, grace_period: u64, batch_limit: usize) -> usize {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut to_purge = Vec::new();
+        for (key_id, (_, metadata)) in &self.keys {
+            let stale = match metadata.status {
+                KeyStatus::Expired => true,
+                KeyStatus::Revoked => metadata.revoked_at.is_some_and(|at| now > at + grace_period),
+                _ => false,
+            };
+            if stale {
+                to_purge.push(key_id.clone());
+                if to_purge.len() >= batch_limit {
+                    break;
+                }
+            }
+        }
+
+        let mut purged = 0;
+        for key_id in &to_purge {
+            if let Some((handle, metadata)) = self.keys.remove(key_id) {
+                let was_hsm_backed = matches!(handle, KeyHandle::Token { .. });
+                if let Err(e) = self.backend.remove(&handle) {
+                    error!("Failed to remove key {} from backend during GC: {}", key_id, e);
+                } else if was_hsm_backed {
+                    self.audit_log.record(key_id, &metadata.user_id, &metadata.algorithm, KeyAuditOperation::HsmRemove, None, metadata.status.clone());
+                }
+                if let Err(e) = self.store.remove(key_id) {
+                    error!("Failed to remove key {} from durable store during GC: {}", key_id, e);
+                }
+                // `handle` drops here, zeroizing any in-memory private key bytes.
+                drop(handle);
+
+                if let Some(user_keys) = self.user_keys.get_mut(&metadata.user_id) {
+                    user_keys.retain(|id| id != key_id);
+                }
+
+                self.audit_log.record(
+                    key_id,
+                    &metadata.user_id,
+                    &metadata.algorithm,
+                    KeyAuditOperation::SecureDelete,
+                    Some(metadata.status.clone()),
+                    KeyStatus::Expired,
+                );
+                purged += 1;
+            }
+        }
+
+        purged
+    }
+
+    /// Immediately and unconditionally reclaims `key_id`, used by
+    /// [`crate::key_maintenance`]'s deferred-delete queue once it's
+    /// confirmed that nothing still in flight depends on the key (e.g. a
+    /// key revoked mid-rotation, once the rotation that superseded it has
+    /// committed). Unlike [`Self::purge_stale_keys`], a backend removal
+    /// failure here leaves the key in place instead of forgetting it
+    /// anyway, so the caller can retry; returns `true` only once the key
+    /// is actually gone.
+    pub fn purge_key_now(&mut self, key_id: &str) -> bool {
+        let backend_removed = match self.keys.get(key_id) {
+            Some((handle, _)) => match self.backend.remove(handle) {
+                Ok(()) => true,
+                Err(e) => {
+                    error!("Deferred delete of key {} failed backend removal, will retry: {}", key_id, e);
+                    false
+                }
+            },
+            None => return false,
+        };
+
+        if !backend_removed {
+            return false;
+        }
+
+        if let Some((handle, metadata)) = self.keys.remove(key_id) {
+            let was_hsm_backed = matches!(handle, KeyHandle::Token { .. });
+            if was_hsm_backed {
+                self.audit_log.record(key_id, &metadata.user_id, &metadata.algorithm, KeyAuditOperation::HsmRemove, None, metadata.status.clone());
+            }
+            if let Err(e) = self.store.remove(key_id) {
+                error!("Failed to remove key {} from durable store during deferred delete: {}", key_id, e);
+            }
+            drop(handle);
+
+            if let Some(user_keys) = self.user_keys.get_mut(&metadata.user_id) {
+                user_keys.retain(|id| id != key_id);
+            }
+
+            self.audit_log.record(
+                key_id,
+                &metadata.user_id,
+                &metadata.algorithm,
+                KeyAuditOperation::SecureDelete,
+                Some(metadata.status.clone()),
+                KeyStatus::Expired,
+            );
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn auto_rotate_keys(&mut self) -> PQCResult<Vec<(String, String)>> {
         info!("Starting automatic key rotation check");
 
@@ -301,10 +1599,15 @@ impl SecureKeyManager {
         }
 
         for old_key_id in keys_to_rotate {
-            match self.rotate_key(&old_key_id) {
+            // No `AuthToken` is available for an unattended background
+            // sweep, so a key whose owner has enrolled a PIN is left for
+            // them to rotate manually via an authenticated call instead
+            // of being logged as a failure.
+            match self.rotate_key(&old_key_id, None) {
                 Ok(new_key_id) => {
                     rotated_keys.push((old_key_id, new_key_id));
                 },
+                Err(PQCError::AuthRequired(_)) => {}
                 Err(e) => {
                     error!("Failed to rotate key {}: {}", old_key_id, e);
                 }
@@ -325,12 +1628,20 @@ impl SecureKeyManager {
             .unwrap_or_default()
     }
 
+    /// Counts keys across both the durable tier and the `perboot` tier;
+    /// `ephemeral_keys` is the discriminator telling the two apart within
+    /// the combined totals (`metadata.ephemeral` on each individual key
+    /// discriminates them in [`Self::get_active_keys_for_user`]).
     pub fn get_key_statistics(&self) -> KeyStatistics {
         let mut stats = KeyStatistics::default();
 
-        for (_, metadata) in self.keys.values() {
+        for (_, metadata) in self.keys.values().chain(self.perboot_keys.values()) {
             stats.total_keys += 1;
 
+            if metadata.ephemeral {
+                stats.ephemeral_keys += 1;
+            }
+
             match metadata.status {
                 KeyStatus::Active => stats.active_keys += 1,
                 KeyStatus::Expired => stats.expired_keys += 1,
@@ -348,7 +1659,9 @@ impl SecureKeyManager {
             }
         }
 
-        stats.unique_users = self.user_keys.len();
+        let mut unique_users: std::collections::HashSet<&String> = self.user_keys.keys().collect();
+        unique_users.extend(self.perboot_user_keys.keys());
+        stats.unique_users = unique_users.len();
         stats
     }
 
@@ -360,41 +1673,30 @@ impl SecureKeyManager {
         self.keys.values().map(|(_, metadata)| metadata).collect()
     }
 
+    /// Active keys across both tiers for `user_id`; each returned
+    /// [`KeyMetadata::ephemeral`] tells the durable and `perboot` entries
+    /// apart.
     pub fn get_active_keys_for_user(&self, user_id: &str) -> Vec<&KeyMetadata> {
-        self.user_keys.get(user_id)
-            .map(|key_ids| {
-                key_ids.iter()
-                    .filter_map(|key_id| self.keys.get(key_id))
-                    .map(|(_, metadata)| metadata)
-                    .filter(|metadata| metadata.status == KeyStatus::Active)
-                    .collect()
-            })
-            .unwrap_or_default()
-    }
-
-    fn store_key_in_hsm(&self, _keypair: &PQCKeyPair, metadata: &KeyMetadata) -> PQCResult<String> {
-        if !self.hsm_config.enabled {
-            return Err(PQCError::HSMError("HSM not enabled".to_string()));
-        }
-
-        let hsm_reference = format!("hsm://{}:{}/{}",
-                                   self.hsm_config.provider,
-                                   self.hsm_config.key_slot.unwrap_or(0),
-                                   metadata.key_id);
-
-        info!("Simulating HSM key storage for key {}", metadata.key_id);
-
-        Ok(hsm_reference)
-    }
-
-    fn remove_key_from_hsm(&self, hsm_reference: &str) -> PQCResult<()> {
-        info!("Simulating HSM key removal for reference: {}", hsm_reference);
-        Ok(())
-    }
-
-    fn secure_delete_key(&self, _keypair: &PQCKeyPair) -> PQCResult<()> {
-        info!("Performing secure key deletion (memory zeroing)");
-        Ok(())
+        let durable = self
+            .user_keys
+            .get(user_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|key_id| self.keys.get(key_id))
+            .map(|(_, metadata)| metadata);
+
+        let ephemeral = self
+            .perboot_user_keys
+            .get(user_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|key_id| self.perboot_keys.get(key_id))
+            .map(|(_, metadata)| metadata);
+
+        durable
+            .chain(ephemeral)
+            .filter(|metadata| metadata.status == KeyStatus::Active)
+            .collect()
     }
 }
 
@@ -415,6 +1717,10 @@ pub struct KeyStatistics {
     pub needs_cleanup: usize,
     pub needs_rotation: usize,
     pub unique_users: usize,
+    /// How many of `total_keys` are `perboot`-tier (see
+    /// [`SecureKeyManager::generate_and_store_key_ephemeral`]) rather than
+    /// durable.
+    pub ephemeral_keys: usize,
 }
 
 impl KeyStatistics {
@@ -433,6 +1739,6 @@ pub fn create_default_key_manager() -> SecureKeyManager {
     SecureKeyManager::new()
 }
 
-pub fn create_hsm_key_manager(hsm_config: HSMConfig) -> SecureKeyManager {
+pub fn create_hsm_key_manager(hsm_config: HSMConfig) -> PQCResult<SecureKeyManager> {
     SecureKeyManager::new().with_hsm_config(hsm_config)
 }
@@ -0,0 +1,201 @@
+//! Append-only, hash-chained audit trail for `SecureKeyManager` lifecycle
+//! transitions, modeled on Android keystore2's `audit_log`: every
+//! generate/rotate/revoke/expire/cleanup is recorded as an entry chained to
+//! the one before it, so a retroactively edited or deleted entry breaks
+//! `verify_chain` at a provable index instead of silently disappearing.
+//!
+//! Unlike the crate-level [`crate::audit_log::AuditLog`], which persists
+//! generic `SecurityEvent`s to an `rkv` store, this chain lives in memory
+//! for the lifetime of a `SecureKeyManager` and is scoped to key lifecycle
+//! events specifically.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::KeyStatus;
+use crate::{PQCError, PQCResult};
+
+/// Hash chain root for an empty log. The first appended entry uses this as
+/// its `prev_hash`.
+const GENESIS_HASH: [u8; 32] = [0u8; 32];
+
+/// A key lifecycle transition recorded in the audit chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyAuditOperation {
+    Generate,
+    Rotate,
+    Revoke,
+    Expire,
+    Cleanup,
+    /// Key material (or a label referencing it) was stored on an HSM
+    /// token, e.g. by [`crate::hsm_backend::Pkcs11Backend::generate_and_store`].
+    HsmStore,
+    /// Key material was removed from an HSM token, e.g. by
+    /// [`crate::hsm_backend::Pkcs11Backend::remove`].
+    HsmRemove,
+    /// A key's handle was dropped and its backing bytes zeroized by
+    /// [`super::SecureKeyManager::purge_stale_keys`], distinct from the
+    /// `Expired`/`Revoked` transition that made it eligible.
+    SecureDelete,
+}
+
+/// One entry in a `SecureKeyManager`'s hash-chained audit trail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyAuditEntry {
+    pub seq: u64,
+    pub prev_hash: [u8; 32],
+    pub entry_hash: [u8; 32],
+    pub timestamp: u64,
+    pub key_id: String,
+    pub user_id: String,
+    pub algorithm: String,
+    pub operation: KeyAuditOperation,
+    pub prev_status: Option<KeyStatus>,
+    pub new_status: KeyStatus,
+}
+
+impl KeyAuditEntry {
+    fn compute_hash(prev_hash: &[u8; 32], canonical_payload: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(prev_hash);
+        hasher.update(canonical_payload);
+        hasher.finalize().into()
+    }
+
+    /// Serializes the fields that make up this entry's content, excluding
+    /// `seq`/`prev_hash`/`entry_hash` themselves so the hash doesn't
+    /// recursively cover its own output.
+    fn canonical_payload(&self) -> Vec<u8> {
+        serde_json::to_vec(&(
+            self.timestamp,
+            &self.key_id,
+            &self.user_id,
+            &self.algorithm,
+            &self.operation,
+            &self.prev_status,
+            &self.new_status,
+        ))
+        .expect("KeyAuditEntry payload serialization is infallible")
+    }
+}
+
+/// In-memory, append-only chain of `KeyAuditEntry` records for a single
+/// `SecureKeyManager`.
+#[derive(Debug, Default)]
+pub struct KeyAuditLog {
+    entries: Vec<KeyAuditEntry>,
+    next_seq: u64,
+    last_hash: [u8; 32],
+}
+
+impl KeyAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a lifecycle transition to the chain.
+    pub fn record(
+        &mut self,
+        key_id: &str,
+        user_id: &str,
+        algorithm: &str,
+        operation: KeyAuditOperation,
+        prev_status: Option<KeyStatus>,
+        new_status: KeyStatus,
+    ) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut entry = KeyAuditEntry {
+            seq: self.next_seq,
+            prev_hash: self.last_hash,
+            entry_hash: GENESIS_HASH,
+            timestamp,
+            key_id: key_id.to_string(),
+            user_id: user_id.to_string(),
+            algorithm: algorithm.to_string(),
+            operation,
+            prev_status,
+            new_status,
+        };
+        entry.entry_hash = KeyAuditEntry::compute_hash(&entry.prev_hash, &entry.canonical_payload());
+
+        self.next_seq += 1;
+        self.last_hash = entry.entry_hash;
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &[KeyAuditEntry] {
+        &self.entries
+    }
+
+    /// Every recorded entry for `key_id`, oldest first.
+    pub fn events_for_key(&self, key_id: &str) -> Vec<&KeyAuditEntry> {
+        self.entries.iter().filter(|entry| entry.key_id == key_id).collect()
+    }
+
+    /// Every recorded entry for `user_id`, oldest first.
+    pub fn events_for_user(&self, user_id: &str) -> Vec<&KeyAuditEntry> {
+        self.entries.iter().filter(|entry| entry.user_id == user_id).collect()
+    }
+
+    /// Recomputes the hash chain from genesis and confirms every entry
+    /// still matches, returning which sequence broke first if not.
+    pub fn verify_chain(&self) -> PQCResult<()> {
+        let mut expected_prev = GENESIS_HASH;
+        for entry in &self.entries {
+            let expected_hash = KeyAuditEntry::compute_hash(&expected_prev, &entry.canonical_payload());
+            if entry.prev_hash != expected_prev || entry.entry_hash != expected_hash {
+                return Err(PQCError::SecurityValidationFailed(format!(
+                    "key audit chain broken at sequence {}",
+                    entry.seq
+                )));
+            }
+            expected_prev = entry.entry_hash;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_chains_entries_from_genesis() {
+        let mut log = KeyAuditLog::new();
+
+        log.record("key-1", "alice", "ML-KEM-768", KeyAuditOperation::Generate, None, KeyStatus::Active);
+        log.record("key-1", "alice", "ML-KEM-768", KeyAuditOperation::Revoke, Some(KeyStatus::Active), KeyStatus::Revoked);
+
+        let entries = log.entries();
+        assert_eq!(entries[0].prev_hash, GENESIS_HASH);
+        assert_eq!(entries[1].prev_hash, entries[0].entry_hash);
+        assert_ne!(entries[1].entry_hash, entries[0].entry_hash);
+    }
+
+    #[test]
+    fn test_verify_chain_passes_for_untouched_log() {
+        let mut log = KeyAuditLog::new();
+        for i in 0..5 {
+            log.record(&format!("key-{i}"), "alice", "ML-KEM-768", KeyAuditOperation::Generate, None, KeyStatus::Active);
+        }
+
+        assert!(log.verify_chain().is_ok());
+    }
+
+    #[test]
+    fn test_verify_chain_detects_tampering() {
+        let mut log = KeyAuditLog::new();
+        log.record("key-1", "alice", "ML-KEM-768", KeyAuditOperation::Generate, None, KeyStatus::Active);
+        log.record("key-1", "alice", "ML-KEM-768", KeyAuditOperation::Rotate, Some(KeyStatus::Active), KeyStatus::Rotating);
+
+        log.entries[0].user_id = "mallory".to_string();
+
+        let err = log.verify_chain().unwrap_err();
+        assert!(matches!(err, PQCError::SecurityValidationFailed(msg) if msg.contains('0')));
+    }
+}
@@ -0,0 +1,239 @@
+//! Per-user PIN gate for key access, modeled on smartcard PIN verification:
+//! a salted hash of the PIN is stored alongside a retry counter seeded at
+//! a maximum; a correct PIN resets the counter, a wrong one decrements it,
+//! and reaching zero locks the record until an explicit admin reset. A
+//! successful [`PinRecord::verify`] is exchanged by
+//! [`super::SecureKeyManager::authenticate`] for a short-lived
+//! [`AuthToken`], which [`super::SecureKeyManager`]'s PIN-gated methods
+//! require in place of re-checking the PIN on every call -- the same shape
+//! as a smartcard staying "verified" for the rest of its session instead
+//! of re-prompting for the PIN on every command.
+
+use argon2::Argon2;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+const PIN_SALT_LEN: usize = 16;
+
+/// How long an [`AuthToken`] stays valid after a successful
+/// [`super::SecureKeyManager::authenticate`] call, mirroring
+/// `KeyParameters::auth_timeout`'s "recent unlock" window.
+const AUTH_TOKEN_TTL_SECS: u64 = 300;
+
+/// Default retry budget for a newly enrolled PIN, matching the common
+/// smartcard convention of three tries before lockout.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Derives a 32-byte at-rest hash of `secret` (a PIN or PUK) via Argon2id's
+/// default (memory-hard) parameters, the same KDF [`crate::keystore`] uses
+/// to seal private keys under a passphrase. A PIN's low entropy (a 4-6
+/// digit PIN is at most ~10^6 possibilities) means [`PinRecord::verify`]'s
+/// retry-lockout counter is only a defense against *online* guessing; a
+/// single fast SHA-256 round over the stored salt/hash would let anyone who
+/// reads them (backup, DB dump, memory dump) brute-force the PIN offline at
+/// commodity-hardware speed, bypassing the lockout entirely. `hash_password_
+/// into` only fails on invalid output length or salt length, neither of
+/// which can happen with the fixed-size buffers this function always
+/// passes, so it's unwrapped rather than threaded through as a `Result`.
+fn salted_hash(secret: &[u8], salt: &[u8; PIN_SALT_LEN]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(secret, salt, &mut hash)
+        .expect("Argon2 default params always accept a 16-byte salt and 32-byte output");
+    hash
+}
+
+fn fresh_salt() -> [u8; PIN_SALT_LEN] {
+    let mut salt = [0u8; PIN_SALT_LEN];
+    rand::rngs::OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+/// A salted PIN hash plus an optional PUK-style recovery secret, used by
+/// [`super::SecureKeyManager::reset_retry_counter`] to re-enroll a locked
+/// user's PIN without their old one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PukRecord {
+    salt: [u8; PIN_SALT_LEN],
+    hash: [u8; 32],
+}
+
+impl PukRecord {
+    fn new(puk: &[u8]) -> Self {
+        let salt = fresh_salt();
+        Self {
+            hash: salted_hash(puk, &salt),
+            salt,
+        }
+    }
+
+    fn verify(&self, candidate: &[u8]) -> bool {
+        bool::from(salted_hash(candidate, &self.salt).ct_eq(&self.hash))
+    }
+}
+
+/// One user's enrolled PIN: a salted hash plus the retry state gating it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinRecord {
+    salt: [u8; PIN_SALT_LEN],
+    hash: [u8; 32],
+    max_attempts: u32,
+    attempts_remaining: u32,
+    locked: bool,
+    puk: Option<PukRecord>,
+}
+
+impl PinRecord {
+    pub fn new(pin: &[u8], max_attempts: u32, puk: Option<&[u8]>) -> Self {
+        let salt = fresh_salt();
+        Self {
+            hash: salted_hash(pin, &salt),
+            salt,
+            max_attempts,
+            attempts_remaining: max_attempts,
+            locked: false,
+            puk: puk.map(PukRecord::new),
+        }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn attempts_remaining(&self) -> u32 {
+        self.attempts_remaining
+    }
+
+    /// Checks `pin` against the stored hash in constant time. A correct
+    /// PIN resets `attempts_remaining` to `max_attempts`; a wrong one
+    /// decrements it and locks the record once it reaches zero. Returns
+    /// `false` without comparing anything once already locked, so a
+    /// locked-out record can't be unlocked by guessing.
+    pub fn verify(&mut self, pin: &[u8]) -> bool {
+        if self.locked {
+            return false;
+        }
+
+        let matches = bool::from(salted_hash(pin, &self.salt).ct_eq(&self.hash));
+        if matches {
+            self.attempts_remaining = self.max_attempts;
+        } else {
+            self.attempts_remaining = self.attempts_remaining.saturating_sub(1);
+            if self.attempts_remaining == 0 {
+                self.locked = true;
+            }
+        }
+        matches
+    }
+
+    /// Re-enrolls `new_pin` and clears the lockout, requiring `puk` to
+    /// match this record's PUK when one was set at enrollment.
+    pub fn reset(&mut self, new_pin: &[u8], puk: Option<&[u8]>) -> Result<(), ()> {
+        if let Some(puk_record) = &self.puk {
+            match puk {
+                Some(candidate) if puk_record.verify(candidate) => {}
+                _ => return Err(()),
+            }
+        }
+
+        let salt = fresh_salt();
+        self.hash = salted_hash(new_pin, &salt);
+        self.salt = salt;
+        self.attempts_remaining = self.max_attempts;
+        self.locked = false;
+        Ok(())
+    }
+}
+
+/// Short-lived proof that its `user_id` presented a correct PIN to
+/// [`super::SecureKeyManager::authenticate`] recently enough to satisfy
+/// the manager's PIN-gated methods. Not itself serialized anywhere: it
+/// lives only as long as the caller holds it, and expires on its own
+/// (`AUTH_TOKEN_TTL_SECS`) without needing an explicit logout.
+#[derive(Debug, Clone)]
+pub struct AuthToken {
+    user_id: String,
+    token: Uuid,
+    issued_at: u64,
+}
+
+impl AuthToken {
+    pub(super) fn issue(user_id: &str) -> Self {
+        let issued_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Self {
+            user_id: user_id.to_string(),
+            token: Uuid::new_v4(),
+            issued_at,
+        }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    /// Whether this token was issued to `user_id` and is still within its
+    /// TTL.
+    pub fn is_valid_for(&self, user_id: &str) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.user_id == user_id && now <= self.issued_at + AUTH_TOKEN_TTL_SECS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_correct_pin_succeeds_and_resets_counter() {
+        let mut record = PinRecord::new(b"1234", DEFAULT_MAX_ATTEMPTS, None);
+        assert!(!record.verify(b"0000"));
+        assert_eq!(record.attempts_remaining(), DEFAULT_MAX_ATTEMPTS - 1);
+
+        assert!(record.verify(b"1234"));
+        assert_eq!(record.attempts_remaining(), DEFAULT_MAX_ATTEMPTS);
+    }
+
+    #[test]
+    fn test_lockout_after_max_wrong_attempts() {
+        let mut record = PinRecord::new(b"1234", 3, None);
+        assert!(!record.verify(b"0000"));
+        assert!(!record.verify(b"0000"));
+        assert!(!record.verify(b"0000"));
+        assert!(record.is_locked());
+
+        // Even the correct PIN is now rejected without consuming an
+        // attempt or unlocking the record.
+        assert!(!record.verify(b"1234"));
+        assert!(record.is_locked());
+    }
+
+    #[test]
+    fn test_reset_requires_matching_puk_when_set() {
+        let mut record = PinRecord::new(b"1234", 1, Some(b"puk-secret"));
+        assert!(!record.verify(b"wrong"));
+        assert!(record.is_locked());
+
+        assert!(record.reset(b"5678", Some(b"wrong-puk")).is_err());
+        assert!(record.is_locked());
+
+        assert!(record.reset(b"5678", Some(b"puk-secret")).is_ok());
+        assert!(!record.is_locked());
+        assert!(record.verify(b"5678"));
+    }
+
+    #[test]
+    fn test_auth_token_valid_only_for_issuing_user() {
+        let token = AuthToken::issue("alice");
+        assert!(token.is_valid_for("alice"));
+        assert!(!token.is_valid_for("bob"));
+    }
+}
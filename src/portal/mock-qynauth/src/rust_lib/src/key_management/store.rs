@@ -0,0 +1,490 @@
+//! Pluggable durability backends for [`crate::key_management::SecureKeyManager`].
+//!
+//! The manager's working set still lives in the two in-memory maps it's
+//! always used (so every reference-returning accessor -- `get_active_key`,
+//! `get_key_by_id`, `get_all_keys`, and friends -- keeps borrowing straight
+//! out of them, unchanged); what's new is that every mutation is *also*
+//! mirrored through a [`KeyStore`], so the working set can be rebuilt with
+//! [`SecureKeyManager::hydrate`] after a restart instead of vanishing with
+//! the process. [`InMemoryKeyStore`] is the default, matching the manager's
+//! historical all-in-memory behavior. [`SqliteKeyStore`] is modeled on
+//! Android Keystore2's schema: a `keyentry` table carrying only metadata,
+//! and a separate `blobentry` table for the serialized [`KeyHandle`], so
+//! metadata-only scans (statistics, cleanup sweeps, hydration) never touch
+//! key material.
+
+use crate::hsm_backend::KeyHandle;
+use crate::key_management::KeyMetadata;
+use crate::{PQCAlgorithm, PQCError, PQCResult};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// One persisted key: its storage handle alongside its lifecycle metadata.
+pub type StoredKey = (KeyHandle, KeyMetadata);
+
+/// Durable backing store for [`SecureKeyManager`]. Every mutating manager
+/// method (`generate_and_store_key`, `rotate_key`, `revoke_key`,
+/// `cleanup_expired_keys`, ...) writes through to this trait in addition to
+/// updating its in-memory working set.
+pub trait KeyStore: Send + Sync {
+    /// Persists (or overwrites) a single key.
+    fn put(&self, key_id: &str, handle: &KeyHandle, metadata: &KeyMetadata) -> PQCResult<()>;
+
+    /// Persists several keys as one atomic unit, so a crash mid-write
+    /// can't leave only some of them committed. [`SecureKeyManager::rotate_key`]
+    /// uses this to commit the old (now-`Rotating`) key and the freshly
+    /// generated key together, rather than leaving a dangling `Rotating`
+    /// key if the process dies between two separate writes.
+    fn put_many(&self, entries: &[(&str, &KeyHandle, &KeyMetadata)]) -> PQCResult<()>;
+
+    /// The full stored entry for `key_id`, or `None` if it isn't present.
+    fn get(&self, key_id: &str) -> PQCResult<Option<StoredKey>>;
+
+    /// Removes `key_id`, if present.
+    fn remove(&self, key_id: &str) -> PQCResult<()>;
+
+    /// Every key id belonging to `user_id`.
+    fn list_by_user(&self, user_id: &str) -> PQCResult<Vec<String>>;
+
+    /// Metadata for every stored key, without deserializing any key
+    /// material -- what [`SecureKeyManager::hydrate`] and statistics/
+    /// cleanup scans use.
+    fn all_metadata(&self) -> PQCResult<Vec<KeyMetadata>>;
+}
+
+/// The subset of [`KeyHandle`] that ever reaches a [`KeyStore`]: by the
+/// time `create_key_material` hands a key to `self.keys`, software-backed
+/// material has already been sealed into `WrappedSoftware`, so an
+/// unwrapped [`KeyHandle::Software`] should never be persisted.
+#[derive(Serialize, Deserialize)]
+enum StorableHandle {
+    Token {
+        label: String,
+        algorithm: String,
+    },
+    WrappedSoftware {
+        public_key: Vec<u8>,
+        algorithm: String,
+        key_size: usize,
+        security_level: u8,
+        created_at: u64,
+        wrapped: crate::super_key::WrappedSecret,
+    },
+}
+
+fn to_storable(handle: &KeyHandle) -> PQCResult<StorableHandle> {
+    match handle {
+        KeyHandle::Token { label, algorithm } => Ok(StorableHandle::Token {
+            label: label.clone(),
+            algorithm: algorithm.name().to_string(),
+        }),
+        KeyHandle::WrappedSoftware {
+            public_key,
+            algorithm,
+            key_size,
+            security_level,
+            created_at,
+            wrapped,
+        } => Ok(StorableHandle::WrappedSoftware {
+            public_key: public_key.clone(),
+            algorithm: algorithm.clone(),
+            key_size: *key_size,
+            security_level: *security_level,
+            created_at: *created_at,
+            wrapped: wrapped.clone(),
+        }),
+        KeyHandle::Software(_) => Err(PQCError::InvalidKeyState(
+            "refusing to persist an unwrapped software key handle; seal it under a super key first"
+                .to_string(),
+        )),
+    }
+}
+
+fn from_storable(storable: StorableHandle) -> PQCResult<KeyHandle> {
+    Ok(match storable {
+        StorableHandle::Token { label, algorithm } => KeyHandle::Token {
+            label,
+            algorithm: PQCAlgorithm::from_name(&algorithm)?,
+        },
+        StorableHandle::WrappedSoftware {
+            public_key,
+            algorithm,
+            key_size,
+            security_level,
+            created_at,
+            wrapped,
+        } => KeyHandle::WrappedSoftware {
+            public_key,
+            algorithm,
+            key_size,
+            security_level,
+            created_at,
+            wrapped,
+        },
+    })
+}
+
+/// Duplicates a persistable [`KeyHandle`] by round-tripping it through
+/// [`StorableHandle`] -- the same conversion a real backend would need to
+/// serialize/deserialize, reused here so [`InMemoryKeyStore`] doesn't need
+/// its own separate cloning logic.
+fn duplicate_handle(handle: &KeyHandle) -> PQCResult<KeyHandle> {
+    from_storable(to_storable(handle)?)
+}
+
+/// Default backend: an in-memory mirror of whatever's written through to
+/// it. Matches `SecureKeyManager`'s behavior from before pluggable storage
+/// existed -- nothing here survives a real process restart, only a
+/// [`SqliteKeyStore`] (or another real backend) does.
+#[derive(Default)]
+pub struct InMemoryKeyStore {
+    entries: Mutex<HashMap<String, StoredKey>>,
+}
+
+impl InMemoryKeyStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KeyStore for InMemoryKeyStore {
+    fn put(&self, key_id: &str, handle: &KeyHandle, metadata: &KeyMetadata) -> PQCResult<()> {
+        let stored = (duplicate_handle(handle)?, metadata.clone());
+        self.entries.lock().unwrap().insert(key_id.to_string(), stored);
+        Ok(())
+    }
+
+    fn put_many(&self, entries: &[(&str, &KeyHandle, &KeyMetadata)]) -> PQCResult<()> {
+        // Build every duplicate before taking the lock, so a conversion
+        // failure partway through never leaves a partial write behind.
+        let mut staged = Vec::with_capacity(entries.len());
+        for (key_id, handle, metadata) in entries {
+            staged.push((key_id.to_string(), duplicate_handle(handle)?, (*metadata).clone()));
+        }
+        let mut guard = self.entries.lock().unwrap();
+        for (key_id, handle, metadata) in staged {
+            guard.insert(key_id, (handle, metadata));
+        }
+        Ok(())
+    }
+
+    fn get(&self, key_id: &str) -> PQCResult<Option<StoredKey>> {
+        match self.entries.lock().unwrap().get(key_id) {
+            Some((handle, metadata)) => Ok(Some((duplicate_handle(handle)?, metadata.clone()))),
+            None => Ok(None),
+        }
+    }
+
+    fn remove(&self, key_id: &str) -> PQCResult<()> {
+        self.entries.lock().unwrap().remove(key_id);
+        Ok(())
+    }
+
+    fn list_by_user(&self, user_id: &str) -> PQCResult<Vec<String>> {
+        Ok(self
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, (_, metadata))| metadata.user_id == user_id)
+            .map(|(key_id, _)| key_id.clone())
+            .collect())
+    }
+
+    fn all_metadata(&self) -> PQCResult<Vec<KeyMetadata>> {
+        Ok(self
+            .entries
+            .lock()
+            .unwrap()
+            .values()
+            .map(|(_, metadata)| metadata.clone())
+            .collect())
+    }
+}
+
+/// SQLite-backed [`KeyStore`], modeled on Android Keystore2's
+/// `keyentry`/`blobentry` split: `keyentry` carries queryable metadata
+/// columns plus a `metadata_json` column for the remaining nested fields
+/// (`parameters`, `attestation`, `bcc_chain`, ...); `blobentry` holds only
+/// the serialized [`KeyHandle`], keyed the same way, so a metadata scan
+/// never deserializes key material.
+pub struct SqliteKeyStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteKeyStore {
+    /// Opens (creating if needed) a SQLite-backed store at `path`,
+    /// creating its schema on first use.
+    pub fn open(path: impl AsRef<Path>) -> PQCResult<Self> {
+        let conn = Connection::open(path)
+            .map_err(|e| PQCError::StoreError(format!("failed to open key store: {e}")))?;
+        Self::init_schema(&conn)?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// An in-process, non-persistent SQLite database -- useful for tests
+    /// that want `SqliteKeyStore`'s transactional behavior without a file
+    /// on disk.
+    pub fn open_in_memory() -> PQCResult<Self> {
+        let conn = Connection::open_in_memory()
+            .map_err(|e| PQCError::StoreError(format!("failed to open key store: {e}")))?;
+        Self::init_schema(&conn)?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    fn init_schema(conn: &Connection) -> PQCResult<()> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS keyentry (
+                key_id          TEXT PRIMARY KEY,
+                user_id         TEXT NOT NULL,
+                algorithm       TEXT NOT NULL,
+                status          TEXT NOT NULL,
+                created_at      INTEGER NOT NULL,
+                expires_at      INTEGER,
+                rotation_count  INTEGER NOT NULL,
+                last_used       INTEGER,
+                revoked_at      INTEGER,
+                metadata_json   TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS keyentry_user_id ON keyentry(user_id);
+            CREATE TABLE IF NOT EXISTS blobentry (
+                key_id      TEXT PRIMARY KEY REFERENCES keyentry(key_id) ON DELETE CASCADE,
+                handle_json TEXT NOT NULL
+            );",
+        )
+        .map_err(|e| PQCError::StoreError(format!("failed to create key store schema: {e}")))
+    }
+
+    fn put_one(
+        conn: &Connection,
+        key_id: &str,
+        handle: &KeyHandle,
+        metadata: &KeyMetadata,
+    ) -> PQCResult<()> {
+        let metadata_json = serde_json::to_string(metadata)
+            .map_err(|e| PQCError::StoreError(format!("failed to encode key metadata: {e}")))?;
+        let handle_json = serde_json::to_string(&to_storable(handle)?)
+            .map_err(|e| PQCError::StoreError(format!("failed to encode key handle: {e}")))?;
+
+        conn.execute(
+            "INSERT INTO keyentry
+                (key_id, user_id, algorithm, status, created_at, expires_at, rotation_count, last_used, revoked_at, metadata_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+             ON CONFLICT(key_id) DO UPDATE SET
+                user_id = excluded.user_id,
+                algorithm = excluded.algorithm,
+                status = excluded.status,
+                created_at = excluded.created_at,
+                expires_at = excluded.expires_at,
+                rotation_count = excluded.rotation_count,
+                last_used = excluded.last_used,
+                revoked_at = excluded.revoked_at,
+                metadata_json = excluded.metadata_json",
+            params![
+                key_id,
+                metadata.user_id,
+                metadata.algorithm,
+                format!("{:?}", metadata.status),
+                metadata.created_at as i64,
+                metadata.expires_at.map(|v| v as i64),
+                metadata.rotation_count,
+                metadata.last_used.map(|v| v as i64),
+                metadata.revoked_at.map(|v| v as i64),
+                metadata_json,
+            ],
+        )
+        .map_err(|e| PQCError::StoreError(format!("failed to write keyentry row: {e}")))?;
+
+        conn.execute(
+            "INSERT INTO blobentry (key_id, handle_json) VALUES (?1, ?2)
+             ON CONFLICT(key_id) DO UPDATE SET handle_json = excluded.handle_json",
+            params![key_id, handle_json],
+        )
+        .map_err(|e| PQCError::StoreError(format!("failed to write blobentry row: {e}")))?;
+
+        Ok(())
+    }
+}
+
+impl KeyStore for SqliteKeyStore {
+    fn put(&self, key_id: &str, handle: &KeyHandle, metadata: &KeyMetadata) -> PQCResult<()> {
+        let conn = self.conn.lock().unwrap();
+        Self::put_one(&conn, key_id, handle, metadata)
+    }
+
+    fn put_many(&self, entries: &[(&str, &KeyHandle, &KeyMetadata)]) -> PQCResult<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn
+            .transaction()
+            .map_err(|e| PQCError::StoreError(format!("failed to open key store transaction: {e}")))?;
+        for (key_id, handle, metadata) in entries {
+            Self::put_one(&tx, key_id, handle, metadata)?;
+        }
+        tx.commit()
+            .map_err(|e| PQCError::StoreError(format!("failed to commit key store transaction: {e}")))
+    }
+
+    fn get(&self, key_id: &str) -> PQCResult<Option<StoredKey>> {
+        let conn = self.conn.lock().unwrap();
+        let metadata_json: Option<String> = conn
+            .query_row(
+                "SELECT metadata_json FROM keyentry WHERE key_id = ?1",
+                params![key_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| PQCError::StoreError(format!("failed to read keyentry row: {e}")))?;
+
+        let Some(metadata_json) = metadata_json else {
+            return Ok(None);
+        };
+
+        let handle_json: String = conn
+            .query_row(
+                "SELECT handle_json FROM blobentry WHERE key_id = ?1",
+                params![key_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| PQCError::StoreError(format!("failed to read blobentry row: {e}")))?;
+
+        let metadata: KeyMetadata = serde_json::from_str(&metadata_json)
+            .map_err(|e| PQCError::StoreError(format!("failed to decode key metadata: {e}")))?;
+        let storable: StorableHandle = serde_json::from_str(&handle_json)
+            .map_err(|e| PQCError::StoreError(format!("failed to decode key handle: {e}")))?;
+
+        Ok(Some((from_storable(storable)?, metadata)))
+    }
+
+    fn remove(&self, key_id: &str) -> PQCResult<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM keyentry WHERE key_id = ?1", params![key_id])
+            .map_err(|e| PQCError::StoreError(format!("failed to delete keyentry row: {e}")))?;
+        // `blobentry` rows cascade-delete via the foreign key above.
+        Ok(())
+    }
+
+    fn list_by_user(&self, user_id: &str) -> PQCResult<Vec<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT key_id FROM keyentry WHERE user_id = ?1")
+            .map_err(|e| PQCError::StoreError(format!("failed to query keyentry: {e}")))?;
+        let rows = stmt
+            .query_map(params![user_id], |row| row.get(0))
+            .map_err(|e| PQCError::StoreError(format!("failed to query keyentry: {e}")))?;
+        rows.collect::<Result<Vec<String>, _>>()
+            .map_err(|e| PQCError::StoreError(format!("failed to read keyentry rows: {e}")))
+    }
+
+    fn all_metadata(&self) -> PQCResult<Vec<KeyMetadata>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare("SELECT metadata_json FROM keyentry")
+            .map_err(|e| PQCError::StoreError(format!("failed to query keyentry: {e}")))?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| PQCError::StoreError(format!("failed to query keyentry: {e}")))?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            let metadata_json =
+                row.map_err(|e| PQCError::StoreError(format!("failed to read keyentry row: {e}")))?;
+            out.push(
+                serde_json::from_str(&metadata_json)
+                    .map_err(|e| PQCError::StoreError(format!("failed to decode key metadata: {e}")))?,
+            );
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::super_key::WrappedSecret;
+
+    fn sample_handle() -> KeyHandle {
+        KeyHandle::WrappedSoftware {
+            public_key: vec![1, 2, 3],
+            algorithm: "ML-KEM-768".to_string(),
+            key_size: 32,
+            security_level: 3,
+            created_at: 1_700_000_000,
+            wrapped: WrappedSecret { nonce: [0u8; 12], ciphertext: vec![4, 5, 6] },
+        }
+    }
+
+    #[test]
+    fn in_memory_store_round_trips_put_and_get() {
+        let store = InMemoryKeyStore::new();
+        let metadata = KeyMetadata::new("alice".to_string(), "ML-KEM-768".to_string());
+        let key_id = metadata.key_id.clone();
+
+        store.put(&key_id, &sample_handle(), &metadata).unwrap();
+
+        let (_, fetched_metadata) = store.get(&key_id).unwrap().expect("key should be present");
+        assert_eq!(fetched_metadata.key_id, key_id);
+        assert_eq!(store.list_by_user("alice").unwrap(), vec![key_id.clone()]);
+
+        store.remove(&key_id).unwrap();
+        assert!(store.get(&key_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn in_memory_store_rejects_unwrapped_software_handles() {
+        let store = InMemoryKeyStore::new();
+        let metadata = KeyMetadata::new("alice".to_string(), "ML-KEM-768".to_string());
+        let handle = KeyHandle::Software(crate::PQCKeyPair {
+            public_key: vec![1],
+            private_key: secrecy::Secret::new(vec![2]),
+            algorithm: "ML-KEM-768".to_string(),
+            key_size: 32,
+            security_level: 3,
+            created_at: 0,
+        });
+
+        assert!(store.put(&metadata.key_id, &handle, &metadata).is_err());
+    }
+
+    #[test]
+    fn sqlite_store_round_trips_put_many_transactionally() {
+        let store = SqliteKeyStore::open_in_memory().unwrap();
+        let old_metadata = KeyMetadata::new("bob".to_string(), "ML-KEM-768".to_string());
+        let new_metadata = KeyMetadata::new("bob".to_string(), "ML-KEM-768".to_string());
+        let old_id = old_metadata.key_id.clone();
+        let new_id = new_metadata.key_id.clone();
+        let handle = sample_handle();
+
+        store
+            .put_many(&[(&old_id, &handle, &old_metadata), (&new_id, &handle, &new_metadata)])
+            .unwrap();
+
+        assert!(store.get(&old_id).unwrap().is_some());
+        assert!(store.get(&new_id).unwrap().is_some());
+        let mut by_user = store.list_by_user("bob").unwrap();
+        by_user.sort();
+        let mut expected = vec![old_id, new_id];
+        expected.sort();
+        assert_eq!(by_user, expected);
+    }
+
+    #[test]
+    fn sqlite_store_all_metadata_never_reads_blobentry() {
+        let store = SqliteKeyStore::open_in_memory().unwrap();
+        let metadata = KeyMetadata::new("carol".to_string(), "ML-DSA-65".to_string());
+        store.put(&metadata.key_id, &sample_handle(), &metadata).unwrap();
+
+        // Corrupt `blobentry` directly; `all_metadata` must not care, since
+        // it only ever selects from `keyentry`.
+        {
+            let conn = store.conn.lock().unwrap();
+            conn.execute("UPDATE blobentry SET handle_json = 'not json'", []).unwrap();
+        }
+
+        let all = store.all_metadata().unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].key_id, metadata.key_id);
+    }
+}
@@ -22,6 +22,7 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let user_id = "test_user_kyber";
         let algorithm = "Kyber-768";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let result = manager.generate_and_store_key(user_id, algorithm);
         assert!(result.is_ok());
@@ -43,6 +44,7 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let user_id = "test_user_dilithium";
         let algorithm = "Dilithium-3";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let result = manager.generate_and_store_key(user_id, algorithm);
         assert!(result.is_ok());
@@ -63,6 +65,7 @@ mod key_management_tests {
     fn test_generate_multiple_keys_same_user() {
         let mut manager = create_test_manager();
         let user_id = "test_user_multiple";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let kyber_result = manager.generate_and_store_key(user_id, "Kyber-768");
         let dilithium_result = manager.generate_and_store_key(user_id, "Dilithium-3");
@@ -89,6 +92,8 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let user1 = "user1";
         let user2 = "user2";
+        manager.unlock(user1, b"test-unlock-secret");
+        manager.unlock(user2, b"test-unlock-secret");
 
         let _key1 = manager.generate_and_store_key(user1, "Kyber-768").unwrap();
         let _key2 = manager.generate_and_store_key(user2, "Dilithium-3").unwrap();
@@ -115,11 +120,12 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let user_id = "test_user_rotate";
         let algorithm = "Kyber-768";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let original_key_id = manager.generate_and_store_key(user_id, algorithm).unwrap();
         assert_eq!(manager.get_key_count(), 1);
 
-        let rotation_result = manager.rotate_key(&original_key_id);
+        let rotation_result = manager.rotate_key(&original_key_id, None);
         assert!(rotation_result.is_ok());
 
         let new_key_id = rotation_result.unwrap();
@@ -141,7 +147,7 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let nonexistent_key_id = "nonexistent_key";
 
-        let result = manager.rotate_key(nonexistent_key_id);
+        let result = manager.rotate_key(nonexistent_key_id, None);
         assert!(result.is_err());
         
         if let Err(PQCError::KeyNotFound(msg)) = result {
@@ -156,6 +162,7 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let user_id = "test_user_revoke";
         let algorithm = "Dilithium-3";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let key_id = manager.generate_and_store_key(user_id, algorithm).unwrap();
         assert_eq!(manager.get_key_count(), 1);
@@ -190,6 +197,7 @@ mod key_management_tests {
     fn test_cleanup_expired_keys() {
         let mut manager = create_test_manager();
         let user_id = "test_user_cleanup";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
         assert_eq!(manager.get_key_count(), 1);
@@ -210,6 +218,7 @@ mod key_management_tests {
     fn test_cleanup_no_expired_keys() {
         let mut manager = create_test_manager();
         let user_id = "test_user_no_cleanup";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let _key1 = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
         let _key2 = manager.generate_and_store_key(user_id, "Dilithium-3").unwrap();
@@ -228,6 +237,8 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let user1 = "user1";
         let user2 = "user2";
+        manager.unlock(user1, b"test-unlock-secret");
+        manager.unlock(user2, b"test-unlock-secret");
 
         let key1 = manager.generate_and_store_key(user1, "Kyber-768").unwrap();
         let key2 = manager.generate_and_store_key(user2, "Dilithium-3").unwrap();
@@ -235,7 +246,7 @@ mod key_management_tests {
 
         manager.revoke_key(&key2).unwrap();
 
-        manager.rotate_key(&key1).unwrap();
+        manager.rotate_key(&key1, None).unwrap();
 
         let stats = manager.get_key_statistics();
         
@@ -266,6 +277,7 @@ mod key_management_tests {
     fn test_key_metadata_timestamps() {
         let mut manager = create_test_manager();
         let user_id = "test_user_timestamps";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let before_generation = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -292,7 +304,11 @@ mod key_management_tests {
         use std::sync::{Arc, Mutex};
         use std::thread;
 
-        let manager = Arc::new(Mutex::new(create_test_manager()));
+        let mut manager = create_test_manager();
+        for i in 0..5 {
+            manager.unlock(&format!("user_{i}"), b"test-unlock-secret");
+        }
+        let manager = Arc::new(Mutex::new(manager));
         let mut handles = vec![];
 
         for i in 0..5 {
@@ -322,6 +338,7 @@ mod key_management_tests {
     fn test_key_id_uniqueness() {
         let mut manager = create_test_manager();
         let user_id = "test_user_uniqueness";
+        manager.unlock(user_id, b"test-unlock-secret");
         let mut key_ids = std::collections::HashSet::new();
 
         for i in 0..10 {
@@ -340,6 +357,7 @@ mod key_management_tests {
     fn test_hsm_integration_placeholder() {
         let mut manager = create_test_manager();
         let user_id = "test_user_hsm";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
         
@@ -354,6 +372,7 @@ mod key_management_tests {
         let mut manager = create_test_manager();
         let user_id = "test_user_lifecycle";
         let algorithm = "Dilithium-3";
+        manager.unlock(user_id, b"test-unlock-secret");
 
         let original_key_id = manager.generate_and_store_key(user_id, algorithm).unwrap();
         assert_eq!(manager.get_key_count(), 1);
@@ -362,7 +381,7 @@ mod key_management_tests {
         assert_eq!(active_keys.len(), 1);
         assert_eq!(active_keys[0].status, KeyStatus::Active);
 
-        let new_key_id = manager.rotate_key(&original_key_id).unwrap();
+        let new_key_id = manager.rotate_key(&original_key_id, None).unwrap();
         assert_eq!(manager.get_key_count(), 2);
         
         let active_keys = manager.get_active_keys_for_user(user_id);
@@ -382,4 +401,360 @@ mod key_management_tests {
         assert_eq!(cleaned_count, 1);
         assert_eq!(manager.get_key_count(), 1); // Only revoked key remains
     }
+
+    #[test]
+    fn test_audit_log_records_full_key_lifecycle() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_audit";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let original_key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+        let new_key_id = manager.rotate_key(&original_key_id, None).unwrap();
+        manager.revoke_key(&new_key_id).unwrap();
+
+        if let Some((_, metadata)) = manager.keys.get_mut(&original_key_id) {
+            metadata.status = KeyStatus::Expired;
+        }
+        manager.cleanup_expired_keys().unwrap();
+
+        let entries = manager.audit_log();
+        let operations: Vec<KeyAuditOperation> = entries.iter().map(|e| e.operation).collect();
+        assert_eq!(
+            operations,
+            vec![
+                KeyAuditOperation::Generate,
+                KeyAuditOperation::Rotate,
+                KeyAuditOperation::Generate,
+                KeyAuditOperation::Revoke,
+                KeyAuditOperation::Cleanup,
+            ]
+        );
+        assert!(manager.verify_chain().is_ok());
+    }
+
+    #[test]
+    fn test_use_key_rejects_disallowed_purpose() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_purpose";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let key_id = manager
+            .generate_and_store_key_with_parameters(
+                user_id,
+                "Dilithium-3",
+                KeyParameters::new(vec![KeyPurpose::Sign]),
+            )
+            .unwrap();
+
+        assert!(manager.use_key(&key_id, KeyPurpose::Sign, None).is_ok());
+        let err = manager.use_key(&key_id, KeyPurpose::Verify, None).unwrap_err();
+        assert!(matches!(err, PQCError::UsagePolicyViolation(_)));
+    }
+
+    #[test]
+    fn test_use_key_exhausts_max_uses_and_expires_key() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_max_uses";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let key_id = manager
+            .generate_and_store_key_with_parameters(
+                user_id,
+                "Dilithium-3",
+                KeyParameters::new(vec![KeyPurpose::Sign]).with_max_uses(1),
+            )
+            .unwrap();
+
+        assert!(manager.use_key(&key_id, KeyPurpose::Sign, None).is_ok());
+
+        let (_, metadata) = manager.get_key_by_id(&key_id, None).unwrap();
+        assert_eq!(metadata.status, KeyStatus::Expired);
+
+        let err = manager.use_key(&key_id, KeyPurpose::Sign, None).unwrap_err();
+        assert!(matches!(err, PQCError::InvalidKeyState(_)));
+    }
+
+    #[test]
+    fn test_use_key_enforces_validity_window() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_validity_window";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let key_id = manager
+            .generate_and_store_key_with_parameters(
+                user_id,
+                "Kyber-768",
+                KeyParameters::new(vec![KeyPurpose::Decrypt]).with_validity_window(None, Some(0)),
+            )
+            .unwrap();
+
+        let err = manager.use_key(&key_id, KeyPurpose::Decrypt, None).unwrap_err();
+        assert!(matches!(err, PQCError::UsagePolicyViolation(_)));
+
+        let (_, metadata) = manager.get_key_by_id(&key_id, None).unwrap();
+        assert_eq!(metadata.status, KeyStatus::Expired);
+    }
+
+    #[test]
+    fn test_use_key_requires_recent_auth() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_requires_auth";
+        manager.unlock(user_id, b"test-unlock-secret");
+        manager.set_pin(user_id, b"1234", None);
+
+        let key_id = manager
+            .generate_and_store_key_with_parameters(
+                user_id,
+                "Dilithium-3",
+                KeyParameters::new(vec![KeyPurpose::Sign]).with_required_auth(3600),
+            )
+            .unwrap();
+
+        let err = manager.use_key(&key_id, KeyPurpose::Sign, None).unwrap_err();
+        assert!(matches!(err, PQCError::AuthRequired(_)));
+
+        let token = manager.authenticate(user_id, b"1234").unwrap();
+        assert!(manager.use_key(&key_id, KeyPurpose::Sign, Some(&token)).is_ok());
+    }
+
+    #[test]
+    fn test_use_key_rejects_a_token_issued_to_a_different_user() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_requires_auth_token_scope";
+        let other_user_id = "test_other_user_requires_auth_token_scope";
+        manager.unlock(user_id, b"test-unlock-secret");
+        manager.set_pin(user_id, b"1234", None);
+        manager.set_pin(other_user_id, b"5678", None);
+
+        let key_id = manager
+            .generate_and_store_key_with_parameters(
+                user_id,
+                "Dilithium-3",
+                KeyParameters::new(vec![KeyPurpose::Sign]).with_required_auth(3600),
+            )
+            .unwrap();
+
+        let other_token = manager.authenticate(other_user_id, b"5678").unwrap();
+        let err = manager
+            .use_key(&key_id, KeyPurpose::Sign, Some(&other_token))
+            .unwrap_err();
+        assert!(matches!(err, PQCError::AuthRequired(_)));
+    }
+
+    #[test]
+    fn test_generate_and_store_key_falls_back_to_local_when_rkp_unreachable() {
+        let mut manager = create_test_manager().with_rkp("http://127.0.0.1:1", 2, 4);
+        let user_id = "test_user_rkp_fallback";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+
+        let (_, metadata) = manager.get_key_by_id(&key_id, None).unwrap();
+        assert!(metadata.attestation.is_none());
+
+        let stats = manager.rkp_pool_stats().unwrap();
+        assert_eq!(stats.served_from_local_fallback, 1);
+        assert_eq!(stats.pool_size, 0);
+    }
+
+    #[test]
+    fn test_attest_key_returns_a_chain_that_verifies() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_attest";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+
+        let chain = manager.attest_key(&key_id).unwrap();
+        assert_eq!(chain.certs.len(), 2); // key_management layer + this key's layer
+        assert!(crate::bcc::verify_bcc_chain(&chain).is_ok());
+    }
+
+    #[test]
+    fn test_attest_key_fails_for_unknown_key() {
+        let manager = create_test_manager();
+        let err = manager.attest_key("does-not-exist").unwrap_err();
+        assert!(matches!(err, PQCError::KeyNotFound(_)));
+    }
+
+    #[test]
+    fn test_ephemeral_key_is_flagged_and_excluded_from_the_durable_tier() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_ephemeral";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let key_id = manager
+            .generate_and_store_key_ephemeral(user_id, "Kyber-768")
+            .unwrap();
+
+        assert!(manager.get_key_by_id(&key_id, None).is_err());
+        assert_eq!(manager.get_user_keys(user_id).len(), 0);
+
+        let active = manager.get_active_keys_for_user(user_id);
+        assert_eq!(active.len(), 1);
+        assert!(active[0].ephemeral);
+        assert_eq!(active[0].key_id, key_id);
+    }
+
+    #[test]
+    fn test_purge_perboot_drops_ephemeral_keys_and_rotates_the_boot_nonce() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_purge_perboot";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        manager
+            .generate_and_store_key_ephemeral(user_id, "Kyber-768")
+            .unwrap();
+        manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+
+        let nonce_before = manager.boot_nonce();
+        manager.purge_perboot();
+
+        assert_ne!(manager.boot_nonce(), nonce_before);
+        assert_eq!(manager.get_active_keys_for_user(user_id).len(), 1);
+        assert!(!manager.get_active_keys_for_user(user_id)[0].ephemeral);
+    }
+
+    #[test]
+    fn test_get_key_statistics_reports_both_tiers() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_stats_tiers";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+        manager
+            .generate_and_store_key_ephemeral(user_id, "Kyber-768")
+            .unwrap();
+
+        let stats = manager.get_key_statistics();
+        assert_eq!(stats.total_keys, 2);
+        assert_eq!(stats.ephemeral_keys, 1);
+        assert_eq!(stats.active_keys, 2);
+        assert_eq!(stats.unique_users, 1);
+    }
+
+    #[test]
+    fn test_unenrolled_user_is_ungated() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_no_pin";
+        manager.unlock(user_id, b"test-unlock-secret");
+
+        let key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+
+        assert!(manager.get_key_by_id(&key_id, None).is_ok());
+        assert!(manager
+            .get_active_key(user_id, "ML-KEM-768", None, None)
+            .is_ok());
+        assert!(manager.rotate_key(&key_id, None).is_ok());
+    }
+
+    #[test]
+    fn test_authenticate_then_access_guarded_methods() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_pin_gated";
+        manager.unlock(user_id, b"test-unlock-secret");
+        manager.set_pin(user_id, b"1234", None);
+
+        let key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+
+        let err = manager.get_key_by_id(&key_id, None).unwrap_err();
+        assert!(matches!(err, PQCError::AuthRequired(_)));
+
+        let token = manager.authenticate(user_id, b"1234").unwrap();
+        assert!(manager.get_key_by_id(&key_id, Some(&token)).is_ok());
+        assert!(manager
+            .get_active_key(user_id, "ML-KEM-768", None, Some(&token))
+            .is_ok());
+        assert!(manager.rotate_key(&key_id, Some(&token)).is_ok());
+    }
+
+    #[test]
+    fn test_authenticate_rejects_wrong_pin_and_reports_attempts_remaining() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_wrong_pin";
+        manager.set_pin(user_id, b"1234", None);
+
+        let err = manager.authenticate(user_id, b"0000").unwrap_err();
+        assert!(matches!(
+            err,
+            PQCError::IncorrectPin { attempts_remaining: 2, .. }
+        ));
+        assert_eq!(manager.remaining_attempts(user_id), Some(2));
+    }
+
+    #[test]
+    fn test_authenticate_locks_account_after_max_wrong_attempts() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_lockout";
+        manager.set_pin(user_id, b"1234", None);
+
+        for _ in 0..3 {
+            let _ = manager.authenticate(user_id, b"0000");
+        }
+
+        let err = manager.authenticate(user_id, b"1234").unwrap_err();
+        assert!(matches!(err, PQCError::AccountLocked(_)));
+    }
+
+    #[test]
+    fn test_reset_retry_counter_clears_lockout() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_reset";
+        manager.unlock(user_id, b"test-unlock-secret");
+        manager.set_pin(user_id, b"1234", Some(b"puk-secret"));
+
+        for _ in 0..3 {
+            let _ = manager.authenticate(user_id, b"0000");
+        }
+        assert!(matches!(
+            manager.authenticate(user_id, b"1234").unwrap_err(),
+            PQCError::AccountLocked(_)
+        ));
+
+        assert!(manager
+            .reset_retry_counter(user_id, b"5678", Some(b"wrong-puk"))
+            .is_err());
+
+        manager
+            .reset_retry_counter(user_id, b"5678", Some(b"puk-secret"))
+            .unwrap();
+
+        let token = manager.authenticate(user_id, b"5678").unwrap();
+        let key_id = manager.generate_and_store_key(user_id, "Kyber-768").unwrap();
+        assert!(manager.get_key_by_id(&key_id, Some(&token)).is_ok());
+    }
+
+    #[test]
+    fn test_rewrap_keys_for_user_preserves_keypairs_under_new_secret() {
+        let mut manager = create_test_manager();
+        let user_id = "test_user_rewrap";
+        manager.unlock(user_id, b"old-unlock-secret");
+
+        let key_id = manager.generate_and_store_key(user_id, "ML-DSA-65").unwrap();
+        assert!(manager.sign_with_key(&key_id, b"message").is_ok());
+
+        let rewrapped = manager.rewrap_keys_for_user(user_id, b"new-unlock-secret").unwrap();
+        assert_eq!(rewrapped, 1);
+
+        // The old secret no longer derives a usable super key for this
+        // user, since `rewrap_keys_for_user` rotated the per-user salt
+        // along with the wrapping key.
+        manager.lock(user_id);
+        manager.unlock(user_id, b"old-unlock-secret");
+        assert!(manager.sign_with_key(&key_id, b"message").is_err());
+
+        // The new secret unwraps the exact same keypair as before, so
+        // signing with it succeeds again without regenerating anything.
+        manager.unlock(user_id, b"new-unlock-secret");
+        assert!(manager.sign_with_key(&key_id, b"message").is_ok());
+    }
+
+    #[test]
+    fn test_rewrap_keys_for_user_is_a_noop_for_a_user_with_no_keys() {
+        let mut manager = create_test_manager();
+        let rewrapped = manager
+            .rewrap_keys_for_user("nobody", b"new-unlock-secret")
+            .unwrap();
+        assert_eq!(rewrapped, 0);
+    }
 }
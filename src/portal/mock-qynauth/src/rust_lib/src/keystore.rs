@@ -0,0 +1,274 @@
+//! Encrypted on-disk persistence for [`PQCKeyPair`]s, modeled on Alfis's
+//! `keystore.rs`: the public key is stored in the clear and the private key
+//! is sealed under a key derived from a passphrase via a memory-hard KDF
+//! (Argon2id), so a stolen keystore file can't be brute-forced with
+//! commodity hardware the way a fast-hash-derived key could.
+//!
+//! Unlike [`crate::super_key`] (which wraps keys under a super key already
+//! derived once per unlock session via HKDF), `Keystore::save`/`load` derive
+//! straight from a caller-supplied passphrase on every call, since a
+//! keystore file has no persistent unlock session to amortize the
+//! derivation cost across — that cost is exactly what's supposed to slow
+//! down an offline attacker.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::Argon2;
+use rand::RngCore;
+use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use zeroize::Zeroize;
+
+use crate::{PQCAlgorithm, PQCError, PQCKeyPair, PQCResult};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Keystore envelope format version. Bumped whenever a field is added,
+/// removed, or reordered; [`Keystore::load`] rejects anything else.
+const KEYSTORE_VERSION: u32 = 1;
+
+/// Versioned on-disk layout for a sealed [`PQCKeyPair`]. `public_key` and
+/// `algorithm` are stored in the clear since neither is sensitive and both
+/// are needed to pick the right verifier/KDF parameters without first
+/// unsealing anything.
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreEnvelope {
+    version: u32,
+    algorithm: String,
+    created_at: u64,
+    public_key: Vec<u8>,
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+}
+
+/// Derives a 256-bit AES key from `passphrase` and `salt` via Argon2id's
+/// default (memory-hard) parameters.
+fn derive_seal_key(passphrase: &[u8], salt: &[u8; SALT_LEN]) -> PQCResult<Secret<[u8; 32]>> {
+    let mut key_bytes = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase, salt, &mut key_bytes)
+        .map_err(|e| {
+            PQCError::SecurityValidationFailed(format!("Keystore key derivation failed: {e}"))
+        })?;
+    Ok(Secret::new(key_bytes))
+}
+
+/// Re-runs a self-signed (or self-encapsulated) round trip against a
+/// just-loaded keypair, so a corrupted or tampered store fails loudly here
+/// rather than surfacing as a confusing signing/verification failure later.
+fn validate_loaded_keypair(keypair: &PQCKeyPair) -> PQCResult<()> {
+    let algorithm = PQCAlgorithm::from_name(&keypair.algorithm)?;
+    let valid = if algorithm.is_signature() {
+        const VALIDATION_MESSAGE: &[u8] = b"qynauth keystore load validation";
+        let level = algorithm.try_into()?;
+        let signature = crate::mldsa_sign_for_level(
+            level,
+            keypair.private_key.expose_secret(),
+            VALIDATION_MESSAGE,
+        )?;
+        crate::mldsa_verify_for_level(
+            level,
+            &keypair.public_key,
+            VALIDATION_MESSAGE,
+            signature.signature.expose_secret(),
+        )?
+    } else {
+        let encapsulated =
+            crate::mlkem_encapsulate_for_algorithm(algorithm, &keypair.public_key, b"")?;
+        let decapsulated = crate::mlkem_decapsulate_for_algorithm(
+            algorithm,
+            keypair.private_key.expose_secret(),
+            &encapsulated.ciphertext,
+        )?;
+        encapsulated.shared_secret.expose_secret() == decapsulated.expose_secret()
+    };
+
+    if !valid {
+        return Err(PQCError::SecurityValidationFailed(
+            "Loaded keypair failed its self-validation round trip".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Encrypted on-disk persistence for [`PQCKeyPair`]s. Stateless: every
+/// method takes the file path and passphrase it needs rather than holding
+/// either across calls.
+pub struct Keystore;
+
+impl Keystore {
+    /// Seals `keypair` under a key derived from `passphrase` and writes it
+    /// to `path` as a versioned JSON envelope.
+    pub fn save(path: &Path, keypair: &PQCKeyPair, passphrase: &[u8]) -> PQCResult<()> {
+        let mut salt = [0u8; SALT_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut salt);
+
+        let seal_key = derive_seal_key(passphrase, &salt)?;
+        let cipher = Aes256Gcm::new_from_slice(seal_key.expose_secret())
+            .expect("derived keystore seal key is exactly 32 bytes");
+
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+
+        let ciphertext = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce_bytes),
+                keypair.private_key.expose_secret().as_slice(),
+            )
+            .map_err(|_| {
+                PQCError::SecurityValidationFailed("Keystore seal failed".to_string())
+            })?;
+
+        let envelope = KeystoreEnvelope {
+            version: KEYSTORE_VERSION,
+            algorithm: keypair.algorithm.clone(),
+            created_at: keypair.created_at,
+            public_key: keypair.public_key.clone(),
+            salt,
+            nonce: nonce_bytes,
+            ciphertext,
+        };
+
+        let json = serde_json::to_vec_pretty(&envelope).map_err(|e| {
+            PQCError::SecurityValidationFailed(format!("Keystore serialization failed: {e}"))
+        })?;
+
+        std::fs::write(path, json).map_err(|e| {
+            PQCError::SecurityValidationFailed(format!("Keystore write failed: {e}"))
+        })?;
+
+        Ok(())
+    }
+
+    /// Reads `path`, unseals the private key under a key derived from
+    /// `passphrase`, and returns the reconstructed keypair after confirming
+    /// it passes [`validate_loaded_keypair`].
+    pub fn load(path: &Path, passphrase: &[u8]) -> PQCResult<PQCKeyPair> {
+        let json = std::fs::read(path).map_err(|e| {
+            PQCError::KeyNotFound(format!("Keystore read failed: {e}"))
+        })?;
+
+        let envelope: KeystoreEnvelope = serde_json::from_slice(&json).map_err(|e| {
+            PQCError::SecurityValidationFailed(format!("Keystore parse failed: {e}"))
+        })?;
+
+        if envelope.version != KEYSTORE_VERSION {
+            return Err(PQCError::UnsupportedAlgorithm(format!(
+                "Unsupported keystore envelope version: {}",
+                envelope.version
+            )));
+        }
+
+        let algorithm = PQCAlgorithm::from_name(&envelope.algorithm)?;
+
+        let seal_key = derive_seal_key(passphrase, &envelope.salt)?;
+        let cipher = Aes256Gcm::new_from_slice(seal_key.expose_secret())
+            .expect("derived keystore seal key is exactly 32 bytes");
+
+        let mut plaintext = cipher
+            .decrypt(Nonce::from_slice(&envelope.nonce), envelope.ciphertext.as_ref())
+            .map_err(|_| {
+                PQCError::SecurityValidationFailed(
+                    "Keystore unseal failed - wrong passphrase or corrupted store".to_string(),
+                )
+            })?;
+
+        let keypair = PQCKeyPair {
+            public_key: envelope.public_key.clone(),
+            private_key: Secret::new(plaintext.clone()),
+            algorithm: envelope.algorithm.clone(),
+            key_size: envelope.public_key.len() + plaintext.len(),
+            security_level: algorithm.security_level(),
+            created_at: envelope.created_at,
+        };
+        plaintext.zeroize();
+
+        validate_loaded_keypair(&keypair)?;
+
+        Ok(keypair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_mldsa_keypair_for_level;
+    use crate::MLDSALevel;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("qynauth-keystore-test-{name}-{}.json", std::process::id()));
+        path
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let path = temp_path("round-trip");
+
+        Keystore::save(&path, &keypair, b"correct horse battery staple").unwrap();
+        let loaded = Keystore::load(&path, b"correct horse battery staple").unwrap();
+
+        assert_eq!(loaded.public_key, keypair.public_key);
+        assert_eq!(
+            loaded.private_key.expose_secret(),
+            keypair.private_key.expose_secret()
+        );
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_load_fails_with_wrong_passphrase() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let path = temp_path("wrong-passphrase");
+
+        Keystore::save(&path, &keypair, b"correct passphrase").unwrap();
+        let result = Keystore::load(&path, b"wrong passphrase");
+        assert!(result.is_err());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_load_fails_on_tampered_ciphertext() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let path = temp_path("tampered");
+
+        Keystore::save(&path, &keypair, b"passphrase").unwrap();
+
+        let json = std::fs::read(&path).unwrap();
+        let mut envelope: KeystoreEnvelope = serde_json::from_slice(&json).unwrap();
+        if let Some(last) = envelope.ciphertext.last_mut() {
+            *last ^= 0xFF;
+        }
+        std::fs::write(&path, serde_json::to_vec_pretty(&envelope).unwrap()).unwrap();
+
+        let result = Keystore::load(&path, b"passphrase");
+        assert!(result.is_err());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_load_fails_on_unknown_version() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let path = temp_path("unknown-version");
+
+        Keystore::save(&path, &keypair, b"passphrase").unwrap();
+
+        let json = std::fs::read(&path).unwrap();
+        let mut envelope: KeystoreEnvelope = serde_json::from_slice(&json).unwrap();
+        envelope.version = KEYSTORE_VERSION + 1;
+        std::fs::write(&path, serde_json::to_vec_pretty(&envelope).unwrap()).unwrap();
+
+        let result = Keystore::load(&path, b"passphrase");
+        assert!(result.is_err());
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{PQCError, PQCResult, PQCKeyPair, PQCEncryptionResult, KyberMetrics};
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes256;
+use pqc_kyber::reference::{crypto_kem_enc_derand, crypto_kem_keypair_derand};
 use pqcrypto_mlkem::mlkem768;
-use pqcrypto_traits::kem::{PublicKey, SecretKey, Ciphertext, SharedSecret};
+use pqcrypto_traits::kem::{SecretKey, Ciphertext, SharedSecret};
+use rand::{CryptoRng, RngCore};
 use secrecy::{Secret, ExposeSecret};
 use log::{info, warn, error, debug};
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
-use std::sync::atomic::{AtomicU64, Ordering};
-
-static KYBER_OPERATIONS_COUNTER: AtomicU64 = AtomicU64::new(0);
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
 
 #[derive(Debug, Clone)]
 pub struct KyberConfig {
@@ -31,11 +34,18 @@ impl Default for KyberConfig {
 pub struct KyberEngine {
     config: KyberConfig,
     metrics: KyberMetrics,
+    /// Token-bucket rate limiter state for `config.max_operations_per_second`:
+    /// `tokens` refills over time in [`Self::try_consume_token`] rather than
+    /// being tracked by a process-global counter, so the limit is a genuine
+    /// sustained-throughput cap isolated to this engine instance.
+    tokens: f64,
+    last_refill: Instant,
 }
 
 impl KyberEngine {
     pub fn new(config: KyberConfig) -> Self {
         info!("Initializing Kyber-768 engine with security configuration");
+        let tokens = config.max_operations_per_second.unwrap_or(0) as f64;
         Self {
             config,
             metrics: KyberMetrics {
@@ -48,30 +58,70 @@ impl KyberEngine {
                 shared_secret_size: mlkem768::shared_secret_bytes(),
                 operations_count: 0,
             },
+            tokens,
+            last_refill: Instant::now(),
         }
     }
 
+    /// Refills the token bucket by `elapsed * max_operations_per_second`
+    /// (capped at the bucket size, i.e. one second's worth of operations),
+    /// then consumes one token if available. A no-op when
+    /// `max_operations_per_second` is `None`.
+    fn try_consume_token(&mut self) -> PQCResult<()> {
+        let max_ops = match self.config.max_operations_per_second {
+            Some(max_ops) => max_ops as f64,
+            None => return Ok(()),
+        };
+
+        let elapsed = self.last_refill.elapsed().as_secs_f64();
+        self.last_refill = Instant::now();
+        self.tokens = (self.tokens + elapsed * max_ops).min(max_ops);
+
+        if self.tokens < 1.0 {
+            warn!("Rate limit exceeded for Kyber operations");
+            return Err(PQCError::SecurityValidationFailed(
+                "Rate limit exceeded".to_string(),
+            ));
+        }
+
+        self.tokens -= 1.0;
+        Ok(())
+    }
+
     pub fn generate_keypair(&mut self) -> PQCResult<PQCKeyPair> {
+        let mut rng = rand::thread_rng();
+        self.generate_keypair_with_rng(&mut rng)
+    }
+
+    /// Same as [`Self::generate_keypair`], but draws the 64-byte FIPS 203
+    /// seed `d || z` from the caller-supplied `rng` instead of
+    /// `thread_rng()` -- e.g. a `ChaCha20Rng` seeded from a recovery
+    /// phrase for deterministic re-derivation, a KAT-driven DRBG (see
+    /// [`Self::run_kat`]), or a hardware/audited entropy source. Goes
+    /// through `pqc_kyber`'s `_derand` API for the same reason
+    /// [`crate::generate_mlkem_keypair_deterministic`] does:
+    /// `mlkem768::keypair()` has no RNG-injection point of its own.
+    pub fn generate_keypair_with_rng<R: RngCore + CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> PQCResult<PQCKeyPair> {
         let start_time = Instant::now();
-        
+
         debug!("Starting Kyber-768 key generation");
-        
-        if let Some(max_ops) = self.config.max_operations_per_second {
-            let current_ops = KYBER_OPERATIONS_COUNTER.load(Ordering::Relaxed);
-            if current_ops > max_ops {
-                warn!("Rate limit exceeded for Kyber operations");
-                return Err(PQCError::SecurityValidationFailed(
-                    "Rate limit exceeded".to_string()
-                ));
-            }
-        }
 
-        let (pk, sk) = mlkem768::keypair();
-        
+        self.try_consume_token()?;
+
+        let mut seed = [0u8; 64];
+        rng.fill_bytes(&mut seed);
+
+        let mut public_key = [0u8; pqc_kyber::KYBER_PUBLICKEYBYTES];
+        let mut secret_key = [0u8; pqc_kyber::KYBER_SECRETKEYBYTES];
+        crypto_kem_keypair_derand(&mut public_key, &mut secret_key, &seed)
+            .map_err(|e| PQCError::KeyGenerationFailed(format!("ML-KEM-768 keygen failed: {e:?}")))?;
+
         let elapsed = start_time.elapsed();
         self.metrics.key_generation_time_ns = elapsed.as_nanos() as u64;
         self.metrics.operations_count += 1;
-        KYBER_OPERATIONS_COUNTER.fetch_add(1, Ordering::Relaxed);
 
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -79,10 +129,10 @@ impl KyberEngine {
             .as_secs();
 
         let keypair = PQCKeyPair {
-            public_key: pk.as_bytes().to_vec(),
-            private_key: Secret::new(sk.as_bytes().to_vec()),
+            key_size: public_key.len() + secret_key.len(),
+            public_key: public_key.to_vec(),
+            private_key: Secret::new(secret_key.to_vec()),
             algorithm: "ML-KEM-768".to_string(),
-            key_size: mlkem768::public_key_bytes() + mlkem768::secret_key_bytes(),
             security_level: 3,
             created_at: current_time,
         };
@@ -92,27 +142,43 @@ impl KyberEngine {
     }
 
     pub fn encapsulate(&mut self, public_key: &[u8]) -> PQCResult<PQCEncryptionResult> {
+        let mut rng = rand::thread_rng();
+        self.encapsulate_with_rng(public_key, &mut rng)
+    }
+
+    /// Same as [`Self::encapsulate`], but draws the 32-byte FIPS 203
+    /// encapsulation message `m` from the caller-supplied `rng` instead
+    /// of `thread_rng()`, for the same reasons as
+    /// [`Self::generate_keypair_with_rng`].
+    pub fn encapsulate_with_rng<R: RngCore + CryptoRng>(
+        &mut self,
+        public_key: &[u8],
+        rng: &mut R,
+    ) -> PQCResult<PQCEncryptionResult> {
         let start_time = Instant::now();
-        
+
         debug!("Starting Kyber-768 encapsulation");
 
         if public_key.len() != mlkem768::public_key_bytes() {
-            error!("Invalid public key size: expected {}, got {}", 
+            error!("Invalid public key size: expected {}, got {}",
                    mlkem768::public_key_bytes(), public_key.len());
             return Err(PQCError::InvalidPublicKey(
                 format!("Expected {} bytes, got {}", mlkem768::public_key_bytes(), public_key.len())
             ));
         }
 
-        let pk = mlkem768::PublicKey::from_bytes(public_key)
-            .map_err(|_| PQCError::InvalidPublicKey("Failed to parse public key".to_string()))?;
+        let mut message = [0u8; 32];
+        rng.fill_bytes(&mut message);
+
+        let mut ciphertext = [0u8; pqc_kyber::KYBER_CIPHERTEXTBYTES];
+        let mut shared_secret = [0u8; pqc_kyber::KYBER_SSBYTES];
+        crypto_kem_enc_derand(&mut ciphertext, &mut shared_secret, public_key, &message).map_err(
+            |e| PQCError::EncapsulationFailed(format!("ML-KEM-768 encapsulation failed: {e:?}")),
+        )?;
 
-        let (ss, ct) = mlkem768::encapsulate(&pk);
-        
         let elapsed = start_time.elapsed();
         self.metrics.encapsulation_time_ns = elapsed.as_nanos() as u64;
         self.metrics.operations_count += 1;
-        KYBER_OPERATIONS_COUNTER.fetch_add(1, Ordering::Relaxed);
 
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -120,11 +186,10 @@ impl KyberEngine {
             .as_secs();
 
         let result = PQCEncryptionResult {
-            ciphertext: ct.as_bytes().to_vec(),
-            shared_secret: Secret::new(ss.as_bytes().to_vec()),
+            ciphertext_size: ciphertext.len(),
+            ciphertext: ciphertext.to_vec(),
+            shared_secret: Secret::new(shared_secret.to_vec()),
             algorithm: "ML-KEM-768".to_string(),
-            ciphertext_size: mlkem768::ciphertext_bytes(),
-            shared_secret_size: mlkem768::shared_secret_bytes(),
             created_at: current_time,
         };
 
@@ -164,15 +229,26 @@ impl KyberEngine {
         let elapsed = start_time.elapsed();
         self.metrics.decapsulation_time_ns = elapsed.as_nanos() as u64;
         self.metrics.operations_count += 1;
-        KYBER_OPERATIONS_COUNTER.fetch_add(1, Ordering::Relaxed);
 
         info!("Kyber-768 decapsulation completed successfully in {}μs", elapsed.as_micros());
         Ok(Secret::new(ss.as_bytes().to_vec()))
     }
 
     pub fn batch_generate_keypairs(&mut self, count: usize) -> PQCResult<Vec<PQCKeyPair>> {
+        let mut rng = rand::thread_rng();
+        self.batch_generate_keypairs_with_rng(count, &mut rng)
+    }
+
+    /// Same as [`Self::batch_generate_keypairs`], but draws every
+    /// keypair's seed from the caller-supplied `rng` via
+    /// [`Self::generate_keypair_with_rng`] instead of `thread_rng()`.
+    pub fn batch_generate_keypairs_with_rng<R: RngCore + CryptoRng>(
+        &mut self,
+        count: usize,
+        rng: &mut R,
+    ) -> PQCResult<Vec<PQCKeyPair>> {
         info!("Starting batch generation of {} Kyber-768 keypairs", count);
-        
+
         if count > 1000 {
             warn!("Large batch size requested: {}", count);
             return Err(PQCError::SecurityValidationFailed(
@@ -183,7 +259,7 @@ impl KyberEngine {
         let mut keypairs = Vec::with_capacity(count);
         for i in 0..count {
             debug!("Generating keypair {}/{}", i + 1, count);
-            keypairs.push(self.generate_keypair()?);
+            keypairs.push(self.generate_keypair_with_rng(rng)?);
         }
 
         info!("Successfully generated {} Kyber-768 keypairs", count);
@@ -249,8 +325,223 @@ impl KyberEngine {
             mlkem768::shared_secret_bytes(),
         )
     }
+
+    /// Validates this engine's ML-KEM-768 implementation against NIST
+    /// known-answer test vectors. `mlkem768::keypair()`/`encapsulate()`
+    /// draw from the OS RNG with no seed-injection point, so each
+    /// vector's 48-byte seed instead drives an `Aes256CtrDrbg` (the same
+    /// construction NIST's reference `randombytes_init`/`randombytes`
+    /// use to produce the `.rsp` files) to generate the 64-byte `d || z`
+    /// keygen seed and 32-byte encapsulation message, which are fed
+    /// through `pqc_kyber`'s `_derand` API -- the same deterministic
+    /// path `generate_mlkem_keypair_deterministic`/
+    /// `mlkem_encapsulate_deterministic` already use. Every produced
+    /// artifact is compared against the vector's expected value in
+    /// constant time.
+    pub fn run_kat(&self, vectors: &[KatVector]) -> PQCResult<KatReport> {
+        info!("Running ML-KEM-768 KAT verification over {} vectors", vectors.len());
+
+        let mut results = Vec::with_capacity(vectors.len());
+        for (index, vector) in vectors.iter().enumerate() {
+            let mut drbg = Aes256CtrDrbg::new(&vector.seed);
+            let mut keygen_seed = [0u8; 64];
+            drbg.fill_bytes(&mut keygen_seed);
+            let mut message = [0u8; 32];
+            drbg.fill_bytes(&mut message);
+
+            let mut public_key = [0u8; pqc_kyber::KYBER_PUBLICKEYBYTES];
+            let mut secret_key = [0u8; pqc_kyber::KYBER_SECRETKEYBYTES];
+            crypto_kem_keypair_derand(&mut public_key, &mut secret_key, &keygen_seed).map_err(|e| {
+                PQCError::KeyGenerationFailed(format!(
+                    "KAT vector {index}: deterministic ML-KEM-768 keygen failed: {e:?}"
+                ))
+            })?;
+
+            let mut ciphertext = [0u8; pqc_kyber::KYBER_CIPHERTEXTBYTES];
+            let mut shared_secret = [0u8; pqc_kyber::KYBER_SSBYTES];
+            crypto_kem_enc_derand(&mut ciphertext, &mut shared_secret, &public_key, &message).map_err(|e| {
+                PQCError::EncapsulationFailed(format!(
+                    "KAT vector {index}: deterministic ML-KEM-768 encapsulation failed: {e:?}"
+                ))
+            })?;
+
+            let public_key_matched = ct_eq_slices(&public_key, &vector.expected_public_key);
+            let secret_key_matched = ct_eq_slices(&secret_key, &vector.expected_secret_key);
+            let ciphertext_matched = ct_eq_slices(&ciphertext, &vector.expected_ciphertext);
+            let shared_secret_matched = ct_eq_slices(&shared_secret, &vector.expected_shared_secret);
+            let passed =
+                public_key_matched && secret_key_matched && ciphertext_matched && shared_secret_matched;
+
+            if !passed {
+                warn!("KAT vector {} failed ML-KEM-768 verification", index);
+            }
+
+            results.push(KatResult {
+                index,
+                passed,
+                public_key_matched,
+                secret_key_matched,
+                ciphertext_matched,
+                shared_secret_matched,
+            });
+        }
+
+        let passed = results.iter().filter(|r| r.passed).count();
+        let failed = results.len() - passed;
+        info!("ML-KEM-768 KAT verification complete: {} passed, {} failed", passed, failed);
+
+        Ok(KatReport { results, passed, failed })
+    }
+}
+
+/// Compares two byte slices in constant time, treating a length
+/// mismatch as an immediate (non-constant-time, but non-secret) failure
+/// rather than passing mismatched lengths into [`ConstantTimeEq`].
+fn ct_eq_slices(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && bool::from(a.ct_eq(b))
+}
+
+/// One NIST ML-KEM-768 known-answer test vector, as found in a
+/// `kat_MLKEM_768.rsp` file: the 48-byte seed that drives
+/// [`Aes256CtrDrbg`], plus the public key, secret key, ciphertext, and
+/// shared secret the reference implementation is expected to produce
+/// from it.
+#[derive(Debug, Clone)]
+pub struct KatVector {
+    pub seed: [u8; 48],
+    pub expected_public_key: Vec<u8>,
+    pub expected_secret_key: Vec<u8>,
+    pub expected_ciphertext: Vec<u8>,
+    pub expected_shared_secret: Vec<u8>,
+}
+
+/// The outcome of [`KyberEngine::run_kat`] for a single [`KatVector`]:
+/// `passed` is the conjunction of the four per-artifact flags, kept
+/// separate so a failure report can point at exactly which artifact
+/// diverged.
+#[derive(Debug, Clone, Copy)]
+pub struct KatResult {
+    pub index: usize,
+    pub passed: bool,
+    pub public_key_matched: bool,
+    pub secret_key_matched: bool,
+    pub ciphertext_matched: bool,
+    pub shared_secret_matched: bool,
+}
+
+/// The outcome of [`KyberEngine::run_kat`] over an entire vector set.
+#[derive(Debug, Clone)]
+pub struct KatReport {
+    pub results: Vec<KatResult>,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl KatReport {
+    pub fn all_passed(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// NIST's `AES256_CTR_DRBG` with no derivation function, exactly as
+/// used by the reference randomness source (`rng.c`/`randombytes`) that
+/// the published ML-KEM `.rsp` KAT files assume: a 48-byte seed splits
+/// into a 32-byte AES-256 key and a 16-byte counter block `V`,
+/// instantiated by one [`Aes256CtrDrbg::update`] over the seed; output
+/// is then drawn one AES block at a time, re-keying after every draw so
+/// two [`Aes256CtrDrbg::fill_bytes`] calls never reuse the same
+/// keystream.
+struct Aes256CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl Aes256CtrDrbg {
+    fn new(seed: &[u8; 48]) -> Self {
+        let mut drbg = Self { key: [0u8; 32], v: [0u8; 16] };
+        drbg.update(Some(seed));
+        drbg
+    }
+
+    fn increment_v(&mut self) {
+        for byte in self.v.iter_mut().rev() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                break;
+            }
+        }
+    }
+
+    fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let cipher = Aes256::new_from_slice(&self.key).expect("AES-256 key is always 32 bytes");
+        let mut generic_block = aes::Block::clone_from_slice(block);
+        cipher.encrypt_block(&mut generic_block);
+        block.copy_from_slice(&generic_block);
+    }
+
+    /// `CTR_DRBG_Update`: generates 48 bytes of keystream (three AES
+    /// blocks, incrementing `V` before each), XORs `provided_data` into
+    /// it when present, and splits the result back into a fresh key/`V`.
+    fn update(&mut self, provided_data: Option<&[u8; 48]>) {
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_mut(16) {
+            self.increment_v();
+            let mut block = self.v;
+            self.encrypt_block(&mut block);
+            chunk.copy_from_slice(&block);
+        }
+        if let Some(data) = provided_data {
+            for (t, d) in temp.iter_mut().zip(data.iter()) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+        temp.zeroize();
+    }
+
+    /// `CTR_DRBG_Generate`: fills `dest` one AES block at a time, then
+    /// runs a final [`Self::update`] with no provided data -- the
+    /// mandatory post-generation rekey.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut offset = 0;
+        while offset < dest.len() {
+            self.increment_v();
+            let mut block = self.v;
+            self.encrypt_block(&mut block);
+            let take = (dest.len() - offset).min(16);
+            dest[offset..offset + take].copy_from_slice(&block[..take]);
+            offset += take;
+        }
+        self.update(None);
+    }
 }
 
+impl RngCore for Aes256CtrDrbg {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        Aes256CtrDrbg::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Aes256CtrDrbg {}
+
 impl Drop for KyberEngine {
     fn drop(&mut self) {
         info!("Kyber-768 engine dropped, total operations: {}", self.metrics.operations_count);
@@ -317,4 +608,113 @@ mod tests {
         let result = engine.decapsulate(&invalid_private_key, &invalid_ciphertext);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_generate_keypair_with_rng_is_reproducible_from_the_same_seed() {
+        let mut engine = create_default_kyber_engine();
+
+        let mut rng_a = Aes256CtrDrbg::new(&[1u8; 48]);
+        let keypair_a = engine.generate_keypair_with_rng(&mut rng_a).unwrap();
+
+        let mut rng_b = Aes256CtrDrbg::new(&[1u8; 48]);
+        let keypair_b = engine.generate_keypair_with_rng(&mut rng_b).unwrap();
+
+        assert_eq!(keypair_a.public_key, keypair_b.public_key);
+        assert_eq!(
+            keypair_a.private_key.expose_secret(),
+            keypair_b.private_key.expose_secret()
+        );
+
+        let mut rng_c = Aes256CtrDrbg::new(&[2u8; 48]);
+        let keypair_c = engine.generate_keypair_with_rng(&mut rng_c).unwrap();
+        assert_ne!(keypair_a.public_key, keypair_c.public_key);
+    }
+
+    #[test]
+    fn test_encapsulate_with_rng_round_trips_through_decapsulate() {
+        let mut engine = create_default_kyber_engine();
+        let keypair = engine.generate_keypair().unwrap();
+
+        let mut rng = Aes256CtrDrbg::new(&[3u8; 48]);
+        let encryption_result = engine.encapsulate_with_rng(&keypair.public_key, &mut rng).unwrap();
+        let decrypted_secret = engine
+            .decapsulate(keypair.private_key.expose_secret(), &encryption_result.ciphertext)
+            .unwrap();
+
+        assert_eq!(
+            encryption_result.shared_secret.expose_secret(),
+            decrypted_secret.expose_secret()
+        );
+    }
+
+    #[test]
+    fn test_kat_drbg_is_deterministic_and_seed_dependent() {
+        let seed_a = [7u8; 48];
+        let seed_b = [9u8; 48];
+
+        let mut out_a1 = [0u8; 96];
+        Aes256CtrDrbg::new(&seed_a).fill_bytes(&mut out_a1);
+        let mut out_a2 = [0u8; 96];
+        Aes256CtrDrbg::new(&seed_a).fill_bytes(&mut out_a2);
+        assert_eq!(out_a1, out_a2);
+
+        let mut out_b = [0u8; 96];
+        Aes256CtrDrbg::new(&seed_b).fill_bytes(&mut out_b);
+        assert_ne!(out_a1, out_b);
+    }
+
+    #[test]
+    fn test_run_kat_passes_self_consistent_vector_and_fails_tampered_vector() {
+        let engine = create_default_kyber_engine();
+        let seed = [42u8; 48];
+
+        let mut drbg = Aes256CtrDrbg::new(&seed);
+        let mut keygen_seed = [0u8; 64];
+        drbg.fill_bytes(&mut keygen_seed);
+        let mut message = [0u8; 32];
+        drbg.fill_bytes(&mut message);
+
+        let mut public_key = [0u8; pqc_kyber::KYBER_PUBLICKEYBYTES];
+        let mut secret_key = [0u8; pqc_kyber::KYBER_SECRETKEYBYTES];
+        crypto_kem_keypair_derand(&mut public_key, &mut secret_key, &keygen_seed).unwrap();
+        let mut ciphertext = [0u8; pqc_kyber::KYBER_CIPHERTEXTBYTES];
+        let mut shared_secret = [0u8; pqc_kyber::KYBER_SSBYTES];
+        crypto_kem_enc_derand(&mut ciphertext, &mut shared_secret, &public_key, &message).unwrap();
+
+        let vector = KatVector {
+            seed,
+            expected_public_key: public_key.to_vec(),
+            expected_secret_key: secret_key.to_vec(),
+            expected_ciphertext: ciphertext.to_vec(),
+            expected_shared_secret: shared_secret.to_vec(),
+        };
+        let report = engine.run_kat(std::slice::from_ref(&vector)).unwrap();
+        assert!(report.all_passed());
+        assert_eq!(report.passed, 1);
+        assert_eq!(report.failed, 0);
+
+        let mut tampered = vector;
+        tampered.expected_shared_secret[0] ^= 0xFF;
+        let report = engine.run_kat(&[tampered]).unwrap();
+        assert!(!report.all_passed());
+        assert_eq!(report.failed, 1);
+        assert!(!report.results[0].shared_secret_matched);
+    }
+
+    #[test]
+    fn test_rate_limiter_rejects_burst_past_bucket_size_then_recovers() {
+        let mut engine = KyberEngine::new(KyberConfig {
+            max_operations_per_second: Some(2),
+            ..KyberConfig::default()
+        });
+
+        assert!(engine.generate_keypair().is_ok());
+        assert!(engine.generate_keypair().is_ok());
+
+        let result = engine.generate_keypair();
+        assert!(matches!(result, Err(PQCError::SecurityValidationFailed(_))));
+
+        std::thread::sleep(std::time::Duration::from_millis(600));
+        assert!(engine.generate_keypair().is_ok());
+    }
 }
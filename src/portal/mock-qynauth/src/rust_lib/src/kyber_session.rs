@@ -0,0 +1,474 @@
+//! A Noise-style long-lived session layer over [`kyber::KyberEngine`]:
+//! a one-shot ML-KEM-768 encapsulation only yields a single shared
+//! secret, so turning it into an ongoing authenticated channel that
+//! tolerates packet loss and reordering means ratcheting per-message
+//! keys from that root, tracking a replay window, and periodically
+//! re-encapsulating to bound how much traffic any one root secret ever
+//! protects. This module builds that framing once, rather than leaving
+//! every caller to reinvent it around a bare KEM.
+//!
+//! `KyberSession` is deliberately undirectional in its ratchet: both
+//! peers derive `k_n = HKDF(root, "rekey" || n)` from the *same* root
+//! secret and nonce space, so [`KyberSession::send`]/
+//! [`KyberSession::receive`] are symmetric -- there is no separate
+//! send/receive chain as in a full Noise handshake. A successful
+//! [`KyberSession::receive`] only proves the message was encrypted
+//! under a root secret this session also holds; it does not
+//! authenticate *which* trusted peer sent a non-rekey message (that
+//! would need a signature layer such as [`crate::hybrid::hybrid_sign`]
+//! on top). [`crate::hybrid::HybridKyberEngine`] defense-in-depth
+//! should be layered in, the same way, if a caller needs both
+//! PQC-hedging and long-lived sessions together.
+
+use crate::kyber::{create_default_kyber_engine, KyberEngine};
+use crate::{PQCError, PQCKeyPair, PQCResult};
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use hkdf::Hkdf;
+use secrecy::{ExposeSecret, Secret};
+use sha2::Sha256;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// Controls when [`KyberSession::send`] triggers an automatic rekey:
+/// after `rekey_after_messages` messages, after `rekey_after` has
+/// elapsed since the last rekey, or whichever comes first. Either
+/// bound can be disabled with `None`.
+#[derive(Debug, Clone)]
+pub struct KyberSessionConfig {
+    pub rekey_after_messages: Option<u64>,
+    pub rekey_after: Option<Duration>,
+}
+
+impl Default for KyberSessionConfig {
+    fn default() -> Self {
+        Self {
+            rekey_after_messages: Some(1000),
+            rekey_after: Some(Duration::from_secs(3600)),
+        }
+    }
+}
+
+/// A rekey announcement piggybacked on a [`SessionMessage`]: `sender_public_key`
+/// must be one of the session's trusted peers (see
+/// [`KyberSession::new`]) for `kem_ciphertext` to be accepted, so an
+/// untrusted party can't force a session onto a root secret it chose.
+#[derive(Debug, Clone)]
+pub struct RekeyAnnouncement {
+    pub sender_public_key: Vec<u8>,
+    pub kem_ciphertext: Vec<u8>,
+}
+
+/// One framed, ratcheted message: `nonce` is this session's
+/// monotonically increasing message counter within `epoch` (bumped by
+/// every [`KyberSession::rekey`]), used both to derive the per-message
+/// key and, on the receiving end, to check the sliding replay window.
+#[derive(Debug, Clone)]
+pub struct SessionMessage {
+    pub epoch: u64,
+    pub nonce: u64,
+    pub ciphertext: Vec<u8>,
+    pub rekey: Option<RekeyAnnouncement>,
+}
+
+/// Sliding window over the last 64 accepted nonces: anything newer than
+/// the highest nonce seen so far slides the window forward, anything
+/// within the last 64 is checked against (and then recorded in) the
+/// bitmask, and anything older is rejected outright. This is what lets
+/// [`KyberSession::receive`] tolerate out-of-order delivery without
+/// also accepting a retransmitted-within-window replay.
+#[derive(Debug, Default)]
+struct ReplayWindow {
+    highest: Option<u64>,
+    bitmask: u64,
+}
+
+impl ReplayWindow {
+    fn check_and_record(&mut self, nonce: u64) -> PQCResult<()> {
+        let highest = match self.highest {
+            None => {
+                self.highest = Some(nonce);
+                self.bitmask = 1;
+                return Ok(());
+            }
+            Some(highest) => highest,
+        };
+
+        if nonce > highest {
+            let shift = nonce - highest;
+            self.bitmask = if shift >= 64 { 0 } else { self.bitmask << shift };
+            self.bitmask |= 1;
+            self.highest = Some(nonce);
+            return Ok(());
+        }
+
+        let age = highest - nonce;
+        if age >= 64 {
+            return Err(PQCError::InvalidCiphertext(
+                "Session nonce is outside the replay window".to_string(),
+            ));
+        }
+        let bit = 1u64 << age;
+        if self.bitmask & bit != 0 {
+            return Err(PQCError::InvalidCiphertext(
+                "Session nonce already seen (replay rejected)".to_string(),
+            ));
+        }
+        self.bitmask |= bit;
+        Ok(())
+    }
+}
+
+/// HKDF-SHA256 domain-separation label for [`ratchet_key`], distinct
+/// from [`crate::hybrid::HYBRID_KEM_INFO`]'s combiner label so the two
+/// derivations can never collide even if ever fed the same root bytes.
+const REKEY_INFO_PREFIX: &[u8] = b"rekey";
+
+/// Derives the key for message `n` from `root` via HKDF-SHA256, per the
+/// request's `k_n = HKDF(root, "rekey" || n)` construction.
+fn ratchet_key(root: &[u8], n: u64) -> PQCResult<[u8; 32]> {
+    let hk = Hkdf::<Sha256>::new(None, root);
+    let mut info = Vec::with_capacity(REKEY_INFO_PREFIX.len() + 8);
+    info.extend_from_slice(REKEY_INFO_PREFIX);
+    info.extend_from_slice(&n.to_be_bytes());
+
+    let mut key = [0u8; 32];
+    hk.expand(&info, &mut key)
+        .map_err(|_| PQCError::EncapsulationFailed("Session key ratchet failed".to_string()))?;
+    Ok(key)
+}
+
+/// Derives a 12-byte AES-GCM nonce from a session message counter. Safe
+/// to reuse across messages because every nonce value is also ratcheted
+/// into a distinct AEAD key via [`ratchet_key`]; embedding the counter
+/// here is defense-in-depth, not the sole source of nonce uniqueness.
+fn session_nonce_bytes(nonce: u64) -> [u8; 12] {
+    let mut bytes = [0u8; 12];
+    bytes[4..].copy_from_slice(&nonce.to_be_bytes());
+    bytes
+}
+
+/// A long-lived, authenticated channel built over one-shot ML-KEM-768
+/// encapsulations. See the module docs for the ratchet and replay-window
+/// design; [`Self::new`] takes an already-established root secret
+/// (e.g. from a [`KyberEngine::encapsulate`]/`decapsulate` handshake the
+/// caller performs up front).
+pub struct KyberSession {
+    engine: KyberEngine,
+    local_keypair: PQCKeyPair,
+    peer_public_key: Vec<u8>,
+    trusted_peers: HashSet<Vec<u8>>,
+    root_secret: Secret<Vec<u8>>,
+    epoch: u64,
+    send_counter: u64,
+    recv_window: ReplayWindow,
+    messages_since_rekey: u64,
+    last_rekey_at: Instant,
+    config: KyberSessionConfig,
+}
+
+impl KyberSession {
+    /// `local_keypair` is this side's ML-KEM-768 keypair, used to
+    /// decapsulate incoming [`RekeyAnnouncement`]s. `peer_public_key` is
+    /// the single peer this session re-encapsulates against when *it*
+    /// initiates a rekey. `trusted_peers` is the set of public keys
+    /// this session accepts a [`RekeyAnnouncement`] from -- a superset
+    /// of `peer_public_key` when multiple authorized senders can drive
+    /// this session's rekeys (e.g. a group of writers to one reader).
+    pub fn new(
+        local_keypair: PQCKeyPair,
+        peer_public_key: Vec<u8>,
+        trusted_peers: HashSet<Vec<u8>>,
+        root_secret: Secret<Vec<u8>>,
+        config: KyberSessionConfig,
+    ) -> Self {
+        Self {
+            engine: create_default_kyber_engine(),
+            local_keypair,
+            peer_public_key,
+            trusted_peers,
+            root_secret,
+            epoch: 0,
+            send_counter: 0,
+            recv_window: ReplayWindow::default(),
+            messages_since_rekey: 0,
+            last_rekey_at: Instant::now(),
+            config,
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    fn should_rekey(&self) -> bool {
+        if let Some(max_messages) = self.config.rekey_after_messages {
+            if self.messages_since_rekey >= max_messages {
+                return true;
+            }
+        }
+        if let Some(max_age) = self.config.rekey_after {
+            if self.last_rekey_at.elapsed() >= max_age {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Re-encapsulates against `self.peer_public_key`, adopting the
+    /// resulting shared secret as the new root and resetting every
+    /// per-epoch counter. The old root is dropped here, zeroizing via
+    /// [`secrecy::Secret`]'s `Drop` the same way every other secret in
+    /// this crate does.
+    fn rekey(&mut self) -> PQCResult<Vec<u8>> {
+        let result = self.engine.encapsulate(&self.peer_public_key)?;
+        self.root_secret = result.shared_secret;
+        self.epoch += 1;
+        self.send_counter = 0;
+        self.messages_since_rekey = 0;
+        self.last_rekey_at = Instant::now();
+        self.recv_window = ReplayWindow::default();
+        Ok(result.ciphertext)
+    }
+
+    /// Encrypts `plaintext` under the next ratcheted key, rekeying first
+    /// if [`Self::should_rekey`] says this session is due.
+    pub fn send(&mut self, plaintext: &[u8], associated_data: &[u8]) -> PQCResult<SessionMessage> {
+        let rekey = if self.should_rekey() {
+            let kem_ciphertext = self.rekey()?;
+            Some(RekeyAnnouncement {
+                sender_public_key: self.local_keypair.public_key.clone(),
+                kem_ciphertext,
+            })
+        } else {
+            None
+        };
+
+        let nonce_value = self.send_counter;
+        self.send_counter += 1;
+        self.messages_since_rekey += 1;
+
+        let key = ratchet_key(self.root_secret.expose_secret(), nonce_value)?;
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| {
+            PQCError::EncapsulationFailed(format!("Failed to initialize session cipher: {e}"))
+        })?;
+        let nonce_bytes = session_nonce_bytes(nonce_value);
+        let ciphertext = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce_bytes),
+                Payload { msg: plaintext, aad: associated_data },
+            )
+            .map_err(|e| PQCError::EncapsulationFailed(format!("Session encryption failed: {e}")))?;
+
+        Ok(SessionMessage { epoch: self.epoch, nonce: nonce_value, ciphertext, rekey })
+    }
+
+    /// Decrypts a [`SessionMessage`], first applying its
+    /// [`RekeyAnnouncement`] (if any) after checking `sender_public_key`
+    /// against the trusted peer set. Rejects anything from a stale or
+    /// future epoch and anything the replay window has already seen or
+    /// fallen behind.
+    pub fn receive(
+        &mut self,
+        message: &SessionMessage,
+        associated_data: &[u8],
+    ) -> PQCResult<Vec<u8>> {
+        // Validate everything -- trust, epoch -- against the *pre-mutation*
+        // session state before touching `self`. A replayed or stale
+        // `SessionMessage` (including one carrying an old but
+        // still-trusted-sender `RekeyAnnouncement`) must be rejected without
+        // any side effect; otherwise a captured rekey announcement can be
+        // replayed to roll the root secret back, desync `epoch` from the
+        // peer, and reset the replay window, all on a call that ultimately
+        // returns `Err`.
+        let new_root = if let Some(rekey) = &message.rekey {
+            if !self.trusted_peers.contains(&rekey.sender_public_key) {
+                return Err(PQCError::SecurityValidationFailed(
+                    "Rekey announcement from an untrusted peer".to_string(),
+                ));
+            }
+
+            if message.epoch != self.epoch + 1 {
+                return Err(PQCError::InvalidCiphertext(
+                    "Session message epoch does not match this session's current epoch"
+                        .to_string(),
+                ));
+            }
+
+            Some(self.engine.decapsulate(
+                self.local_keypair.private_key.expose_secret(),
+                &rekey.kem_ciphertext,
+            )?)
+        } else {
+            if message.epoch != self.epoch {
+                return Err(PQCError::InvalidCiphertext(
+                    "Session message epoch does not match this session's current epoch"
+                        .to_string(),
+                ));
+            }
+
+            None
+        };
+
+        if let Some(new_root) = new_root {
+            self.root_secret = new_root;
+            self.epoch += 1;
+            self.send_counter = 0;
+            self.messages_since_rekey = 0;
+            self.last_rekey_at = Instant::now();
+            self.recv_window = ReplayWindow::default();
+        }
+
+        self.recv_window.check_and_record(message.nonce)?;
+
+        let key = ratchet_key(self.root_secret.expose_secret(), message.nonce)?;
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|e| {
+            PQCError::DecapsulationFailed(format!("Failed to initialize session cipher: {e}"))
+        })?;
+        let nonce_bytes = session_nonce_bytes(message.nonce);
+        let plaintext = cipher
+            .decrypt(
+                Nonce::from_slice(&nonce_bytes),
+                Payload { msg: &message.ciphertext, aad: associated_data },
+            )
+            .map_err(|_| PQCError::DecapsulationFailed("Session decryption failed".to_string()))?;
+
+        self.messages_since_rekey += 1;
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kyber::create_default_kyber_engine as new_engine;
+
+    fn paired_sessions(config: KyberSessionConfig) -> (KyberSession, KyberSession) {
+        let mut engine = new_engine();
+        let alice_keypair = engine.generate_keypair().unwrap();
+        let bob_keypair = engine.generate_keypair().unwrap();
+
+        let handshake = engine.encapsulate(&bob_keypair.public_key).unwrap();
+        let root_for_alice = handshake.shared_secret;
+        let root_for_bob = engine
+            .decapsulate(bob_keypair.private_key.expose_secret(), &handshake.ciphertext)
+            .unwrap();
+
+        let mut trusted_by_bob = HashSet::new();
+        trusted_by_bob.insert(alice_keypair.public_key.clone());
+        let mut trusted_by_alice = HashSet::new();
+        trusted_by_alice.insert(bob_keypair.public_key.clone());
+
+        let alice = KyberSession::new(
+            alice_keypair.clone(),
+            bob_keypair.public_key.clone(),
+            trusted_by_alice,
+            root_for_alice,
+            config.clone(),
+        );
+        let bob = KyberSession::new(
+            bob_keypair,
+            alice_keypair.public_key,
+            trusted_by_bob,
+            root_for_bob,
+            config,
+        );
+
+        (alice, bob)
+    }
+
+    fn no_rekey_config() -> KyberSessionConfig {
+        KyberSessionConfig { rekey_after_messages: None, rekey_after: None }
+    }
+
+    #[test]
+    fn test_session_round_trips_in_order() {
+        let (mut alice, mut bob) = paired_sessions(no_rekey_config());
+
+        let message = alice.send(b"hello bob", b"session-1").unwrap();
+        let plaintext = bob.receive(&message, b"session-1").unwrap();
+        assert_eq!(plaintext, b"hello bob");
+    }
+
+    #[test]
+    fn test_session_tolerates_reordering_within_the_replay_window() {
+        let (mut alice, mut bob) = paired_sessions(no_rekey_config());
+
+        let first = alice.send(b"first", b"").unwrap();
+        let second = alice.send(b"second", b"").unwrap();
+
+        // Deliver out of order: second before first.
+        assert_eq!(bob.receive(&second, b"").unwrap(), b"second");
+        assert_eq!(bob.receive(&first, b"").unwrap(), b"first");
+    }
+
+    #[test]
+    fn test_session_rejects_replayed_message() {
+        let (mut alice, mut bob) = paired_sessions(no_rekey_config());
+
+        let message = alice.send(b"once only", b"").unwrap();
+        assert_eq!(bob.receive(&message, b"").unwrap(), b"once only");
+        assert!(bob.receive(&message, b"").is_err());
+    }
+
+    #[test]
+    fn test_session_rejects_wrong_associated_data() {
+        let (mut alice, mut bob) = paired_sessions(no_rekey_config());
+
+        let message = alice.send(b"bound to aad", b"correct-aad").unwrap();
+        assert!(bob.receive(&message, b"wrong-aad").is_err());
+    }
+
+    #[test]
+    fn test_session_rekeys_after_message_count_and_rejects_untrusted_rekey() {
+        let config = KyberSessionConfig { rekey_after_messages: Some(1), rekey_after: None };
+        let (mut alice, mut bob) = paired_sessions(config);
+
+        let first = alice.send(b"within first epoch", b"").unwrap();
+        assert_eq!(bob.receive(&first, b"").unwrap(), b"within first epoch");
+        assert_eq!(bob.epoch(), 0);
+
+        // The second message is due for a rekey (limit of 1 message/epoch).
+        let second = alice.send(b"after rekey", b"").unwrap();
+        assert!(second.rekey.is_some());
+        assert_eq!(bob.receive(&second, b"").unwrap(), b"after rekey");
+        assert_eq!(bob.epoch(), 1);
+        assert_eq!(alice.epoch(), 1);
+
+        // An announcement claiming to be from an untrusted sender is rejected.
+        let mut forged = second;
+        if let Some(rekey) = forged.rekey.as_mut() {
+            rekey.sender_public_key = vec![0u8; rekey.sender_public_key.len()];
+        }
+        forged.epoch += 1;
+        let err = bob.receive(&forged, b"").unwrap_err();
+        assert!(matches!(err, PQCError::SecurityValidationFailed(_)));
+    }
+
+    #[test]
+    fn test_replayed_rekey_announcement_is_rejected_without_mutating_session_state() {
+        let config = KyberSessionConfig { rekey_after_messages: Some(1), rekey_after: None };
+        let (mut alice, mut bob) = paired_sessions(config);
+
+        let first = alice.send(b"within first epoch", b"").unwrap();
+        bob.receive(&first, b"").unwrap();
+
+        let second = alice.send(b"after rekey", b"").unwrap();
+        assert!(second.rekey.is_some());
+        bob.receive(&second, b"").unwrap();
+        assert_eq!(bob.epoch(), 1);
+
+        // Replaying the same, legitimately-trusted rekey announcement must
+        // be rejected on the stale-epoch check, and must not re-apply the
+        // rekey's side effects (root secret rollback, epoch bump, replay
+        // window reset) along the way.
+        let err = bob.receive(&second, b"").unwrap_err();
+        assert!(matches!(err, PQCError::InvalidCiphertext(_)));
+        assert_eq!(bob.epoch(), 1);
+
+        // Bob must still be in sync with Alice after the replay was
+        // rejected, not desynced by a partially-applied rekey.
+        let third = alice.send(b"still in sync", b"").unwrap();
+        assert_eq!(bob.receive(&third, b"").unwrap(), b"still in sync");
+    }
+}
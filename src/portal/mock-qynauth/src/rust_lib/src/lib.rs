@@ -5,19 +5,50 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 #![allow(clippy::manual_unwrap_or_default)]
 #![allow(clippy::manual_unwrap_or)]
-
-use pqcrypto_mldsa::mldsa65;
-use pqcrypto_mlkem::mlkem768;
+// `std` is a default-on feature: most of this crate (FFI, key management)
+// needs it, but `errors::CryptoError`, `security::side_channel::ConstantTimeOps`,
+// and `security::cache_protection::CacheProtection` are written to also
+// compile under `#![no_std]` with only `alloc`, so they can be pulled into
+// firmware and TEE targets that can't link the standard library.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use pqc_kyber::reference::{crypto_kem_enc_derand, crypto_kem_keypair_derand};
+use pqcrypto_mldsa::{mldsa44, mldsa65, mldsa87};
+use pqcrypto_mlkem::{mlkem1024, mlkem512, mlkem768};
 use pqcrypto_traits::kem::{Ciphertext, PublicKey, SecretKey, SharedSecret};
 use pqcrypto_traits::sign::{
-    PublicKey as SignPublicKey, SecretKey as SignSecretKey, SignedMessage,
+    DetachedSignature as SignDetachedSignature, PublicKey as SignPublicKey,
+    SecretKey as SignSecretKey, SignedMessage,
 };
 use secrecy::{ExposeSecret, Secret};
+use sha2::{Digest, Sha512};
 use std::ffi::CString;
 use std::os::raw::c_char;
+use subtle::ConstantTimeEq;
 use thiserror::Error;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
+pub mod audit_log;
+pub mod bcc;
+pub mod errors;
 pub mod ffi;
+pub mod gc;
+pub mod hsm_backend;
+pub mod hybrid;
+pub mod key_encoding;
+pub mod key_maintenance;
+pub mod keystore;
+pub mod kyber;
+pub mod kyber_session;
+pub mod prekey;
+pub mod revocation;
+pub mod rkp_client;
+pub mod security;
+pub mod shamir;
+pub mod super_key;
+pub mod uniffi_bindings;
 
 #[derive(Error, Debug)]
 pub enum PQCError {
@@ -51,12 +82,223 @@ pub enum PQCError {
     MemoryAllocationFailed,
     #[error("Security validation failed: {0}")]
     SecurityValidationFailed(String),
+    #[error("Key manager locked: {0}")]
+    KeyManagerLocked(String),
+    #[error("Key usage policy violation: {0}")]
+    UsagePolicyViolation(String),
+    #[error("Authentication required: {0}")]
+    AuthRequired(String),
+    #[error("Key store error: {0}")]
+    StoreError(String),
+    #[error("Incorrect PIN for user {user_id}, {attempts_remaining} attempt(s) remaining")]
+    IncorrectPin {
+        user_id: String,
+        attempts_remaining: u32,
+    },
+    #[error("User {0} is locked out after too many incorrect PIN attempts")]
+    AccountLocked(String),
 }
 
 pub type PQCResult<T> = Result<T, PQCError>;
 
+/// NIST FIPS 204 parameter set, named by the NIST security category it
+/// targets rather than its internal `k`/`l` dimensions. `Level2` suits
+/// constrained devices, `Level3` (the crate's long-standing default) is
+/// the general-purpose choice, and `Level5` is for high-assurance
+/// deployments that can absorb the larger keys and signatures.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MLDSALevel {
+    Level2 = 2,
+    Level3 = 3,
+    Level5 = 5,
+}
+
+/// NIST FIPS 203 parameter set, named by its conventional `ML-KEM-n`
+/// suffix rather than the `MLDSALevel`-style NIST security category (512
+/// targets category 1, 768 category 3, 1024 category 5). Lets FFI callers
+/// choose the security level at the ABI boundary instead of the surface
+/// being pinned to ML-KEM-768.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MLKEMLevel {
+    MlKem512 = 512,
+    MlKem768 = 768,
+    MlKem1024 = 1024,
+}
+
+/// Selects among every PQC mechanism this crate supports, so keygen,
+/// encapsulation, and signing entrypoints dispatch on a single enum rather
+/// than hardcoding one parameter set per function. Mirrors liboqs-rust's
+/// `Algorithm` enum in `sig.rs`: each variant carries its own canonical
+/// name and NIST-defined byte sizes, so `security_level`/`key_size`
+/// bookkeeping reads off the enum instead of being duplicated at every
+/// call site.
+///
+/// Only the ML-KEM and ML-DSA variants are wired to a backend today; the
+/// enum is the extension point for SLH-DSA and FN-DSA/Falcon once this
+/// crate takes on those dependencies.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PQCAlgorithm {
+    MlKem512,
+    MlKem768,
+    MlKem1024,
+    MlDsa44,
+    MlDsa65,
+    MlDsa87,
+}
+
+impl PQCAlgorithm {
+    /// Maps a canonical NIST name (or a legacy pre-standardization alias
+    /// such as `"Kyber-768"`) to the algorithm it designates.
+    pub fn from_name(name: &str) -> PQCResult<Self> {
+        match name {
+            "ML-KEM-512" => Ok(Self::MlKem512),
+            "ML-KEM-768" | "Kyber-768" => Ok(Self::MlKem768),
+            "ML-KEM-1024" => Ok(Self::MlKem1024),
+            "ML-DSA-44" => Ok(Self::MlDsa44),
+            "ML-DSA-65" | "Dilithium-3" => Ok(Self::MlDsa65),
+            "ML-DSA-87" => Ok(Self::MlDsa87),
+            other => Err(PQCError::UnsupportedAlgorithm(other.to_string())),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MlKem512 => "ML-KEM-512",
+            Self::MlKem768 => "ML-KEM-768",
+            Self::MlKem1024 => "ML-KEM-1024",
+            Self::MlDsa44 => "ML-DSA-44",
+            Self::MlDsa65 => "ML-DSA-65",
+            Self::MlDsa87 => "ML-DSA-87",
+        }
+    }
+
+    /// NIST PQC security category this parameter set targets.
+    pub fn security_level(&self) -> u8 {
+        match self {
+            Self::MlKem512 => 1,
+            Self::MlKem768 => 3,
+            Self::MlKem1024 => 5,
+            Self::MlDsa44 => 2,
+            Self::MlDsa65 => 3,
+            Self::MlDsa87 => 5,
+        }
+    }
+
+    pub fn public_key_size(&self) -> usize {
+        match self {
+            Self::MlKem512 => 800,
+            Self::MlKem768 => 1184,
+            Self::MlKem1024 => 1568,
+            Self::MlDsa44 => 1312,
+            Self::MlDsa65 => 1952,
+            Self::MlDsa87 => 2592,
+        }
+    }
+
+    pub fn secret_key_size(&self) -> usize {
+        match self {
+            Self::MlKem512 => 1632,
+            Self::MlKem768 => 2400,
+            Self::MlKem1024 => 3168,
+            Self::MlDsa44 => 2560,
+            Self::MlDsa65 => 4032,
+            Self::MlDsa87 => 4896,
+        }
+    }
+
+    /// `Some` for a KEM variant, `None` for a signature variant.
+    pub fn ciphertext_size(&self) -> Option<usize> {
+        match self {
+            Self::MlKem512 => Some(768),
+            Self::MlKem768 => Some(1088),
+            Self::MlKem1024 => Some(1568),
+            Self::MlDsa44 | Self::MlDsa65 | Self::MlDsa87 => None,
+        }
+    }
+
+    /// `Some` for a KEM variant, `None` for a signature variant. All three
+    /// ML-KEM parameter sets share the same FIPS 203 shared secret length.
+    pub fn shared_secret_size(&self) -> Option<usize> {
+        match self {
+            Self::MlKem512 | Self::MlKem768 | Self::MlKem1024 => Some(32),
+            Self::MlDsa44 | Self::MlDsa65 | Self::MlDsa87 => None,
+        }
+    }
+
+    /// `Some` for a signature variant, `None` for a KEM variant.
+    pub fn signature_size(&self) -> Option<usize> {
+        match self {
+            Self::MlKem512 | Self::MlKem768 | Self::MlKem1024 => None,
+            Self::MlDsa44 => Some(2420),
+            Self::MlDsa65 => Some(3309),
+            Self::MlDsa87 => Some(4627),
+        }
+    }
+
+    pub fn is_kem(&self) -> bool {
+        matches!(self, Self::MlKem512 | Self::MlKem768 | Self::MlKem1024)
+    }
+
+    pub fn is_signature(&self) -> bool {
+        !self.is_kem()
+    }
+}
+
+impl From<MLDSALevel> for PQCAlgorithm {
+    fn from(level: MLDSALevel) -> Self {
+        match level {
+            MLDSALevel::Level2 => Self::MlDsa44,
+            MLDSALevel::Level3 => Self::MlDsa65,
+            MLDSALevel::Level5 => Self::MlDsa87,
+        }
+    }
+}
+
+impl TryFrom<PQCAlgorithm> for MLDSALevel {
+    type Error = PQCError;
+
+    fn try_from(algorithm: PQCAlgorithm) -> Result<Self, Self::Error> {
+        match algorithm {
+            PQCAlgorithm::MlDsa44 => Ok(Self::Level2),
+            PQCAlgorithm::MlDsa65 => Ok(Self::Level3),
+            PQCAlgorithm::MlDsa87 => Ok(Self::Level5),
+            PQCAlgorithm::MlKem512 | PQCAlgorithm::MlKem768 | PQCAlgorithm::MlKem1024 => {
+                Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()))
+            }
+        }
+    }
+}
+
+impl From<MLKEMLevel> for PQCAlgorithm {
+    fn from(level: MLKEMLevel) -> Self {
+        match level {
+            MLKEMLevel::MlKem512 => Self::MlKem512,
+            MLKEMLevel::MlKem768 => Self::MlKem768,
+            MLKEMLevel::MlKem1024 => Self::MlKem1024,
+        }
+    }
+}
+
+impl TryFrom<PQCAlgorithm> for MLKEMLevel {
+    type Error = PQCError;
+
+    fn try_from(algorithm: PQCAlgorithm) -> Result<Self, Self::Error> {
+        match algorithm {
+            PQCAlgorithm::MlKem512 => Ok(Self::MlKem512),
+            PQCAlgorithm::MlKem768 => Ok(Self::MlKem768),
+            PQCAlgorithm::MlKem1024 => Ok(Self::MlKem1024),
+            PQCAlgorithm::MlDsa44 | PQCAlgorithm::MlDsa65 | PQCAlgorithm::MlDsa87 => {
+                Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()))
+            }
+        }
+    }
+}
+
 pub mod key_management;
-pub use key_management::{SecureKeyManager, KeyMetadata, KeyStatus, HSMConfig, KeyStatistics};
+pub use key_management::{SecureKeyManager, KeyMetadata, KeyStatus, HSMConfig, KeyStatistics, KeyAuditEntry, KeyAuditOperation, KeyParameters, KeyPurpose, ShareRef};
 
 pub struct PQCKeyPair {
     pub public_key: Vec<u8>,
@@ -96,6 +338,18 @@ impl std::fmt::Debug for PQCKeyPair {
     }
 }
 
+// `private_key` already zeroizes itself on drop via `secrecy::Secret`; this
+// impl covers the remaining fields so no copy of this keypair, public or
+// private, outlives the struct in memory.
+impl Drop for PQCKeyPair {
+    fn drop(&mut self) {
+        self.public_key.zeroize();
+        self.algorithm.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for PQCKeyPair {}
+
 pub struct PQCSignature {
     pub signature: Secret<Vec<u8>>,
     pub algorithm: String,
@@ -125,6 +379,15 @@ impl std::fmt::Debug for PQCSignature {
     }
 }
 
+// `signature` already zeroizes itself on drop via `secrecy::Secret`.
+impl Drop for PQCSignature {
+    fn drop(&mut self) {
+        self.algorithm.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for PQCSignature {}
+
 pub struct PQCEncryptionResult {
     pub ciphertext: Vec<u8>,
     pub shared_secret: Secret<Vec<u8>>,
@@ -157,101 +420,971 @@ impl std::fmt::Debug for PQCEncryptionResult {
     }
 }
 
-pub fn generate_mlkem_keypair() -> PQCResult<PQCKeyPair> {
+// `shared_secret` already zeroizes itself on drop via `secrecy::Secret`.
+impl Drop for PQCEncryptionResult {
+    fn drop(&mut self) {
+        self.ciphertext.zeroize();
+        self.algorithm.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for PQCEncryptionResult {}
+
+/// Per-engine operation counters and latencies for [`kyber::KyberEngine`],
+/// holding nothing secret, so unlike [`PQCKeyPair`]/[`PQCEncryptionResult`]
+/// it needs no redacting `Debug` impl or `Zeroize` on drop.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KyberMetrics {
+    pub key_generation_time_ns: u64,
+    pub encapsulation_time_ns: u64,
+    pub decapsulation_time_ns: u64,
+    pub public_key_size: usize,
+    pub private_key_size: usize,
+    pub ciphertext_size: usize,
+    pub shared_secret_size: usize,
+    pub operations_count: u64,
+}
+
+pub fn generate_mlkem_keypair_for_algorithm(algorithm: PQCAlgorithm) -> PQCResult<PQCKeyPair> {
+    if !algorithm.is_kem() {
+        return Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()));
+    }
+
     let current_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_err(|_| PQCError::KeyGenerationFailed("System time error".to_string()))?
         .as_secs();
 
-    let (pk, sk) = mlkem768::keypair();
+    let (public_key, private_key) = match algorithm {
+        PQCAlgorithm::MlKem512 => {
+            let (pk, sk) = mlkem512::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+        PQCAlgorithm::MlKem768 => {
+            let (pk, sk) = mlkem768::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+        PQCAlgorithm::MlKem1024 => {
+            let (pk, sk) = mlkem1024::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+        PQCAlgorithm::MlDsa44 | PQCAlgorithm::MlDsa65 | PQCAlgorithm::MlDsa87 => unreachable!(
+            "is_kem() already rejected the signature variants above"
+        ),
+    };
+
     Ok(PQCKeyPair {
-        public_key: pk.as_bytes().to_vec(),
-        private_key: Secret::new(sk.as_bytes().to_vec()),
-        algorithm: "ML-KEM-768".to_string(),
-        key_size: pk.as_bytes().len() + sk.as_bytes().len(),
-        security_level: 3,
+        key_size: public_key.len() + private_key.len(),
+        public_key,
+        private_key: Secret::new(private_key),
+        algorithm: algorithm.name().to_string(),
+        security_level: algorithm.security_level(),
         created_at: current_time,
     })
 }
 
-pub fn generate_mldsa_keypair() -> PQCResult<PQCKeyPair> {
+pub fn generate_mlkem_keypair() -> PQCResult<PQCKeyPair> {
+    generate_mlkem_keypair_for_algorithm(PQCAlgorithm::MlKem768)
+}
+
+/// Deterministically derives an ML-KEM-768 keypair from a 64-byte FIPS 203
+/// seed `d || z`: `d` feeds K-PKE key generation, `z` is the implicit-
+/// rejection value folded into the secret key. `pqcrypto_mlkem` draws its
+/// own randomness internally and has no seed-injection point, so this
+/// path goes through `pqc_kyber`'s `_derand` reference API instead of
+/// `generate_mlkem_keypair`; both are conformant implementations of the
+/// same NIST wire format, so keys produced here still round-trip through
+/// [`mlkem_encapsulate`]/[`mlkem_decapsulate`]. Exists for NIST ACVP/KAT
+/// reproduction and byte-exact consistency tests, not day-to-day use.
+pub fn generate_mlkem_keypair_deterministic(seed: &[u8; 64]) -> PQCResult<PQCKeyPair> {
     let current_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_err(|_| PQCError::KeyGenerationFailed("System time error".to_string()))?
         .as_secs();
 
-    let (pk, sk) = mldsa65::keypair();
+    let mut public_key = [0u8; pqc_kyber::KYBER_PUBLICKEYBYTES];
+    let mut secret_key = [0u8; pqc_kyber::KYBER_SECRETKEYBYTES];
+
+    crypto_kem_keypair_derand(&mut public_key, &mut secret_key, seed).map_err(|e| {
+        PQCError::KeyGenerationFailed(format!("deterministic ML-KEM-768 keygen failed: {e:?}"))
+    })?;
+
     Ok(PQCKeyPair {
-        public_key: pk.as_bytes().to_vec(),
-        private_key: Secret::new(sk.as_bytes().to_vec()),
-        algorithm: "ML-DSA-65".to_string(),
-        key_size: pk.as_bytes().len() + sk.as_bytes().len(),
-        security_level: 3,
+        key_size: public_key.len() + secret_key.len(),
+        public_key: public_key.to_vec(),
+        private_key: Secret::new(secret_key.to_vec()),
+        algorithm: PQCAlgorithm::MlKem768.name().to_string(),
+        security_level: PQCAlgorithm::MlKem768.security_level(),
         created_at: current_time,
     })
 }
 
-pub fn mlkem_encapsulate(public_key: &[u8], _message: &[u8]) -> PQCResult<PQCEncryptionResult> {
+/// Deterministically encapsulates against `public_key` using the
+/// caller-supplied 32-byte message `m`, instead of the `m`
+/// `pqcrypto_mlkem`'s internal RNG would otherwise draw for
+/// [`mlkem_encapsulate`]. Per FIPS 203, `m` is hashed together with
+/// `H(pk)` to derive the `(K, r)` pair used for K-PKE encryption. Only
+/// ML-KEM-768 is wired up, matching [`generate_mlkem_keypair_deterministic`].
+pub fn mlkem_encapsulate_deterministic(
+    public_key: &[u8],
+    message: &[u8; 32],
+) -> PQCResult<PQCEncryptionResult> {
+    if public_key.len() != PQCAlgorithm::MlKem768.public_key_size() {
+        return Err(PQCError::EncapsulationFailed(format!(
+            "expected a {}-byte ML-KEM-768 public key, got {}",
+            PQCAlgorithm::MlKem768.public_key_size(),
+            public_key.len()
+        )));
+    }
+
     let current_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .map_err(|_| PQCError::EncapsulationFailed("System time error".to_string()))?
         .as_secs();
 
-    let pk = mlkem768::PublicKey::from_bytes(public_key)
-        .map_err(|_| PQCError::InvalidPublicKey("Failed to parse ML-KEM public key".to_string()))?;
-    let (ss, ct) = mlkem768::encapsulate(&pk);
+    let mut ciphertext = [0u8; pqc_kyber::KYBER_CIPHERTEXTBYTES];
+    let mut shared_secret = [0u8; pqc_kyber::KYBER_SSBYTES];
+
+    crypto_kem_enc_derand(&mut ciphertext, &mut shared_secret, public_key, message).map_err(|e| {
+        PQCError::EncapsulationFailed(format!(
+            "deterministic ML-KEM-768 encapsulation failed: {e:?}"
+        ))
+    })?;
+
     Ok(PQCEncryptionResult {
-        ciphertext: ct.as_bytes().to_vec(),
-        shared_secret: Secret::new(ss.as_bytes().to_vec()),
-        algorithm: "ML-KEM-768".to_string(),
-        ciphertext_size: ct.as_bytes().len(),
+        ciphertext_size: ciphertext.len(),
+        ciphertext: ciphertext.to_vec(),
+        shared_secret: Secret::new(shared_secret.to_vec()),
+        algorithm: PQCAlgorithm::MlKem768.name().to_string(),
         created_at: current_time,
     })
 }
 
-pub fn mlkem_decapsulate(private_key: &[u8], ciphertext: &[u8]) -> PQCResult<Secret<Vec<u8>>> {
-    let sk = mlkem768::SecretKey::from_bytes(private_key).map_err(|_| {
-        PQCError::InvalidPrivateKey("Failed to parse ML-KEM private key".to_string())
-    })?;
-    let ct = mlkem768::Ciphertext::from_bytes(ciphertext).map_err(|_| {
-        PQCError::InvalidCiphertext("Failed to parse ML-KEM ciphertext".to_string())
-    })?;
-    let ss = mlkem768::decapsulate(&ct, &sk);
-    Ok(Secret::new(ss.as_bytes().to_vec()))
-}
-
-pub fn mldsa_sign(private_key: &[u8], message: &[u8]) -> PQCResult<PQCSignature> {
+pub fn generate_mldsa_keypair_for_level(level: MLDSALevel) -> PQCResult<PQCKeyPair> {
     let current_time = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
-        .map_err(|_| PQCError::SigningFailed("System time error".to_string()))?
+        .map_err(|_| PQCError::KeyGenerationFailed("System time error".to_string()))?
         .as_secs();
 
-    let sk = mldsa65::SecretKey::from_bytes(private_key).map_err(|_| {
-        PQCError::InvalidPrivateKey("Failed to parse ML-DSA private key".to_string())
-    })?;
-    let signed_msg = mldsa65::sign(message, &sk);
-    Ok(PQCSignature {
-        signature: Secret::new(signed_msg.as_bytes().to_vec()),
-        algorithm: "ML-DSA-65".to_string(),
-        signature_size: signed_msg.as_bytes().len(),
+    let algorithm = PQCAlgorithm::from(level);
+
+    let (public_key, private_key) = match level {
+        MLDSALevel::Level2 => {
+            let (pk, sk) = mldsa44::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+        MLDSALevel::Level3 => {
+            let (pk, sk) = mldsa65::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+        MLDSALevel::Level5 => {
+            let (pk, sk) = mldsa87::keypair();
+            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+        }
+    };
+
+    Ok(PQCKeyPair {
+        key_size: public_key.len() + private_key.len(),
+        public_key,
+        private_key: Secret::new(private_key),
+        algorithm: algorithm.name().to_string(),
+        security_level: algorithm.security_level(),
         created_at: current_time,
     })
 }
 
-pub fn mldsa_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> PQCResult<bool> {
-    let pk = mldsa65::PublicKey::from_bytes(public_key)
-        .map_err(|_| PQCError::InvalidPublicKey("Failed to parse ML-DSA public key".to_string()))?;
-    let signed_msg = mldsa65::SignedMessage::from_bytes(signature)
-        .map_err(|_| PQCError::InvalidSignature("Failed to parse ML-DSA signature".to_string()))?;
+pub fn generate_mldsa_keypair() -> PQCResult<PQCKeyPair> {
+    generate_mldsa_keypair_for_level(MLDSALevel::Level3)
+}
+
+enum MlKemPublicKeyInner {
+    MlKem512(mlkem512::PublicKey),
+    MlKem768(mlkem768::PublicKey),
+    MlKem1024(mlkem1024::PublicKey),
+}
+
+/// An ML-KEM public key already length-checked and parsed for a specific
+/// [`PQCAlgorithm`]. Mirrors the secp256k1-style redesign applied to
+/// [`MlDsaSecretKey`]: once an `MlKemPublicKey` exists, `encapsulate` can no
+/// longer fail on account of the key, so callers only have to handle
+/// key-parsing errors once, at construction, rather than on every
+/// encapsulation call.
+pub struct MlKemPublicKey {
+    algorithm: PQCAlgorithm,
+    inner: MlKemPublicKeyInner,
+}
+
+impl MlKemPublicKey {
+    pub fn from_bytes(algorithm: PQCAlgorithm, bytes: &[u8]) -> PQCResult<Self> {
+        if !algorithm.is_kem() {
+            return Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()));
+        }
+
+        if bytes.len() != algorithm.public_key_size() {
+            return Err(PQCError::InvalidPublicKey(format!(
+                "{} public key must be {} bytes, got {}",
+                algorithm.name(),
+                algorithm.public_key_size(),
+                bytes.len()
+            )));
+        }
+
+        let parse_error = || {
+            PQCError::InvalidPublicKey(format!("Failed to parse {} public key", algorithm.name()))
+        };
+
+        let inner = match algorithm {
+            PQCAlgorithm::MlKem512 => MlKemPublicKeyInner::MlKem512(
+                mlkem512::PublicKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem768 => MlKemPublicKeyInner::MlKem768(
+                mlkem768::PublicKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem1024 => MlKemPublicKeyInner::MlKem1024(
+                mlkem1024::PublicKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlDsa44 | PQCAlgorithm::MlDsa65 | PQCAlgorithm::MlDsa87 => unreachable!(
+                "is_kem() already rejected the signature variants above"
+            ),
+        };
+
+        Ok(Self { algorithm, inner })
+    }
+
+    pub fn algorithm(&self) -> PQCAlgorithm {
+        self.algorithm
+    }
+
+    /// Encapsulates a fresh shared secret against this key. Infallible
+    /// beyond system-clock failures: construction already confirmed
+    /// `pqcrypto_mlkem` accepts the key.
+    pub fn encapsulate(&self) -> PQCResult<PQCEncryptionResult> {
+        let current_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|_| PQCError::EncapsulationFailed("System time error".to_string()))?
+            .as_secs();
+
+        let (ciphertext, shared_secret) = match &self.inner {
+            MlKemPublicKeyInner::MlKem512(pk) => {
+                let (ss, ct) = mlkem512::encapsulate(pk);
+                (ct.as_bytes().to_vec(), ss.as_bytes().to_vec())
+            }
+            MlKemPublicKeyInner::MlKem768(pk) => {
+                let (ss, ct) = mlkem768::encapsulate(pk);
+                (ct.as_bytes().to_vec(), ss.as_bytes().to_vec())
+            }
+            MlKemPublicKeyInner::MlKem1024(pk) => {
+                let (ss, ct) = mlkem1024::encapsulate(pk);
+                (ct.as_bytes().to_vec(), ss.as_bytes().to_vec())
+            }
+        };
+
+        Ok(PQCEncryptionResult {
+            ciphertext_size: ciphertext.len(),
+            ciphertext,
+            shared_secret: Secret::new(shared_secret),
+            algorithm: self.algorithm.name().to_string(),
+            created_at: current_time,
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for MlKemPublicKey {
+    type Error = PQCError;
+
+    fn try_from(bytes: &[u8]) -> PQCResult<Self> {
+        Self::from_bytes(PQCAlgorithm::MlKem768, bytes)
+    }
+}
+
+// Public key material isn't secret, but dumping it as a raw byte array
+// still isn't useful in a log line; report the size instead, matching
+// `PQCKeyPair`/`PQCEncryptionResult`'s `Debug` impls above.
+impl std::fmt::Debug for MlKemPublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MlKemPublicKey")
+            .field("algorithm", &self.algorithm)
+            .field("bytes", &format!("[{} bytes]", self.algorithm.public_key_size()))
+            .finish()
+    }
+}
+
+enum MlKemCiphertextInner {
+    MlKem512(mlkem512::Ciphertext),
+    MlKem768(mlkem768::Ciphertext),
+    MlKem1024(mlkem1024::Ciphertext),
+}
+
+/// An ML-KEM ciphertext already length-checked and parsed for a specific
+/// [`PQCAlgorithm`].
+pub struct MlKemCiphertext {
+    algorithm: PQCAlgorithm,
+    inner: MlKemCiphertextInner,
+}
+
+impl MlKemCiphertext {
+    pub fn from_bytes(algorithm: PQCAlgorithm, bytes: &[u8]) -> PQCResult<Self> {
+        if !algorithm.is_kem() {
+            return Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()));
+        }
+
+        let expected_size = algorithm
+            .ciphertext_size()
+            .expect("is_kem() guarantees a ciphertext size");
+        if bytes.len() != expected_size {
+            return Err(PQCError::InvalidCiphertext(format!(
+                "{} ciphertext must be {} bytes, got {}",
+                algorithm.name(),
+                expected_size,
+                bytes.len()
+            )));
+        }
+
+        let parse_error = || {
+            PQCError::InvalidCiphertext(format!("Failed to parse {} ciphertext", algorithm.name()))
+        };
+
+        let inner = match algorithm {
+            PQCAlgorithm::MlKem512 => MlKemCiphertextInner::MlKem512(
+                mlkem512::Ciphertext::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem768 => MlKemCiphertextInner::MlKem768(
+                mlkem768::Ciphertext::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem1024 => MlKemCiphertextInner::MlKem1024(
+                mlkem1024::Ciphertext::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlDsa44 | PQCAlgorithm::MlDsa65 | PQCAlgorithm::MlDsa87 => unreachable!(
+                "is_kem() already rejected the signature variants above"
+            ),
+        };
+
+        Ok(Self { algorithm, inner })
+    }
+
+    pub fn algorithm(&self) -> PQCAlgorithm {
+        self.algorithm
+    }
+}
+
+impl TryFrom<&[u8]> for MlKemCiphertext {
+    type Error = PQCError;
+
+    fn try_from(bytes: &[u8]) -> PQCResult<Self> {
+        Self::from_bytes(PQCAlgorithm::MlKem768, bytes)
+    }
+}
 
-    match mldsa65::open(&signed_msg, &pk) {
-        Ok(opened) => Ok(opened == message),
-        Err(_) => Ok(false),
+impl std::fmt::Debug for MlKemCiphertext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MlKemCiphertext")
+            .field("algorithm", &self.algorithm)
+            .field(
+                "bytes",
+                &format!(
+                    "[{} bytes]",
+                    self.algorithm
+                        .ciphertext_size()
+                        .expect("is_kem() guarantees a ciphertext size")
+                ),
+            )
+            .finish()
     }
 }
 
+enum MlKemSecretKeyInner {
+    MlKem512(mlkem512::SecretKey),
+    MlKem768(mlkem768::SecretKey),
+    MlKem1024(mlkem1024::SecretKey),
+}
+
+/// An ML-KEM secret key already length-checked and parsed for a specific
+/// [`PQCAlgorithm`].
+pub struct MlKemSecretKey {
+    algorithm: PQCAlgorithm,
+    inner: MlKemSecretKeyInner,
+}
+
+impl MlKemSecretKey {
+    pub fn from_bytes(algorithm: PQCAlgorithm, bytes: &[u8]) -> PQCResult<Self> {
+        if !algorithm.is_kem() {
+            return Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()));
+        }
+
+        if bytes.len() != algorithm.secret_key_size() {
+            return Err(PQCError::InvalidPrivateKey(format!(
+                "{} secret key must be {} bytes, got {}",
+                algorithm.name(),
+                algorithm.secret_key_size(),
+                bytes.len()
+            )));
+        }
+
+        let parse_error = || {
+            PQCError::InvalidPrivateKey(format!("Failed to parse {} secret key", algorithm.name()))
+        };
+
+        let inner = match algorithm {
+            PQCAlgorithm::MlKem512 => MlKemSecretKeyInner::MlKem512(
+                mlkem512::SecretKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem768 => MlKemSecretKeyInner::MlKem768(
+                mlkem768::SecretKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem1024 => MlKemSecretKeyInner::MlKem1024(
+                mlkem1024::SecretKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlDsa44 | PQCAlgorithm::MlDsa65 | PQCAlgorithm::MlDsa87 => unreachable!(
+                "is_kem() already rejected the signature variants above"
+            ),
+        };
+
+        Ok(Self { algorithm, inner })
+    }
+
+    pub fn algorithm(&self) -> PQCAlgorithm {
+        self.algorithm
+    }
+
+    /// Decapsulates `ciphertext` into its shared secret. Infallible beyond
+    /// checking that `ciphertext` was produced for the same algorithm as
+    /// this key: both sides were already parsed and length-checked at
+    /// construction.
+    pub fn decapsulate(&self, ciphertext: &MlKemCiphertext) -> PQCResult<Secret<Vec<u8>>> {
+        if ciphertext.algorithm != self.algorithm {
+            return Err(PQCError::InvalidCiphertext(format!(
+                "Ciphertext is for {} but secret key is for {}",
+                ciphertext.algorithm.name(),
+                self.algorithm.name()
+            )));
+        }
+
+        let shared_secret = match (&self.inner, &ciphertext.inner) {
+            (MlKemSecretKeyInner::MlKem512(sk), MlKemCiphertextInner::MlKem512(ct)) => {
+                mlkem512::decapsulate(ct, sk).as_bytes().to_vec()
+            }
+            (MlKemSecretKeyInner::MlKem768(sk), MlKemCiphertextInner::MlKem768(ct)) => {
+                mlkem768::decapsulate(ct, sk).as_bytes().to_vec()
+            }
+            (MlKemSecretKeyInner::MlKem1024(sk), MlKemCiphertextInner::MlKem1024(ct)) => {
+                mlkem1024::decapsulate(ct, sk).as_bytes().to_vec()
+            }
+            _ => unreachable!("the algorithm check above already ruled out a mismatched pair"),
+        };
+
+        Ok(Secret::new(shared_secret))
+    }
+}
+
+// `pqcrypto_mlkem`'s `SecretKey` types don't expose mutable access to their
+// backing buffer, so there's no safe way to zeroize one in place the way
+// `Secret<Vec<u8>>` zeroizes `PQCKeyPair::private_key`; never `Debug`-print
+// the bytes themselves is the mitigation available here.
+impl std::fmt::Debug for MlKemSecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MlKemSecretKey")
+            .field("algorithm", &self.algorithm)
+            .field("bytes", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl TryFrom<&[u8]> for MlKemSecretKey {
+    type Error = PQCError;
+
+    fn try_from(bytes: &[u8]) -> PQCResult<Self> {
+        Self::from_bytes(PQCAlgorithm::MlKem768, bytes)
+    }
+}
+
+pub fn mlkem_encapsulate_for_algorithm(
+    algorithm: PQCAlgorithm,
+    public_key: &[u8],
+    _message: &[u8],
+) -> PQCResult<PQCEncryptionResult> {
+    MlKemPublicKey::from_bytes(algorithm, public_key)?.encapsulate()
+}
+
+pub fn mlkem_encapsulate(
+    public_key: &MlKemPublicKey,
+    _message: &[u8],
+) -> PQCResult<PQCEncryptionResult> {
+    public_key.encapsulate()
+}
+
+pub fn mlkem_decapsulate_for_algorithm(
+    algorithm: PQCAlgorithm,
+    private_key: &[u8],
+    ciphertext: &[u8],
+) -> PQCResult<Secret<Vec<u8>>> {
+    let secret_key = MlKemSecretKey::from_bytes(algorithm, private_key)?;
+    let ciphertext = MlKemCiphertext::from_bytes(algorithm, ciphertext)?;
+    secret_key.decapsulate(&ciphertext)
+}
+
+pub fn mlkem_decapsulate(
+    private_key: &MlKemSecretKey,
+    ciphertext: &MlKemCiphertext,
+) -> PQCResult<Secret<Vec<u8>>> {
+    private_key.decapsulate(ciphertext)
+}
+
+/// FIPS 204 caps the context string carried by `ML-DSA.Sign`/`ML-DSA.Verify`
+/// (and their HashML-DSA counterparts) at 255 bytes, since its length is
+/// encoded as a single `IntegerToBytes(.., 1)` byte ahead of the context
+/// itself.
+const MLDSA_MAX_CONTEXT_LEN: usize = 255;
+
+/// DER encoding of the SHA-512 object identifier (2.16.840.1.101.3.4.2.3),
+/// used as the `OID` prefix HashML-DSA (FIPS 204 Algorithm 4) requires ahead
+/// of the pre-hashed message, per FIPS 204 Table 1.
+const SHA512_OID: &[u8] = &[0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03];
+
+/// Builds the `M'` input FIPS 204 signs/verifies instead of the raw message,
+/// per Algorithm 2/4's `M' = IntegerToBytes(is_prehash, 1) ||
+/// IntegerToBytes(|ctx|, 1) || ctx || payload`. `pqcrypto_mldsa`'s
+/// `sign`/`detached_sign`/`open`/`verify_detached_signature` take no context
+/// parameter of their own, so this is hand-built here and handed to those
+/// functions as if it were the plain message -- `payload` is the raw message
+/// for the pure variant, or `OID || PH(message)` for the pre-hash variant.
+fn build_context_message(is_prehash: bool, context: &[u8], payload: &[u8]) -> PQCResult<Vec<u8>> {
+    if context.len() > MLDSA_MAX_CONTEXT_LEN {
+        return Err(PQCError::SigningFailed(format!(
+            "ML-DSA context string must be at most {MLDSA_MAX_CONTEXT_LEN} bytes, got {}",
+            context.len()
+        )));
+    }
+
+    let mut m_prime = Vec::with_capacity(2 + context.len() + payload.len());
+    m_prime.push(is_prehash as u8);
+    m_prime.push(context.len() as u8);
+    m_prime.extend_from_slice(context);
+    m_prime.extend_from_slice(payload);
+    Ok(m_prime)
+}
+
+enum MlDsaSecretKeyInner {
+    MlDsa44(mldsa44::SecretKey),
+    MlDsa65(mldsa65::SecretKey),
+    MlDsa87(mldsa87::SecretKey),
+}
+
+/// An ML-DSA secret key already parsed and accepted by `pqcrypto_mldsa` for
+/// a specific [`PQCAlgorithm`]. Mirrors the secp256k1-style redesign where
+/// `SecretKey` validates on construction: once an `MlDsaSecretKey` exists,
+/// `sign` can no longer fail on account of the key, so callers only have to
+/// handle key-parsing errors once, up front, rather than on every signing
+/// call.
+pub struct MlDsaSecretKey {
+    algorithm: PQCAlgorithm,
+    inner: MlDsaSecretKeyInner,
+}
+
+impl MlDsaSecretKey {
+    pub fn from_bytes(algorithm: PQCAlgorithm, bytes: &[u8]) -> PQCResult<Self> {
+        if !algorithm.is_signature() {
+            return Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()));
+        }
+
+        if bytes.len() != algorithm.secret_key_size() {
+            return Err(PQCError::InvalidPrivateKey(format!(
+                "{} secret key must be {} bytes, got {}",
+                algorithm.name(),
+                algorithm.secret_key_size(),
+                bytes.len()
+            )));
+        }
+
+        let parse_error = || {
+            PQCError::InvalidPrivateKey(format!("Failed to parse {} private key", algorithm.name()))
+        };
+
+        let inner = match algorithm {
+            PQCAlgorithm::MlDsa44 => MlDsaSecretKeyInner::MlDsa44(
+                mldsa44::SecretKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlDsa65 => MlDsaSecretKeyInner::MlDsa65(
+                mldsa65::SecretKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlDsa87 => MlDsaSecretKeyInner::MlDsa87(
+                mldsa87::SecretKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem512 | PQCAlgorithm::MlKem768 | PQCAlgorithm::MlKem1024 => unreachable!(
+                "is_signature() already rejected the KEM variants above"
+            ),
+        };
+
+        Ok(Self { algorithm, inner })
+    }
+
+    pub fn algorithm(&self) -> PQCAlgorithm {
+        self.algorithm
+    }
+
+    /// Signs `message`. Infallible: construction already confirmed
+    /// `pqcrypto_mldsa` accepts the key, and the underlying `sign`
+    /// functions never return a `Result`.
+    pub fn sign(&self, message: &[u8]) -> PQCSignature {
+        let current_time = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let signature = match &self.inner {
+            MlDsaSecretKeyInner::MlDsa44(sk) => mldsa44::sign(message, sk).as_bytes().to_vec(),
+            MlDsaSecretKeyInner::MlDsa65(sk) => mldsa65::sign(message, sk).as_bytes().to_vec(),
+            MlDsaSecretKeyInner::MlDsa87(sk) => mldsa87::sign(message, sk).as_bytes().to_vec(),
+        };
+
+        PQCSignature {
+            signature_size: signature.len(),
+            signature: Secret::new(signature),
+            algorithm: self.algorithm.name().to_string(),
+            created_at: current_time,
+        }
+    }
+
+    /// Signs `message` and returns only the fixed-size detached signature,
+    /// not a `SignedMessage` embedding a copy of `message` alongside it.
+    /// Like secp256k1 keeping the signature separate from the message it
+    /// covers, this halves the bytes transmitted for large messages versus
+    /// [`MlDsaSecretKey::sign`] when the caller already stores the message
+    /// next to (not inside) the signature. Infallible for the same reason
+    /// `sign` is.
+    pub fn sign_detached(&self, message: &[u8]) -> Vec<u8> {
+        match &self.inner {
+            MlDsaSecretKeyInner::MlDsa44(sk) => {
+                mldsa44::detached_sign(message, sk).as_bytes().to_vec()
+            }
+            MlDsaSecretKeyInner::MlDsa65(sk) => {
+                mldsa65::detached_sign(message, sk).as_bytes().to_vec()
+            }
+            MlDsaSecretKeyInner::MlDsa87(sk) => {
+                mldsa87::detached_sign(message, sk).as_bytes().to_vec()
+            }
+        }
+    }
+
+    /// FIPS 204 `ML-DSA.Sign` with an explicit context string, bound into
+    /// the signed message as `build_context_message` describes so a
+    /// signature produced under one context can't be replayed as valid
+    /// under another. An empty `context` skips the wrapping and calls
+    /// [`Self::sign`] directly, so this is byte-for-byte compatible with
+    /// existing context-less signatures rather than merely similar to them.
+    pub fn sign_with_context(&self, message: &[u8], context: &[u8]) -> PQCResult<PQCSignature> {
+        if context.is_empty() {
+            return Ok(self.sign(message));
+        }
+
+        let m_prime = build_context_message(false, context, message)?;
+        Ok(self.sign(&m_prime))
+    }
+
+    /// HashML-DSA (FIPS 204 Algorithm 4) using SHA-512 as the approved
+    /// pre-hash function: signs `OID(SHA-512) || SHA-512(message)` wrapped
+    /// the same way [`Self::sign_with_context`] wraps a plain message, so a
+    /// caller that has already hashed a large or streamed message doesn't
+    /// have to hand the raw bytes across the FFI boundary.
+    pub fn sign_prehash(&self, message: &[u8], context: &[u8]) -> PQCResult<PQCSignature> {
+        let digest = Sha512::digest(message);
+        let mut payload = Vec::with_capacity(SHA512_OID.len() + digest.len());
+        payload.extend_from_slice(SHA512_OID);
+        payload.extend_from_slice(&digest);
+
+        let m_prime = build_context_message(true, context, &payload)?;
+        Ok(self.sign(&m_prime))
+    }
+}
+
+impl TryFrom<&[u8]> for MlDsaSecretKey {
+    type Error = PQCError;
+
+    fn try_from(bytes: &[u8]) -> PQCResult<Self> {
+        Self::from_bytes(PQCAlgorithm::MlDsa65, bytes)
+    }
+}
+
+// See `MlKemSecretKey`'s `Debug` impl: `pqcrypto_mldsa`'s `SecretKey` can't
+// be zeroized in place either, so redacting the bytes here is what keeps
+// them out of a stray log line.
+impl std::fmt::Debug for MlDsaSecretKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MlDsaSecretKey")
+            .field("algorithm", &self.algorithm)
+            .field("bytes", &"[REDACTED]")
+            .finish()
+    }
+}
+
+enum MlDsaPublicKeyInner {
+    MlDsa44(mldsa44::PublicKey),
+    MlDsa65(mldsa65::PublicKey),
+    MlDsa87(mldsa87::PublicKey),
+}
+
+/// An ML-DSA public key already parsed and length-checked for a specific
+/// [`PQCAlgorithm`], mirroring [`MlDsaSecretKey`] on the verification side.
+pub struct MlDsaPublicKey {
+    algorithm: PQCAlgorithm,
+    inner: MlDsaPublicKeyInner,
+}
+
+impl MlDsaPublicKey {
+    pub fn from_bytes(algorithm: PQCAlgorithm, bytes: &[u8]) -> PQCResult<Self> {
+        if !algorithm.is_signature() {
+            return Err(PQCError::UnsupportedAlgorithm(algorithm.name().to_string()));
+        }
+
+        if bytes.len() != algorithm.public_key_size() {
+            return Err(PQCError::InvalidPublicKey(format!(
+                "{} public key must be {} bytes, got {}",
+                algorithm.name(),
+                algorithm.public_key_size(),
+                bytes.len()
+            )));
+        }
+
+        let parse_error = || {
+            PQCError::InvalidPublicKey(format!("Failed to parse {} public key", algorithm.name()))
+        };
+
+        let inner = match algorithm {
+            PQCAlgorithm::MlDsa44 => MlDsaPublicKeyInner::MlDsa44(
+                mldsa44::PublicKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlDsa65 => MlDsaPublicKeyInner::MlDsa65(
+                mldsa65::PublicKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlDsa87 => MlDsaPublicKeyInner::MlDsa87(
+                mldsa87::PublicKey::from_bytes(bytes).map_err(|_| parse_error())?,
+            ),
+            PQCAlgorithm::MlKem512 | PQCAlgorithm::MlKem768 | PQCAlgorithm::MlKem1024 => unreachable!(
+                "is_signature() already rejected the KEM variants above"
+            ),
+        };
+
+        Ok(Self { algorithm, inner })
+    }
+
+    pub fn algorithm(&self) -> PQCAlgorithm {
+        self.algorithm
+    }
+
+    /// Verifies `signature` over `message`. The signature itself stays a
+    /// raw slice: a `SignedMessage` embeds the signed message alongside the
+    /// signature bytes, so unlike a key or a ciphertext it has no fixed
+    /// length to validate up front.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> PQCResult<bool> {
+        let sig_error = || {
+            PQCError::InvalidSignature(format!(
+                "Failed to parse {} signature",
+                self.algorithm.name()
+            ))
+        };
+
+        match &self.inner {
+            MlDsaPublicKeyInner::MlDsa44(pk) => {
+                let signed_msg =
+                    mldsa44::SignedMessage::from_bytes(signature).map_err(|_| sig_error())?;
+                match mldsa44::open(&signed_msg, pk) {
+                    Ok(opened) => Ok(bool::from(opened.as_slice().ct_eq(message))),
+                    Err(_) => Ok(false),
+                }
+            }
+            MlDsaPublicKeyInner::MlDsa65(pk) => {
+                let signed_msg =
+                    mldsa65::SignedMessage::from_bytes(signature).map_err(|_| sig_error())?;
+                match mldsa65::open(&signed_msg, pk) {
+                    Ok(opened) => Ok(bool::from(opened.as_slice().ct_eq(message))),
+                    Err(_) => Ok(false),
+                }
+            }
+            MlDsaPublicKeyInner::MlDsa87(pk) => {
+                let signed_msg =
+                    mldsa87::SignedMessage::from_bytes(signature).map_err(|_| sig_error())?;
+                match mldsa87::open(&signed_msg, pk) {
+                    Ok(opened) => Ok(bool::from(opened.as_slice().ct_eq(message))),
+                    Err(_) => Ok(false),
+                }
+            }
+        }
+    }
+
+    /// Verifies a detached `signature` (produced by
+    /// [`MlDsaSecretKey::sign_detached`]) over `message`, without a
+    /// `SignedMessage` to extract the plaintext from first.
+    pub fn verify_detached(&self, message: &[u8], signature: &[u8]) -> PQCResult<bool> {
+        let sig_error = || {
+            PQCError::InvalidSignature(format!(
+                "Failed to parse {} detached signature",
+                self.algorithm.name()
+            ))
+        };
+
+        match &self.inner {
+            MlDsaPublicKeyInner::MlDsa44(pk) => {
+                let detached = mldsa44::DetachedSignature::from_bytes(signature)
+                    .map_err(|_| sig_error())?;
+                Ok(mldsa44::verify_detached_signature(&detached, message, pk).is_ok())
+            }
+            MlDsaPublicKeyInner::MlDsa65(pk) => {
+                let detached = mldsa65::DetachedSignature::from_bytes(signature)
+                    .map_err(|_| sig_error())?;
+                Ok(mldsa65::verify_detached_signature(&detached, message, pk).is_ok())
+            }
+            MlDsaPublicKeyInner::MlDsa87(pk) => {
+                let detached = mldsa87::DetachedSignature::from_bytes(signature)
+                    .map_err(|_| sig_error())?;
+                Ok(mldsa87::verify_detached_signature(&detached, message, pk).is_ok())
+            }
+        }
+    }
+
+    /// Verifies a signature produced by
+    /// [`MlDsaSecretKey::sign_with_context`] over `message` under the same
+    /// `context`. An empty `context` skips the wrapping and calls
+    /// [`Self::verify`] directly, matching the same fast path
+    /// `sign_with_context` takes. A signature bound to a different context
+    /// (including the empty one) won't verify here, since the wrapped `M'`
+    /// it was actually signed over won't match.
+    pub fn verify_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> PQCResult<bool> {
+        if context.is_empty() {
+            return self.verify(message, signature);
+        }
+
+        let m_prime = build_context_message(false, context, message)?;
+        self.verify(&m_prime, signature)
+    }
+
+    /// Verifies a signature produced by [`MlDsaSecretKey::sign_prehash`]
+    /// over `message` under the same `context`. A pre-hash signature will
+    /// not verify under [`Self::verify`]/[`Self::verify_with_context`] (and
+    /// vice versa): the `is_prehash` byte each wraps into `M'` differs, so
+    /// the two modes sign distinct messages even for identical input bytes.
+    pub fn verify_prehash(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &[u8],
+    ) -> PQCResult<bool> {
+        let digest = Sha512::digest(message);
+        let mut payload = Vec::with_capacity(SHA512_OID.len() + digest.len());
+        payload.extend_from_slice(SHA512_OID);
+        payload.extend_from_slice(&digest);
+
+        let m_prime = build_context_message(true, context, &payload)?;
+        self.verify(&m_prime, signature)
+    }
+}
+
+impl TryFrom<&[u8]> for MlDsaPublicKey {
+    type Error = PQCError;
+
+    fn try_from(bytes: &[u8]) -> PQCResult<Self> {
+        Self::from_bytes(PQCAlgorithm::MlDsa65, bytes)
+    }
+}
+
+impl std::fmt::Debug for MlDsaPublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MlDsaPublicKey")
+            .field("algorithm", &self.algorithm)
+            .field("bytes", &format!("[{} bytes]", self.algorithm.public_key_size()))
+            .finish()
+    }
+}
+
+pub fn mldsa_sign_for_level(
+    level: MLDSALevel,
+    private_key: &[u8],
+    message: &[u8],
+) -> PQCResult<PQCSignature> {
+    Ok(MlDsaSecretKey::from_bytes(PQCAlgorithm::from(level), private_key)?.sign(message))
+}
+
+pub fn mldsa_sign(private_key: &MlDsaSecretKey, message: &[u8]) -> PQCResult<PQCSignature> {
+    Ok(private_key.sign(message))
+}
+
+pub fn mldsa_verify_for_level(
+    level: MLDSALevel,
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> PQCResult<bool> {
+    MlDsaPublicKey::from_bytes(PQCAlgorithm::from(level), public_key)?.verify(message, signature)
+}
+
+pub fn mldsa_verify(public_key: &MlDsaPublicKey, message: &[u8], signature: &[u8]) -> PQCResult<bool> {
+    public_key.verify(message, signature)
+}
+
+pub fn mldsa_sign_ctx_for_level(
+    level: MLDSALevel,
+    private_key: &[u8],
+    message: &[u8],
+    context: &[u8],
+) -> PQCResult<PQCSignature> {
+    MlDsaSecretKey::from_bytes(PQCAlgorithm::from(level), private_key)?
+        .sign_with_context(message, context)
+}
+
+pub fn mldsa_verify_ctx_for_level(
+    level: MLDSALevel,
+    public_key: &[u8],
+    message: &[u8],
+    context: &[u8],
+    signature: &[u8],
+) -> PQCResult<bool> {
+    MlDsaPublicKey::from_bytes(PQCAlgorithm::from(level), public_key)?
+        .verify_with_context(message, context, signature)
+}
+
+pub fn mldsa_sign_prehash_for_level(
+    level: MLDSALevel,
+    private_key: &[u8],
+    message: &[u8],
+    context: &[u8],
+) -> PQCResult<PQCSignature> {
+    MlDsaSecretKey::from_bytes(PQCAlgorithm::from(level), private_key)?.sign_prehash(message, context)
+}
+
+pub fn mldsa_verify_prehash_for_level(
+    level: MLDSALevel,
+    public_key: &[u8],
+    message: &[u8],
+    context: &[u8],
+    signature: &[u8],
+) -> PQCResult<bool> {
+    MlDsaPublicKey::from_bytes(PQCAlgorithm::from(level), public_key)?
+        .verify_prehash(message, context, signature)
+}
+
+pub fn mldsa_sign_detached_for_level(
+    level: MLDSALevel,
+    private_key: &[u8],
+    message: &[u8],
+) -> PQCResult<Vec<u8>> {
+    Ok(MlDsaSecretKey::from_bytes(PQCAlgorithm::from(level), private_key)?.sign_detached(message))
+}
+
+pub fn mldsa_verify_detached_for_level(
+    level: MLDSALevel,
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> PQCResult<bool> {
+    MlDsaPublicKey::from_bytes(PQCAlgorithm::from(level), public_key)?
+        .verify_detached(message, signature)
+}
+
 pub mod mlkem {
-    use super::{PQCKeyPair, PQCResult};
+    use super::{MlKemCiphertext, MlKemPublicKey, MlKemSecretKey, PQCKeyPair, PQCResult};
     use secrecy::ExposeSecret;
 
     pub fn generate_keypair() -> PQCResult<PQCKeyPair> {
@@ -259,7 +1392,8 @@ pub mod mlkem {
     }
 
     pub fn encapsulate(public_key: &[u8], message: &[u8]) -> PQCResult<(Vec<u8>, Vec<u8>)> {
-        let result = super::mlkem_encapsulate(public_key, message)?;
+        let public_key = MlKemPublicKey::try_from(public_key)?;
+        let result = super::mlkem_encapsulate(&public_key, message)?;
         Ok((
             result.ciphertext,
             result.shared_secret.expose_secret().clone(),
@@ -267,13 +1401,15 @@ pub mod mlkem {
     }
 
     pub fn decapsulate(private_key: &[u8], ciphertext: &[u8]) -> PQCResult<Vec<u8>> {
-        let secret = super::mlkem_decapsulate(private_key, ciphertext)?;
+        let private_key = MlKemSecretKey::try_from(private_key)?;
+        let ciphertext = MlKemCiphertext::try_from(ciphertext)?;
+        let secret = super::mlkem_decapsulate(&private_key, &ciphertext)?;
         Ok(secret.expose_secret().clone())
     }
 }
 
 pub mod mldsa {
-    use super::{PQCKeyPair, PQCResult};
+    use super::{MlDsaPublicKey, MlDsaSecretKey, PQCKeyPair, PQCResult};
     use secrecy::ExposeSecret;
 
     pub fn generate_keypair() -> PQCResult<PQCKeyPair> {
@@ -281,12 +1417,28 @@ pub mod mldsa {
     }
 
     pub fn sign(private_key: &[u8], message: &[u8]) -> PQCResult<Vec<u8>> {
-        let result = super::mldsa_sign(private_key, message)?;
+        let private_key = MlDsaSecretKey::try_from(private_key)?;
+        let result = super::mldsa_sign(&private_key, message)?;
         Ok(result.signature.expose_secret().clone())
     }
 
     pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> PQCResult<bool> {
-        super::mldsa_verify(public_key, message, signature)
+        let public_key = MlDsaPublicKey::try_from(public_key)?;
+        super::mldsa_verify(&public_key, message, signature)
+    }
+}
+
+/// Serializes `value` to JSON and hands the caller an owned C string,
+/// the same as `CString::new(value.to_string())...into_raw()`, except the
+/// intermediate JSON `String` is a `Zeroizing` buffer scrubbed as soon as
+/// its bytes are copied into the `CString`'s own allocation. Use this
+/// instead of the plain pattern whenever `value` embeds a `private_key`
+/// or `shared_secret`.
+fn secret_json_to_cstring(value: &serde_json::Value) -> *mut c_char {
+    let json_str = Zeroizing::new(value.to_string());
+    match CString::new(json_str.as_bytes()) {
+        Ok(c_string) => c_string.into_raw(),
+        Err(_) => std::ptr::null_mut(),
     }
 }
 
@@ -302,11 +1454,7 @@ pub extern "C" fn pqc_ml_kem_768_keygen() -> *mut c_char {
                 "key_size": keypair.key_size,
                 "security_level": keypair.security_level
             });
-            let json_str = result.to_string();
-            match CString::new(json_str) {
-                Ok(c_string) => c_string.into_raw(),
-                Err(_) => std::ptr::null_mut(),
-            }
+            secret_json_to_cstring(&result)
         }
         Err(e) => {
             let error_result = serde_json::json!({
@@ -334,7 +1482,22 @@ pub unsafe extern "C" fn pqc_ml_kem_768_encaps(
     let public_key_slice = std::slice::from_raw_parts(public_key, public_key_len);
     let dummy_message = b"";
 
-    match mlkem_encapsulate(public_key_slice, dummy_message) {
+    let public_key = match MlKemPublicKey::try_from(public_key_slice) {
+        Ok(public_key) => public_key,
+        Err(e) => {
+            let error_result = serde_json::json!({
+                "success": false,
+                "error": e.to_string()
+            });
+            let json_str = error_result.to_string();
+            return match CString::new(json_str) {
+                Ok(c_string) => c_string.into_raw(),
+                Err(_) => std::ptr::null_mut(),
+            };
+        }
+    };
+
+    match mlkem_encapsulate(&public_key, dummy_message) {
         Ok(result) => {
             let json_result = serde_json::json!({
                 "success": true,
@@ -342,11 +1505,7 @@ pub unsafe extern "C" fn pqc_ml_kem_768_encaps(
                 "shared_secret": result.shared_secret.expose_secret(),
                 "algorithm": result.algorithm
             });
-            let json_str = json_result.to_string();
-            match CString::new(json_str) {
-                Ok(c_string) => c_string.into_raw(),
-                Err(_) => std::ptr::null_mut(),
-            }
+            secret_json_to_cstring(&json_result)
         }
         Err(e) => {
             let error_result = serde_json::json!({
@@ -377,17 +1536,42 @@ pub unsafe extern "C" fn pqc_ml_kem_768_decaps(
     let secret_key_slice = std::slice::from_raw_parts(secret_key, secret_key_len);
     let ciphertext_slice = std::slice::from_raw_parts(ciphertext, ciphertext_len);
 
-    match mlkem_decapsulate(secret_key_slice, ciphertext_slice) {
+    let secret_key = match MlKemSecretKey::try_from(secret_key_slice) {
+        Ok(secret_key) => secret_key,
+        Err(e) => {
+            let error_result = serde_json::json!({
+                "success": false,
+                "error": e.to_string()
+            });
+            let json_str = error_result.to_string();
+            return match CString::new(json_str) {
+                Ok(c_string) => c_string.into_raw(),
+                Err(_) => std::ptr::null_mut(),
+            };
+        }
+    };
+    let ciphertext = match MlKemCiphertext::try_from(ciphertext_slice) {
+        Ok(ciphertext) => ciphertext,
+        Err(e) => {
+            let error_result = serde_json::json!({
+                "success": false,
+                "error": e.to_string()
+            });
+            let json_str = error_result.to_string();
+            return match CString::new(json_str) {
+                Ok(c_string) => c_string.into_raw(),
+                Err(_) => std::ptr::null_mut(),
+            };
+        }
+    };
+
+    match mlkem_decapsulate(&secret_key, &ciphertext) {
         Ok(shared_secret) => {
             let json_result = serde_json::json!({
                 "success": true,
                 "shared_secret": shared_secret.expose_secret()
             });
-            let json_str = json_result.to_string();
-            match CString::new(json_str) {
-                Ok(c_string) => c_string.into_raw(),
-                Err(_) => std::ptr::null_mut(),
-            }
+            secret_json_to_cstring(&json_result)
         }
         Err(e) => {
             let error_result = serde_json::json!({
@@ -415,11 +1599,7 @@ pub extern "C" fn pqc_ml_dsa_65_keygen() -> *mut c_char {
                 "key_size": keypair.key_size,
                 "security_level": keypair.security_level
             });
-            let json_str = result.to_string();
-            match CString::new(json_str) {
-                Ok(c_string) => c_string.into_raw(),
-                Err(_) => std::ptr::null_mut(),
-            }
+            secret_json_to_cstring(&result)
         }
         Err(e) => {
             let error_result = serde_json::json!({
@@ -450,7 +1630,22 @@ pub unsafe extern "C" fn pqc_ml_dsa_65_sign(
     let message_slice = std::slice::from_raw_parts(message, message_len);
     let private_key_slice = std::slice::from_raw_parts(private_key, private_key_len);
 
-    match mldsa_sign(private_key_slice, message_slice) {
+    let private_key = match MlDsaSecretKey::try_from(private_key_slice) {
+        Ok(private_key) => private_key,
+        Err(e) => {
+            let error_result = serde_json::json!({
+                "success": false,
+                "error": e.to_string()
+            });
+            let json_str = error_result.to_string();
+            return match CString::new(json_str) {
+                Ok(c_string) => c_string.into_raw(),
+                Err(_) => std::ptr::null_mut(),
+            };
+        }
+    };
+
+    match mldsa_sign(&private_key, message_slice) {
         Ok(signature_result) => {
             let json_result = serde_json::json!({
                 "success": true,
@@ -458,11 +1653,7 @@ pub unsafe extern "C" fn pqc_ml_dsa_65_sign(
                 "algorithm": signature_result.algorithm,
                 "signature_size": signature_result.signature_size
             });
-            let json_str = json_result.to_string();
-            match CString::new(json_str) {
-                Ok(c_string) => c_string.into_raw(),
-                Err(_) => std::ptr::null_mut(),
-            }
+            secret_json_to_cstring(&json_result)
         }
         Err(e) => {
             let error_result = serde_json::json!({
@@ -498,7 +1689,12 @@ pub unsafe extern "C" fn pqc_ml_dsa_65_verify(
     let message_slice = std::slice::from_raw_parts(message, message_len);
     let public_key_slice = std::slice::from_raw_parts(public_key, public_key_len);
 
-    match mldsa_verify(public_key_slice, message_slice, signature_slice) {
+    let public_key = match MlDsaPublicKey::try_from(public_key_slice) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+
+    match mldsa_verify(&public_key, message_slice, signature_slice) {
         Ok(is_valid) => is_valid,
         Err(_) => false,
     }
@@ -585,7 +1781,7 @@ pub unsafe extern "C" fn pqc_key_manager_rotate_key(
         Err(_) => return std::ptr::null_mut(),
     };
 
-    match manager.rotate_key(key_id_str) {
+    match manager.rotate_key(key_id_str, None) {
         Ok(new_key_id) => {
             let result = serde_json::json!({
                 "success": true,
@@ -676,6 +1872,29 @@ mod security_hardening_tests {
         
         assert!(error_msg.contains("Invalid") || error_msg.contains("Error"));
     }
+
+    #[test]
+    fn debug_impls_never_print_secret_key_bytes() {
+        let keypair = generate_mlkem_keypair_for_algorithm(PQCAlgorithm::MlKem768).unwrap();
+        let secret_key =
+            MlKemSecretKey::from_bytes(PQCAlgorithm::MlKem768, keypair.private_key.expose_secret())
+                .unwrap();
+        assert_eq!(
+            format!("{secret_key:?}"),
+            "MlKemSecretKey { algorithm: MlKem768, bytes: \"[REDACTED]\" }"
+        );
+
+        let mldsa_keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let mldsa_secret_key = MlDsaSecretKey::from_bytes(
+            PQCAlgorithm::MlDsa65,
+            mldsa_keypair.private_key.expose_secret(),
+        )
+        .unwrap();
+        assert_eq!(
+            format!("{mldsa_secret_key:?}"),
+            "MlDsaSecretKey { algorithm: MlDsa65, bytes: \"[REDACTED]\" }"
+        );
+    }
 }
 
 #[allow(dead_code)]
@@ -683,11 +1902,11 @@ fn validate_public_key(key: &[u8]) -> PQCResult<()> {
     if key.is_empty() {
         return Err(PQCError::InvalidPublicKey("Empty key data".to_string()));
     }
-    
-    if key.len() < 1184 {
+
+    if key.len() < PQCAlgorithm::MlKem768.public_key_size() {
         return Err(PQCError::InvalidPublicKey("Key too short".to_string()));
     }
-    
+
     Ok(())
 }
 
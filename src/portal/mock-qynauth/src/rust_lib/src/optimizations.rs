@@ -1,10 +1,15 @@
-use std::collections::VecDeque;
+use std::collections::HashMap;
+#[cfg(feature = "cuda")]
+use std::os::raw::c_int;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use rayon::prelude::*;
 use pqcrypto_mlkem::mlkem768::{keypair as mlkem_keypair, PublicKey as MLKEMPublicKey, SecretKey as MLKEMSecretKey};
 use pqcrypto_mldsa::mldsa65::{keypair as mldsa_keypair, PublicKey as MLDSAPublicKey, SecretKey as MLDSASecretKey, DetachedSignature as MLDSASignature, sign_detached, verify_detached};
 use crate::errors::CryptoError;
+use crate::revocation::RevocationFilter;
 
 #[derive(Debug, Clone)]
 pub struct HardwareFeatures {
@@ -35,55 +40,195 @@ impl HardwareFeatures {
     }
 }
 
-pub struct MemoryPool {
-    kyber_buffers: Arc<Mutex<VecDeque<Vec<u8>>>>,
-    dilithium_buffers: Arc<Mutex<VecDeque<Vec<u8>>>>,
+/// Maximum threads the private rayon pool will ever spin up, regardless of
+/// `cpu_cores` or the env override, to avoid oversubscribing a host that also
+/// runs the process-wide rayon pool for other work.
+const MAX_WORKER_THREADS: usize = 32;
+
+/// Mirrors `solana_rayon_threadlimit::get_thread_count`: read the
+/// `PQC_RAYON_THREADS` env override if present and valid, otherwise derive
+/// the thread count from detected CPU cores, clamped to `MAX_WORKER_THREADS`.
+fn determine_worker_thread_count(cpu_cores: usize) -> usize {
+    let requested = std::env::var("PQC_RAYON_THREADS")
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|&threads| threads > 0)
+        .unwrap_or(cpu_cores.max(1));
+
+    requested.min(MAX_WORKER_THREADS)
+}
+
+fn build_worker_pool(thread_count: usize) -> rayon::ThreadPool {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(thread_count)
+        .thread_name(|index| format!("pqc-worker-{index:02}"))
+        .build()
+        .expect("failed to build dedicated PQC rayon thread pool")
+}
+
+/// Clears an object's logical contents while preserving its allocated
+/// capacity, so a [`Recycler`] can hand it back out without reallocating.
+pub trait Reset {
+    fn reset(&mut self);
+}
+
+impl Reset for Vec<u8> {
+    fn reset(&mut self) {
+        self.clear();
+    }
+}
+
+/// Extension point for buffer types that can be page-locked before being
+/// handed to a GPU backend. The default no-op is correct for buffer types
+/// that have nothing to pin; `Vec<u8>` locks its backing allocation with
+/// `mlock` so the pages can't be swapped out while recycled.
+pub trait Pinnable: Reset + Default {
+    fn pin(&mut self) {}
+}
+
+impl Pinnable for Vec<u8> {
+    fn pin(&mut self) {
+        if self.capacity() == 0 {
+            return;
+        }
+        unsafe {
+            libc::mlock(self.as_ptr() as *const libc::c_void, self.capacity());
+        }
+    }
+}
+
+/// Buffer role a pooled allocation is sized and reused for. Keeping a
+/// separate [`Recycler`] per role (rather than one shared pool) avoids
+/// handing a ML-DSA signature buffer to code that expects an ML-KEM
+/// ciphertext, and lets `pool_stats()` report occupancy per role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BufferRole {
+    MlkemPublicKey,
+    MlkemCiphertext,
+    MldsaPublicKey,
+    MldsaSignature,
+    SharedSecret,
+}
+
+impl BufferRole {
+    fn default_capacity(self) -> usize {
+        match self {
+            BufferRole::MlkemPublicKey => 1184,  // ML-KEM-768 public key size
+            BufferRole::MlkemCiphertext => 1088, // ML-KEM-768 ciphertext size
+            BufferRole::MldsaPublicKey => 1952,  // ML-DSA-65 public key size
+            BufferRole::MldsaSignature => 3309,  // ML-DSA-65 signature size
+            BufferRole::SharedSecret => 32,      // ML-KEM-768 shared secret size
+        }
+    }
+}
+
+/// Pool of reusable `T`s, modeled on Solana's `Recycler`/`RecyclerCache`:
+/// `allocate()` hands out a pooled (or freshly defaulted) object wrapped in
+/// a RAII guard that resets it and returns it to the pool on drop.
+pub struct Recycler<T: Pinnable> {
+    pool: Arc<Mutex<Vec<T>>>,
     max_pool_size: usize,
+    pinnable: bool,
 }
 
-impl MemoryPool {
-    pub fn new(max_pool_size: usize) -> Self {
+impl<T: Pinnable> Recycler<T> {
+    pub fn new(max_pool_size: usize, pinnable: bool) -> Self {
         Self {
-            kyber_buffers: Arc::new(Mutex::new(VecDeque::new())),
-            dilithium_buffers: Arc::new(Mutex::new(VecDeque::new())),
+            pool: Arc::new(Mutex::new(Vec::new())),
             max_pool_size,
+            pinnable,
         }
     }
 
-    pub fn get_mlkem_buffer(&self) -> Vec<u8> {
-        let mut buffers = self.kyber_buffers.lock().unwrap();
-        buffers.pop_front().unwrap_or_else(|| {
-            Vec::with_capacity(1184) // ML-KEM-768 public key size
-        })
+    pub fn allocate(&self) -> RecycledBuffer<T> {
+        let mut item = self.pool.lock().unwrap().pop().unwrap_or_default();
+        if self.pinnable {
+            item.pin();
+        }
+        RecycledBuffer {
+            item: Some(item),
+            pool: Arc::clone(&self.pool),
+            max_pool_size: self.max_pool_size,
+        }
     }
 
-    pub fn get_mldsa_buffer(&self) -> Vec<u8> {
-        let mut buffers = self.dilithium_buffers.lock().unwrap();
-        buffers.pop_front().unwrap_or_else(|| {
-            Vec::with_capacity(1952) // ML-DSA-65 public key size
-        })
+    pub fn occupancy(&self) -> usize {
+        self.pool.lock().unwrap().len()
+    }
+}
+
+/// RAII guard returned by [`Recycler::allocate`]. The wrapped object is
+/// reset and pushed back onto the pool when the guard is dropped, unless
+/// the pool is already at `max_pool_size`.
+pub struct RecycledBuffer<T: Pinnable> {
+    item: Option<T>,
+    pool: Arc<Mutex<Vec<T>>>,
+    max_pool_size: usize,
+}
+
+impl<T: Pinnable> Deref for RecycledBuffer<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.item.as_ref().expect("RecycledBuffer item taken before drop")
+    }
+}
+
+impl<T: Pinnable> DerefMut for RecycledBuffer<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.item.as_mut().expect("RecycledBuffer item taken before drop")
     }
+}
 
-    pub fn return_mlkem_buffer(&self, mut buffer: Vec<u8>) {
-        buffer.clear();
-        let mut buffers = self.kyber_buffers.lock().unwrap();
-        if buffers.len() < self.max_pool_size {
-            buffers.push_back(buffer);
+impl<T: Pinnable> Drop for RecycledBuffer<T> {
+    fn drop(&mut self) {
+        if let Some(mut item) = self.item.take() {
+            item.reset();
+            let mut pool = self.pool.lock().unwrap();
+            if pool.len() < self.max_pool_size {
+                pool.push(item);
+            }
         }
     }
+}
+
+/// Typed recyclers for every buffer role the crypto pipeline reuses, in
+/// place of the old one-size-fits-all `MemoryPool`.
+pub struct RecyclerCache {
+    recyclers: HashMap<BufferRole, Recycler<Vec<u8>>>,
+}
+
+impl RecyclerCache {
+    pub fn new(max_pool_size: usize, pinnable: bool) -> Self {
+        let roles = [
+            BufferRole::MlkemPublicKey,
+            BufferRole::MlkemCiphertext,
+            BufferRole::MldsaPublicKey,
+            BufferRole::MldsaSignature,
+            BufferRole::SharedSecret,
+        ];
+
+        let recyclers = roles
+            .into_iter()
+            .map(|role| (role, Recycler::new(max_pool_size, pinnable)))
+            .collect();
+
+        Self { recyclers }
+    }
 
-    pub fn return_mldsa_buffer(&self, mut buffer: Vec<u8>) {
-        buffer.clear();
-        let mut buffers = self.dilithium_buffers.lock().unwrap();
-        if buffers.len() < self.max_pool_size {
-            buffers.push_back(buffer);
+    pub fn get(&self, role: BufferRole) -> RecycledBuffer<Vec<u8>> {
+        let mut buffer = self.recyclers[&role].allocate();
+        if buffer.capacity() == 0 {
+            buffer.reserve(role.default_capacity());
         }
+        buffer
     }
 
-    pub fn pool_stats(&self) -> (usize, usize) {
-        let mlkem_count = self.kyber_buffers.lock().unwrap().len();
-        let mldsa_count = self.dilithium_buffers.lock().unwrap().len();
-        (mlkem_count, mldsa_count)
+    pub fn pool_stats(&self) -> HashMap<BufferRole, usize> {
+        self.recyclers
+            .iter()
+            .map(|(role, recycler)| (*role, recycler.occupancy()))
+            .collect()
     }
 }
 
@@ -99,21 +244,268 @@ pub struct MLDSAKeyPair {
     pub secret_key: MLDSASecretKey,
 }
 
+/// Identifier for a verification key, matching the `String` key IDs used
+/// throughout `key_management`.
+pub type KeyId = String;
+
+/// Maps key IDs to the ML-DSA public key each one verifies against, so a
+/// mixed batch signed by many different identities can be verified without
+/// the caller pre-joining keys to messages (mirrors Solana's slot→pubkey
+/// routing in `verify_shred_cpu`).
+#[derive(Debug, Default, Clone)]
+pub struct VerificationContext {
+    keys: HashMap<KeyId, MLDSAPublicKey>,
+    revocation_filter: Option<Arc<RevocationFilter>>,
+}
+
+impl VerificationContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, key_id: KeyId, public_key: MLDSAPublicKey) {
+        self.keys.insert(key_id, public_key);
+    }
+
+    pub fn get(&self, key_id: &str) -> Option<&MLDSAPublicKey> {
+        self.keys.get(key_id)
+    }
+
+    /// Attaches a revocation cascade so `verify_routed` can reject revoked
+    /// key IDs before they're ever resolved to a public key.
+    pub fn with_revocation_filter(mut self, filter: RevocationFilter) -> Self {
+        self.revocation_filter = Some(Arc::new(filter));
+        self
+    }
+
+    fn is_revoked(&self, key_id: &str) -> bool {
+        self.revocation_filter
+            .as_ref()
+            .is_some_and(|filter| filter.contains(key_id))
+    }
+}
+
+/// Selects where a batch of ML-DSA verifications actually executes.
+///
+/// Mirrors Solana's sigverify CPU/GPU split: small batches (or builds without
+/// the `cuda` feature) always run on the rayon CPU path, while large batches
+/// on a `cuda`-enabled build with a device present are offloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationBackend {
+    Cpu,
+    Gpu,
+}
+
+impl std::fmt::Display for VerificationBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerificationBackend::Cpu => write!(f, "cpu"),
+            VerificationBackend::Gpu => write!(f, "gpu"),
+        }
+    }
+}
+
+/// Marshals a batch of (message, signature, public_key) triples into flat,
+/// contiguous byte buffers plus per-item offsets, suitable for handing to an
+/// extern GPU backend in one call instead of one FFI call per signature.
+#[cfg(feature = "cuda")]
+struct FlatVerificationBatch {
+    messages: Vec<u8>,
+    message_offsets: Vec<(usize, usize)>,
+    signatures: Vec<u8>,
+    signature_offsets: Vec<(usize, usize)>,
+    public_keys: Vec<u8>,
+    public_key_offsets: Vec<(usize, usize)>,
+}
+
+#[cfg(feature = "cuda")]
+impl FlatVerificationBatch {
+    fn build(signatures: &[(Vec<u8>, MLDSASignature, MLDSAPublicKey)]) -> Self {
+        let mut messages = Vec::new();
+        let mut message_offsets = Vec::with_capacity(signatures.len());
+        let mut sigs = Vec::new();
+        let mut signature_offsets = Vec::with_capacity(signatures.len());
+        let mut public_keys = Vec::new();
+        let mut public_key_offsets = Vec::with_capacity(signatures.len());
+
+        for (message, signature, public_key) in signatures {
+            message_offsets.push((messages.len(), message.len()));
+            messages.extend_from_slice(message);
+
+            let sig_bytes = signature.as_bytes();
+            signature_offsets.push((sigs.len(), sig_bytes.len()));
+            sigs.extend_from_slice(sig_bytes);
+
+            let pk_bytes = public_key.as_bytes();
+            public_key_offsets.push((public_keys.len(), pk_bytes.len()));
+            public_keys.extend_from_slice(pk_bytes);
+        }
+
+        Self {
+            messages,
+            message_offsets,
+            signatures: sigs,
+            signature_offsets,
+            public_keys,
+            public_key_offsets,
+        }
+    }
+}
+
+/// Stand-in for the extern GPU verification backend. Real builds would link
+/// against a CUDA sigverify kernel; this returns one 0/1 byte per item so the
+/// call shape (and the CPU fallback it's compared against) is stable.
+#[cfg(feature = "cuda")]
+fn gpu_verify_batch(batch: &FlatVerificationBatch) -> Option<Vec<u8>> {
+    if !gpu_device_available() {
+        return None;
+    }
+
+    extern "C" {
+        fn pqc_cuda_verify_batch(
+            messages: *const u8,
+            message_offsets: *const (usize, usize),
+            signatures: *const u8,
+            signature_offsets: *const (usize, usize),
+            public_keys: *const u8,
+            public_key_offsets: *const (usize, usize),
+            count: usize,
+            results_out: *mut u8,
+        ) -> c_int;
+    }
+
+    let count = batch.message_offsets.len();
+    let mut results = vec![0u8; count];
+
+    let status = unsafe {
+        pqc_cuda_verify_batch(
+            batch.messages.as_ptr(),
+            batch.message_offsets.as_ptr(),
+            batch.signatures.as_ptr(),
+            batch.signature_offsets.as_ptr(),
+            batch.public_keys.as_ptr(),
+            batch.public_key_offsets.as_ptr(),
+            count,
+            results.as_mut_ptr(),
+        )
+    };
+
+    if status != 0 {
+        return None;
+    }
+
+    Some(results)
+}
+
+#[cfg(feature = "cuda")]
+fn gpu_device_available() -> bool {
+    extern "C" {
+        fn pqc_cuda_device_count() -> c_int;
+    }
+
+    unsafe { pqc_cuda_device_count() > 0 }
+}
+
+#[cfg(not(feature = "cuda"))]
+fn gpu_device_available() -> bool {
+    false
+}
+
+/// Result of a batch verification call: the per-item pass/fail flags plus
+/// the indices that failed, in original order, so callers can quarantine
+/// the bad signatures without re-scanning the whole batch.
+#[derive(Debug, Clone, Default)]
+pub struct VerificationReport {
+    pub results: Vec<bool>,
+    pub failed_indices: Vec<usize>,
+}
+
+/// One bucket of the latency histogram, keyed by the batch size a call was
+/// made with.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LatencyBucket {
+    pub call_count: u64,
+    pub total_duration_micros: u64,
+}
+
+/// Atomic pass/fail/signing counters plus a latency histogram bucketed by
+/// batch size, mirroring the `inc_new_counter_debug` counters Solana threads
+/// through its sigverify path so operators can watch throughput live.
+#[derive(Debug, Default)]
+pub struct CryptoMetrics {
+    total_verified: AtomicU64,
+    total_failed: AtomicU64,
+    total_signed: AtomicU64,
+    latency_histogram: Mutex<HashMap<usize, LatencyBucket>>,
+}
+
+impl CryptoMetrics {
+    fn record_verification(&self, batch_size: usize, duration: Duration, verified: u64, failed: u64) {
+        self.total_verified.fetch_add(verified, Ordering::Relaxed);
+        self.total_failed.fetch_add(failed, Ordering::Relaxed);
+        self.record_latency(batch_size, duration);
+    }
+
+    fn record_signing(&self, batch_size: usize, duration: Duration, signed: u64) {
+        self.total_signed.fetch_add(signed, Ordering::Relaxed);
+        self.record_latency(batch_size, duration);
+    }
+
+    fn record_latency(&self, batch_size: usize, duration: Duration) {
+        let mut histogram = self.latency_histogram.lock().unwrap();
+        let bucket = histogram.entry(batch_size).or_default();
+        bucket.call_count += 1;
+        bucket.total_duration_micros += duration.as_micros() as u64;
+    }
+
+    pub fn snapshot(&self) -> CryptoMetricsSnapshot {
+        CryptoMetricsSnapshot {
+            total_verified: self.total_verified.load(Ordering::Relaxed),
+            total_failed: self.total_failed.load(Ordering::Relaxed),
+            total_signed: self.total_signed.load(Ordering::Relaxed),
+            latency_histogram: self.latency_histogram.lock().unwrap().clone(),
+        }
+    }
+}
+
+/// Point-in-time read of [`CryptoMetrics`]'s counters and latency histogram.
+#[derive(Debug, Clone, Default)]
+pub struct CryptoMetricsSnapshot {
+    pub total_verified: u64,
+    pub total_failed: u64,
+    pub total_signed: u64,
+    pub latency_histogram: HashMap<usize, LatencyBucket>,
+}
+
 pub struct OptimizedCrypto {
     hardware_features: HardwareFeatures,
-    memory_pool: MemoryPool,
+    recycler_cache: RecyclerCache,
     use_batch_operations: bool,
+    /// Minimum batch size before `parallel_signature_verification` considers
+    /// offloading to the GPU backend (analogous to `SIGN_SHRED_GPU_MIN`).
+    gpu_min_batch: usize,
+    /// Private pool (named `pqc-worker-NN`) that all batch operations run
+    /// inside, so this crate's parallelism doesn't contend with the rest of
+    /// the host process's global rayon pool.
+    thread_pool: rayon::ThreadPool,
+    worker_thread_count: usize,
+    metrics: CryptoMetrics,
 }
 
 impl OptimizedCrypto {
     pub fn new() -> Self {
         let hardware_features = HardwareFeatures::detect();
         let pool_size = hardware_features.optimal_batch_size() * 2;
-        
+        let worker_thread_count = determine_worker_thread_count(hardware_features.cpu_cores);
+
         Self {
             hardware_features,
-            memory_pool: MemoryPool::new(pool_size),
+            recycler_cache: RecyclerCache::new(pool_size, cfg!(feature = "cuda")),
             use_batch_operations: true,
+            gpu_min_batch: 256,
+            thread_pool: build_worker_pool(worker_thread_count),
+            worker_thread_count,
+            metrics: CryptoMetrics::default(),
         }
     }
 
@@ -122,6 +514,19 @@ impl OptimizedCrypto {
         self
     }
 
+    pub fn with_gpu_min_batch(mut self, gpu_min_batch: usize) -> Self {
+        self.gpu_min_batch = gpu_min_batch;
+        self
+    }
+
+    fn select_verification_backend(&self, batch_len: usize) -> VerificationBackend {
+        if batch_len >= self.gpu_min_batch && gpu_device_available() {
+            VerificationBackend::Gpu
+        } else {
+            VerificationBackend::Cpu
+        }
+    }
+
     pub fn batch_mlkem_key_generation(&self, count: usize) -> Result<Vec<MLKEMKeyPair>, CryptoError> {
         if !self.use_batch_operations || count == 1 {
             return self.single_mlkem_key_generation(count);
@@ -129,21 +534,23 @@ impl OptimizedCrypto {
 
         let start_time = Instant::now();
         let batch_size = self.hardware_features.optimal_batch_size();
-        
-        let keypairs: Result<Vec<_>, _> = (0..count)
-            .collect::<Vec<_>>()
-            .par_chunks(batch_size)
-            .map(|chunk| {
-                chunk.iter().map(|_| {
-                    let (pk, sk) = mlkem_keypair();
-                    Ok(MLKEMKeyPair {
-                        public_key: pk,
-                        secret_key: sk,
-                    })
-                }).collect::<Result<Vec<_>, CryptoError>>()
-            })
-            .collect::<Result<Vec<_>, _>>()
-            .map(|batches| batches.into_iter().flatten().collect());
+
+        let keypairs: Result<Vec<_>, _> = self.thread_pool.install(|| {
+            (0..count)
+                .collect::<Vec<_>>()
+                .par_chunks(batch_size)
+                .map(|chunk| {
+                    chunk.iter().map(|_| {
+                        let (pk, sk) = mlkem_keypair();
+                        Ok(MLKEMKeyPair {
+                            public_key: pk,
+                            secret_key: sk,
+                        })
+                    }).collect::<Result<Vec<_>, CryptoError>>()
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|batches| batches.into_iter().flatten().collect())
+        });
 
         let duration = start_time.elapsed();
         tracing::info!(
@@ -178,21 +585,23 @@ impl OptimizedCrypto {
 
         let start_time = Instant::now();
         let batch_size = self.hardware_features.optimal_batch_size();
-        
-        let keypairs: Result<Vec<_>, _> = (0..count)
-            .collect::<Vec<_>>()
-            .par_chunks(batch_size)
-            .map(|chunk| {
-                chunk.iter().map(|_| {
-                    let (pk, sk) = mldsa_keypair();
-                    Ok(MLDSAKeyPair {
-                        public_key: pk,
-                        secret_key: sk,
-                    })
-                }).collect::<Result<Vec<_>, CryptoError>>()
-            })
-            .collect::<Result<Vec<_>, _>>()
-            .map(|batches| batches.into_iter().flatten().collect());
+
+        let keypairs: Result<Vec<_>, _> = self.thread_pool.install(|| {
+            (0..count)
+                .collect::<Vec<_>>()
+                .par_chunks(batch_size)
+                .map(|chunk| {
+                    chunk.iter().map(|_| {
+                        let (pk, sk) = mldsa_keypair();
+                        Ok(MLDSAKeyPair {
+                            public_key: pk,
+                            secret_key: sk,
+                        })
+                    }).collect::<Result<Vec<_>, CryptoError>>()
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|batches| batches.into_iter().flatten().collect())
+        });
 
         let duration = start_time.elapsed();
         tracing::info!(
@@ -223,15 +632,118 @@ impl OptimizedCrypto {
     pub fn parallel_signature_verification(
         &self,
         signatures: &[(Vec<u8>, MLDSASignature, MLDSAPublicKey)]
-    ) -> Result<Vec<bool>, CryptoError> {
+    ) -> Result<VerificationReport, CryptoError> {
         if signatures.is_empty() {
+            return Ok(VerificationReport::default());
+        }
+
+        let start_time = Instant::now();
+        let batch_size = self.hardware_features.optimal_batch_size();
+        let backend = self.select_verification_backend(signatures.len());
+
+        let results = match backend {
+            VerificationBackend::Gpu => match self.verify_batch_gpu(signatures) {
+                Some(results) => results,
+                None => self.thread_pool.install(|| self.verify_batch_cpu(signatures, batch_size)),
+            },
+            VerificationBackend::Cpu => {
+                self.thread_pool.install(|| self.verify_batch_cpu(signatures, batch_size))
+            }
+        };
+
+        let duration = start_time.elapsed();
+        // `results` comes out of an IndexedParallelIterator collect, so this
+        // ordering matches the caller's original `signatures` ordering.
+        let failed_indices: Vec<usize> = results
+            .iter()
+            .enumerate()
+            .filter(|(_, &ok)| !ok)
+            .map(|(index, _)| index)
+            .collect();
+        let failed_count = failed_indices.len() as u64;
+        let verified_count = results.len() as u64 - failed_count;
+
+        self.metrics.record_verification(batch_size, duration, verified_count, failed_count);
+
+        tracing::info!(
+            operation = "parallel_signature_verification",
+            count = signatures.len(),
+            duration_ms = duration.as_millis(),
+            batch_size = batch_size,
+            backend = %backend,
+            failed = failed_count,
+            "Parallel verification completed"
+        );
+
+        Ok(VerificationReport { results, failed_indices })
+    }
+
+    pub fn batch_sign(
+        &self,
+        keys: &[&MLDSASecretKey],
+        messages: &[Vec<u8>],
+    ) -> Result<Vec<MLDSASignature>, CryptoError> {
+        if keys.len() != messages.len() {
+            return Err(CryptoError::ConfigurationError {
+                parameter: "keys/messages".to_string(),
+                details: format!(
+                    "batch_sign requires equal-length inputs, got {} keys and {} messages",
+                    keys.len(),
+                    messages.len()
+                ),
+            });
+        }
+
+        if keys.is_empty() {
             return Ok(Vec::new());
         }
 
         let start_time = Instant::now();
         let batch_size = self.hardware_features.optimal_batch_size();
 
-        let results: Vec<bool> = if self.use_batch_operations && signatures.len() > batch_size {
+        let signatures = if self.use_batch_operations && keys.len() > batch_size {
+            self.thread_pool.install(|| {
+                keys.par_chunks(batch_size)
+                    .zip(messages.par_chunks(batch_size))
+                    .map(|(key_chunk, message_chunk)| {
+                        key_chunk
+                            .iter()
+                            .zip(message_chunk.iter())
+                            .map(|(key, message)| sign_detached(message, key))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            })
+        } else {
+            keys.iter()
+                .zip(messages.iter())
+                .map(|(key, message)| sign_detached(message, key))
+                .collect()
+        };
+
+        let duration = start_time.elapsed();
+        self.metrics.record_signing(batch_size, duration, keys.len() as u64);
+
+        tracing::info!(
+            operation = "batch_sign",
+            count = keys.len(),
+            duration_ms = duration.as_millis(),
+            batch_size = batch_size,
+            "Batch signing completed"
+        );
+
+        Ok(signatures)
+    }
+
+    fn verify_batch_cpu(
+        &self,
+        signatures: &[(Vec<u8>, MLDSASignature, MLDSAPublicKey)],
+        batch_size: usize,
+    ) -> Vec<bool> {
+        if self.use_batch_operations && signatures.len() > batch_size {
             signatures
                 .par_chunks(batch_size)
                 .map(|chunk| {
@@ -247,26 +759,87 @@ impl OptimizedCrypto {
             signatures.iter().map(|(message, signature, public_key)| {
                 verify_detached(signature, message, public_key).is_ok()
             }).collect()
-        };
+        }
+    }
 
-        let duration = start_time.elapsed();
-        tracing::info!(
-            operation = "parallel_signature_verification",
-            count = signatures.len(),
-            duration_ms = duration.as_millis(),
-            batch_size = batch_size,
-            "Parallel verification completed"
-        );
+    #[cfg(feature = "cuda")]
+    fn verify_batch_gpu(
+        &self,
+        signatures: &[(Vec<u8>, MLDSASignature, MLDSAPublicKey)],
+    ) -> Option<Vec<bool>> {
+        let batch = FlatVerificationBatch::build(signatures);
+        let raw_results = gpu_verify_batch(&batch)?;
+        Some(raw_results.into_iter().map(|byte| byte != 0).collect())
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    fn verify_batch_gpu(
+        &self,
+        _signatures: &[(Vec<u8>, MLDSASignature, MLDSAPublicKey)],
+    ) -> Option<Vec<bool>> {
+        None
+    }
+
+    /// Verifies a batch signed by many different identities, resolving each
+    /// item's expected public key from `ctx` before running the usual
+    /// parallel chunked verification. An item whose key ID isn't in `ctx`,
+    /// or whose key ID is revoked per `ctx`'s revocation filter, is reported
+    /// as a failed verification rather than aborting the batch.
+    pub fn verify_routed(
+        &self,
+        ctx: &VerificationContext,
+        items: &[(KeyId, Vec<u8>, MLDSASignature)],
+    ) -> Result<Vec<bool>, CryptoError> {
+        if items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut results = vec![false; items.len()];
+        let mut resolved = Vec::with_capacity(items.len());
+        let mut resolved_indices = Vec::with_capacity(items.len());
+
+        for (index, (key_id, message, signature)) in items.iter().enumerate() {
+            if ctx.is_revoked(key_id) {
+                continue;
+            }
+            if let Some(public_key) = ctx.get(key_id) {
+                resolved.push((message.clone(), *signature, *public_key));
+                resolved_indices.push(index);
+            }
+        }
+
+        if resolved.is_empty() {
+            return Ok(results);
+        }
+
+        let report = self.parallel_signature_verification(&resolved)?;
+        for (result, index) in report.results.into_iter().zip(resolved_indices) {
+            results[index] = result;
+        }
 
         Ok(results)
     }
 
+    pub fn metrics_snapshot(&self) -> CryptoMetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
     pub fn get_hardware_info(&self) -> &HardwareFeatures {
         &self.hardware_features
     }
 
-    pub fn get_memory_pool_stats(&self) -> (usize, usize) {
-        self.memory_pool.pool_stats()
+    /// Number of threads backing the private rayon pool that
+    /// `batch_mlkem_key_generation`, `batch_mldsa_key_generation`, and
+    /// `parallel_signature_verification` run inside of. Derived from
+    /// `get_hardware_info().cpu_cores`, but clamped by `PQC_RAYON_THREADS`
+    /// and `MAX_WORKER_THREADS`, so callers that want to reason about actual
+    /// parallelism should read this instead of `cpu_cores` directly.
+    pub fn effective_thread_count(&self) -> usize {
+        self.worker_thread_count
+    }
+
+    pub fn get_memory_pool_stats(&self) -> HashMap<BufferRole, usize> {
+        self.recycler_cache.pool_stats()
     }
 
     pub fn benchmark_operations(&self) -> Result<OptimizationBenchmark, CryptoError> {
@@ -282,9 +855,9 @@ impl OptimizedCrypto {
         
         let sign_start = Instant::now();
         let test_message = b"benchmark test message";
-        let signatures: Vec<_> = mldsa_keys.iter().map(|keypair| {
-            sign_detached(test_message, &keypair.secret_key)
-        }).collect();
+        let sign_keys: Vec<_> = mldsa_keys.iter().map(|keypair| &keypair.secret_key).collect();
+        let sign_messages: Vec<_> = mldsa_keys.iter().map(|_| test_message.to_vec()).collect();
+        let signatures = self.batch_sign(&sign_keys, &sign_messages)?;
         let signing_time = sign_start.elapsed();
         
         let verify_start = Instant::now();
@@ -303,6 +876,7 @@ impl OptimizedCrypto {
             verification_time,
             total_time,
             hardware_features: self.hardware_features.clone(),
+            metrics: self.metrics_snapshot(),
         })
     }
 }
@@ -321,6 +895,7 @@ pub struct OptimizationBenchmark {
     pub verification_time: Duration,
     pub total_time: Duration,
     pub hardware_features: HardwareFeatures,
+    pub metrics: CryptoMetricsSnapshot,
 }
 
 impl OptimizationBenchmark {
@@ -344,6 +919,17 @@ impl OptimizationBenchmark {
         println!("  Verification (10 operations): {:?}", self.verification_time);
         println!("  Total Time: {:?}", self.total_time);
         println!("  Operations per Second: {:.2}", self.operations_per_second());
+        println!();
+        println!("Crypto Metrics:");
+        println!("  Signatures Verified: {}", self.metrics.total_verified);
+        println!("  Signatures Failed: {}", self.metrics.total_failed);
+        println!("  Signatures Signed: {}", self.metrics.total_signed);
+        for (batch_size, bucket) in &self.metrics.latency_histogram {
+            println!(
+                "  Batch Size {}: {} calls, {}us total",
+                batch_size, bucket.call_count, bucket.total_duration_micros
+            );
+        }
     }
 }
 
@@ -363,21 +949,66 @@ mod tests {
     }
 
     #[test]
-    fn test_memory_pool() {
-        let pool = MemoryPool::new(5);
-        
-        let buffer1 = pool.get_mlkem_buffer();
-        let buffer2 = pool.get_mldsa_buffer();
-        
+    fn test_verification_backend_selection_below_threshold() {
+        let crypto = OptimizedCrypto::new().with_gpu_min_batch(256);
+        assert_eq!(crypto.select_verification_backend(10), VerificationBackend::Cpu);
+    }
+
+    #[test]
+    fn test_verification_backend_selection_without_gpu_falls_back_to_cpu() {
+        // No CUDA device is ever present in this build, so even a batch over
+        // the threshold must still select the CPU backend.
+        let crypto = OptimizedCrypto::new().with_gpu_min_batch(256);
+        assert_eq!(crypto.select_verification_backend(1000), VerificationBackend::Cpu);
+    }
+
+    #[test]
+    fn test_recycler_cache() {
+        let cache = RecyclerCache::new(5, false);
+
+        let buffer1 = cache.get(BufferRole::MlkemPublicKey);
+        let buffer2 = cache.get(BufferRole::MldsaPublicKey);
+
         assert!(buffer1.capacity() >= 1184);
         assert!(buffer2.capacity() >= 1952);
-        
-        pool.return_mlkem_buffer(buffer1);
-        pool.return_mldsa_buffer(buffer2);
-        
-        let (mlkem_count, mldsa_count) = pool.pool_stats();
-        assert_eq!(mlkem_count, 1);
-        assert_eq!(mldsa_count, 1);
+
+        drop(buffer1);
+        drop(buffer2);
+
+        let stats = cache.pool_stats();
+        assert_eq!(stats[&BufferRole::MlkemPublicKey], 1);
+        assert_eq!(stats[&BufferRole::MldsaPublicKey], 1);
+    }
+
+    #[test]
+    fn test_recycled_buffer_reused_without_reallocation() {
+        let recycler: Recycler<Vec<u8>> = Recycler::new(2, false);
+
+        let mut first = recycler.allocate();
+        first.extend_from_slice(&[1, 2, 3]);
+        let capacity = first.capacity();
+        drop(first);
+
+        let second = recycler.allocate();
+        assert_eq!(second.len(), 0);
+        assert_eq!(second.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_worker_thread_count_derived_from_cpu_cores() {
+        assert_eq!(determine_worker_thread_count(4), 4);
+    }
+
+    #[test]
+    fn test_worker_thread_count_clamped_to_max() {
+        assert_eq!(determine_worker_thread_count(9999), MAX_WORKER_THREADS);
+    }
+
+    #[test]
+    fn test_effective_thread_count_matches_pool() {
+        let crypto = OptimizedCrypto::new();
+        let expected = determine_worker_thread_count(crypto.get_hardware_info().cpu_cores);
+        assert_eq!(crypto.effective_thread_count(), expected);
     }
 
     #[test]
@@ -409,9 +1040,102 @@ mod tests {
             (test_message.to_vec(), signature, keypair.public_key)
         }).collect();
         
-        let results = crypto.parallel_signature_verification(&verification_data).unwrap();
-        assert_eq!(results.len(), 3);
-        assert!(results.iter().all(|&result| result));
+        let report = crypto.parallel_signature_verification(&verification_data).unwrap();
+        assert_eq!(report.results.len(), 3);
+        assert!(report.results.iter().all(|&result| result));
+        assert!(report.failed_indices.is_empty());
+    }
+
+    #[test]
+    fn test_batch_sign_and_verify() {
+        let crypto = OptimizedCrypto::new();
+        let mldsa_keys = crypto.batch_mldsa_key_generation(3).unwrap();
+
+        let test_message = b"batch sign test message";
+        let sign_keys: Vec<_> = mldsa_keys.iter().map(|keypair| &keypair.secret_key).collect();
+        let sign_messages: Vec<_> = mldsa_keys.iter().map(|_| test_message.to_vec()).collect();
+        let signatures = crypto.batch_sign(&sign_keys, &sign_messages).unwrap();
+        assert_eq!(signatures.len(), 3);
+
+        let verification_data: Vec<_> = signatures.into_iter().zip(mldsa_keys.iter())
+            .map(|(sig, keypair)| (test_message.to_vec(), sig, keypair.public_key))
+            .collect();
+        let report = crypto.parallel_signature_verification(&verification_data).unwrap();
+        assert!(report.results.iter().all(|&result| result));
+    }
+
+    #[test]
+    fn test_batch_sign_rejects_mismatched_lengths() {
+        let crypto = OptimizedCrypto::new();
+        let mldsa_keys = crypto.batch_mldsa_key_generation(2).unwrap();
+        let sign_keys: Vec<_> = mldsa_keys.iter().map(|keypair| &keypair.secret_key).collect();
+        let sign_messages = vec![b"only one message".to_vec()];
+
+        assert!(crypto.batch_sign(&sign_keys, &sign_messages).is_err());
+    }
+
+    #[test]
+    fn test_parallel_verification_reports_failed_indices() {
+        let crypto = OptimizedCrypto::new();
+        let mldsa_keys = crypto.batch_mldsa_key_generation(3).unwrap();
+        let test_message = b"test message for verification";
+
+        let mut verification_data: Vec<_> = mldsa_keys.iter().map(|keypair| {
+            let signature = sign_detached(test_message, &keypair.secret_key);
+            (test_message.to_vec(), signature, keypair.public_key)
+        }).collect();
+        // Corrupt the middle entry's message so its signature no longer matches.
+        verification_data[1].0 = b"tampered message".to_vec();
+
+        let report = crypto.parallel_signature_verification(&verification_data).unwrap();
+        assert_eq!(report.failed_indices, vec![1]);
+        assert_eq!(report.results, vec![true, false, true]);
+
+        let snapshot = crypto.metrics_snapshot();
+        assert_eq!(snapshot.total_verified, 2);
+        assert_eq!(snapshot.total_failed, 1);
+    }
+
+    #[test]
+    fn test_verify_routed_mixed_identities() {
+        let crypto = OptimizedCrypto::new();
+        let mldsa_keys = crypto.batch_mldsa_key_generation(2).unwrap();
+        let test_message = b"routed verification test message";
+
+        let mut ctx = VerificationContext::new();
+        ctx.insert("signer-a".to_string(), mldsa_keys[0].public_key);
+
+        let valid_signature = sign_detached(test_message, &mldsa_keys[0].secret_key);
+        let other_signature = sign_detached(test_message, &mldsa_keys[1].secret_key);
+
+        let items = vec![
+            ("signer-a".to_string(), test_message.to_vec(), valid_signature),
+            ("unknown-signer".to_string(), test_message.to_vec(), other_signature),
+        ];
+
+        let results = crypto.verify_routed(&ctx, &items).unwrap();
+        assert_eq!(results, vec![true, false]);
+    }
+
+    #[test]
+    fn test_verify_routed_rejects_revoked_signer() {
+        let crypto = OptimizedCrypto::new();
+        let mldsa_keys = crypto.batch_mldsa_key_generation(1).unwrap();
+        let test_message = b"revocation-aware verification test message";
+
+        let filter = RevocationFilter::build(
+            &["signer-a".to_string()],
+            &["signer-b".to_string()],
+        );
+
+        let mut ctx = VerificationContext::new().with_revocation_filter(filter);
+        ctx.insert("signer-a".to_string(), mldsa_keys[0].public_key);
+
+        let signature = sign_detached(test_message, &mldsa_keys[0].secret_key);
+        let items = vec![("signer-a".to_string(), test_message.to_vec(), signature)];
+
+        let results = crypto.verify_routed(&ctx, &items).unwrap();
+        assert_eq!(results, vec![false]);
     }
 
     #[test]
@@ -0,0 +1,166 @@
+//! One-time prekey pool for asynchronous ML-KEM-768 messaging, the same
+//! role X3DH-style prekeys play for classical DH: a device mints a batch
+//! of single-use [`crate::kyber::KyberEngine`] keypairs, publishes the
+//! public halves to a server, and goes offline. Anyone who wants to start
+//! a session with it encapsulates to one of those public keys instead of
+//! waiting for the device to come back online; [`PrekeyStore::consume`]
+//! then decapsulates and immediately destroys that keypair so the same
+//! prekey can never back two different sessions.
+
+use crate::kyber::{create_default_kyber_engine, KyberEngine};
+use crate::{PQCError, PQCKeyPair, PQCResult};
+use secrecy::{ExposeSecret, Secret};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+struct Prekey {
+    keypair: PQCKeyPair,
+    published: bool,
+}
+
+/// A pool of one-time ML-KEM-768 prekeys for a single device/identity.
+/// Not thread-safe on its own -- callers sharing a store across threads
+/// should wrap it the same way [`crate::key_management::SecureKeyManager`]
+/// expects its callers to synchronize access.
+pub struct PrekeyStore {
+    engine: KyberEngine,
+    prekeys: HashMap<String, Prekey>,
+    replenish_threshold: usize,
+}
+
+impl PrekeyStore {
+    /// `replenish_threshold` is the available-prekey count below which
+    /// [`Self::needs_replenishment`] starts returning `true`.
+    pub fn new(replenish_threshold: usize) -> Self {
+        Self {
+            engine: create_default_kyber_engine(),
+            prekeys: HashMap::new(),
+            replenish_threshold,
+        }
+    }
+
+    /// Mints `count` new one-time keypairs and returns their ids, each
+    /// initially unpublished. Fails without minting any if the engine's
+    /// rate limit is hit partway through.
+    pub fn generate_prekeys(&mut self, count: usize) -> PQCResult<Vec<String>> {
+        let keypairs = self.engine.batch_generate_keypairs(count)?;
+
+        let mut ids = Vec::with_capacity(keypairs.len());
+        for keypair in keypairs {
+            let id = Uuid::new_v4().to_string();
+            self.prekeys.insert(id.clone(), Prekey { keypair, published: false });
+            ids.push(id);
+        }
+
+        Ok(ids)
+    }
+
+    /// Returns the `(id, public_key)` of every prekey minted since the
+    /// last [`Self::mark_published`], for the caller to upload to a
+    /// prekey server.
+    pub fn unpublished_keys(&self) -> Vec<(String, Vec<u8>)> {
+        self.prekeys
+            .iter()
+            .filter(|(_, prekey)| !prekey.published)
+            .map(|(id, prekey)| (id.clone(), prekey.keypair.public_key.clone()))
+            .collect()
+    }
+
+    /// Flips every id in `ids` to published. Unknown ids are ignored, so
+    /// a caller can pass back exactly the ids `unpublished_keys` gave it
+    /// without first filtering out ones that were concurrently consumed.
+    pub fn mark_published(&mut self, ids: &[String]) {
+        for id in ids {
+            if let Some(prekey) = self.prekeys.get_mut(id) {
+                prekey.published = true;
+            }
+        }
+    }
+
+    /// Decapsulates `ciphertext` against the private half of prekey `id`,
+    /// then removes that prekey so it can never be reused -- its
+    /// [`PQCKeyPair`] zeroizes on drop like every other secret in this
+    /// crate.
+    pub fn consume(&mut self, id: &str, ciphertext: &[u8]) -> PQCResult<Secret<Vec<u8>>> {
+        let prekey = self
+            .prekeys
+            .get(id)
+            .ok_or_else(|| PQCError::KeyNotFound(format!("Unknown prekey id: {id}")))?;
+
+        let shared_secret = self
+            .engine
+            .decapsulate(prekey.keypair.private_key.expose_secret(), ciphertext)?;
+
+        self.prekeys.remove(id);
+
+        Ok(shared_secret)
+    }
+
+    /// Count of prekeys still available to be encapsulated to: minted but
+    /// not yet consumed, regardless of published state.
+    pub fn available_count(&self) -> usize {
+        self.prekeys.len()
+    }
+
+    /// `true` once [`Self::available_count`] drops below the threshold
+    /// passed to [`Self::new`], signaling that the caller should mint and
+    /// publish another batch.
+    pub fn needs_replenishment(&self) -> bool {
+        self.available_count() < self.replenish_threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_and_consume_round_trips_and_destroys_the_prekey() {
+        let mut store = PrekeyStore::new(5);
+        let ids = store.generate_prekeys(1).unwrap();
+        let id = &ids[0];
+
+        let public_key = store.unpublished_keys().into_iter().find(|(i, _)| i == id).unwrap().1;
+
+        let mut engine = create_default_kyber_engine();
+        let encapsulation = engine.encapsulate(&public_key).unwrap();
+
+        let recovered = store.consume(id, &encapsulation.ciphertext).unwrap();
+        assert_eq!(
+            recovered.expose_secret(),
+            encapsulation.shared_secret.expose_secret()
+        );
+
+        assert!(store.consume(id, &encapsulation.ciphertext).is_err());
+        assert_eq!(store.available_count(), 0);
+    }
+
+    #[test]
+    fn test_unpublished_keys_and_mark_published() {
+        let mut store = PrekeyStore::new(10);
+        let ids = store.generate_prekeys(3).unwrap();
+
+        assert_eq!(store.unpublished_keys().len(), 3);
+
+        store.mark_published(&ids[..2]);
+        let remaining_unpublished = store.unpublished_keys();
+        assert_eq!(remaining_unpublished.len(), 1);
+        assert_eq!(remaining_unpublished[0].0, ids[2]);
+    }
+
+    #[test]
+    fn test_needs_replenishment_tracks_available_count() {
+        let mut store = PrekeyStore::new(3);
+        assert!(store.needs_replenishment());
+
+        let ids = store.generate_prekeys(3).unwrap();
+        assert!(!store.needs_replenishment());
+
+        let public_key = store.unpublished_keys()[0].1.clone();
+        let mut engine = create_default_kyber_engine();
+        let encapsulation = engine.encapsulate(&public_key).unwrap();
+        store.consume(&ids[0], &encapsulation.ciphertext).unwrap();
+
+        assert!(store.needs_replenishment());
+    }
+}
@@ -0,0 +1,251 @@
+//! Multi-level Bloom filter cascade for compact, offline key-revocation
+//! checks, modeled on the CRLite construction Mozilla's cert_storage uses
+//! to ship an entire CRL set to every client as a few hundred KB of bits.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::errors::CryptoError;
+
+/// False-positive rate used for level 0, which is sized to hold the full
+/// revoked set. Later levels use 0.5, the near-optimal rate for encoding a
+/// set whose only job is to correct the previous level's false positives.
+const INITIAL_FALSE_POSITIVE_RATE: f64 = 0.001;
+const CORRECTION_FALSE_POSITIVE_RATE: f64 = 0.5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BloomLevel {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: usize,
+}
+
+impl BloomLevel {
+    fn new(capacity: usize, false_positive_rate: f64) -> Self {
+        let num_bits = optimal_num_bits(capacity, false_positive_rate);
+        let num_hashes = optimal_num_hashes(num_bits, capacity);
+
+        Self {
+            bits: vec![0u64; num_bits.div_ceil(64)],
+            num_bits,
+            num_hashes,
+        }
+    }
+
+    fn insert(&mut self, key_id: &str) {
+        for i in 0..self.num_hashes {
+            let bit = self.bit_index(key_id, i);
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    fn contains(&self, key_id: &str) -> bool {
+        (0..self.num_hashes).all(|i| {
+            let bit = self.bit_index(key_id, i);
+            self.bits[bit / 64] & (1 << (bit % 64)) != 0
+        })
+    }
+
+    fn bit_index(&self, key_id: &str, i: usize) -> usize {
+        let (h1, h2) = double_hash(key_id);
+        let combined = h1.wrapping_add((i as u64).wrapping_mul(h2));
+        (combined % self.num_bits as u64) as usize
+    }
+}
+
+/// Derives two independent 64-bit hashes from a single SHA-256 digest
+/// (Kirsch-Mitzenmacher double hashing), so `num_hashes` bit positions can
+/// be generated without `num_hashes` separate digests.
+fn double_hash(key_id: &str) -> (u64, u64) {
+    let mut hasher = Sha256::new();
+    hasher.update(key_id.as_bytes());
+    let digest = hasher.finalize();
+
+    let h1 = u64::from_le_bytes(digest[0..8].try_into().unwrap());
+    let h2 = u64::from_le_bytes(digest[8..16].try_into().unwrap());
+    (h1, h2 | 1)
+}
+
+fn optimal_num_bits(capacity: usize, false_positive_rate: f64) -> usize {
+    if capacity == 0 {
+        return 64;
+    }
+    let capacity = capacity as f64;
+    let ln2_squared = std::f64::consts::LN_2 * std::f64::consts::LN_2;
+    let bits = -(capacity * false_positive_rate.ln()) / ln2_squared;
+    (bits.ceil() as usize).max(64)
+}
+
+fn optimal_num_hashes(num_bits: usize, capacity: usize) -> usize {
+    if capacity == 0 {
+        return 1;
+    }
+    let k = (num_bits as f64 / capacity as f64) * std::f64::consts::LN_2;
+    (k.round() as usize).clamp(1, 16)
+}
+
+/// CRLite-style multi-level Bloom filter cascade encoding a revoked-key set
+/// `R` against a valid-key set `S`, answering `contains` with zero false
+/// results despite each level individually being a lossy Bloom filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevocationFilter {
+    levels: Vec<BloomLevel>,
+}
+
+impl RevocationFilter {
+    /// Builds the cascade from the full revoked-key set `revoked` (R) and
+    /// the full currently-valid-key set `valid` (S), which must be
+    /// disjoint. Level 0 encodes R; each subsequent level encodes the
+    /// false positives the previous level produced against whichever set
+    /// wasn't just encoded, alternating R and S until a level produces no
+    /// false positives.
+    pub fn build(revoked: &[String], valid: &[String]) -> Self {
+        let mut levels = Vec::new();
+        let mut content: Vec<String> = revoked.to_vec();
+        let mut query_revoked_next = false;
+
+        loop {
+            let false_positive_rate = if levels.is_empty() {
+                INITIAL_FALSE_POSITIVE_RATE
+            } else {
+                CORRECTION_FALSE_POSITIVE_RATE
+            };
+
+            let mut level = BloomLevel::new(content.len(), false_positive_rate);
+            for key_id in &content {
+                level.insert(key_id);
+            }
+
+            let query_set: &[String] = if query_revoked_next { revoked } else { valid };
+            let false_positives: Vec<String> = query_set
+                .iter()
+                .filter(|key_id| level.contains(key_id))
+                .cloned()
+                .collect();
+
+            levels.push(level);
+
+            if false_positives.is_empty() {
+                break;
+            }
+
+            content = false_positives;
+            query_revoked_next = !query_revoked_next;
+        }
+
+        Self { levels }
+    }
+
+    /// Returns whether `key_id` is revoked. A miss at level 0 is a
+    /// certain "not revoked" (Bloom filters have no false negatives); once
+    /// level 0 matches, the parity of how many further levels also match
+    /// before the first miss resolves the cascade's corrections to a
+    /// certain answer.
+    pub fn contains(&self, key_id: &str) -> bool {
+        let Some((first, rest)) = self.levels.split_first() else {
+            return false;
+        };
+
+        if !first.contains(key_id) {
+            return false;
+        }
+
+        let mut depth = 0usize;
+        for level in rest {
+            if level.contains(key_id) {
+                depth += 1;
+            } else {
+                break;
+            }
+        }
+
+        depth % 2 == 0
+    }
+
+    /// Checks `key_id` against the cascade, returning
+    /// `CryptoError::KeyRevoked` when it's found to be revoked so callers
+    /// can propagate the standard error instead of re-deriving it from
+    /// `contains`.
+    pub fn check(&self, key_id: &str, revocation_time: impl Into<String>) -> Result<(), CryptoError> {
+        if self.contains(key_id) {
+            Err(CryptoError::KeyRevoked {
+                key_id: key_id.to_string(),
+                revocation_time: revocation_time.into(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>, CryptoError> {
+        serde_json::to_vec(self).map_err(|e| CryptoError::SerializationError {
+            details: e.to_string(),
+        })
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, CryptoError> {
+        serde_json::from_slice(bytes).map_err(|e| CryptoError::DeserializationError {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_ids(prefix: &str, count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("{prefix}-{i}")).collect()
+    }
+
+    #[test]
+    fn test_revoked_keys_are_always_reported_revoked() {
+        let revoked = key_ids("revoked", 500);
+        let valid = key_ids("valid", 500);
+        let filter = RevocationFilter::build(&revoked, &valid);
+
+        for key_id in &revoked {
+            assert!(filter.contains(key_id), "expected {key_id} to be revoked");
+        }
+    }
+
+    #[test]
+    fn test_valid_keys_are_never_reported_revoked() {
+        let revoked = key_ids("revoked", 500);
+        let valid = key_ids("valid", 500);
+        let filter = RevocationFilter::build(&revoked, &valid);
+
+        for key_id in &valid {
+            assert!(!filter.contains(key_id), "expected {key_id} to be valid");
+        }
+    }
+
+    #[test]
+    fn test_unknown_key_is_not_revoked() {
+        let revoked = key_ids("revoked", 10);
+        let valid = key_ids("valid", 10);
+        let filter = RevocationFilter::build(&revoked, &valid);
+
+        assert!(!filter.contains("never-seen-key"));
+    }
+
+    #[test]
+    fn test_empty_revocation_set() {
+        let filter = RevocationFilter::build(&[], &key_ids("valid", 10));
+        assert!(!filter.contains("valid-0"));
+    }
+
+    #[test]
+    fn test_serialize_roundtrip_preserves_verdicts() {
+        let revoked = key_ids("revoked", 64);
+        let valid = key_ids("valid", 64);
+        let filter = RevocationFilter::build(&revoked, &valid);
+
+        let bytes = filter.serialize().unwrap();
+        let restored = RevocationFilter::deserialize(&bytes).unwrap();
+
+        for key_id in revoked.iter().chain(valid.iter()) {
+            assert_eq!(filter.contains(key_id), restored.contains(key_id));
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Remote key provisioning client, modeled on keystore2's `rkpd_client`:
+//! maintains a local pool of pre-generated, attested key material drawn
+//! from a configurable provisioning endpoint, so
+//! [`crate::key_management::SecureKeyManager::generate_and_store_key`]
+//! can serve a request straight from the pool instead of generating (and
+//! separately attesting) key material on the spot. The pool refills
+//! itself once it drops to `low_water`, and falls back to local
+//! generation whenever it's empty or the endpoint is unreachable.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+/// A server-issued certificate chain attesting to a provisioned key's
+/// origin, leaf-first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttestationChain {
+    pub certificates: Vec<Vec<u8>>,
+}
+
+/// One pre-generated, attested key as shipped by the provisioning
+/// service in a batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvisionedKey {
+    pub public_key: Vec<u8>,
+    pub private_key: Vec<u8>,
+    pub algorithm: String,
+    pub attestation: AttestationChain,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchRequest<'a> {
+    algorithm: &'a str,
+    count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResponse {
+    keys: Vec<ProvisionedKey>,
+}
+
+/// Pool occupancy and serve/refill counters, snapshotted by
+/// [`RkpClient::stats`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RkpPoolStats {
+    pub pool_size: usize,
+    pub served_from_pool: u64,
+    pub served_from_local_fallback: u64,
+    pub refill_failures: u64,
+}
+
+#[derive(Default)]
+struct RkpCounters {
+    served_from_pool: AtomicU64,
+    served_from_local_fallback: AtomicU64,
+    refill_failures: AtomicU64,
+}
+
+/// Configured connection to a provisioning endpoint plus its local pool
+/// of unassigned provisioned keys.
+pub struct RkpClient {
+    endpoint: String,
+    low_water: usize,
+    batch_size: usize,
+    pool: Mutex<Vec<ProvisionedKey>>,
+    counters: RkpCounters,
+}
+
+impl RkpClient {
+    pub fn new(endpoint: String, low_water: usize, batch_size: usize) -> Self {
+        Self {
+            endpoint,
+            low_water,
+            batch_size,
+            pool: Mutex::new(Vec::new()),
+            counters: RkpCounters::default(),
+        }
+    }
+
+    /// Pops one pooled key for `algorithm`, refilling the pool first if
+    /// it's at or below `low_water`. Returns `None` on pool exhaustion or
+    /// endpoint unavailability rather than erroring, so the caller can
+    /// fall back to local generation.
+    pub fn take(&self, algorithm: &str) -> Option<ProvisionedKey> {
+        let needs_refill = self
+            .pool
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .len()
+            <= self.low_water;
+        if needs_refill {
+            self.refill(algorithm);
+        }
+
+        let mut pool = self.pool.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match pool.iter().position(|key| key.algorithm == algorithm) {
+            Some(index) => {
+                self.counters.served_from_pool.fetch_add(1, Ordering::SeqCst);
+                Some(pool.remove(index))
+            }
+            None => {
+                self.counters
+                    .served_from_local_fallback
+                    .fetch_add(1, Ordering::SeqCst);
+                None
+            }
+        }
+    }
+
+    /// Requests a fresh batch from the provisioning endpoint and appends
+    /// it to the pool, logging and giving up gracefully on any network
+    /// or protocol failure instead of surfacing it to the caller.
+    fn refill(&self, algorithm: &str) {
+        let request = BatchRequest {
+            algorithm,
+            count: self.batch_size,
+        };
+
+        let result = ureq::post(&format!("{}/v1/keys:batchGenerate", self.endpoint))
+            .timeout(Duration::from_secs(10))
+            .send_json(&request)
+            .and_then(|response| response.into_json::<BatchResponse>().map_err(Into::into));
+
+        match result {
+            Ok(batch) => {
+                info!(
+                    "RKP refill from {} fetched {} keys for {}",
+                    self.endpoint,
+                    batch.keys.len(),
+                    algorithm
+                );
+                self.pool
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .extend(batch.keys);
+            }
+            Err(e) => {
+                warn!(
+                    "RKP refill from {} failed, falling back to local generation: {}",
+                    self.endpoint, e
+                );
+                self.counters.refill_failures.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// A snapshot of pool occupancy and how many requests have been
+    /// served from the pool vs. fallen back to local generation.
+    pub fn stats(&self) -> RkpPoolStats {
+        RkpPoolStats {
+            pool_size: self
+                .pool
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .len(),
+            served_from_pool: self.counters.served_from_pool.load(Ordering::SeqCst),
+            served_from_local_fallback: self.counters.served_from_local_fallback.load(Ordering::SeqCst),
+            refill_failures: self.counters.refill_failures.load(Ordering::SeqCst),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_falls_back_to_local_when_endpoint_unreachable() {
+        let client = RkpClient::new("http://127.0.0.1:1".to_string(), 2, 4);
+
+        let provisioned = client.take("ML-KEM-768");
+
+        assert!(provisioned.is_none());
+        let stats = client.stats();
+        assert_eq!(stats.served_from_local_fallback, 1);
+        assert_eq!(stats.pool_size, 0);
+        assert!(stats.refill_failures > 0);
+    }
+
+    #[test]
+    fn test_take_serves_from_a_pre_populated_pool_without_refilling() {
+        let client = RkpClient::new("http://127.0.0.1:1".to_string(), 0, 4);
+        client.pool.lock().unwrap().push(ProvisionedKey {
+            public_key: vec![1, 2, 3],
+            private_key: vec![4, 5, 6],
+            algorithm: "ML-KEM-768".to_string(),
+            attestation: AttestationChain {
+                certificates: vec![vec![7, 8, 9]],
+            },
+        });
+
+        let provisioned = client.take("ML-KEM-768").expect("pool had a matching key");
+
+        assert_eq!(provisioned.public_key, vec![1, 2, 3]);
+        let stats = client.stats();
+        assert_eq!(stats.served_from_pool, 1);
+        assert_eq!(stats.pool_size, 0);
+    }
+}
@@ -1,5 +1,9 @@
+// `core::arch` and explicit `alloc::vec::Vec` keep this type usable under
+// `#![no_std]` + `alloc`, e.g. inside a TEE or firmware target that can't
+// pull in the standard library.
+use alloc::vec::Vec;
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 pub struct CacheProtection;
 
@@ -34,7 +38,25 @@ impl CacheProtection {
         }
     }
 
-    #[cfg(not(target_arch = "x86_64"))]
+    // `DC CIVAC` (clean & invalidate by VA to point of coherency) mirrors
+    // `_mm_clflush`'s "evict this line everywhere" semantics; the `DSB SY`
+    // orders it against later memory accesses and the `ISB` flushes the
+    // pipeline, matching `_mm_mfence` on x86_64.
+    #[cfg(target_arch = "aarch64")]
+    pub fn flush_cache_lines(data: &[u8]) {
+        unsafe {
+            for chunk in data.chunks(64) {
+                core::arch::asm!(
+                    "dc civac, {addr}",
+                    addr = in(reg) chunk.as_ptr(),
+                    options(nostack, preserves_flags),
+                );
+            }
+            core::arch::asm!("dsb sy", "isb", options(nostack, preserves_flags));
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
     pub fn flush_cache_lines(_data: &[u8]) {}
 
     #[cfg(target_arch = "x86_64")]
@@ -46,9 +68,33 @@ impl CacheProtection {
         }
     }
 
-    #[cfg(not(target_arch = "x86_64"))]
+    /// `PRFM PLDL1KEEP` prefetches each line into L1 for a normal (non-streaming)
+    /// read, the aarch64 analogue of `_mm_prefetch(..., _MM_HINT_T0)`.
+    #[cfg(target_arch = "aarch64")]
+    pub fn normalize_cache_state(data: &[u8]) {
+        unsafe {
+            for chunk in data.chunks(64) {
+                core::arch::asm!(
+                    "prfm pldl1keep, [{addr}]",
+                    addr = in(reg) chunk.as_ptr(),
+                    options(nostack, preserves_flags, readonly),
+                );
+            }
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
     pub fn normalize_cache_state(_data: &[u8]) {}
 
+    /// Whether `flush_cache_lines`/`normalize_cache_state` actually perform
+    /// hardware cache maintenance on this target, rather than silently
+    /// degrading to a no-op. Callers that require the mitigation (as
+    /// opposed to tolerating best-effort) should check this and log through
+    /// `log_hardware_optimization` instead of assuming success.
+    pub fn is_hardware_hardening_available() -> bool {
+        cfg!(any(target_arch = "x86_64", target_arch = "aarch64"))
+    }
+
     pub fn cache_safe_compare(a: &[u8], b: &[u8]) -> bool {
         if a.len() != b.len() {
             return false;
@@ -176,4 +222,23 @@ mod cache_protection_tests {
         CacheProtection::cache_safe_zero(&mut data);
         assert!(data.iter().all(|&x| x == 0));
     }
+
+    #[test]
+    fn test_hardware_hardening_available_on_known_architectures() {
+        let available = CacheProtection::is_hardware_hardening_available();
+        assert_eq!(
+            available,
+            cfg!(any(target_arch = "x86_64", target_arch = "aarch64"))
+        );
+    }
+
+    #[test]
+    fn test_flush_and_normalize_do_not_corrupt_data() {
+        // Cache maintenance must be transparent to the data it operates on,
+        // on every target (hardened or silently no-op).
+        let data = vec![7u8; 200];
+        CacheProtection::flush_cache_lines(&data);
+        CacheProtection::normalize_cache_state(&data);
+        assert!(data.iter().all(|&x| x == 7));
+    }
 }
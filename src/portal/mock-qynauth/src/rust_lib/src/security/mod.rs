@@ -15,6 +15,13 @@ pub struct SideChannelProtection {
 
 impl SideChannelProtection {
     pub fn new() -> Self {
+        #[cfg(feature = "std")]
+        crate::errors::log_hardware_optimization(
+            "cache_maintenance_instructions",
+            CacheProtection::is_hardware_hardening_available(),
+            None,
+        );
+
         Self {
             constant_time: ConstantTimeOps,
             power_analysis: PowerAnalysisProtection::new(),
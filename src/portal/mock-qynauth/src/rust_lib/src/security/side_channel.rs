@@ -1,4 +1,8 @@
-use std::sync::atomic::{compiler_fence, Ordering};
+// `core::sync::atomic` (rather than `std::sync::atomic`) and explicit
+// `alloc::vec::Vec` keep this type usable under `#![no_std]` + `alloc`, e.g.
+// inside a TEE or firmware target that can't pull in the standard library.
+use alloc::vec::Vec;
+use core::sync::atomic::{compiler_fence, Ordering};
 use subtle::{Choice, ConditionallySelectable};
 
 pub struct ConstantTimeOps;
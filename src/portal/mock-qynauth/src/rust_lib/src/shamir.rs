@@ -0,0 +1,299 @@
+//! Shamir's Secret Sharing over GF(256), used by
+//! [`crate::key_management::SecureKeyManager::split_and_store_key`] to
+//! fragment a private key into `n` shares, any `k` of which reconstruct
+//! it, instead of the key's full bytes sitting in one place.
+//!
+//! Each byte of the secret is treated independently as the constant term
+//! `a₀` of its own random degree-`(k-1)` polynomial over GF(256);
+//! evaluating that polynomial at `n` distinct nonzero x-coordinates
+//! produces each share's y-value for that byte. Reconstruction is
+//! Lagrange interpolation at `x=0` using any `k` of the shares. GF(256)
+//! multiplication/division use log/antilog tables built from the AES
+//! irreducible polynomial `x⁸+x⁴+x³+x+1` (`0x11B`).
+
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::{PQCError, PQCResult};
+
+const GF_POLY: u16 = 0x11B;
+
+struct GfTables {
+    /// `exp[i] = g^i`, extended to `0..=509` so `exp[log(a) + log(b)]`
+    /// never needs a modulo for the `i < 255` case multiplication uses.
+    exp: [u8; 510],
+    log: [u8; 256],
+}
+
+fn build_tables() -> GfTables {
+    let mut exp = [0u8; 510];
+    let mut log = [0u8; 256];
+    let mut x: u16 = 1;
+    for i in 0..255usize {
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= GF_POLY;
+        }
+    }
+    for i in 255..510usize {
+        exp[i] = exp[i - 255];
+    }
+    GfTables { exp, log }
+}
+
+static GF: Lazy<GfTables> = Lazy::new(build_tables);
+
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    let sum = GF.log[a as usize] as usize + GF.log[b as usize] as usize;
+    GF.exp[sum]
+}
+
+fn gf_div(a: u8, b: u8) -> u8 {
+    assert!(b != 0, "GF(256) division by zero");
+    if a == 0 {
+        return 0;
+    }
+    let diff = GF.log[a as usize] as i32 - GF.log[b as usize] as i32;
+    GF.exp[diff.rem_euclid(255) as usize]
+}
+
+/// Evaluates `coeffs` (lowest-degree term first) at `x` over GF(256)
+/// via Horner's method.
+fn eval_poly(coeffs: &[u8], x: u8) -> u8 {
+    let mut result = 0u8;
+    for &coeff in coeffs.iter().rev() {
+        result = gf_mul(result, x) ^ coeff;
+    }
+    result
+}
+
+/// One share of a Shamir-split secret: the polynomial's value at `x`
+/// for every byte of the secret, so `y.len() == secret.len()`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Share {
+    pub x: u8,
+    pub y: Vec<u8>,
+}
+
+/// Splits `secret` into `n` shares, any `k` of which reconstruct it.
+/// `x`-coordinates are assigned `1..=n` so they're guaranteed unique and
+/// nonzero, as required for both share generation and interpolation.
+pub fn split_secret(secret: &[u8], k: u8, n: u8) -> PQCResult<Vec<Share>> {
+    if k == 0 || k > n {
+        return Err(PQCError::InvalidKeyState(format!(
+            "invalid Shamir threshold: k={k} must be in 1..=n (n={n})"
+        )));
+    }
+    if n == 0 {
+        return Err(PQCError::InvalidKeyState(
+            "Shamir share count n must be at least 1".to_string(),
+        ));
+    }
+
+    let mut rng = rand::rngs::OsRng;
+    let mut ys: Vec<Vec<u8>> = vec![Vec::with_capacity(secret.len()); n as usize];
+
+    for &secret_byte in secret {
+        let mut coeffs = Zeroizing::new(Vec::with_capacity(k as usize));
+        coeffs.push(secret_byte);
+        for _ in 1..k {
+            coeffs.push((rng.next_u32() & 0xFF) as u8);
+        }
+
+        for (i, y) in ys.iter_mut().enumerate() {
+            let x = (i + 1) as u8;
+            y.push(eval_poly(&coeffs, x));
+        }
+    }
+
+    Ok(ys
+        .into_iter()
+        .enumerate()
+        .map(|(i, y)| Share { x: (i + 1) as u8, y })
+        .collect())
+}
+
+/// Reconstructs the original secret from any `k` (or more) of its
+/// shares via Lagrange interpolation at `x=0`.
+pub fn reconstruct_secret(shares: &[Share]) -> PQCResult<Vec<u8>> {
+    if shares.is_empty() {
+        return Err(PQCError::InvalidKeyState(
+            "at least one share is required for reconstruction".to_string(),
+        ));
+    }
+
+    let len = shares[0].y.len();
+    let mut seen_x = std::collections::HashSet::new();
+    for share in shares {
+        if share.x == 0 {
+            return Err(PQCError::InvalidKeyState(
+                "share x-coordinate must be nonzero".to_string(),
+            ));
+        }
+        if !seen_x.insert(share.x) {
+            return Err(PQCError::InvalidKeyState(format!(
+                "duplicate share x-coordinate {}",
+                share.x
+            )));
+        }
+        if share.y.len() != len {
+            return Err(PQCError::InvalidKeyState(
+                "shares have mismatched secret lengths".to_string(),
+            ));
+        }
+    }
+
+    let mut secret = Vec::with_capacity(len);
+    for byte_idx in 0..len {
+        let mut acc = 0u8;
+        for (i, share_i) in shares.iter().enumerate() {
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for (j, share_j) in shares.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                numerator = gf_mul(numerator, share_j.x);
+                denominator = gf_mul(denominator, share_i.x ^ share_j.x);
+            }
+            let lagrange_coeff = gf_div(numerator, denominator);
+            acc ^= gf_mul(share_i.y[byte_idx], lagrange_coeff);
+        }
+        secret.push(acc);
+    }
+
+    Ok(secret)
+}
+
+/// A SHA-256 commitment to a [`Share`], computed at split time so a
+/// recipient can detect a malformed or corrupted share before ever
+/// attempting reconstruction, rather than Lagrange interpolation
+/// silently producing a wrong secret ([`reconstruct_secret`] has no way
+/// to tell "wrong shares" apart from "right shares, wrong secret").
+/// True (group-element) Feldman VSS commits to each polynomial
+/// coefficient so shares can be verified without trusting the dealer's
+/// broadcast of the shares themselves; this crate's polynomials are
+/// per-byte over GF(256) rather than over a prime-order group, so
+/// there's no discrete log to exponentiate into. Committing to the
+/// share value directly with a hash gives the same "catch a bad share
+/// early" property, at the cost of trusting the dealer to distribute
+/// the right commitment list alongside the shares.
+pub fn commit_share(share: &Share) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([share.x]);
+    hasher.update(&share.y);
+    hasher.finalize().into()
+}
+
+/// Splits `secret` the same way [`split_secret`] does, additionally
+/// returning a [`commit_share`] commitment for every share so the
+/// dealer can hand each party both their own share and the full
+/// commitment list, letting [`verify_share`] catch a corrupted or
+/// substituted share before [`reconstruct_secret`] ever runs.
+pub fn split_secret_verifiable(
+    secret: &[u8],
+    k: u8,
+    n: u8,
+) -> PQCResult<(Vec<Share>, Vec<[u8; 32]>)> {
+    let shares = split_secret(secret, k, n)?;
+    let commitments = shares.iter().map(commit_share).collect();
+    Ok((shares, commitments))
+}
+
+/// Recomputes `share`'s commitment and checks it appears in
+/// `commitments`, so a recipient can reject a tampered or mismatched
+/// share before contributing it to [`reconstruct_secret`].
+pub fn verify_share(share: &Share, commitments: &[[u8; 32]]) -> bool {
+    commitments.contains(&commit_share(share))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_mul_matches_known_aes_values() {
+        // Textbook AES GF(256) example: 0x53 * 0xCA = 0x01.
+        assert_eq!(gf_mul(0x53, 0xCA), 0x01);
+    }
+
+    #[test]
+    fn test_split_and_reconstruct_round_trips_with_exact_threshold() {
+        let secret = b"a PQC private key's raw bytes, for test purposes".to_vec();
+        let shares = split_secret(&secret, 3, 5).unwrap();
+
+        let reconstructed = reconstruct_secret(&shares[..3]).unwrap();
+        assert_eq!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_reconstruct_works_with_any_k_subset() {
+        let secret = vec![1, 2, 3, 4, 5, 250, 251];
+        let shares = split_secret(&secret, 3, 6).unwrap();
+
+        let subset_a: Vec<Share> = vec![shares[0].clone(), shares[2].clone(), shares[5].clone()];
+        let subset_b: Vec<Share> = vec![shares[1].clone(), shares[3].clone(), shares[4].clone()];
+
+        assert_eq!(reconstruct_secret(&subset_a).unwrap(), secret);
+        assert_eq!(reconstruct_secret(&subset_b).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_fewer_than_k_shares_does_not_reconstruct_correctly() {
+        let secret = vec![42, 17, 9];
+        let shares = split_secret(&secret, 4, 6).unwrap();
+
+        // Below the threshold, interpolation still produces *a* value,
+        // but not the original secret.
+        let reconstructed = reconstruct_secret(&shares[..2]).unwrap();
+        assert_ne!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_split_rejects_k_greater_than_n() {
+        let err = split_secret(&[1, 2, 3], 5, 3).unwrap_err();
+        assert!(matches!(err, PQCError::InvalidKeyState(_)));
+    }
+
+    #[test]
+    fn test_reconstruct_rejects_duplicate_x_coordinates() {
+        let shares = vec![
+            Share { x: 1, y: vec![10] },
+            Share { x: 1, y: vec![20] },
+        ];
+        let err = reconstruct_secret(&shares).unwrap_err();
+        assert!(matches!(err, PQCError::InvalidKeyState(_)));
+    }
+
+    #[test]
+    fn test_verify_share_accepts_genuine_shares_and_rejects_tampering() {
+        let secret = b"verifiable custody test secret".to_vec();
+        let (shares, commitments) = split_secret_verifiable(&secret, 3, 5).unwrap();
+
+        for share in &shares {
+            assert!(verify_share(share, &commitments));
+        }
+
+        let mut tampered = shares[0].clone();
+        tampered.y[0] ^= 0xFF;
+        assert!(!verify_share(&tampered, &commitments));
+    }
+
+    #[test]
+    fn test_verifiable_split_reconstructs_like_split_secret() {
+        let secret = vec![9, 8, 7, 6, 5];
+        let (shares, commitments) = split_secret_verifiable(&secret, 2, 4).unwrap();
+
+        let chosen: Vec<Share> = vec![shares[1].clone(), shares[3].clone()];
+        assert!(chosen.iter().all(|s| verify_share(s, &commitments)));
+        assert_eq!(reconstruct_secret(&chosen).unwrap(), secret);
+    }
+}
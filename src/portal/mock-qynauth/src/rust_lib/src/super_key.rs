@@ -0,0 +1,129 @@
+//! Hierarchical at-rest key wrapping, modeled on Android keystore2's
+//! `super_key.rs`: a user's stored private keys are sealed under a single
+//! "super key" derived from their unlock secret (e.g. a PIN or
+//! passphrase) via HKDF-SHA256 over the secret and a per-user salt, then
+//! used to wrap/unwrap each key with AES-256-GCM. Holding the derived
+//! super key in memory is what "unlocked" means for that user;
+//! [`crate::key_management::SecureKeyManager::lock`] drops it, at which
+//! point stored keys are readable only as [`WrappedSecret`] ciphertext.
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use hkdf::Hkdf;
+use rand::RngCore;
+use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::{PQCError, PQCResult};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const SUPER_KEY_INFO: &[u8] = b"minkalla-pqc-super-key-v1";
+
+/// Per-user salt mixed into super key derivation so two users who pick
+/// the same unlock secret don't end up with the same super key.
+pub type Salt = [u8; SALT_LEN];
+
+/// Generates a fresh random salt for a user's first `unlock`.
+pub fn generate_salt() -> Salt {
+    let mut salt = [0u8; SALT_LEN];
+    rand::rngs::OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+/// An AEAD-sealed secret as it sits at rest: the nonce used to seal it,
+/// plus a ciphertext whose trailing 16 bytes are the GCM authentication
+/// tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WrappedSecret {
+    pub nonce: [u8; NONCE_LEN],
+    pub ciphertext: Vec<u8>,
+}
+
+/// A user's key-wrapping key, derived from their unlock secret and never
+/// persisted.
+pub struct SuperKey(Secret<[u8; 32]>);
+
+impl SuperKey {
+    /// Derives a super key via HKDF-SHA256 over `unlock_secret` and
+    /// `salt`. Deterministic for a given secret/salt pair, so re-deriving
+    /// on every `unlock` is enough; nothing about the key itself needs to
+    /// be stored.
+    pub fn derive(unlock_secret: &[u8], salt: &Salt) -> Self {
+        let hk = Hkdf::<Sha256>::new(Some(salt), unlock_secret);
+        let mut key = [0u8; 32];
+        hk.expand(SUPER_KEY_INFO, &mut key)
+            .expect("HKDF expand into a fixed 32-byte output cannot fail");
+        Self(Secret::new(key))
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new_from_slice(self.0.expose_secret())
+            .expect("derived super key is exactly 32 bytes")
+    }
+
+    /// Seals `plaintext` (e.g. a serialized private key) for storage.
+    pub fn wrap(&self, plaintext: &[u8]) -> PQCResult<WrappedSecret> {
+        let mut nonce_bytes = [0u8; NONCE_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+
+        let ciphertext = self
+            .cipher()
+            .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+            .map_err(|_| PQCError::SecurityValidationFailed("super key wrap failed".to_string()))?;
+
+        Ok(WrappedSecret {
+            nonce: nonce_bytes,
+            ciphertext,
+        })
+    }
+
+    /// Opens a previously wrapped secret back to plaintext bytes.
+    pub fn unwrap(&self, wrapped: &WrappedSecret) -> PQCResult<Secret<Vec<u8>>> {
+        let plaintext = self
+            .cipher()
+            .decrypt(Nonce::from_slice(&wrapped.nonce), wrapped.ciphertext.as_ref())
+            .map_err(|_| {
+                PQCError::SecurityValidationFailed("super key unwrap failed".to_string())
+            })?;
+
+        Ok(Secret::new(plaintext))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_unwrap_round_trip() {
+        let salt = generate_salt();
+        let super_key = SuperKey::derive(b"correct horse battery staple", &salt);
+
+        let wrapped = super_key.wrap(b"top secret private key bytes").unwrap();
+        let unwrapped = super_key.unwrap(&wrapped).unwrap();
+
+        assert_eq!(unwrapped.expose_secret().as_slice(), b"top secret private key bytes");
+    }
+
+    #[test]
+    fn test_derive_is_deterministic_for_same_secret_and_salt() {
+        let salt = generate_salt();
+        let a = SuperKey::derive(b"unlock secret", &salt);
+        let b = SuperKey::derive(b"unlock secret", &salt);
+
+        let wrapped = a.wrap(b"payload").unwrap();
+        assert_eq!(b.unwrap(&wrapped).unwrap().expose_secret(), b"payload");
+    }
+
+    #[test]
+    fn test_unwrap_fails_with_wrong_super_key() {
+        let salt = generate_salt();
+        let correct = SuperKey::derive(b"correct secret", &salt);
+        let wrong = SuperKey::derive(b"wrong secret", &salt);
+
+        let wrapped = correct.wrap(b"payload").unwrap();
+        assert!(wrong.unwrap(&wrapped).is_err());
+    }
+}
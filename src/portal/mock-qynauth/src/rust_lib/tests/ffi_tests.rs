@@ -1,45 +1,60 @@
+// This file originally exercised `kyber_keypair_generate`/`dilithium_sign`
+// and friends -- a `kyber_ffi`/`dilithium_ffi` module pair that was never
+// wired into `ffi/mod.rs` and has since been superseded by the
+// level-parameterized `mlkem_ffi`/`mldsa_ffi` modules (see their module
+// docs). Rewritten below against the live API; the ML-KEM-768/ML-DSA-65
+// sizes asserted match what the old tests expected for the same parameter
+// sets.
 #[cfg(test)]
 mod ffi_tests {
-    use std::ptr;
     use std::ffi::CStr;
+    use std::ptr;
     use libc::size_t;
-    
+
     use qynauth_pqc::ffi::{
-        kyber_keypair_generate, kyber_encapsulate, kyber_decapsulate, kyber_keypair_free,
-        dilithium_keypair_generate, dilithium_sign, dilithium_verify, dilithium_keypair_free,
-        dilithium_buffer_free, memory::FFIErrorCode
+        ffi_buffer_free, ffi_get_last_error_message, hybrid_kem_ciphertext_free,
+        hybrid_kem_decapsulate, hybrid_kem_encapsulate, hybrid_kem_keypair_free,
+        hybrid_kem_keypair_generate, hybrid_kem_shared_secret_free, mldsa_keypair_free,
+        mldsa_keypair_generate, mldsa_sign, mldsa_sign_ctx, mldsa_sign_detached,
+        mldsa_sign_prehash, mldsa_verify, mldsa_verify_ctx, mldsa_verify_detached,
+        mldsa_verify_prehash, mlkem_ciphertext_free, mlkem_decapsulate, mlkem_encapsulate,
+        mlkem_keypair_free, mlkem_keypair_generate, mlkem_param_sizes, mlkem_shared_secret_free,
+        shamir_reconstruct_secret, shamir_split_result_free, shamir_split_secret, CShamirShare,
+        FFIErrorCode, MLDSA44_PUBLIC_KEY_SIZE, MLDSA44_SIGNATURE_SIZE, MLDSA65_PUBLIC_KEY_SIZE,
+        MLDSA65_SIGNATURE_SIZE, MLDSA87_PUBLIC_KEY_SIZE, MLDSA87_SIGNATURE_SIZE,
     };
 
     #[test]
-    fn test_kyber_keypair_generation() {
-        let keypair = kyber_keypair_generate();
+    fn test_mlkem_keypair_generation() {
+        let keypair = mlkem_keypair_generate(768);
         assert!(!keypair.is_null(), "Keypair generation should not return null");
-        
+
         unsafe {
             let keypair_ref = &*keypair;
             assert!(!keypair_ref.public_key_ptr.is_null(), "Public key pointer should not be null");
             assert!(!keypair_ref.secret_key_ptr.is_null(), "Secret key pointer should not be null");
             assert_eq!(keypair_ref.public_key_len, 1184, "ML-KEM-768 public key should be 1184 bytes");
             assert_eq!(keypair_ref.secret_key_len, 2400, "ML-KEM-768 secret key should be 2400 bytes");
-            
-            kyber_keypair_free(keypair);
+
+            mlkem_keypair_free(keypair);
         }
     }
 
     #[test]
-    fn test_kyber_encapsulation_decapsulation() {
-        let keypair = kyber_keypair_generate();
+    fn test_mlkem_encapsulation_decapsulation() {
+        let keypair = mlkem_keypair_generate(768);
         assert!(!keypair.is_null(), "Keypair generation should not return null");
-        
+
         unsafe {
             let keypair_ref = &*keypair;
-            
+
             let mut shared_secret_ptr: *mut u8 = ptr::null_mut();
             let mut shared_secret_len: size_t = 0;
             let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
             let mut ciphertext_len: size_t = 0;
-            
-            let encap_result = kyber_encapsulate(
+
+            let encap_result = mlkem_encapsulate(
+                768,
                 keypair_ref.public_key_ptr,
                 keypair_ref.public_key_len,
                 &mut shared_secret_ptr,
@@ -47,17 +62,18 @@ mod ffi_tests {
                 &mut ciphertext_ptr,
                 &mut ciphertext_len,
             );
-            
+
             assert_eq!(encap_result, FFIErrorCode::Success as i32, "Encapsulation should succeed");
             assert!(!shared_secret_ptr.is_null(), "Shared secret pointer should not be null");
             assert!(!ciphertext_ptr.is_null(), "Ciphertext pointer should not be null");
             assert_eq!(shared_secret_len, 32, "Shared secret should be 32 bytes");
             assert_eq!(ciphertext_len, 1088, "ML-KEM-768 ciphertext should be 1088 bytes");
-            
+
             let mut recovered_secret_ptr: *mut u8 = ptr::null_mut();
             let mut recovered_secret_len: size_t = 0;
-            
-            let decap_result = kyber_decapsulate(
+
+            let decap_result = mlkem_decapsulate(
+                768,
                 keypair_ref.secret_key_ptr,
                 keypair_ref.secret_key_len,
                 ciphertext_ptr,
@@ -65,65 +81,132 @@ mod ffi_tests {
                 &mut recovered_secret_ptr,
                 &mut recovered_secret_len,
             );
-            
+
             assert_eq!(decap_result, FFIErrorCode::Success as i32, "Decapsulation should succeed");
             assert!(!recovered_secret_ptr.is_null(), "Recovered secret pointer should not be null");
             assert_eq!(recovered_secret_len, 32, "Recovered secret should be 32 bytes");
-            
+
             let original_secret = std::slice::from_raw_parts(shared_secret_ptr, shared_secret_len);
             let recovered_secret = std::slice::from_raw_parts(recovered_secret_ptr, recovered_secret_len);
             assert_eq!(original_secret, recovered_secret, "Shared secrets should match");
-            
-            qynauth_pqc::ffi::kyber_buffer_free(shared_secret_ptr, shared_secret_len);
-            qynauth_pqc::ffi::kyber_buffer_free(ciphertext_ptr, ciphertext_len);
-            qynauth_pqc::ffi::kyber_buffer_free(recovered_secret_ptr, recovered_secret_len);
-            kyber_keypair_free(keypair);
+
+            mlkem_shared_secret_free(shared_secret_ptr, shared_secret_len);
+            mlkem_ciphertext_free(ciphertext_ptr, ciphertext_len);
+            mlkem_shared_secret_free(recovered_secret_ptr, recovered_secret_len);
+            mlkem_keypair_free(keypair);
         }
     }
 
+    // FIPS 203's implicit rejection: a validly-sized but corrupted
+    // ciphertext must not make `mlkem_decapsulate` error out or otherwise
+    // leak which bytes were wrong -- it silently returns a 32-byte secret
+    // that deterministically differs from the one the honest ciphertext
+    // would have produced.
     #[test]
-    fn test_dilithium_keypair_generation() {
-        let keypair = dilithium_keypair_generate();
+    fn test_mlkem_decapsulate_implicitly_rejects_a_corrupted_ciphertext() {
+        let keypair = mlkem_keypair_generate(768);
         assert!(!keypair.is_null(), "Keypair generation should not return null");
-        
+
+        unsafe {
+            let keypair_ref = &*keypair;
+
+            let mut shared_secret_ptr: *mut u8 = ptr::null_mut();
+            let mut shared_secret_len: size_t = 0;
+            let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
+            let mut ciphertext_len: size_t = 0;
+
+            let encap_result = mlkem_encapsulate(
+                768,
+                keypair_ref.public_key_ptr,
+                keypair_ref.public_key_len,
+                &mut shared_secret_ptr,
+                &mut shared_secret_len,
+                &mut ciphertext_ptr,
+                &mut ciphertext_len,
+            );
+            assert_eq!(encap_result, FFIErrorCode::Success as i32, "Encapsulation should succeed");
+
+            let ciphertext = std::slice::from_raw_parts_mut(ciphertext_ptr, ciphertext_len);
+            ciphertext[0] ^= 0xFF;
+            ciphertext[1] ^= 0xFF;
+            ciphertext[2] ^= 0xFF;
+            ciphertext[3] ^= 0xFF;
+
+            let mut recovered_secret_ptr: *mut u8 = ptr::null_mut();
+            let mut recovered_secret_len: size_t = 0;
+
+            let decap_result = mlkem_decapsulate(
+                768,
+                keypair_ref.secret_key_ptr,
+                keypair_ref.secret_key_len,
+                ciphertext_ptr,
+                ciphertext_len,
+                &mut recovered_secret_ptr,
+                &mut recovered_secret_len,
+            );
+
+            assert_eq!(
+                decap_result,
+                FFIErrorCode::Success as i32,
+                "Decapsulating a corrupted-but-correctly-sized ciphertext must not error"
+            );
+            assert_eq!(recovered_secret_len, 32);
+
+            let original_secret = std::slice::from_raw_parts(shared_secret_ptr, shared_secret_len);
+            let recovered_secret = std::slice::from_raw_parts(recovered_secret_ptr, recovered_secret_len);
+            assert_ne!(
+                original_secret, recovered_secret,
+                "A corrupted ciphertext must decapsulate to a different secret"
+            );
+
+            mlkem_shared_secret_free(shared_secret_ptr, shared_secret_len);
+            mlkem_ciphertext_free(ciphertext_ptr, ciphertext_len);
+            mlkem_shared_secret_free(recovered_secret_ptr, recovered_secret_len);
+            mlkem_keypair_free(keypair);
+        }
+    }
+
+    #[test]
+    fn test_mldsa_keypair_generation() {
+        let keypair = mldsa_keypair_generate(3);
+        assert!(!keypair.is_null(), "Keypair generation should not return null");
+
         unsafe {
             let keypair_ref = &*keypair;
             assert!(!keypair_ref.public_key_ptr.is_null(), "Public key pointer should not be null");
-            assert!(!keypair_ref.secret_key_ptr.is_null(), "Secret key pointer should not be null");
             assert_eq!(keypair_ref.public_key_len, 1952, "ML-DSA-65 public key should be 1952 bytes");
-            assert_eq!(keypair_ref.secret_key_len, 4032, "ML-DSA-65 secret key should be 4032 bytes");
-            
-            dilithium_keypair_free(keypair);
+
+            mldsa_keypair_free(keypair);
         }
     }
 
     #[test]
-    fn test_dilithium_signing_verification() {
-        let keypair = dilithium_keypair_generate();
+    fn test_mldsa_signing_verification() {
+        let keypair = mldsa_keypair_generate(3);
         assert!(!keypair.is_null(), "Keypair generation should not return null");
-        
+
         unsafe {
             let keypair_ref = &*keypair;
             let message = b"Hello, Quantum-Safe World!";
-            
+
             let mut signature_ptr: *mut u8 = ptr::null_mut();
             let mut signature_len: size_t = 0;
-            
-            let sign_result = dilithium_sign(
-                keypair_ref.secret_key_ptr,
-                keypair_ref.secret_key_len,
+
+            let sign_result = mldsa_sign(
+                3,
+                keypair_ref.secret_key_handle,
                 message.as_ptr(),
                 message.len(),
                 &mut signature_ptr,
                 &mut signature_len,
             );
-            
+
             assert_eq!(sign_result, FFIErrorCode::Success as i32, "Signing should succeed");
             assert!(!signature_ptr.is_null(), "Signature pointer should not be null");
             assert!(signature_len > 0, "Signature length should be positive");
-            assert!(signature_len <= 4595, "ML-DSA-65 signature should be at most 4595 bytes");
-            
-            let verify_result = dilithium_verify(
+
+            let verify_result = mldsa_verify(
+                3,
                 keypair_ref.public_key_ptr,
                 keypair_ref.public_key_len,
                 message.as_ptr(),
@@ -131,11 +214,12 @@ mod ffi_tests {
                 signature_ptr,
                 signature_len,
             );
-            
+
             assert_eq!(verify_result, FFIErrorCode::Success as i32, "Verification should succeed");
-            
+
             let wrong_message = b"Different message";
-            let verify_wrong_result = dilithium_verify(
+            let verify_wrong_result = mldsa_verify(
+                3,
                 keypair_ref.public_key_ptr,
                 keypair_ref.public_key_len,
                 wrong_message.as_ptr(),
@@ -143,29 +227,240 @@ mod ffi_tests {
                 signature_ptr,
                 signature_len,
             );
-            
-            assert_eq!(verify_wrong_result, FFIErrorCode::SignatureVerificationFailed as i32, 
-                      "Verification with wrong message should fail");
-            
-            dilithium_buffer_free(signature_ptr, signature_len);
-            dilithium_keypair_free(keypair);
+
+            assert_eq!(
+                verify_wrong_result,
+                FFIErrorCode::SignatureVerificationFailed as i32,
+                "Verification with wrong message should fail"
+            );
+
+            ffi_buffer_free(signature_ptr, signature_len);
+            mldsa_keypair_free(keypair);
+        }
+    }
+
+    #[test]
+    fn test_mldsa_sign_ctx_differing_contexts_are_not_interchangeable() {
+        let keypair = mldsa_keypair_generate(3);
+        assert!(!keypair.is_null(), "Keypair generation should not return null");
+
+        unsafe {
+            let keypair_ref = &*keypair;
+            let message = b"Hello, Quantum-Safe World!";
+            let context_a = b"context-a";
+            let context_b = b"context-b";
+
+            let mut signature_ptr: *mut u8 = ptr::null_mut();
+            let mut signature_len: size_t = 0;
+
+            let sign_result = mldsa_sign_ctx(
+                3,
+                keypair_ref.secret_key_handle,
+                message.as_ptr(),
+                message.len(),
+                context_a.as_ptr(),
+                context_a.len(),
+                &mut signature_ptr,
+                &mut signature_len,
+            );
+            assert_eq!(sign_result, FFIErrorCode::Success as i32, "Context signing should succeed");
+
+            let verify_same_context = mldsa_verify_ctx(
+                3,
+                keypair_ref.public_key_ptr,
+                keypair_ref.public_key_len,
+                message.as_ptr(),
+                message.len(),
+                context_a.as_ptr(),
+                context_a.len(),
+                signature_ptr,
+                signature_len,
+            );
+            assert_eq!(
+                verify_same_context,
+                FFIErrorCode::Success as i32,
+                "Verification under the signing context should succeed"
+            );
+
+            let verify_other_context = mldsa_verify_ctx(
+                3,
+                keypair_ref.public_key_ptr,
+                keypair_ref.public_key_len,
+                message.as_ptr(),
+                message.len(),
+                context_b.as_ptr(),
+                context_b.len(),
+                signature_ptr,
+                signature_len,
+            );
+            assert_eq!(
+                verify_other_context,
+                FFIErrorCode::SignatureVerificationFailed as i32,
+                "Verification under a different context should fail"
+            );
+
+            ffi_buffer_free(signature_ptr, signature_len);
+            mldsa_keypair_free(keypair);
+        }
+    }
+
+    #[test]
+    fn test_mldsa_sign_ctx_empty_context_matches_plain_sign() {
+        let keypair = mldsa_keypair_generate(3);
+        assert!(!keypair.is_null(), "Keypair generation should not return null");
+
+        unsafe {
+            let keypair_ref = &*keypair;
+            let message = b"Hello, Quantum-Safe World!";
+
+            let mut ctx_signature_ptr: *mut u8 = ptr::null_mut();
+            let mut ctx_signature_len: size_t = 0;
+
+            let sign_result = mldsa_sign_ctx(
+                3,
+                keypair_ref.secret_key_handle,
+                message.as_ptr(),
+                message.len(),
+                ptr::null(),
+                0,
+                &mut ctx_signature_ptr,
+                &mut ctx_signature_len,
+            );
+            assert_eq!(
+                sign_result,
+                FFIErrorCode::Success as i32,
+                "Empty-context signing should succeed"
+            );
+
+            let verify_result = mldsa_verify(
+                3,
+                keypair_ref.public_key_ptr,
+                keypair_ref.public_key_len,
+                message.as_ptr(),
+                message.len(),
+                ctx_signature_ptr,
+                ctx_signature_len,
+            );
+            assert_eq!(
+                verify_result,
+                FFIErrorCode::Success as i32,
+                "An empty-context signature should verify under the plain verifier"
+            );
+
+            ffi_buffer_free(ctx_signature_ptr, ctx_signature_len);
+            mldsa_keypair_free(keypair);
+        }
+    }
+
+    #[test]
+    fn test_mldsa_sign_prehash_fails_under_plain_verify() {
+        let keypair = mldsa_keypair_generate(3);
+        assert!(!keypair.is_null(), "Keypair generation should not return null");
+
+        unsafe {
+            let keypair_ref = &*keypair;
+            let message = b"Hello, Quantum-Safe World!";
+
+            let mut signature_ptr: *mut u8 = ptr::null_mut();
+            let mut signature_len: size_t = 0;
+
+            let sign_result = mldsa_sign_prehash(
+                3,
+                keypair_ref.secret_key_handle,
+                message.as_ptr(),
+                message.len(),
+                ptr::null(),
+                0,
+                &mut signature_ptr,
+                &mut signature_len,
+            );
+            assert_eq!(sign_result, FFIErrorCode::Success as i32, "Pre-hash signing should succeed");
+
+            let verify_plain_result = mldsa_verify(
+                3,
+                keypair_ref.public_key_ptr,
+                keypair_ref.public_key_len,
+                message.as_ptr(),
+                message.len(),
+                signature_ptr,
+                signature_len,
+            );
+            assert_eq!(
+                verify_plain_result,
+                FFIErrorCode::SignatureVerificationFailed as i32,
+                "A pre-hash signature should not verify under the plain verifier"
+            );
+
+            let verify_prehash_result = mldsa_verify_prehash(
+                3,
+                keypair_ref.public_key_ptr,
+                keypair_ref.public_key_len,
+                message.as_ptr(),
+                message.len(),
+                ptr::null(),
+                0,
+                signature_ptr,
+                signature_len,
+            );
+            assert_eq!(
+                verify_prehash_result,
+                FFIErrorCode::Success as i32,
+                "The pre-hash verifier should accept its own signature"
+            );
+
+            ffi_buffer_free(signature_ptr, signature_len);
+            mldsa_keypair_free(keypair);
+        }
+    }
+
+    #[test]
+    fn test_mldsa_sign_ctx_rejects_oversized_context() {
+        let keypair = mldsa_keypair_generate(3);
+        assert!(!keypair.is_null(), "Keypair generation should not return null");
+
+        unsafe {
+            let keypair_ref = &*keypair;
+            let message = b"Hello, Quantum-Safe World!";
+            let oversized_context = [0u8; 256];
+
+            let mut signature_ptr: *mut u8 = ptr::null_mut();
+            let mut signature_len: size_t = 0;
+
+            let sign_result = mldsa_sign_ctx(
+                3,
+                keypair_ref.secret_key_handle,
+                message.as_ptr(),
+                message.len(),
+                oversized_context.as_ptr(),
+                oversized_context.len(),
+                &mut signature_ptr,
+                &mut signature_len,
+            );
+            assert_eq!(
+                sign_result,
+                FFIErrorCode::InvalidInput as i32,
+                "A context over 255 bytes should be rejected"
+            );
+
+            mldsa_keypair_free(keypair);
         }
     }
 
     #[test]
     fn test_memory_safety() {
-        let keypair = kyber_keypair_generate();
+        let keypair = mlkem_keypair_generate(768);
         assert!(!keypair.is_null(), "Keypair generation should not return null");
-        
+
         unsafe {
             let keypair_ref = &*keypair;
-            
+
             let mut shared_secret_ptr: *mut u8 = ptr::null_mut();
             let mut shared_secret_len: size_t = 0;
             let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
             let mut ciphertext_len: size_t = 0;
-            
-            let encap_result = kyber_encapsulate(
+
+            let encap_result = mlkem_encapsulate(
+                768,
                 keypair_ref.public_key_ptr,
                 keypair_ref.public_key_len,
                 &mut shared_secret_ptr,
@@ -173,19 +468,19 @@ mod ffi_tests {
                 &mut ciphertext_ptr,
                 &mut ciphertext_len,
             );
-            
+
             assert_eq!(encap_result, FFIErrorCode::Success as i32, "Encapsulation should succeed");
-            
-            qynauth_pqc::ffi::kyber_buffer_free(shared_secret_ptr, shared_secret_len);
-            qynauth_pqc::ffi::kyber_buffer_free(ciphertext_ptr, ciphertext_len);
-            kyber_keypair_free(keypair);
+
+            mlkem_shared_secret_free(shared_secret_ptr, shared_secret_len);
+            mlkem_ciphertext_free(ciphertext_ptr, ciphertext_len);
+            mlkem_keypair_free(keypair);
         }
-        
-        let dilithium_keypair = dilithium_keypair_generate();
-        assert!(!dilithium_keypair.is_null(), "Dilithium keypair generation should not return null");
-        
+
+        let mldsa_keypair = mldsa_keypair_generate(3);
+        assert!(!mldsa_keypair.is_null(), "ML-DSA keypair generation should not return null");
+
         unsafe {
-            dilithium_keypair_free(dilithium_keypair);
+            mldsa_keypair_free(mldsa_keypair);
         }
     }
 
@@ -196,8 +491,9 @@ mod ffi_tests {
             let mut shared_secret_len: size_t = 0;
             let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
             let mut ciphertext_len: size_t = 0;
-            
-            let encap_result = kyber_encapsulate(
+
+            let encap_result = mlkem_encapsulate(
+                768,
                 ptr::null(),
                 0,
                 &mut shared_secret_ptr,
@@ -205,47 +501,117 @@ mod ffi_tests {
                 &mut ciphertext_ptr,
                 &mut ciphertext_len,
             );
-            
-            assert_eq!(encap_result, FFIErrorCode::NullPointer as i32, 
-                      "Encapsulation with null pointer should fail");
-            
+
+            assert_eq!(
+                encap_result,
+                FFIErrorCode::NullPointer as i32,
+                "Encapsulation with null pointer should fail"
+            );
+
             let mut signature_ptr: *mut u8 = ptr::null_mut();
             let mut signature_len: size_t = 0;
-            
-            let sign_result = dilithium_sign(
-                ptr::null(),
+
+            let sign_result = mldsa_sign(
+                3,
                 0,
                 ptr::null(),
                 0,
                 &mut signature_ptr,
                 &mut signature_len,
             );
-            
-            assert_eq!(sign_result, FFIErrorCode::NullPointer as i32, 
-                      "Signing with null pointer should fail");
+
+            assert_eq!(
+                sign_result,
+                FFIErrorCode::NullPointer as i32,
+                "Signing with a null message pointer should fail"
+            );
         }
     }
 
     #[test]
     fn test_invalid_key_format() {
         unsafe {
-            let invalid_key = [0u8; 100];
-            let message = b"Test message";
-            
-            let mut signature_ptr: *mut u8 = ptr::null_mut();
-            let mut signature_len: size_t = 0;
-            
-            let sign_result = dilithium_sign(
-                invalid_key.as_ptr(),
-                invalid_key.len(),
-                message.as_ptr(),
-                message.len(),
-                &mut signature_ptr,
-                &mut signature_len,
+            let invalid_public_key = [0u8; 100];
+            let keypair = mlkem_keypair_generate(768);
+            assert!(!keypair.is_null());
+            let keypair_ref = &*keypair;
+
+            let mut shared_secret_ptr: *mut u8 = ptr::null_mut();
+            let mut shared_secret_len: size_t = 0;
+            let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
+            let mut ciphertext_len: size_t = 0;
+
+            let encap_result = mlkem_encapsulate(
+                768,
+                invalid_public_key.as_ptr(),
+                invalid_public_key.len(),
+                &mut shared_secret_ptr,
+                &mut shared_secret_len,
+                &mut ciphertext_ptr,
+                &mut ciphertext_len,
+            );
+
+            assert_eq!(
+                encap_result,
+                FFIErrorCode::InvalidPublicKeySize as i32,
+                "Encapsulating with a wrong-sized public key should fail"
+            );
+
+            let invalid_ciphertext = [0u8; 100];
+            let mut recovered_secret_ptr: *mut u8 = ptr::null_mut();
+            let mut recovered_secret_len: size_t = 0;
+
+            let decap_result = mlkem_decapsulate(
+                768,
+                keypair_ref.secret_key_ptr,
+                keypair_ref.secret_key_len,
+                invalid_ciphertext.as_ptr(),
+                invalid_ciphertext.len(),
+                &mut recovered_secret_ptr,
+                &mut recovered_secret_len,
+            );
+
+            assert_eq!(
+                decap_result,
+                FFIErrorCode::InvalidCiphertextSize as i32,
+                "Decapsulating a wrong-sized ciphertext should fail"
             );
-            
-            assert_eq!(sign_result, FFIErrorCode::InvalidKeyFormat as i32, 
-                      "Signing with invalid key format should fail");
+
+            let invalid_secret_key = [0u8; 100];
+            let decap_bad_secret_result = mlkem_decapsulate(
+                768,
+                invalid_secret_key.as_ptr(),
+                invalid_secret_key.len(),
+                invalid_ciphertext.as_ptr(),
+                invalid_ciphertext.len(),
+                &mut recovered_secret_ptr,
+                &mut recovered_secret_len,
+            );
+
+            assert_eq!(
+                decap_bad_secret_result,
+                FFIErrorCode::InvalidSecretKeySize as i32,
+                "Decapsulating with a wrong-sized secret key should fail"
+            );
+
+            let invalid_signature = [0u8; 100];
+            let verify_result = mldsa_verify(
+                3,
+                invalid_public_key.as_ptr(),
+                invalid_public_key.len(),
+                b"Test message".as_ptr(),
+                b"Test message".len(),
+                invalid_signature.as_ptr(),
+                invalid_signature.len(),
+            );
+
+            assert_eq!(
+                verify_result,
+                FFIErrorCode::InvalidPublicKeySize as i32,
+                "Verifying with a wrong-sized public key should fail"
+            );
+
+            mlkem_keypair_free(keypair);
         }
     }
 
@@ -256,8 +622,9 @@ mod ffi_tests {
             let mut shared_secret_len: size_t = 0;
             let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
             let mut ciphertext_len: size_t = 0;
-            
-            kyber_encapsulate(
+
+            mlkem_encapsulate(
+                768,
                 ptr::null(),
                 0,
                 &mut shared_secret_ptr,
@@ -265,13 +632,322 @@ mod ffi_tests {
                 &mut ciphertext_ptr,
                 &mut ciphertext_len,
             );
-            
-            let error_ptr = qynauth_pqc::ffi::kyber_get_last_error();
+
+            let error_ptr = ffi_get_last_error_message();
             assert!(!error_ptr.is_null(), "Error message should be available");
-            
+
             let error_cstr = CStr::from_ptr(error_ptr);
             let error_str = error_cstr.to_str().expect("Error message should be valid UTF-8");
             assert!(!error_str.is_empty(), "Error message should not be empty");
         }
     }
+
+    // ML-KEM-768 is covered in detail by the tests above; this sweeps all
+    // three NIST security levels to confirm none of them is secretly
+    // baked into the ABI.
+    #[test]
+    fn test_mlkem_keypair_generation_is_correctly_sized_at_every_level() {
+        for level in [512, 768, 1024] {
+            let mut expected_public_key_len: size_t = 0;
+            let mut expected_secret_key_len: size_t = 0;
+            let mut expected_ciphertext_len: size_t = 0;
+            let mut expected_shared_secret_len: size_t = 0;
+
+            let sizes_result = mlkem_param_sizes(
+                level,
+                &mut expected_public_key_len,
+                &mut expected_secret_key_len,
+                &mut expected_ciphertext_len,
+                &mut expected_shared_secret_len,
+            );
+            assert_eq!(sizes_result, FFIErrorCode::Success as i32, "level {level} sizes");
+
+            let keypair = mlkem_keypair_generate(level);
+            assert!(!keypair.is_null(), "level {level} keypair generation should not return null");
+
+            unsafe {
+                let keypair_ref = &*keypair;
+                assert_eq!(
+                    keypair_ref.public_key_len, expected_public_key_len,
+                    "level {level} public key length"
+                );
+                assert_eq!(
+                    keypair_ref.secret_key_len, expected_secret_key_len,
+                    "level {level} secret key length"
+                );
+                assert_eq!(keypair_ref.level, level);
+
+                let mut shared_secret_ptr: *mut u8 = ptr::null_mut();
+                let mut shared_secret_len: size_t = 0;
+                let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
+                let mut ciphertext_len: size_t = 0;
+
+                let encap_result = mlkem_encapsulate(
+                    level,
+                    keypair_ref.public_key_ptr,
+                    keypair_ref.public_key_len,
+                    &mut shared_secret_ptr,
+                    &mut shared_secret_len,
+                    &mut ciphertext_ptr,
+                    &mut ciphertext_len,
+                );
+                assert_eq!(encap_result, FFIErrorCode::Success as i32, "level {level} encapsulate");
+                assert_eq!(ciphertext_len, expected_ciphertext_len, "level {level} ciphertext length");
+                assert_eq!(
+                    shared_secret_len, expected_shared_secret_len,
+                    "level {level} shared secret length"
+                );
+
+                let mut recovered_secret_ptr: *mut u8 = ptr::null_mut();
+                let mut recovered_secret_len: size_t = 0;
+                let decap_result = mlkem_decapsulate(
+                    level,
+                    keypair_ref.secret_key_ptr,
+                    keypair_ref.secret_key_len,
+                    ciphertext_ptr,
+                    ciphertext_len,
+                    &mut recovered_secret_ptr,
+                    &mut recovered_secret_len,
+                );
+                assert_eq!(decap_result, FFIErrorCode::Success as i32, "level {level} decapsulate");
+
+                let original_secret = std::slice::from_raw_parts(shared_secret_ptr, shared_secret_len);
+                let recovered_secret =
+                    std::slice::from_raw_parts(recovered_secret_ptr, recovered_secret_len);
+                assert_eq!(original_secret, recovered_secret, "level {level} shared secrets should match");
+
+                mlkem_shared_secret_free(shared_secret_ptr, shared_secret_len);
+                mlkem_ciphertext_free(ciphertext_ptr, ciphertext_len);
+                mlkem_shared_secret_free(recovered_secret_ptr, recovered_secret_len);
+                mlkem_keypair_free(keypair);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mldsa_signing_is_correctly_sized_at_every_level() {
+        let levels_and_sizes = [
+            (2, MLDSA44_PUBLIC_KEY_SIZE, MLDSA44_SIGNATURE_SIZE),
+            (3, MLDSA65_PUBLIC_KEY_SIZE, MLDSA65_SIGNATURE_SIZE),
+            (5, MLDSA87_PUBLIC_KEY_SIZE, MLDSA87_SIGNATURE_SIZE),
+        ];
+
+        for (level, expected_public_key_len, expected_signature_len) in levels_and_sizes {
+            let keypair = mldsa_keypair_generate(level);
+            assert!(!keypair.is_null(), "level {level} keypair generation should not return null");
+
+            unsafe {
+                let keypair_ref = &*keypair;
+                assert_eq!(
+                    keypair_ref.public_key_len, expected_public_key_len,
+                    "level {level} public key length"
+                );
+                assert_eq!(keypair_ref.level, level);
+
+                let message = b"per-level ML-DSA round trip";
+
+                // The attached `mldsa_sign` embeds `message` in its output
+                // (pqcrypto's `SignedMessage` convention), so its length
+                // isn't the fixed per-level signature size on its own --
+                // only that verification round-trips correctly is checked
+                // here, and the detached variant below pins the exact size.
+                let mut signature_ptr: *mut u8 = ptr::null_mut();
+                let mut signature_len: size_t = 0;
+                let sign_result = mldsa_sign(
+                    level,
+                    keypair_ref.secret_key_handle,
+                    message.as_ptr(),
+                    message.len(),
+                    &mut signature_ptr,
+                    &mut signature_len,
+                );
+                assert_eq!(sign_result, FFIErrorCode::Success as i32, "level {level} sign");
+
+                let verify_result = mldsa_verify(
+                    level,
+                    keypair_ref.public_key_ptr,
+                    keypair_ref.public_key_len,
+                    message.as_ptr(),
+                    message.len(),
+                    signature_ptr,
+                    signature_len,
+                );
+                assert_eq!(verify_result, FFIErrorCode::Success as i32, "level {level} verify");
+                ffi_buffer_free(signature_ptr, signature_len);
+
+                let mut detached_signature_ptr: *mut u8 = ptr::null_mut();
+                let mut detached_signature_len: size_t = 0;
+                let sign_detached_result = mldsa_sign_detached(
+                    level,
+                    keypair_ref.secret_key_handle,
+                    message.as_ptr(),
+                    message.len(),
+                    &mut detached_signature_ptr,
+                    &mut detached_signature_len,
+                );
+                assert_eq!(
+                    sign_detached_result,
+                    FFIErrorCode::Success as i32,
+                    "level {level} sign_detached"
+                );
+                assert_eq!(
+                    detached_signature_len, expected_signature_len,
+                    "level {level} detached signature length"
+                );
+
+                let verify_detached_result = mldsa_verify_detached(
+                    level,
+                    keypair_ref.public_key_ptr,
+                    keypair_ref.public_key_len,
+                    message.as_ptr(),
+                    message.len(),
+                    detached_signature_ptr,
+                    detached_signature_len,
+                );
+                assert_eq!(
+                    verify_detached_result,
+                    FFIErrorCode::Success as i32,
+                    "level {level} verify_detached"
+                );
+
+                ffi_buffer_free(detached_signature_ptr, detached_signature_len);
+                mldsa_keypair_free(keypair);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hybrid_kem_round_trip() {
+        unsafe {
+            let alice = hybrid_kem_keypair_generate();
+            assert!(!alice.is_null());
+            let alice_ref = &*alice;
+
+            let mut shared_secret_ptr: *mut u8 = ptr::null_mut();
+            let mut shared_secret_len: size_t = 0;
+            let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
+            let mut ciphertext_len: size_t = 0;
+
+            let encap_result = hybrid_kem_encapsulate(
+                alice_ref.classical_public_key_ptr,
+                alice_ref.classical_public_key_len,
+                alice_ref.pqc_public_key_ptr,
+                alice_ref.pqc_public_key_len,
+                &mut shared_secret_ptr,
+                &mut shared_secret_len,
+                &mut ciphertext_ptr,
+                &mut ciphertext_len,
+            );
+            assert_eq!(encap_result, FFIErrorCode::Success as i32);
+
+            let mut decapsulated_ptr: *mut u8 = ptr::null_mut();
+            let mut decapsulated_len: size_t = 0;
+            let decap_result = hybrid_kem_decapsulate(
+                alice_ref.classical_secret_key_handle,
+                alice_ref.pqc_secret_key_handle,
+                ciphertext_ptr,
+                ciphertext_len,
+                &mut decapsulated_ptr,
+                &mut decapsulated_len,
+            );
+            assert_eq!(decap_result, FFIErrorCode::Success as i32);
+
+            let encapsulated_secret =
+                std::slice::from_raw_parts(shared_secret_ptr, shared_secret_len);
+            let decapsulated_secret =
+                std::slice::from_raw_parts(decapsulated_ptr, decapsulated_len);
+            assert_eq!(encapsulated_secret, decapsulated_secret);
+
+            hybrid_kem_shared_secret_free(shared_secret_ptr, shared_secret_len);
+            hybrid_kem_shared_secret_free(decapsulated_ptr, decapsulated_len);
+            hybrid_kem_ciphertext_free(ciphertext_ptr, ciphertext_len);
+            hybrid_kem_keypair_free(alice);
+        }
+    }
+
+    #[test]
+    fn test_hybrid_kem_encapsulate_rejects_wrong_sized_classical_public_key() {
+        unsafe {
+            let keypair = hybrid_kem_keypair_generate();
+            assert!(!keypair.is_null());
+            let keypair_ref = &*keypair;
+
+            let short_classical_public_key = [0u8; 10];
+            let mut shared_secret_ptr: *mut u8 = ptr::null_mut();
+            let mut shared_secret_len: size_t = 0;
+            let mut ciphertext_ptr: *mut u8 = ptr::null_mut();
+            let mut ciphertext_len: size_t = 0;
+
+            let result = hybrid_kem_encapsulate(
+                short_classical_public_key.as_ptr(),
+                short_classical_public_key.len(),
+                keypair_ref.pqc_public_key_ptr,
+                keypair_ref.pqc_public_key_len,
+                &mut shared_secret_ptr,
+                &mut shared_secret_len,
+                &mut ciphertext_ptr,
+                &mut ciphertext_len,
+            );
+            assert_eq!(result, FFIErrorCode::InvalidPublicKeySize as i32);
+
+            hybrid_kem_keypair_free(keypair);
+        }
+    }
+
+    #[test]
+    fn test_shamir_split_and_reconstruct_round_trip() {
+        unsafe {
+            let secret = b"top secret key material";
+            let split_result = shamir_split_secret(secret.as_ptr(), secret.len(), 3, 5);
+            assert!(!split_result.is_null(), "Split should not return null");
+
+            let split_ref = &*split_result;
+            let shares = std::slice::from_raw_parts(split_ref.shares_ptr, split_ref.shares_len);
+
+            let mut secret_out: *mut u8 = ptr::null_mut();
+            let mut secret_len_out: size_t = 0;
+            let reconstruct_result = shamir_reconstruct_secret(
+                shares.as_ptr(),
+                3,
+                &mut secret_out,
+                &mut secret_len_out,
+            );
+            assert_eq!(
+                reconstruct_result,
+                FFIErrorCode::Success as i32,
+                "Reconstruction from a valid threshold of shares should succeed"
+            );
+            assert_eq!(
+                std::slice::from_raw_parts(secret_out, secret_len_out),
+                secret,
+                "Reconstructed secret should match the original"
+            );
+
+            ffi_buffer_free(secret_out, secret_len_out);
+            shamir_split_result_free(split_result);
+        }
+    }
+
+    #[test]
+    fn test_shamir_reconstruct_secret_rejects_null_share_pointer() {
+        unsafe {
+            let malformed_shares = [CShamirShare { x: 1, y_ptr: ptr::null_mut(), y_len: 16 }];
+
+            let mut secret_out: *mut u8 = ptr::null_mut();
+            let mut secret_len_out: size_t = 0;
+            let result = shamir_reconstruct_secret(
+                malformed_shares.as_ptr(),
+                malformed_shares.len(),
+                &mut secret_out,
+                &mut secret_len_out,
+            );
+
+            assert_eq!(
+                result,
+                FFIErrorCode::NullPointer as i32,
+                "A share with a null y_ptr and nonzero y_len must be rejected, not dereferenced"
+            );
+            assert!(secret_out.is_null(), "No secret should be produced on rejection");
+        }
+    }
 }
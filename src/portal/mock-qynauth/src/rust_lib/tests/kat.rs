@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod kat_tests {
+    use qynauth_pqc::{
+        generate_mldsa_keypair_for_level, generate_mlkem_keypair_deterministic,
+        mlkem_decapsulate_for_algorithm, mlkem_encapsulate_deterministic, MLDSALevel,
+        MlDsaPublicKey, MlDsaSecretKey, PQCAlgorithm,
+    };
+    use secrecy::ExposeSecret;
+
+    fn hex_decode(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("valid hex"))
+            .collect()
+    }
+
+    // These are arbitrary fixed test inputs rather than an official NIST
+    // ACVP/KAT vector: this environment has no network access to pull the
+    // published answer set, so there's no authoritative pk/sk/ct/ss to
+    // assert against byte-for-byte here. What's checked instead is the
+    // property a real KAT run depends on: the same seed/message always
+    // reproduces the same keys/ciphertext/shared secret, and decapsulation
+    // recovers exactly what encapsulation produced. Wiring in the actual
+    // published vectors is left as follow-up work once they can be fetched.
+    const SEED_HEX: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e\
+                            1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f";
+    const MESSAGE_HEX: &str = "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f";
+
+    #[test]
+    fn test_deterministic_keypair_is_reproducible_from_seed() {
+        let seed: [u8; 64] = hex_decode(SEED_HEX).try_into().unwrap();
+
+        let keypair_a = generate_mlkem_keypair_deterministic(&seed).unwrap();
+        let keypair_b = generate_mlkem_keypair_deterministic(&seed).unwrap();
+
+        assert_eq!(keypair_a.public_key, keypair_b.public_key);
+        assert_eq!(
+            keypair_a.private_key.expose_secret(),
+            keypair_b.private_key.expose_secret()
+        );
+        assert_eq!(keypair_a.public_key.len(), PQCAlgorithm::MlKem768.public_key_size());
+    }
+
+    #[test]
+    fn test_deterministic_encapsulation_is_reproducible_from_message() {
+        let seed: [u8; 64] = hex_decode(SEED_HEX).try_into().unwrap();
+        let message: [u8; 32] = hex_decode(MESSAGE_HEX).try_into().unwrap();
+        let keypair = generate_mlkem_keypair_deterministic(&seed).unwrap();
+
+        let encap_a = mlkem_encapsulate_deterministic(&keypair.public_key, &message).unwrap();
+        let encap_b = mlkem_encapsulate_deterministic(&keypair.public_key, &message).unwrap();
+
+        assert_eq!(encap_a.ciphertext, encap_b.ciphertext);
+        assert_eq!(
+            encap_a.shared_secret.expose_secret(),
+            encap_b.shared_secret.expose_secret()
+        );
+    }
+
+    #[test]
+    fn test_deterministic_round_trip_consistency() {
+        let seed: [u8; 64] = hex_decode(SEED_HEX).try_into().unwrap();
+        let message: [u8; 32] = hex_decode(MESSAGE_HEX).try_into().unwrap();
+
+        let keypair = generate_mlkem_keypair_deterministic(&seed).unwrap();
+        let encapsulated = mlkem_encapsulate_deterministic(&keypair.public_key, &message).unwrap();
+
+        let decapsulated = mlkem_decapsulate_for_algorithm(
+            PQCAlgorithm::MlKem768,
+            keypair.private_key.expose_secret(),
+            &encapsulated.ciphertext,
+        )
+        .unwrap();
+
+        assert_eq!(
+            decapsulated.expose_secret(),
+            encapsulated.shared_secret.expose_secret()
+        );
+    }
+
+    // `pqcrypto_mldsa`'s `sign`/`detached_sign` take no external randomness
+    // parameter -- unlike ML-KEM, there is no `pqc_kyber::reference`-style
+    // derand crate backing ML-DSA in this dependency set, so there is no
+    // seed to thread a `*_seeded` FFI entry point through. FIPS 204's
+    // reference algorithm already signs deterministically from `(sk,
+    // message)` alone when no hedged randomness is supplied, which is what
+    // `pqcrypto_mldsa` does, so the same-input-same-signature property a KAT
+    // run depends on already holds for the existing `sign`/`sign_detached`
+    // API without any new entry point. This is asserted below in place of
+    // the ACVP vectors themselves, for the same offline-environment reason
+    // given above.
+    #[test]
+    fn test_mldsa_signing_is_deterministic_for_the_same_key_and_message() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let secret_key =
+            MlDsaSecretKey::from_bytes(PQCAlgorithm::MlDsa65, keypair.private_key.expose_secret())
+                .unwrap();
+
+        let signature_a = secret_key.sign(b"kat message");
+        let signature_b = secret_key.sign(b"kat message");
+
+        assert_eq!(
+            signature_a.signature.expose_secret(),
+            signature_b.signature.expose_secret()
+        );
+    }
+
+    #[test]
+    fn test_mldsa_signing_differs_across_messages() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let secret_key =
+            MlDsaSecretKey::from_bytes(PQCAlgorithm::MlDsa65, keypair.private_key.expose_secret())
+                .unwrap();
+
+        let signature_a = secret_key.sign(b"kat message a");
+        let signature_b = secret_key.sign(b"kat message b");
+
+        assert_ne!(
+            signature_a.signature.expose_secret(),
+            signature_b.signature.expose_secret()
+        );
+    }
+
+    #[test]
+    fn test_mldsa_context_signatures_are_not_interchangeable_across_contexts() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let secret_key =
+            MlDsaSecretKey::from_bytes(PQCAlgorithm::MlDsa65, keypair.private_key.expose_secret())
+                .unwrap();
+        let public_key =
+            MlDsaPublicKey::from_bytes(PQCAlgorithm::MlDsa65, &keypair.public_key).unwrap();
+
+        let signature = secret_key
+            .sign_with_context(b"kat message", b"context-a")
+            .unwrap();
+
+        assert!(public_key
+            .verify_with_context(b"kat message", b"context-a", signature.signature.expose_secret())
+            .unwrap());
+        assert!(!public_key
+            .verify_with_context(b"kat message", b"context-b", signature.signature.expose_secret())
+            .unwrap());
+    }
+
+    #[test]
+    fn test_mldsa_empty_context_matches_plain_sign_and_verify() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let secret_key =
+            MlDsaSecretKey::from_bytes(PQCAlgorithm::MlDsa65, keypair.private_key.expose_secret())
+                .unwrap();
+        let public_key =
+            MlDsaPublicKey::from_bytes(PQCAlgorithm::MlDsa65, &keypair.public_key).unwrap();
+
+        let plain_signature = secret_key.sign(b"kat message");
+        let ctx_signature = secret_key.sign_with_context(b"kat message", b"").unwrap();
+
+        assert_eq!(
+            plain_signature.signature.expose_secret(),
+            ctx_signature.signature.expose_secret()
+        );
+        assert!(public_key
+            .verify_with_context(b"kat message", b"", plain_signature.signature.expose_secret())
+            .unwrap());
+    }
+
+    #[test]
+    fn test_mldsa_prehash_signature_fails_under_plain_verify() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let secret_key =
+            MlDsaSecretKey::from_bytes(PQCAlgorithm::MlDsa65, keypair.private_key.expose_secret())
+                .unwrap();
+        let public_key =
+            MlDsaPublicKey::from_bytes(PQCAlgorithm::MlDsa65, &keypair.public_key).unwrap();
+
+        let prehash_signature = secret_key.sign_prehash(b"kat message", b"").unwrap();
+
+        assert!(!public_key
+            .verify(b"kat message", prehash_signature.signature.expose_secret())
+            .unwrap());
+        assert!(public_key
+            .verify_prehash(b"kat message", b"", prehash_signature.signature.expose_secret())
+            .unwrap());
+    }
+
+    #[test]
+    fn test_mldsa_context_longer_than_255_bytes_is_rejected() {
+        let keypair = generate_mldsa_keypair_for_level(MLDSALevel::Level3).unwrap();
+        let secret_key =
+            MlDsaSecretKey::from_bytes(PQCAlgorithm::MlDsa65, keypair.private_key.expose_secret())
+                .unwrap();
+
+        let oversized_context = vec![0u8; 256];
+        let result = secret_key.sign_with_context(b"kat message", &oversized_context);
+
+        assert!(result.is_err());
+    }
+}
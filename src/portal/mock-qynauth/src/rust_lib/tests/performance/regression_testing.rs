@@ -2,7 +2,8 @@
 use std::time::{Duration, Instant};
 use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
-use std::io::{Read, Write, BufRead, BufReader};
+use std::io::{Read, Write, BufReader};
+use rand::Rng;
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -10,11 +11,20 @@ pub struct PerformanceBaseline {
     pub operation: String,
     pub algorithm: String,
     pub mean_duration_nanos: u64,
+    /// Median duration, checked by [`PerformanceRegressionTester::test_performance_regression`]'s
+    /// fast guard before it bothers with the bootstrap/Welch significance
+    /// test, since a skewed tail can move the mean without the typical
+    /// case having gotten any slower.
+    pub median_duration_nanos: u64,
     pub p95_duration_nanos: u64,
     pub p99_duration_nanos: u64,
     pub memory_usage_bytes: usize,
     pub sample_count: usize,
     pub timestamp: String,
+    /// Raw per-iteration durations the summary stats above were computed
+    /// from, kept around so regressions can be tested with a bootstrap
+    /// confidence interval instead of comparing single point estimates.
+    pub sample_durations_nanos: Vec<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +34,83 @@ pub struct RegressionThreshold {
     pub max_memory_increase_percent: f64,
     pub min_sample_count: usize,
     pub alert_threshold_percent: f64,
+    /// Automated action to take per alert severity for this operation.
+    /// Severities without an explicit binding fall back to
+    /// `default_action_for_severity`.
+    #[serde(default)]
+    pub actions: HashMap<AlertSeverity, AlertAction>,
+}
+
+/// One rule from a declarative threshold config: a glob `pattern` (e.g.
+/// `mlkem_*`) matched against operation names, plus the limits and action
+/// bindings every matching operation inherits. Lets new operations get
+/// sensible thresholds without a source change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdRule {
+    pub pattern: String,
+    pub max_duration_increase_percent: f64,
+    pub max_memory_increase_percent: f64,
+    pub min_sample_count: usize,
+    pub alert_threshold_percent: f64,
+    #[serde(default)]
+    pub actions: HashMap<AlertSeverity, AlertAction>,
+}
+
+/// Top-level shape of the declarative threshold/action config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdConfig {
+    pub rules: Vec<ThresholdRule>,
+}
+
+/// Automated response bound to an alert severity for a given operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertAction {
+    Log,
+    Notify,
+    Rollback,
+}
+
+/// Default action bindings used when a threshold has no explicit `actions`
+/// entry for a severity, preserving the historical behavior (emergencies
+/// roll back, criticals notify, warnings just log).
+fn default_action_for_severity(severity: &AlertSeverity) -> AlertAction {
+    match severity {
+        AlertSeverity::Emergency => AlertAction::Rollback,
+        AlertSeverity::Critical => AlertAction::Notify,
+        AlertSeverity::Warning => AlertAction::Log,
+    }
+}
+
+/// Minimal glob matcher supporting a single `*` wildcard (e.g. `mlkem_*` or
+/// `*_verify`), enough for grouping threshold rules by operation-name
+/// prefix/suffix without pulling in a full glob crate.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, suffix)) => {
+            text.len() >= prefix.len() + suffix.len()
+                && text.starts_with(prefix)
+                && text.ends_with(suffix)
+        }
+    }
+}
+
+/// Controls the warm-up phase run before a real measurement batch is
+/// collected, so cold-cache and JIT/allocator warm-up effects on the first
+/// few iterations of `mlkem_keygen`/`mldsa_sign` don't get misreported as
+/// regressions.
+#[derive(Debug, Clone)]
+pub struct WarmUpConfig {
+    /// Minimum number of warm-up iterations to run before the
+    /// coefficient-of-variation check is even consulted.
+    pub min_warmup_iterations: usize,
+    /// Warm-up stops once the coefficient of variation (stddev/mean) of
+    /// the trailing `min_warmup_iterations`-sized window drops below this.
+    pub target_cv: f64,
+    /// Hard cap so a sample closure that never stabilizes can't warm up
+    /// forever.
+    pub max_warmup_iterations: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +122,15 @@ pub struct RegressionAlert {
     pub increase_percent: f64,
     pub severity: AlertSeverity,
     pub timestamp: String,
+    /// 95% bootstrap confidence interval for the mean difference
+    /// (current - baseline), in nanoseconds. `None` for alert types that
+    /// aren't derived from a resampled duration distribution.
+    pub confidence_interval_nanos: Option<(f64, f64)>,
+    /// Two-tailed p-value from Welch's t-test against the null hypothesis
+    /// that the current and baseline samples have equal means. `None` for
+    /// alert types that aren't derived from a resampled duration
+    /// distribution.
+    pub p_value: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -45,7 +141,8 @@ pub enum RegressionAlertType {
     ErrorRateIncrease,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AlertSeverity {
     Warning,   // 10-25% degradation
     Critical,  // 25-50% degradation
@@ -55,6 +152,9 @@ pub enum AlertSeverity {
 pub struct PerformanceRegressionTester {
     baselines: HashMap<String, PerformanceBaseline>,
     thresholds: HashMap<String, RegressionThreshold>,
+    /// Glob-pattern rules loaded from a declarative config, consulted when
+    /// `thresholds` has no exact entry for an operation.
+    threshold_rules: Vec<ThresholdRule>,
     alerts: Vec<RegressionAlert>,
 }
 
@@ -63,9 +163,10 @@ impl PerformanceRegressionTester {
         let mut tester = Self {
             baselines: HashMap::new(),
             thresholds: HashMap::new(),
+            threshold_rules: Vec::new(),
             alerts: Vec::new(),
         };
-        
+
         tester.initialize_default_thresholds();
         tester
     }
@@ -77,6 +178,7 @@ impl PerformanceRegressionTester {
             max_memory_increase_percent: 50.0,
             min_sample_count: 100,
             alert_threshold_percent: 10.0,
+            actions: HashMap::new(),
         });
 
         self.thresholds.insert("mlkem_encap".to_string(), RegressionThreshold {
@@ -85,6 +187,7 @@ impl PerformanceRegressionTester {
             max_memory_increase_percent: 40.0,
             min_sample_count: 100,
             alert_threshold_percent: 10.0,
+            actions: HashMap::new(),
         });
 
         self.thresholds.insert("mlkem_decap".to_string(), RegressionThreshold {
@@ -93,6 +196,7 @@ impl PerformanceRegressionTester {
             max_memory_increase_percent: 40.0,
             min_sample_count: 100,
             alert_threshold_percent: 10.0,
+            actions: HashMap::new(),
         });
 
         self.thresholds.insert("mldsa_keygen".to_string(), RegressionThreshold {
@@ -101,6 +205,7 @@ impl PerformanceRegressionTester {
             max_memory_increase_percent: 50.0,
             min_sample_count: 100,
             alert_threshold_percent: 15.0,
+            actions: HashMap::new(),
         });
 
         self.thresholds.insert("mldsa_sign".to_string(), RegressionThreshold {
@@ -109,6 +214,7 @@ impl PerformanceRegressionTester {
             max_memory_increase_percent: 45.0,
             min_sample_count: 100,
             alert_threshold_percent: 12.0,
+            actions: HashMap::new(),
         });
 
         self.thresholds.insert("mldsa_verify".to_string(), RegressionThreshold {
@@ -117,55 +223,158 @@ impl PerformanceRegressionTester {
             max_memory_increase_percent: 35.0,
             min_sample_count: 100,
             alert_threshold_percent: 8.0,
+            actions: HashMap::new(),
         });
     }
 
+    /// Loads baselines previously written by `save_baselines_to_file`,
+    /// deserializing the operation -> `PerformanceBaseline` map directly
+    /// via the existing serde derives rather than reconstructing it from
+    /// placeholder values.
     pub fn load_baselines_from_file(&mut self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
         let file = File::open(file_path)?;
         let reader = BufReader::new(file);
-        
-        for line in reader.lines() {
-            let line = line?;
-            if line.starts_with("Operation:") {
-                if let Some(operation) = line.strip_prefix("Operation: ") {
-                    let baseline = PerformanceBaseline {
-                        operation: operation.to_string(),
-                        algorithm: if operation.contains("mlkem") { "ML-KEM-768" } else { "ML-DSA-65" }.to_string(),
-                        mean_duration_nanos: 50_000_000, // 50ms baseline
-                        p95_duration_nanos: 75_000_000,  // 75ms p95
-                        p99_duration_nanos: 100_000_000, // 100ms p99
-                        memory_usage_bytes: 4096,
-                        sample_count: 1000,
-                        timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                    };
-                    self.baselines.insert(operation.to_string(), baseline);
-                }
-            }
+        let baselines: HashMap<String, PerformanceBaseline> = serde_json::from_reader(reader)?;
+        self.baselines.extend(baselines);
+        Ok(())
+    }
+
+    /// Serializes the current operation -> `PerformanceBaseline` map as
+    /// JSON, the counterpart to `load_baselines_from_file`, so a run's
+    /// baselines survive to be compared against in a later run.
+    pub fn save_baselines_to_file(&self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let file = OpenOptions::new().create(true).write(true).truncate(true).open(file_path)?;
+        serde_json::to_writer_pretty(file, &self.baselines)?;
+        Ok(())
+    }
+
+    /// Builds a `PerformanceBaseline` for `operation` from a fresh batch of
+    /// measurements, dropping severe outliers via Tukey fences first so a
+    /// single stalled iteration (a GC pause, a noisy CI neighbor) doesn't
+    /// skew the mean/p95/p99 that future regression checks get compared
+    /// against.
+    pub fn record_baseline(
+        &mut self,
+        operation: &str,
+        algorithm: &str,
+        raw_measurements: &[Duration],
+        memory_usage_bytes: usize,
+    ) {
+        let mut sorted_nanos: Vec<u64> = raw_measurements.iter().map(|d| d.as_nanos() as u64).collect();
+        sorted_nanos.sort();
+
+        let (low_fence, high_fence) = tukey_fences(&sorted_nanos);
+        let mut filtered_nanos: Vec<u64> = sorted_nanos
+            .iter()
+            .copied()
+            .filter(|&nanos| (nanos as f64) >= low_fence && (nanos as f64) <= high_fence)
+            .collect();
+        if filtered_nanos.is_empty() {
+            filtered_nanos = sorted_nanos;
         }
+
+        let filtered_durations: Vec<Duration> = filtered_nanos.iter().map(|&n| Duration::from_nanos(n)).collect();
+        let mean = self.calculate_mean_duration(&filtered_durations);
+        let median = self.calculate_percentile(&filtered_durations, 0.5);
+        let p95 = self.calculate_percentile(&filtered_durations, 0.95);
+        let p99 = self.calculate_percentile(&filtered_durations, 0.99);
+
+        self.baselines.insert(operation.to_string(), PerformanceBaseline {
+            operation: operation.to_string(),
+            algorithm: algorithm.to_string(),
+            mean_duration_nanos: mean.as_nanos() as u64,
+            median_duration_nanos: median.as_nanos() as u64,
+            p95_duration_nanos: p95.as_nanos() as u64,
+            p99_duration_nanos: p99.as_nanos() as u64,
+            memory_usage_bytes,
+            sample_count: filtered_nanos.len(),
+            timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            sample_durations_nanos: filtered_nanos,
+        });
+    }
+
+    /// Loads a declarative JSON threshold/action config (see `ThresholdRule`)
+    /// and stores its rules for glob-pattern matching against operations
+    /// that don't have an exact entry in `thresholds`.
+    pub fn load_threshold_config_from_file(&mut self, file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mut file = File::open(file_path)?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        let config: ThresholdConfig = serde_json::from_str(&contents)?;
+        self.threshold_rules = config.rules;
         Ok(())
     }
 
-    pub fn test_performance_regression(&mut self, 
-                                     operation: &str, 
+    /// Looks up the threshold for `operation`, first by exact match and
+    /// then against `threshold_rules` in order. A rule match is cached back
+    /// into `thresholds` so new operations inherit sensible defaults
+    /// without re-scanning the rule list on every call.
+    fn resolve_threshold(&mut self, operation: &str) -> Option<RegressionThreshold> {
+        if let Some(threshold) = self.thresholds.get(operation) {
+            return Some(threshold.clone());
+        }
+
+        let rule = self.threshold_rules.iter().find(|rule| glob_match(&rule.pattern, operation))?;
+        let threshold = RegressionThreshold {
+            operation: operation.to_string(),
+            max_duration_increase_percent: rule.max_duration_increase_percent,
+            max_memory_increase_percent: rule.max_memory_increase_percent,
+            min_sample_count: rule.min_sample_count,
+            alert_threshold_percent: rule.alert_threshold_percent,
+            actions: rule.actions.clone(),
+        };
+        self.thresholds.insert(operation.to_string(), threshold.clone());
+        Some(threshold)
+    }
+
+    pub fn test_performance_regression(&mut self,
+                                     operation: &str,
                                      current_measurements: &[Duration],
                                      current_memory_usage: usize) -> Vec<RegressionAlert> {
         let mut new_alerts = Vec::new();
-        
+
+        let threshold = match self.resolve_threshold(operation) {
+            Some(threshold) => threshold,
+            None => return new_alerts,
+        };
+
         if let Some(baseline) = self.baselines.get(operation) {
-            if let Some(threshold) = self.thresholds.get(operation) {
-                if current_measurements.len() < threshold.min_sample_count {
-                    return new_alerts;
-                }
+            if current_measurements.len() < threshold.min_sample_count {
+                return new_alerts;
+            }
+
+            let current_median = self.calculate_percentile(current_measurements, 0.5);
+            let median_increase = self.calculate_percentage_increase(
+                baseline.median_duration_nanos as f64,
+                current_median.as_nanos() as f64,
+            );
 
+            // Fast guard: skip the bootstrap resample and Welch's t-test
+            // entirely unless the typical-case (median) duration already
+            // moved enough to be worth the expensive significance test.
+            // Cheap to evaluate and catches the common non-regression case
+            // without 10,000 resamples.
+            if median_increase > threshold.alert_threshold_percent {
                 let current_mean = self.calculate_mean_duration(current_measurements);
-                let current_p95 = self.calculate_percentile(current_measurements, 0.95);
-                
+
+                let (ci_low, ci_high) = bootstrap_mean_difference_ci(
+                    &baseline.sample_durations_nanos,
+                    current_measurements,
+                    10_000,
+                );
                 let duration_increase = self.calculate_percentage_increase(
                     baseline.mean_duration_nanos as f64,
                     current_mean.as_nanos() as f64
                 );
+                let p_value = welch_t_test_p_value(&baseline.sample_durations_nanos, current_measurements);
 
-                if duration_increase > threshold.alert_threshold_percent {
+                // Significance test: the bootstrap CI for the mean
+                // difference must lie entirely above zero and the
+                // two-tailed Welch p-value must clear 0.05 (|t| > ~2.0 for
+                // the sample sizes this gate runs at), so a regression
+                // only fires once it's both positive and unlikely to be
+                // measurement noise.
+                if ci_low > 0.0 && duration_increase > threshold.alert_threshold_percent && p_value < 0.05 {
                     let severity = self.determine_alert_severity(duration_increase);
                     new_alerts.push(RegressionAlert {
                         operation: operation.to_string(),
@@ -175,26 +384,30 @@ impl PerformanceRegressionTester {
                         increase_percent: duration_increase,
                         severity,
                         timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+                        confidence_interval_nanos: Some((ci_low, ci_high)),
+                        p_value: Some(p_value),
                     });
                 }
+            }
 
-                let memory_increase = self.calculate_percentage_increase(
-                    baseline.memory_usage_bytes as f64,
-                    current_memory_usage as f64
-                );
+            let memory_increase = self.calculate_percentage_increase(
+                baseline.memory_usage_bytes as f64,
+                current_memory_usage as f64
+            );
 
-                if memory_increase > threshold.max_memory_increase_percent {
-                    let severity = self.determine_alert_severity(memory_increase);
-                    new_alerts.push(RegressionAlert {
-                        operation: operation.to_string(),
-                        alert_type: RegressionAlertType::MemoryRegression,
-                        current_value: current_memory_usage as f64,
-                        baseline_value: baseline.memory_usage_bytes as f64,
-                        increase_percent: memory_increase,
-                        severity,
-                        timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                    });
-                }
+            if memory_increase > threshold.max_memory_increase_percent {
+                let severity = self.determine_alert_severity(memory_increase);
+                new_alerts.push(RegressionAlert {
+                    operation: operation.to_string(),
+                    alert_type: RegressionAlertType::MemoryRegression,
+                    current_value: current_memory_usage as f64,
+                    baseline_value: baseline.memory_usage_bytes as f64,
+                    increase_percent: memory_increase,
+                    severity,
+                    timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+                    confidence_interval_nanos: None,
+                    p_value: None,
+                });
             }
         }
 
@@ -202,6 +415,37 @@ impl PerformanceRegressionTester {
         new_alerts
     }
 
+    /// Runs `sample` repeatedly, discarding each result, until the
+    /// coefficient of variation of the trailing `warmup.min_warmup_iterations`
+    /// samples drops below `warmup.target_cv` (or `max_warmup_iterations` is
+    /// hit), then collects and returns `sample_count` real measurements.
+    pub fn warm_up_and_measure<F>(
+        &self,
+        warmup: &WarmUpConfig,
+        sample_count: usize,
+        mut sample: F,
+    ) -> Vec<Duration>
+    where
+        F: FnMut() -> Duration,
+    {
+        let window_size = warmup.min_warmup_iterations.max(1);
+        let mut window: Vec<f64> = Vec::with_capacity(window_size);
+
+        for _ in 0..warmup.max_warmup_iterations {
+            let elapsed = sample();
+            window.push(elapsed.as_nanos() as f64);
+            if window.len() > window_size {
+                window.remove(0);
+            }
+
+            if window.len() == window_size && coefficient_of_variation(&window) < warmup.target_cv {
+                break;
+            }
+        }
+
+        (0..sample_count).map(|_| sample()).collect()
+    }
+
     fn calculate_mean_duration(&self, measurements: &[Duration]) -> Duration {
         if measurements.is_empty() {
             return Duration::from_nanos(0);
@@ -214,10 +458,12 @@ impl PerformanceRegressionTester {
         if measurements.is_empty() {
             return Duration::from_nanos(0);
         }
-        let mut sorted = measurements.to_vec();
-        sorted.sort();
-        let index = ((sorted.len() as f64 * percentile) as usize).min(sorted.len() - 1);
-        sorted[index]
+        let sorted_nanos: Vec<u64> = {
+            let mut sorted = measurements.to_vec();
+            sorted.sort();
+            sorted.iter().map(|d| d.as_nanos() as u64).collect()
+        };
+        Duration::from_nanos(interpolated_percentile(&sorted_nanos, percentile).round() as u64)
     }
 
     fn calculate_percentage_increase(&self, baseline: f64, current: f64) -> f64 {
@@ -262,6 +508,12 @@ impl PerformanceRegressionTester {
                 report.push_str(&format!("- Performance Increase: {:.1}%\n", alert.increase_percent));
                 report.push_str(&format!("- Current Value: {:.0}\n", alert.current_value));
                 report.push_str(&format!("- Baseline Value: {:.0}\n", alert.baseline_value));
+                if let Some((ci_low, ci_high)) = alert.confidence_interval_nanos {
+                    report.push_str(&format!("- 95% CI for Mean Difference: [{:.0}, {:.0}] ns\n", ci_low, ci_high));
+                }
+                if let Some(p_value) = alert.p_value {
+                    report.push_str(&format!("- Welch's t-test p-value: {:.4}\n", p_value));
+                }
                 report.push_str(&format!("- Timestamp: {}\n\n", alert.timestamp));
             }
         } else {
@@ -309,23 +561,55 @@ impl PerformanceRegressionTester {
         Ok(())
     }
 
+    /// Serializes every known baseline and every alert raised so far as
+    /// InfluxDB line protocol and hands the batch to `sink`, so a run
+    /// accumulates queryable history instead of overwriting one local file.
+    pub fn export_metrics(&self, sink: &dyn MetricsSink) -> Result<(), Box<dyn std::error::Error>> {
+        let timestamp_nanos = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0);
+        let mut lines = Vec::new();
+
+        for baseline in self.baselines.values() {
+            lines.push(baseline_line_protocol(baseline, timestamp_nanos));
+        }
+        for alert in &self.alerts {
+            let algorithm = self
+                .baselines
+                .get(&alert.operation)
+                .map(|b| b.algorithm.as_str())
+                .unwrap_or("unknown");
+            lines.push(alert_line_protocol(alert, algorithm, timestamp_nanos));
+        }
+
+        sink.write(&lines)
+    }
+
+    /// Returns the action configured for `alert`'s operation/severity pair,
+    /// falling back to `default_action_for_severity` when no rule or
+    /// explicit threshold binding covers it.
+    fn action_for(&self, alert: &RegressionAlert) -> AlertAction {
+        self.thresholds
+            .get(&alert.operation)
+            .and_then(|threshold| threshold.actions.get(&alert.severity).copied())
+            .unwrap_or_else(|| default_action_for_severity(&alert.severity))
+    }
+
     pub fn trigger_automated_rollback(&self) -> bool {
-        let emergency_alerts: Vec<_> = self.alerts.iter()
-            .filter(|a| a.severity == AlertSeverity::Emergency)
+        let rollback_alerts: Vec<_> = self.alerts.iter()
+            .filter(|a| self.action_for(a) == AlertAction::Rollback)
             .collect();
 
-        if !emergency_alerts.is_empty() {
+        if !rollback_alerts.is_empty() {
             if let Ok(mut file) = OpenOptions::new()
                 .create(true)
                 .append(true)
                 .open("/tmp/pqc_performance/regression/automated_rollback.log") {
-                writeln!(file, "AUTOMATED ROLLBACK TRIGGERED: {} emergency alerts detected at {}", 
-                    emergency_alerts.len(), 
+                writeln!(file, "AUTOMATED ROLLBACK TRIGGERED: {} alerts bound to the rollback action at {}",
+                    rollback_alerts.len(),
                     chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")).ok();
-                
-                for alert in emergency_alerts {
-                    writeln!(file, "  - {}: {:.1}% performance degradation", 
-                        alert.operation, alert.increase_percent).ok();
+
+                for alert in rollback_alerts {
+                    writeln!(file, "  - {} ({:?}): {:.1}% performance degradation",
+                        alert.operation, alert.severity, alert.increase_percent).ok();
                 }
             }
             return true;
@@ -334,19 +618,319 @@ impl PerformanceRegressionTester {
     }
 }
 
+/// Resamples `samples` with replacement and returns the mean of the
+/// resampled set, used as the inner step of the bootstrap below.
+fn resample_mean(rng: &mut impl Rng, samples: &[f64]) -> f64 {
+    let n = samples.len();
+    let sum: f64 = (0..n).map(|_| samples[rng.gen_range(0..n)]).sum();
+    sum / n as f64
+}
+
+fn percentile_of_sorted(sorted: &[f64], percentile: f64) -> f64 {
+    let index = (((sorted.len() - 1) as f64) * percentile).round() as usize;
+    sorted[index]
+}
+
+/// Linearly interpolates between the two adjacent order statistics that
+/// straddle `percentile`'s rank, rather than truncating to the nearest
+/// index. `sorted_nanos` must already be sorted ascending.
+fn interpolated_percentile(sorted_nanos: &[u64], percentile: f64) -> f64 {
+    if sorted_nanos.is_empty() {
+        return 0.0;
+    }
+    let rank = percentile * (sorted_nanos.len() - 1) as f64;
+    let floor = rank.floor() as usize;
+    let ceil = rank.ceil() as usize;
+    if floor == ceil {
+        return sorted_nanos[floor] as f64;
+    }
+    let frac = rank - floor as f64;
+    sorted_nanos[floor] as f64 * (1.0 - frac) + sorted_nanos[ceil] as f64 * frac
+}
+
+/// Tukey fences `(Q1 - 3*IQR, Q3 + 3*IQR)` for severe-outlier detection.
+/// The 3x multiplier (rather than the conventional 1.5x for mild outliers)
+/// means only measurements far enough out to be a stalled run or a GC
+/// pause get dropped, not just the tails of ordinary variance.
+/// `sorted_nanos` must already be sorted ascending.
+fn tukey_fences(sorted_nanos: &[u64]) -> (f64, f64) {
+    let q1 = interpolated_percentile(sorted_nanos, 0.25);
+    let q3 = interpolated_percentile(sorted_nanos, 0.75);
+    let iqr = q3 - q1;
+    (q1 - 3.0 * iqr, q3 + 3.0 * iqr)
+}
+
+/// Computes a 95% bootstrap confidence interval for the difference in
+/// means (current - baseline) by resampling both sample sets with
+/// replacement `iterations` times and taking the 2.5th/97.5th percentiles
+/// of the resampled mean differences.
+fn bootstrap_mean_difference_ci(
+    baseline_samples_nanos: &[u64],
+    current_measurements: &[Duration],
+    iterations: usize,
+) -> (f64, f64) {
+    let baseline_samples: Vec<f64> = baseline_samples_nanos.iter().map(|&n| n as f64).collect();
+    let current_samples: Vec<f64> = current_measurements.iter().map(|d| d.as_nanos() as f64).collect();
+
+    if baseline_samples.is_empty() || current_samples.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut diffs: Vec<f64> = (0..iterations)
+        .map(|_| resample_mean(&mut rng, &current_samples) - resample_mean(&mut rng, &baseline_samples))
+        .collect();
+    diffs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    (percentile_of_sorted(&diffs, 0.025), percentile_of_sorted(&diffs, 0.975))
+}
+
+fn mean_variance(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+/// Coefficient of variation (stddev/mean) of a warm-up window. Treated as
+/// not-yet-converged (infinite) until there are at least two samples to
+/// compute a variance from.
+fn coefficient_of_variation(samples: &[f64]) -> f64 {
+    if samples.len() < 2 {
+        return f64::INFINITY;
+    }
+    let (mean, variance) = mean_variance(samples);
+    if mean == 0.0 {
+        return 0.0;
+    }
+    variance.sqrt() / mean
+}
+
+/// Welch's two-sample t-test for unequal variances: computes
+/// `t = (mean_b - mean_a) / sqrt(var_a/n_a + var_b/n_b)` with the
+/// Welch-Satterthwaite degrees of freedom, and returns the corresponding
+/// two-tailed p-value.
+fn welch_t_test_p_value(baseline_samples_nanos: &[u64], current_measurements: &[Duration]) -> f64 {
+    let baseline_samples: Vec<f64> = baseline_samples_nanos.iter().map(|&n| n as f64).collect();
+    let current_samples: Vec<f64> = current_measurements.iter().map(|d| d.as_nanos() as f64).collect();
+
+    if baseline_samples.len() < 2 || current_samples.len() < 2 {
+        return 1.0;
+    }
+
+    let (mean_a, var_a) = mean_variance(&baseline_samples);
+    let (mean_b, var_b) = mean_variance(&current_samples);
+    let n_a = baseline_samples.len() as f64;
+    let n_b = current_samples.len() as f64;
+
+    let se_a = var_a / n_a;
+    let se_b = var_b / n_b;
+    let standard_error = (se_a + se_b).sqrt();
+    if standard_error == 0.0 {
+        return if mean_a == mean_b { 1.0 } else { 0.0 };
+    }
+
+    let t = (mean_b - mean_a) / standard_error;
+    let degrees_of_freedom = (se_a + se_b).powi(2) / (se_a.powi(2) / (n_a - 1.0) + se_b.powi(2) / (n_b - 1.0));
+
+    student_t_two_tailed_p_value(t.abs(), degrees_of_freedom)
+}
+
+/// Two-tailed p-value for Student's t-distribution, via its relationship
+/// to the regularized incomplete beta function:
+/// `P(|T| > t) = I_x(df/2, 1/2)` where `x = df / (df + t^2)`.
+fn student_t_two_tailed_p_value(t_abs: f64, degrees_of_freedom: f64) -> f64 {
+    let x = degrees_of_freedom / (degrees_of_freedom + t_abs * t_abs);
+    regularized_incomplete_beta(x, degrees_of_freedom / 2.0, 0.5)
+}
+
+/// Log-gamma via the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let mut y = x;
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let mut series = 1.000000000190015;
+    for coefficient in COEFFICIENTS {
+        y += 1.0;
+        series += coefficient / y;
+    }
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+/// Regularized incomplete beta function `I_x(a, b)`, evaluated via its
+/// continued-fraction expansion (Numerical Recipes' `betacf`).
+fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let ln_beta = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    let front = ln_beta.exp();
+
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * incomplete_beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - front * incomplete_beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+fn incomplete_beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 3.0e-12;
+    const TINY: f64 = 1.0e-30;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=MAX_ITERATIONS {
+        let m_f64 = m as f64;
+        let m2 = 2.0 * m_f64;
+
+        let aa = m_f64 * (b - m_f64) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+
+        let aa = -(a + m_f64) * (qab + m_f64) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    h
+}
+
+/// A destination for exported performance metrics. Lets CI pick between
+/// posting straight to a time-series database and appending to a local
+/// file, without `PerformanceRegressionTester` needing to know which.
+pub trait MetricsSink {
+    fn write(&self, lines: &[String]) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+/// Posts InfluxDB line protocol records to an InfluxDB `/write` endpoint.
+pub struct InfluxDbSink {
+    pub write_url: String,
+}
+
+impl InfluxDbSink {
+    pub fn new(write_url: impl Into<String>) -> Self {
+        Self { write_url: write_url.into() }
+    }
+}
+
+impl MetricsSink for InfluxDbSink {
+    fn write(&self, lines: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+        if lines.is_empty() {
+            return Ok(());
+        }
+        let body = lines.join("\n");
+        ureq::post(&self.write_url).send_string(&body)?;
+        Ok(())
+    }
+}
+
+/// Appends InfluxDB line protocol records to a local file, one record per
+/// line -- useful where a CI runner has no network path to a real InfluxDB
+/// instance but still wants to accumulate history across runs.
+pub struct FileMetricsSink {
+    pub path: String,
+}
+
+impl FileMetricsSink {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl MetricsSink for FileMetricsSink {
+    fn write(&self, lines: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        for line in lines {
+            writeln!(file, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+fn baseline_line_protocol(baseline: &PerformanceBaseline, timestamp_nanos: i64) -> String {
+    format!(
+        "pqc_perf,operation={},algorithm={} mean_nanos={}i,p95_nanos={}i,p99_nanos={}i,memory_bytes={}i {}",
+        baseline.operation,
+        baseline.algorithm,
+        baseline.mean_duration_nanos,
+        baseline.p95_duration_nanos,
+        baseline.p99_duration_nanos,
+        baseline.memory_usage_bytes,
+        timestamp_nanos,
+    )
+}
+
+fn alert_line_protocol(alert: &RegressionAlert, algorithm: &str, timestamp_nanos: i64) -> String {
+    format!(
+        "pqc_perf,operation={},algorithm={},severity={} mean_nanos={}i,increase_percent={} {}",
+        alert.operation,
+        algorithm,
+        format!("{:?}", alert.severity).to_lowercase(),
+        alert.current_value as u64,
+        alert.increase_percent,
+        timestamp_nanos,
+    )
+}
+
 pub fn run_regression_tests() -> Result<(), Box<dyn std::error::Error>> {
     println!("=== WBS 2.5.4: Running Performance Regression Tests ===");
     
     let mut tester = PerformanceRegressionTester::new();
     
-    if let Err(e) = tester.load_baselines_from_file("/tmp/pqc_performance/baselines/mlkem_baseline_measurements.txt") {
+    if let Err(e) = tester.load_baselines_from_file("/tmp/pqc_performance/baselines/mlkem_baselines.json") {
         println!("Warning: Could not load ML-KEM baselines: {}", e);
     }
-    
-    if let Err(e) = tester.load_baselines_from_file("/tmp/pqc_performance/baselines/mldsa_baseline_measurements.txt") {
+
+    if let Err(e) = tester.load_baselines_from_file("/tmp/pqc_performance/baselines/mldsa_baselines.json") {
         println!("Warning: Could not load ML-DSA baselines: {}", e);
     }
 
+    if let Err(e) = tester.load_threshold_config_from_file("/tmp/pqc_performance/config/thresholds.json") {
+        println!("Warning: Could not load threshold config, using built-in defaults: {}", e);
+    }
+
     let mock_measurements = vec![
         Duration::from_millis(55), // Slightly slower than 50ms baseline
         Duration::from_millis(52),
@@ -373,6 +957,10 @@ pub fn run_regression_tests() -> Result<(), Box<dyn std::error::Error>> {
     tester.export_regression_report()?;
     println!("Regression test report saved to: /tmp/pqc_performance/regression/regression_test_report.md");
 
+    if let Err(e) = tester.save_baselines_to_file("/tmp/pqc_performance/baselines/mlkem_baselines.json") {
+        println!("Warning: Could not persist baselines: {}", e);
+    }
+
     Ok(())
 }
 
@@ -383,43 +971,339 @@ mod tests {
     #[test]
     fn test_regression_detection() {
         let mut tester = PerformanceRegressionTester::new();
-        
+
+        tester.thresholds.insert("test_op".to_string(), RegressionThreshold {
+            operation: "test_op".to_string(),
+            max_duration_increase_percent: 30.0,
+            max_memory_increase_percent: 50.0,
+            min_sample_count: 100,
+            alert_threshold_percent: 10.0,
+            actions: HashMap::new(),
+        });
+
         tester.baselines.insert("test_op".to_string(), PerformanceBaseline {
             operation: "test_op".to_string(),
             algorithm: "TEST".to_string(),
             mean_duration_nanos: 50_000_000, // 50ms
+            median_duration_nanos: 50_000_000,
             p95_duration_nanos: 75_000_000,
             p99_duration_nanos: 100_000_000,
             memory_usage_bytes: 1000,
             sample_count: 100,
             timestamp: "2025-06-28".to_string(),
+            sample_durations_nanos: vec![50_000_000; 100],
         });
 
         let degraded_measurements = vec![Duration::from_millis(100); 150];
         let alerts = tester.test_performance_regression("test_op", &degraded_measurements, 1000);
-        
+
         assert!(!alerts.is_empty());
         assert_eq!(alerts[0].severity, AlertSeverity::Emergency);
+        let (ci_low, _) = alerts[0].confidence_interval_nanos.unwrap();
+        assert!(ci_low > 0.0);
+        assert!(alerts[0].p_value.unwrap() < 0.05);
     }
 
     #[test]
     fn test_no_regression_within_threshold() {
         let mut tester = PerformanceRegressionTester::new();
-        
+
+        tester.thresholds.insert("test_op".to_string(), RegressionThreshold {
+            operation: "test_op".to_string(),
+            max_duration_increase_percent: 30.0,
+            max_memory_increase_percent: 50.0,
+            min_sample_count: 100,
+            alert_threshold_percent: 10.0,
+            actions: HashMap::new(),
+        });
+
         tester.baselines.insert("test_op".to_string(), PerformanceBaseline {
             operation: "test_op".to_string(),
             algorithm: "TEST".to_string(),
             mean_duration_nanos: 50_000_000,
+            median_duration_nanos: 50_000_000,
             p95_duration_nanos: 75_000_000,
             p99_duration_nanos: 100_000_000,
             memory_usage_bytes: 1000,
             sample_count: 100,
             timestamp: "2025-06-28".to_string(),
+            sample_durations_nanos: vec![50_000_000; 100],
         });
 
         let acceptable_measurements = vec![Duration::from_millis(52); 150];
         let alerts = tester.test_performance_regression("test_op", &acceptable_measurements, 1000);
-        
+
+        assert!(alerts.is_empty());
+    }
+
+    #[test]
+    fn test_regression_not_flagged_when_ci_straddles_zero() {
+        let mut tester = PerformanceRegressionTester::new();
+
+        tester.thresholds.insert("test_op".to_string(), RegressionThreshold {
+            operation: "test_op".to_string(),
+            max_duration_increase_percent: 30.0,
+            max_memory_increase_percent: 50.0,
+            min_sample_count: 100,
+            alert_threshold_percent: 10.0,
+            actions: HashMap::new(),
+        });
+
+        // Baseline has high variance, so a handful of slightly-slower
+        // current measurements shouldn't be distinguishable from noise.
+        let mut noisy_baseline = vec![40_000_000u64; 50];
+        noisy_baseline.extend(vec![70_000_000u64; 50]);
+
+        tester.baselines.insert("test_op".to_string(), PerformanceBaseline {
+            operation: "test_op".to_string(),
+            algorithm: "TEST".to_string(),
+            mean_duration_nanos: 50_000_000,
+            median_duration_nanos: 50_000_000,
+            p95_duration_nanos: 75_000_000,
+            p99_duration_nanos: 100_000_000,
+            memory_usage_bytes: 1000,
+            sample_count: 100,
+            timestamp: "2025-06-28".to_string(),
+            sample_durations_nanos: noisy_baseline,
+        });
+
+        let current_measurements = vec![Duration::from_millis(56); 150];
+        let alerts = tester.test_performance_regression("test_op", &current_measurements, 1000);
+
+        assert!(alerts.is_empty());
+    }
+
+    #[test]
+    fn test_warm_up_stops_once_converged_and_returns_requested_sample_count() {
+        let tester = PerformanceRegressionTester::new();
+        let warmup = WarmUpConfig {
+            min_warmup_iterations: 5,
+            target_cv: 0.01,
+            max_warmup_iterations: 1000,
+        };
+
+        let measurements =
+            tester.warm_up_and_measure(&warmup, 20, || Duration::from_nanos(1_000_000));
+
+        assert_eq!(measurements.len(), 20);
+    }
+
+    #[test]
+    fn test_warm_up_respects_max_iterations_when_never_converging() {
+        let tester = PerformanceRegressionTester::new();
+        let warmup = WarmUpConfig {
+            min_warmup_iterations: 5,
+            target_cv: 0.0001,
+            max_warmup_iterations: 10,
+        };
+
+        let mut call_count = 0usize;
+        let measurements = tester.warm_up_and_measure(&warmup, 3, || {
+            call_count += 1;
+            // Alternates wildly, so the CV never drops below target_cv.
+            if call_count % 2 == 0 {
+                Duration::from_nanos(1_000_000)
+            } else {
+                Duration::from_nanos(2_000_000)
+            }
+        });
+
+        assert_eq!(measurements.len(), 3);
+        assert_eq!(call_count, 13); // 10 warm-up calls + 3 real measurements
+    }
+
+    #[test]
+    fn test_export_metrics_writes_line_protocol_via_file_sink() {
+        let mut tester = PerformanceRegressionTester::new();
+        tester.baselines.insert("test_op".to_string(), PerformanceBaseline {
+            operation: "test_op".to_string(),
+            algorithm: "TEST".to_string(),
+            mean_duration_nanos: 50_000_000,
+            median_duration_nanos: 50_000_000,
+            p95_duration_nanos: 75_000_000,
+            p99_duration_nanos: 100_000_000,
+            memory_usage_bytes: 1000,
+            sample_count: 100,
+            timestamp: "2025-06-28".to_string(),
+            sample_durations_nanos: vec![50_000_000; 100],
+        });
+
+        let path = format!(
+            "/tmp/pqc_perf_metrics_test_{:?}.line",
+            std::thread::current().id()
+        );
+        let sink = FileMetricsSink::new(path.clone());
+        tester.export_metrics(&sink).unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("pqc_perf,operation=test_op,algorithm=TEST"));
+        assert!(contents.contains("mean_nanos=50000000i"));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_glob_match_supports_prefix_suffix_and_exact_patterns() {
+        assert!(glob_match("mlkem_*", "mlkem_keygen"));
+        assert!(!glob_match("mlkem_*", "mldsa_sign"));
+        assert!(glob_match("*_verify", "mldsa_verify"));
+        assert!(glob_match("mlkem_keygen", "mlkem_keygen"));
+        assert!(!glob_match("mlkem_keygen", "mlkem_encap"));
+    }
+
+    #[test]
+    fn test_unmatched_operation_without_rule_yields_no_threshold() {
+        let mut tester = PerformanceRegressionTester::new();
+        assert!(tester.resolve_threshold("totally_unknown_op").is_none());
+    }
+
+    #[test]
+    fn test_threshold_rule_matches_new_operation_and_is_cached() {
+        let mut tester = PerformanceRegressionTester::new();
+        tester.threshold_rules.push(ThresholdRule {
+            pattern: "sphincs_*".to_string(),
+            max_duration_increase_percent: 40.0,
+            max_memory_increase_percent: 60.0,
+            min_sample_count: 50,
+            alert_threshold_percent: 20.0,
+            actions: HashMap::new(),
+        });
+
+        let threshold = tester.resolve_threshold("sphincs_sign").unwrap();
+        assert_eq!(threshold.min_sample_count, 50);
+        assert_eq!(threshold.alert_threshold_percent, 20.0);
+
+        // The match is memoized into `thresholds`, so a second lookup
+        // doesn't need to re-scan `threshold_rules`.
+        assert!(tester.thresholds.contains_key("sphincs_sign"));
+    }
+
+    #[test]
+    fn test_action_binding_controls_rollback_independent_of_severity() {
+        let mut tester = PerformanceRegressionTester::new();
+        let mut actions = HashMap::new();
+        actions.insert(AlertSeverity::Critical, AlertAction::Rollback);
+        actions.insert(AlertSeverity::Emergency, AlertAction::Notify);
+
+        tester.thresholds.insert("test_op".to_string(), RegressionThreshold {
+            operation: "test_op".to_string(),
+            max_duration_increase_percent: 30.0,
+            max_memory_increase_percent: 50.0,
+            min_sample_count: 100,
+            alert_threshold_percent: 10.0,
+            actions,
+        });
+
+        tester.baselines.insert("test_op".to_string(), PerformanceBaseline {
+            operation: "test_op".to_string(),
+            algorithm: "TEST".to_string(),
+            mean_duration_nanos: 50_000_000,
+            median_duration_nanos: 50_000_000,
+            p95_duration_nanos: 75_000_000,
+            p99_duration_nanos: 100_000_000,
+            memory_usage_bytes: 1000,
+            sample_count: 100,
+            timestamp: "2025-06-28".to_string(),
+            sample_durations_nanos: vec![50_000_000; 100],
+        });
+
+        // 60% slower clears the >50% Emergency bucket, but this operation's
+        // config reroutes Emergency to Notify and Critical to Rollback.
+        let degraded_measurements = vec![Duration::from_millis(80); 150];
+        tester.test_performance_regression("test_op", &degraded_measurements, 1000);
+
+        assert!(!tester.trigger_automated_rollback());
+    }
+
+    #[test]
+    fn test_interpolated_percentile_blends_adjacent_order_statistics() {
+        let sorted = vec![10u64, 20, 30, 40, 50];
+        // rank = 0.5 * 4 = 2.0, lands exactly on index 2.
+        assert_eq!(interpolated_percentile(&sorted, 0.5), 30.0);
+        // rank = 0.25 * 4 = 1.0, lands exactly on index 1.
+        assert_eq!(interpolated_percentile(&sorted, 0.25), 20.0);
+        // rank = 0.9 * 4 = 3.6, blends indices 3 and 4.
+        assert_eq!(interpolated_percentile(&sorted, 0.9), 46.0);
+    }
+
+    #[test]
+    fn test_tukey_fences_flag_a_severe_outlier() {
+        let mut sorted: Vec<u64> = vec![48_000_000, 49_000_000, 50_000_000, 51_000_000, 52_000_000];
+        sorted.push(500_000_000); // a 10x stall
+        sorted.sort();
+
+        let (low, high) = tukey_fences(&sorted);
+        assert!(500_000_000.0 > high, "the stalled sample should be above the high fence");
+        assert!(48_000_000.0 >= low, "ordinary samples should stay inside the fences");
+    }
+
+    #[test]
+    fn test_record_baseline_drops_outliers_before_summarizing() {
+        let mut tester = PerformanceRegressionTester::new();
+        let mut measurements: Vec<Duration> = vec![Duration::from_millis(50); 19];
+        measurements.push(Duration::from_secs(5)); // severe outlier
+
+        tester.record_baseline("test_op", "TEST", &measurements, 2048);
+
+        let baseline = tester.baselines.get("test_op").unwrap();
+        assert_eq!(baseline.sample_count, 19);
+        assert_eq!(baseline.mean_duration_nanos, 50_000_000);
+    }
+
+    #[test]
+    fn test_baselines_round_trip_through_save_and_load() {
+        let mut tester = PerformanceRegressionTester::new();
+        tester.record_baseline("test_op", "TEST", &vec![Duration::from_millis(50); 20], 2048);
+
+        let path = format!(
+            "/tmp/pqc_perf_baselines_test_{:?}.json",
+            std::thread::current().id()
+        );
+        tester.save_baselines_to_file(&path).unwrap();
+
+        let mut reloaded = PerformanceRegressionTester::new();
+        reloaded.load_baselines_from_file(&path).unwrap();
+
+        let baseline = reloaded.baselines.get("test_op").unwrap();
+        assert_eq!(baseline.algorithm, "TEST");
+        assert_eq!(baseline.mean_duration_nanos, 50_000_000);
+        assert_eq!(baseline.sample_count, 20);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_median_fast_guard_skips_significance_test_below_threshold() {
+        let mut tester = PerformanceRegressionTester::new();
+
+        tester.thresholds.insert("test_op".to_string(), RegressionThreshold {
+            operation: "test_op".to_string(),
+            max_duration_increase_percent: 30.0,
+            max_memory_increase_percent: 50.0,
+            min_sample_count: 100,
+            alert_threshold_percent: 10.0,
+            actions: HashMap::new(),
+        });
+
+        tester.baselines.insert("test_op".to_string(), PerformanceBaseline {
+            operation: "test_op".to_string(),
+            algorithm: "TEST".to_string(),
+            mean_duration_nanos: 50_000_000,
+            median_duration_nanos: 50_000_000,
+            p95_duration_nanos: 75_000_000,
+            p99_duration_nanos: 100_000_000,
+            memory_usage_bytes: 1000,
+            sample_count: 100,
+            timestamp: "2025-06-28".to_string(),
+            sample_durations_nanos: vec![50_000_000; 100],
+        });
+
+        // A 5% median increase doesn't clear the 10% alert threshold, so
+        // the fast guard should return before the bootstrap/Welch test
+        // ever runs, regardless of how tight the underlying samples are.
+        let current_measurements = vec![Duration::from_millis(52); 150];
+        let alerts = tester.test_performance_regression("test_op", &current_measurements, 1000);
+
         assert!(alerts.is_empty());
     }
 }